@@ -14,3 +14,20 @@ pub fn get_logs_directory() -> PathBuf {
 pub fn get_storage_directory() -> PathBuf {
     get_project_directory().join("storage")
 }
+
+pub fn get_exchange_rates_file() -> PathBuf {
+    get_project_directory().join("exchange_rates.json")
+}
+
+pub fn get_trash_settings_file() -> PathBuf {
+    get_project_directory().join("trash_settings.json")
+}
+
+pub fn get_zone_settings_file() -> PathBuf {
+    get_project_directory().join("zone_settings.json")
+}
+
+#[cfg(feature = "sqlite")]
+pub fn get_database_file() -> PathBuf {
+    get_project_directory().join("storage.sqlite")
+}
@@ -10,11 +10,63 @@ mod dirs;
 mod keyboard;
 mod logs;
 
-async fn create_stores() -> StoreType {
+#[cfg(not(feature = "sqlite"))]
+async fn create_stores() -> (StoreType, LoadReport) {
     let path = dirs::get_storage_directory();
-    JsonThreadSafeGeneralStore::new_json(path)
+    let stores = JsonThreadSafeGeneralStore::new_json(path)
         .await
-        .expect("Could not create store")
+        .expect("Could not create store");
+    let load_report = stores.load_report().await;
+    (stores, load_report)
+}
+
+#[cfg(feature = "sqlite")]
+async fn create_stores() -> (StoreType, LoadReport) {
+    let stores = SqliteThreadSafeGeneralStore::new_sqlite(dirs::get_database_file())
+        .await
+        .expect("Could not create store");
+    // Anything saved before switching to SQLite is copied across on first launch
+    let imported = stores
+        .import_json(dirs::get_storage_directory())
+        .await
+        .expect("Could not import existing storage");
+    (stores, imported.load_report)
+}
+
+async fn load_exchange_rates() -> ExchangeRates {
+    let path = dirs::get_exchange_rates_file();
+    // Without rates only compensation in the default base currency can be compared
+    ExchangeRates::load(path).await.unwrap_or_else(|e| {
+        tracing::error!("Could not load exchange rates: {e}");
+        ExchangeRates::default()
+    })
+}
+
+async fn load_trash_settings() -> TrashSettings {
+    let path = dirs::get_trash_settings_file();
+    // The default never purges, so nothing is lost because the file couldn't be read
+    TrashSettings::load(path).await.unwrap_or_else(|e| {
+        tracing::error!("Could not load trash settings: {e}");
+        TrashSettings::default()
+    })
+}
+
+async fn load_zone_settings() -> ZoneSettings {
+    let path = dirs::get_zone_settings_file();
+    // Times can still be shown in the system's zone if the file can't be read
+    ZoneSettings::load(path).await.unwrap_or_else(|e| {
+        tracing::error!("Could not load zone settings: {e}");
+        ZoneSettings::default()
+    })
+}
+
+async fn auto_purge(stores: &StoreType, trash_settings: &TrashSettings) {
+    if let Some(cutoff) = trash_settings.purge_cutoff(Timestamp::now()) {
+        // Not being able to empty the trash shouldn't stop the app from starting
+        if let Err(e) = stores.purge_deleted_before(cutoff).await {
+            tracing::error!("Could not purge old items from the trash: {e}");
+        }
+    }
 }
 
 async fn create_log_fetcher() -> JsonLogFetcher {
@@ -28,11 +80,25 @@ fn main() {
     logs::configure_logging();
 
     let rt = tokio::runtime::Runtime::new().unwrap();
-    let (stores, log_fetcher) = rt.block_on(async { join!(create_stores(), create_log_fetcher()) });
+    let ((stores, load_report), log_fetcher, exchange_rates, trash_settings, zone_settings) = rt
+        .block_on(async {
+            join!(
+                create_stores(),
+                create_log_fetcher(),
+                load_exchange_rates(),
+                load_trash_settings(),
+                load_zone_settings()
+            )
+        });
+    rt.block_on(auto_purge(&stores, &trash_settings));
 
     LaunchBuilder::new()
         .with_context(stores)
+        .with_context(load_report)
         .with_context(log_fetcher)
+        .with_context(exchange_rates)
+        .with_context(trash_settings)
+        .with_context(zone_settings)
         .with_cfg(config::desktop_config())
         .launch(App);
 }
@@ -0,0 +1,239 @@
+//! Compares the indexed `StubStore` with the `Vec` based `StubStore` it replaced.
+//!
+//! Run with `cargo bench -p storage --features field_names`.
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::collections::HashMap;
+use storage::prelude::*;
+use tokio::runtime::Runtime;
+use uuid::Uuid;
+
+const ROLES: usize = 100;
+const SIZES: [usize; 3] = [100, 1_000, 5_000];
+
+/// `StubStore` as it was before it was indexed, every operation is a scan over the whole list
+struct PreviousStubStore<T> {
+    store: Vec<T>,
+    revisions: HashMap<Uuid, Vec<Revision>>,
+}
+
+impl<T> Default for PreviousStubStore<T> {
+    fn default() -> Self {
+        PreviousStubStore {
+            store: Vec::new(),
+            revisions: HashMap::new(),
+        }
+    }
+}
+
+impl<T> PreviousStubStore<T>
+where
+    T: HasId,
+{
+    fn get<I: HasId>(&self, id: I) -> Option<&T> {
+        self.store
+            .iter()
+            .find(|stored_item| id.get_id() == stored_item.get_id())
+    }
+
+    fn replace(&mut self, storable: T) {
+        self.store
+            .retain(|stored_item| storable.get_id() != stored_item.get_id());
+        self.store.push(storable);
+    }
+}
+
+// Stamping is private to the crate, so this does the same work as `prepare_store` through the
+// fields instead
+impl BaseStore<Question> for PreviousStubStore<Question> {
+    async fn store(&mut self, mut storable: Question) -> StorageResult<()> {
+        let now = Timestamp::now();
+        let Some(previous) = self.get(storable.get_id()) else {
+            storable.date_created = Some(storable.date_created.unwrap_or(now));
+            storable.date_updated = Some(storable.date_updated.unwrap_or(now));
+            self.replace(storable);
+            return Ok(());
+        };
+
+        storable.date_created = previous.date_created;
+        storable.date_updated = previous.date_updated;
+        let Some(revision) = Revision::between(previous, &storable, now)? else {
+            return Ok(());
+        };
+        storable.date_created = Some(previous.date_created.unwrap_or(now));
+        storable.date_updated = Some(now);
+        self.revisions
+            .entry(storable.get_id())
+            .or_default()
+            .push(revision);
+        self.replace(storable);
+        Ok(())
+    }
+}
+
+impl<O> RecallById<O> for PreviousStubStore<O>
+where
+    O: HasId + HasDeleted + Clone,
+{
+    async fn recall_by_id<I: HasId>(&self, id: I) -> StorageResult<O> {
+        self.store
+            .iter()
+            .filter(|item| !item.is_deleted())
+            .find(|stored_item| id.get_id() == stored_item.get_id())
+            .cloned()
+            .ok_or(StorageError::NotFound)
+    }
+}
+
+impl<T> RecallByRole<T> for PreviousStubStore<T>
+where
+    T: HasRole + HasDeleted + Clone,
+{
+    async fn recall_by_role<I: HasId>(&self, role: I) -> StorageResult<Vec<T>> {
+        Ok(self
+            .store
+            .iter()
+            .filter(|stored_item| stored_item.get_role_id() == role.get_id())
+            .filter(|item| !item.is_deleted())
+            .cloned()
+            .collect())
+    }
+}
+
+fn questions(count: usize) -> (Vec<Uuid>, Vec<Question>) {
+    let roles: Vec<Uuid> = (0..ROLES).map(|_| Uuid::new_v4()).collect();
+    let questions = (0..count)
+        .map(|index| {
+            Question::new(
+                roles[index % ROLES],
+                format!("Question {index}"),
+                "An answer long enough to make copying it cost something",
+            )
+        })
+        .collect();
+    (roles, questions)
+}
+
+async fn fill<S>(mut store: S, questions: &[Question]) -> S
+where
+    S: BaseStore<Question>,
+{
+    for question in questions {
+        store.store(question.clone()).await.unwrap();
+    }
+    store
+}
+
+fn bench_store(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let mut group = c.benchmark_group("store");
+    for size in SIZES {
+        let (_, questions) = questions(size);
+        group.bench_with_input(
+            BenchmarkId::new("previous", size),
+            &questions,
+            |b, questions| {
+                b.iter(|| runtime.block_on(fill(PreviousStubStore::default(), questions)))
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("indexed", size),
+            &questions,
+            |b, questions| b.iter(|| runtime.block_on(fill(StubStore::default(), questions))),
+        );
+    }
+    group.finish();
+}
+
+fn bench_recall_by_id(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let mut group = c.benchmark_group("recall_by_id");
+    for size in SIZES {
+        let (_, questions) = questions(size);
+        let last = questions.last().unwrap().id;
+        let previous = runtime.block_on(fill(PreviousStubStore::default(), &questions));
+        let indexed = runtime.block_on(fill(StubStore::default(), &questions));
+
+        group.bench_function(BenchmarkId::new("previous", size), |b| {
+            b.iter(|| {
+                runtime
+                    .block_on(RecallById::<Question>::recall_by_id(
+                        &previous,
+                        black_box(last),
+                    ))
+                    .unwrap()
+            })
+        });
+        group.bench_function(BenchmarkId::new("indexed", size), |b| {
+            b.iter(|| {
+                runtime
+                    .block_on(RecallById::<Question>::recall_by_id(
+                        &indexed,
+                        black_box(last),
+                    ))
+                    .unwrap()
+            })
+        });
+        group.bench_function(BenchmarkId::new("indexed_shared", size), |b| {
+            b.iter(|| {
+                runtime
+                    .block_on(RecallById::<Question>::recall_shared_by_id(
+                        &indexed,
+                        black_box(last),
+                    ))
+                    .unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_recall_by_role(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let mut group = c.benchmark_group("recall_by_role");
+    for size in SIZES {
+        let (roles, questions) = questions(size);
+        let role = roles[0];
+        let previous = runtime.block_on(fill(PreviousStubStore::default(), &questions));
+        let indexed = runtime.block_on(fill(StubStore::default(), &questions));
+
+        group.bench_function(BenchmarkId::new("previous", size), |b| {
+            b.iter(|| {
+                runtime
+                    .block_on(RecallByRole::<Question>::recall_by_role(
+                        &previous,
+                        black_box(role),
+                    ))
+                    .unwrap()
+            })
+        });
+        group.bench_function(BenchmarkId::new("indexed", size), |b| {
+            b.iter(|| {
+                runtime
+                    .block_on(RecallByRole::<Question>::recall_by_role(
+                        &indexed,
+                        black_box(role),
+                    ))
+                    .unwrap()
+            })
+        });
+        group.bench_function(BenchmarkId::new("indexed_shared", size), |b| {
+            b.iter(|| {
+                runtime
+                    .block_on(RecallByRole::<Question>::recall_shared_by_role(
+                        &indexed,
+                        black_box(role),
+                    ))
+                    .unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_store,
+    bench_recall_by_id,
+    bench_recall_by_role
+);
+criterion_main!(benches);
@@ -0,0 +1,145 @@
+use crate::storable::Searchable;
+use crate::SearchKind;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+use uuid::Uuid;
+
+/// How far a subscriber can fall behind before it starts missing changes
+const CHANGE_CAPACITY: usize = 256;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ChangeAction {
+    /// Includes coming back out of the trash
+    Created,
+    Updated,
+    /// Includes both moving to the trash and being purged from it
+    Deleted,
+}
+
+/// Something that changed in a store
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub action: ChangeAction,
+    pub kind: SearchKind,
+    pub id: Uuid,
+}
+
+impl Change {
+    pub fn new<O: Searchable>(action: ChangeAction, storable: &O) -> Self {
+        Self {
+            action,
+            kind: O::KIND,
+            id: storable.get_id(),
+        }
+    }
+
+    /// What storing something amounts to, given whether a live version of it was already stored
+    pub(crate) fn stored<O: Searchable>(storable: &O, was_live: bool) -> Self {
+        let action = if storable.is_deleted() {
+            ChangeAction::Deleted
+        } else if was_live {
+            ChangeAction::Updated
+        } else {
+            ChangeAction::Created
+        };
+        Self::new(action, storable)
+    }
+}
+
+/// Sends every change to whoever is subscribed at the time
+#[derive(Debug, Clone)]
+pub(crate) struct ChangeSender {
+    sender: broadcast::Sender<Change>,
+}
+
+impl Default for ChangeSender {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(CHANGE_CAPACITY);
+        Self { sender }
+    }
+}
+
+impl ChangeSender {
+    pub(crate) fn send(&self, change: Change) {
+        // Nobody listening isn't a problem
+        let _ = self.sender.send(change);
+    }
+
+    pub(crate) fn subscribe(&self) -> ChangeReceiver {
+        ChangeReceiver {
+            receiver: self.sender.subscribe(),
+        }
+    }
+}
+
+/// Receives the changes made after subscribing
+#[derive(Debug)]
+pub struct ChangeReceiver {
+    receiver: broadcast::Receiver<Change>,
+}
+
+impl ChangeReceiver {
+    /// Waits for a change that is relevant, returning false once the store has been dropped.
+    ///
+    /// Falling too far behind counts as a relevant change, as any of the missed changes could
+    /// have been.
+    pub async fn wait_for<F: Fn(&Change) -> bool>(&mut self, is_relevant: F) -> bool {
+        loop {
+            match self.receiver.recv().await {
+                Ok(change) if is_relevant(&change) => return true,
+                Ok(_) => {}
+                Err(RecvError::Lagged(_)) => return true,
+                Err(RecvError::Closed) => return false,
+            }
+        }
+    }
+
+    /// The next change if there already is one
+    pub fn try_recv(&mut self) -> Option<Change> {
+        self.receiver.try_recv().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storable::Company;
+    use crate::Timestamp;
+
+    #[tokio::test]
+    async fn test_wait_for_skips_irrelevant_changes() {
+        let sender = ChangeSender::default();
+        let mut receiver = sender.subscribe();
+        let company = Company::new("company");
+        let role = company.create_role("role", Timestamp::now());
+
+        sender.send(Change::new(ChangeAction::Created, &company));
+        sender.send(Change::new(ChangeAction::Created, &role));
+        assert!(
+            receiver
+                .wait_for(|change| change.kind == SearchKind::Role)
+                .await
+        );
+        assert_eq!(receiver.try_recv(), None);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_after_missing_changes() {
+        let sender = ChangeSender::default();
+        let mut receiver = sender.subscribe();
+        let company = Company::new("company");
+        for _ in 0..=CHANGE_CAPACITY {
+            sender.send(Change::new(ChangeAction::Updated, &company));
+        }
+        assert!(receiver.wait_for(|_| false).await);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_after_the_store_is_dropped() {
+        let sender = ChangeSender::default();
+        let mut receiver = sender.subscribe();
+        sender.send(Change::new(ChangeAction::Created, &Company::new("company")));
+        drop(sender);
+        assert!(!receiver.wait_for(|_| false).await);
+    }
+}
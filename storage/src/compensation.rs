@@ -0,0 +1,499 @@
+use crate::storage::write_atomically;
+use crate::{IoResultExt, StorageError, StorageResult};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Assumes a 40-hour week, 52 weeks a year
+pub const HOURS_PER_YEAR: u64 = 2080;
+
+/// Assumes a 5-day week, 52 weeks a year
+pub const DAYS_PER_YEAR: u64 = 260;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PayPeriod {
+    Hourly,
+    Daily,
+    #[default]
+    Annual,
+}
+
+impl PayPeriod {
+    pub const ALL: [PayPeriod; 3] = [PayPeriod::Hourly, PayPeriod::Daily, PayPeriod::Annual];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PayPeriod::Hourly => "hourly",
+            PayPeriod::Daily => "daily",
+            PayPeriod::Annual => "annual",
+        }
+    }
+
+    pub fn annualise(&self, amount: u64) -> u64 {
+        match self {
+            PayPeriod::Hourly => amount.saturating_mul(HOURS_PER_YEAR),
+            PayPeriod::Daily => amount.saturating_mul(DAYS_PER_YEAR),
+            PayPeriod::Annual => amount,
+        }
+    }
+}
+
+impl FromStr for PayPeriod {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PayPeriod::ALL
+            .into_iter()
+            .find(|period| period.as_str() == s.to_lowercase())
+            .ok_or_else(|| format!("Invalid pay_period '{s}'"))
+    }
+}
+
+impl fmt::Display for PayPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PayPeriod::Hourly => write!(f, "per hour"),
+            PayPeriod::Daily => write!(f, "per day"),
+            PayPeriod::Annual => write!(f, "per year"),
+        }
+    }
+}
+
+/// An amount of money that may not have been given.
+///
+/// Form inputs always arrive as strings, so this accepts numbers, numeric strings (ignoring
+/// thousands separators) and treats an empty string the same as null.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct OptionalAmount(pub Option<u64>);
+
+impl From<OptionalAmount> for Option<u64> {
+    fn from(value: OptionalAmount) -> Self {
+        value.0
+    }
+}
+
+impl<'de> Deserialize<'de> for OptionalAmount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum TempAmount {
+            Number(u64),
+            String(String),
+            Null,
+        }
+
+        match TempAmount::deserialize(deserializer)? {
+            TempAmount::Number(n) => Ok(OptionalAmount(Some(n))),
+            TempAmount::Null => Ok(OptionalAmount(None)),
+            TempAmount::String(s) => {
+                let s: String = s.chars().filter(|c| !matches!(c, ',' | '_')).collect();
+                let s = s.trim();
+                if s.is_empty() {
+                    return Ok(OptionalAmount(None));
+                }
+                s.parse()
+                    .map(|n| OptionalAmount(Some(n)))
+                    .map_err(|_| serde::de::Error::custom(format!("Invalid amount '{s}'")))
+            }
+        }
+    }
+}
+
+/// A currency code as entered, kept in upper case however it was typed.
+///
+/// Whether it looks like a real code is left to the checks on the partial it's part of.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CurrencyCode(String);
+
+impl From<&str> for CurrencyCode {
+    fn from(value: &str) -> Self {
+        CurrencyCode(value.trim().to_uppercase())
+    }
+}
+
+impl From<String> for CurrencyCode {
+    fn from(value: String) -> Self {
+        CurrencyCode::from(value.as_str())
+    }
+}
+
+impl From<CurrencyCode> for String {
+    fn from(value: CurrencyCode) -> Self {
+        value.0
+    }
+}
+
+impl Deref for CurrencyCode {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for CurrencyCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(CurrencyCode::from)
+    }
+}
+
+/// An ISO 4217 currency code looks like three letters, eg GBP or USD
+pub fn looks_like_currency_code<S: AsRef<str>>(currency: S) -> bool {
+    let currency = currency.as_ref();
+    currency.len() == 3 && currency.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+/// Compensation converted to an annual figure in a single currency so that it can be compared.
+///
+/// It's ordered by its midpoint, which only means something between figures in the same currency.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalisedCompensation {
+    pub currency: String,
+    pub salary_min: u64,
+    pub salary_max: u64,
+    pub bonus: u64,
+}
+
+impl NormalisedCompensation {
+    /// Halfway between the ends of the salary range plus the bonus, as much as a `u64` can hold
+    pub fn midpoint(&self) -> u64 {
+        let midpoint = (u128::from(self.salary_min) + u128::from(self.salary_max)) / 2
+            + u128::from(self.bonus);
+        u64::try_from(midpoint).unwrap_or(u64::MAX)
+    }
+}
+
+impl Ord for NormalisedCompensation {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.midpoint()
+            .cmp(&other.midpoint())
+            .then_with(|| self.salary_min.cmp(&other.salary_min))
+            .then_with(|| self.salary_max.cmp(&other.salary_max))
+            .then_with(|| self.bonus.cmp(&other.bonus))
+            .then_with(|| self.currency.cmp(&other.currency))
+    }
+}
+
+impl PartialOrd for NormalisedCompensation {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A locally stored table of exchange rates relative to a base currency of the user's choosing.
+///
+/// Each rate is how much one unit of that currency is worth in the base currency. The rates are
+/// edited by hand but the base currency can be changed from inside the app, so like
+/// [`crate::TrashSettings`] they remember where they were loaded from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeRates {
+    base_currency: String,
+    rates: BTreeMap<String, f64>,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl Default for ExchangeRates {
+    fn default() -> Self {
+        Self::new("USD")
+    }
+}
+
+impl ExchangeRates {
+    pub fn new<S: AsRef<str>>(base_currency: S) -> Self {
+        Self {
+            base_currency: base_currency.as_ref().to_uppercase(),
+            rates: BTreeMap::new(),
+            path: PathBuf::new(),
+        }
+    }
+
+    /// Loads the table from disk, a missing file gives an empty table
+    pub async fn load<P: AsRef<Path>>(path: P) -> StorageResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        let mut rates: Self = match tokio::fs::read(&path).await {
+            Ok(data) => {
+                serde_json::from_slice(&data).map_err(|e| StorageError::deserialize(&path, e))?
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(e).at_path(&path),
+        };
+        rates.path = path;
+        Ok(rates)
+    }
+
+    pub async fn save(&self) -> StorageResult<()> {
+        let data = serde_json::to_vec_pretty(self).map_err(StorageError::integrity)?;
+        write_atomically(&self.path, &data).await
+    }
+
+    /// Empty unless the rates were loaded from a file
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get_base_currency(&self) -> &str {
+        &self.base_currency
+    }
+
+    pub fn get_rates(&self) -> &BTreeMap<String, f64> {
+        &self.rates
+    }
+
+    pub fn set_rate<S: AsRef<str>>(&mut self, currency: S, rate: f64) {
+        self.rates.insert(currency.as_ref().to_uppercase(), rate);
+    }
+
+    pub fn get_rate<S: AsRef<str>>(&self, currency: S) -> Option<f64> {
+        let currency = currency.as_ref().to_uppercase();
+        if currency == self.base_currency {
+            return Some(1.0);
+        }
+        self.rates.get(&currency).copied()
+    }
+
+    /// Changes the base currency, re-expressing every rate relative to the new base.
+    ///
+    /// Returns false if there is no rate for the new base currency.
+    pub fn rebase<S: AsRef<str>>(&mut self, base_currency: S) -> bool {
+        let base_currency = base_currency.as_ref().to_uppercase();
+        let Some(new_base_rate) = self.get_rate(&base_currency) else {
+            return false;
+        };
+        let old_base = std::mem::replace(&mut self.base_currency, base_currency.clone());
+        self.rates.remove(&base_currency);
+        self.rates
+            .values_mut()
+            .for_each(|rate| *rate /= new_base_rate);
+        self.rates.insert(old_base, 1.0 / new_base_rate);
+        true
+    }
+
+    /// Converts an amount into the base currency, if the currency's rate is known
+    pub fn convert<S: AsRef<str>>(&self, amount: u64, currency: S) -> Option<u64> {
+        self.get_rate(currency)
+            .map(|rate| (amount as f64 * rate).round() as u64)
+    }
+
+    /// Converts a role's salary range and bonus to an annual figure in the base currency.
+    ///
+    /// If only one end of the salary range is known it is used for both ends. Returns `None` if
+    /// the role has no salary or there is no rate for its currency.
+    pub fn normalise(
+        &self,
+        salary_min: Option<u64>,
+        salary_max: Option<u64>,
+        bonus: Option<u64>,
+        pay_period: PayPeriod,
+        currency: &str,
+    ) -> Option<NormalisedCompensation> {
+        let (min, max) = match (salary_min, salary_max) {
+            (None, None) => return None,
+            (Some(min), None) => (min, min),
+            (None, Some(max)) => (max, max),
+            (Some(min), Some(max)) => (min, max),
+        };
+        Some(NormalisedCompensation {
+            currency: self.base_currency.clone(),
+            salary_min: self.convert(pay_period.annualise(min), currency)?,
+            salary_max: self.convert(pay_period.annualise(max), currency)?,
+            bonus: self.convert(bonus.unwrap_or_default(), currency)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pay_period_annualise() {
+        assert_eq!(PayPeriod::Hourly.annualise(50), 104_000);
+        assert_eq!(PayPeriod::Daily.annualise(500), 130_000);
+        assert_eq!(PayPeriod::Annual.annualise(90_000), 90_000);
+        assert_eq!(PayPeriod::Hourly.annualise(u64::MAX), u64::MAX);
+        assert_eq!(PayPeriod::Daily.annualise(u64::MAX / 2), u64::MAX);
+    }
+
+    #[test]
+    fn test_pay_period_from_str() {
+        for period in PayPeriod::ALL {
+            assert_eq!(PayPeriod::from_str(period.as_str()), Ok(period));
+        }
+        assert!(PayPeriod::from_str("fortnightly").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_optional_amount() {
+        let amount: OptionalAmount = serde_json::from_str("50000").unwrap();
+        assert_eq!(amount, OptionalAmount(Some(50_000)));
+
+        let amount: OptionalAmount = serde_json::from_str("\"50,000\"").unwrap();
+        assert_eq!(amount, OptionalAmount(Some(50_000)));
+
+        let amount: OptionalAmount = serde_json::from_str("\"\"").unwrap();
+        assert_eq!(amount, OptionalAmount(None));
+
+        let amount: OptionalAmount = serde_json::from_str("null").unwrap();
+        assert_eq!(amount, OptionalAmount(None));
+
+        assert!(serde_json::from_str::<OptionalAmount>("\"lots\"").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_currency_code() {
+        let currency: CurrencyCode = serde_json::from_str("\" gbp \"").unwrap();
+        assert_eq!(&*currency, "GBP");
+        assert_eq!(String::from(CurrencyCode::from("eur")), "EUR");
+    }
+
+    #[test]
+    fn test_looks_like_currency_code() {
+        assert!(looks_like_currency_code("GBP"));
+        assert!(looks_like_currency_code("usd"));
+        assert!(!looks_like_currency_code("£"));
+        assert!(!looks_like_currency_code("EURO"));
+    }
+
+    #[test]
+    fn test_convert() {
+        let mut rates = ExchangeRates::new("GBP");
+        rates.set_rate("usd", 0.8);
+        assert_eq!(rates.convert(100, "GBP"), Some(100));
+        assert_eq!(rates.convert(100, "USD"), Some(80));
+        assert_eq!(rates.convert(100, "EUR"), None);
+    }
+
+    #[test]
+    fn test_rebase() {
+        let mut rates = ExchangeRates::new("GBP");
+        rates.set_rate("USD", 0.8);
+        rates.set_rate("EUR", 0.9);
+
+        assert!(rates.rebase("USD"));
+        assert_eq!(rates.get_base_currency(), "USD");
+        assert_eq!(rates.get_rate("GBP"), Some(1.25));
+        assert_eq!(rates.get_rate("EUR"), Some(1.125));
+        assert_eq!(rates.get_rate("USD"), Some(1.0));
+
+        assert!(!rates.rebase("JPY"));
+        assert_eq!(rates.get_base_currency(), "USD");
+    }
+
+    #[test]
+    fn test_normalise() {
+        let mut rates = ExchangeRates::new("GBP");
+        rates.set_rate("USD", 0.8);
+
+        let normalised = rates
+            .normalise(Some(50), Some(60), Some(1000), PayPeriod::Hourly, "USD")
+            .unwrap();
+        assert_eq!(
+            normalised,
+            NormalisedCompensation {
+                currency: "GBP".to_string(),
+                salary_min: 83_200,
+                salary_max: 99_840,
+                bonus: 800,
+            }
+        );
+        assert_eq!(normalised.midpoint(), 92_320);
+
+        let only_max = rates
+            .normalise(None, Some(70_000), None, PayPeriod::Annual, "GBP")
+            .unwrap();
+        assert_eq!(only_max.salary_min, 70_000);
+        assert_eq!(only_max.salary_max, 70_000);
+
+        assert!(rates
+            .normalise(None, None, None, PayPeriod::Annual, "GBP")
+            .is_none());
+        assert!(rates
+            .normalise(Some(1), None, None, PayPeriod::Annual, "JPY")
+            .is_none());
+    }
+
+    #[test]
+    fn test_midpoint_does_not_overflow() {
+        let compensation = NormalisedCompensation {
+            currency: "GBP".to_string(),
+            salary_min: u64::MAX,
+            salary_max: u64::MAX,
+            bonus: 1,
+        };
+        assert_eq!(compensation.midpoint(), u64::MAX);
+    }
+
+    #[test]
+    fn test_order_by_midpoint() {
+        let compensation = |salary_min, salary_max, bonus| NormalisedCompensation {
+            currency: "GBP".to_string(),
+            salary_min,
+            salary_max,
+            bonus,
+        };
+        assert!(compensation(40_000, 60_000, 0) < compensation(30_000, 80_000, 0));
+        assert!(compensation(50_000, 50_000, 5_000) > compensation(40_000, 60_000, 0));
+        // The same midpoint is settled by the rest
+        assert!(compensation(40_000, 60_000, 0) < compensation(50_000, 50_000, 0));
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load() {
+        let path = tempfile::tempdir()
+            .unwrap()
+            .keep()
+            .join("exchange_rates.json");
+
+        let mut rates = ExchangeRates::load(&path).await.unwrap();
+        assert_eq!(rates.get_base_currency(), "USD");
+        assert!(rates.get_rates().is_empty());
+        assert_eq!(rates.get_path(), path);
+
+        rates.set_rate("GBP", 1.25);
+        assert!(rates.rebase("GBP"));
+        rates.save().await.unwrap();
+
+        let loaded = ExchangeRates::load(&path).await.unwrap();
+        assert_eq!(loaded, rates);
+    }
+
+    #[tokio::test]
+    async fn test_load_unreadable() {
+        let path = tempfile::tempdir()
+            .unwrap()
+            .keep()
+            .join("exchange_rates.json");
+        tokio::fs::write(&path, "{ \"base_currency\": ")
+            .await
+            .unwrap();
+
+        let error = ExchangeRates::load(&path).await.unwrap_err();
+        assert!(error.is_deserialize());
+    }
+
+    #[tokio::test]
+    async fn test_save_fails_without_a_directory() {
+        let path = tempfile::tempdir()
+            .unwrap()
+            .keep()
+            .join("missing")
+            .join("exchange_rates.json");
+
+        let rates = ExchangeRates::load(&path).await.unwrap();
+        let error = rates.save().await.unwrap_err();
+        assert!(error.is_io());
+        assert!(!path.exists());
+    }
+}
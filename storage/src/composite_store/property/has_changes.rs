@@ -0,0 +1,6 @@
+use crate::ChangeSender;
+
+/// Composite stores tell their subscribers about changes to any of their stores
+pub(crate) trait HasChanges {
+    fn get_change_sender(&self) -> &ChangeSender;
+}
@@ -1,24 +1,54 @@
-use crate::storable::{HasCompany, HasDeleted, HasId, HasName, HasRole};
-use crate::storage::{BaseStore, RecallByCompany, RecallById, RecallByName, RecallByRole};
-use crate::Sealed;
-use tokio::sync::MutexGuard;
+use crate::composite_store::store::is_live;
+use crate::composite_store::{HasChanges, HasSearchIndex};
+use crate::storable::{
+    HasCompany, HasDeleted, HasId, HasName, HasRole, HasSchemaVersion, Queryable, RestoreRevision,
+    Searchable,
+};
+use crate::storage::{
+    Attachments, BaseStore, Purge, RecallByCompany, RecallById, RecallByName, RecallByQuery,
+    RecallByRole, RecallDeleted, RecallRevisions,
+};
+use crate::{Change, ChangeAction, Query, Revision, Sealed, StorageResult};
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
+use tokio::sync::{RwLockReadGuard, RwLockWriteGuard};
 
 pub trait HasFutureStoreFor<O>: Sealed {
     type Storage;
 
-    async fn get_store<'a>(&'a self) -> MutexGuard<'a, Self::Storage>
+    /// Shared access for reading, any number of readers can hold it at once
+    async fn get_store<'a>(&'a self) -> RwLockReadGuard<'a, Self::Storage>
     where
         <Self as HasFutureStoreFor<O>>::Storage: 'a;
+
+    /// Exclusive access for writing, waits for every reader to finish
+    async fn get_store_mut<'a>(&'a self) -> RwLockWriteGuard<'a, Self::Storage>
+    where
+        <Self as HasFutureStoreFor<O>>::Storage: 'a;
+
+    /// Fails if the storable belongs to something that doesn't exist
+    async fn check_parent(&self, _storable: &O) -> StorageResult<()> {
+        Ok(())
+    }
 }
 
 impl<T, O> BaseStore<O> for T
 where
-    T: HasFutureStoreFor<O>,
-    T::Storage: BaseStore<O>,
-    O: HasId + Clone,
+    T: HasFutureStoreFor<O> + HasSearchIndex + HasChanges,
+    T::Storage: BaseStore<O> + RecallById<O>,
+    O: Searchable + Clone,
 {
-    async fn store(&mut self, storable: O) -> anyhow::Result<()> {
-        self.get_store().await.store(storable).await
+    async fn store(&mut self, storable: O) -> StorageResult<()> {
+        self.check_parent(&storable).await?;
+        let mut store = self.get_store_mut().await;
+        let was_live = is_live(&*store, &storable).await?;
+        store.store(storable.clone()).await?;
+        drop(store);
+
+        self.get_search_index().await.update(&storable);
+        self.get_change_sender()
+            .send(Change::stored(&storable, was_live));
+        Ok(())
     }
 }
 
@@ -28,9 +58,13 @@ where
     T::Storage: RecallById<O>,
     O: HasId + HasDeleted + Clone,
 {
-    async fn recall_by_id<I: HasId>(&self, id: I) -> anyhow::Result<O> {
+    async fn recall_by_id<I: HasId>(&self, id: I) -> StorageResult<O> {
         self.get_store().await.recall_by_id(id).await
     }
+
+    async fn recall_shared_by_id<I: HasId>(&self, id: I) -> StorageResult<Arc<O>> {
+        self.get_store().await.recall_shared_by_id(id).await
+    }
 }
 
 impl<T, O> RecallByName<O> for T
@@ -39,7 +73,7 @@ where
     T::Storage: RecallByName<O>,
     O: HasName + HasDeleted + Clone,
 {
-    async fn recall_by_name<N: AsRef<str>>(&self, name: N) -> anyhow::Result<Vec<O>> {
+    async fn recall_by_name<N: AsRef<str>>(&self, name: N) -> StorageResult<Vec<O>> {
         self.get_store().await.recall_by_name(name).await
     }
 }
@@ -50,9 +84,19 @@ where
     T::Storage: RecallByCompany<O>,
     O: HasCompany + HasDeleted + Clone,
 {
-    async fn recall_by_company<I: HasId>(&self, company_id: I) -> anyhow::Result<Vec<O>> {
+    async fn recall_by_company<I: HasId>(&self, company_id: I) -> StorageResult<Vec<O>> {
         self.get_store().await.recall_by_company(company_id).await
     }
+
+    async fn recall_shared_by_company<I: HasId>(
+        &self,
+        company_id: I,
+    ) -> StorageResult<Vec<Arc<O>>> {
+        self.get_store()
+            .await
+            .recall_shared_by_company(company_id)
+            .await
+    }
 }
 
 impl<T, O> RecallByRole<O> for T
@@ -61,7 +105,85 @@ where
     T::Storage: RecallByRole<O>,
     O: HasRole + HasDeleted + Clone,
 {
-    async fn recall_by_role<I: HasId>(&self, role: I) -> anyhow::Result<Vec<O>> {
+    async fn recall_by_role<I: HasId>(&self, role: I) -> StorageResult<Vec<O>> {
         self.get_store().await.recall_by_role(role).await
     }
+
+    async fn recall_shared_by_role<I: HasId>(&self, role: I) -> StorageResult<Vec<Arc<O>>> {
+        self.get_store().await.recall_shared_by_role(role).await
+    }
+}
+
+impl<T, O> RecallDeleted<O> for T
+where
+    T: HasFutureStoreFor<O>,
+    T::Storage: RecallDeleted<O>,
+    O: HasId + HasDeleted + Clone,
+{
+    async fn recall_deleted(&self) -> StorageResult<Vec<O>> {
+        self.get_store().await.recall_deleted().await
+    }
+}
+
+impl<T, O> Purge<O> for T
+where
+    T: HasFutureStoreFor<O> + HasSearchIndex + HasChanges,
+    T::Storage: Purge<O>,
+    O: Searchable + Clone,
+{
+    async fn purge(&mut self, storable: O) -> StorageResult<()> {
+        let change = Change::new(ChangeAction::Deleted, &storable);
+        self.get_store_mut().await.purge(storable).await?;
+        self.get_search_index().await.remove(change.id);
+        self.get_change_sender().send(change);
+        Ok(())
+    }
+}
+
+impl<T, O> RecallRevisions<O> for T
+where
+    T: HasFutureStoreFor<O> + HasSearchIndex + HasChanges,
+    T::Storage: RecallRevisions<O> + RecallById<O>,
+    O: Searchable + HasSchemaVersion + RestoreRevision + Clone + DeserializeOwned,
+{
+    async fn recall_revisions(&self, storable: &O) -> StorageResult<Vec<Revision>> {
+        self.get_store().await.recall_revisions(storable).await
+    }
+}
+
+impl<T, O> RecallByQuery<O> for T
+where
+    T: HasFutureStoreFor<O>,
+    T::Storage: RecallByQuery<O>,
+    O: Queryable + Clone,
+{
+    async fn recall_by_query(&self, query: &Query) -> StorageResult<Vec<O>> {
+        self.get_store().await.recall_by_query(query).await
+    }
+}
+
+impl<T, O> Attachments<O> for T
+where
+    T: HasFutureStoreFor<O>,
+    T::Storage: Attachments<O>,
+    O: HasId + Clone,
+{
+    async fn attach<I: HasId>(
+        &mut self,
+        storable: I,
+        name: &str,
+        data: &[u8],
+    ) -> StorageResult<()> {
+        self.get_store_mut()
+            .await
+            .attach(storable, name, data)
+            .await
+    }
+
+    async fn recall_attachment<I: HasId>(&self, storable: I, name: &str) -> StorageResult<Vec<u8>> {
+        self.get_store()
+            .await
+            .recall_attachment(storable, name)
+            .await
+    }
 }
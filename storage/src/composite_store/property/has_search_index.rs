@@ -0,0 +1,7 @@
+use crate::SearchIndex;
+use tokio::sync::MutexGuard;
+
+/// Composite stores keep a search index across all of their stores
+pub(crate) trait HasSearchIndex {
+    async fn get_search_index<'a>(&'a self) -> MutexGuard<'a, SearchIndex>;
+}
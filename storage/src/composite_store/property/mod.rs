@@ -1,2 +1,8 @@
 mod has_future_store;
 pub use has_future_store::*;
+
+mod has_search_index;
+pub(crate) use has_search_index::*;
+
+mod has_changes;
+pub(crate) use has_changes::*;
@@ -0,0 +1,807 @@
+use super::Transaction;
+use crate::composite_store::{HasChanges, HasSearchIndex, ThreadSafeGeneralStore};
+use crate::storable::*;
+use crate::storage::*;
+use crate::{Change, ChangeAction, ChangeSender, Query, StorageError, StorageResult, Timestamp};
+use uuid::Uuid;
+
+fn deleted_at<O: SetDeleted>(storables: Vec<O>, date_deleted: Timestamp) -> Vec<O> {
+    storables
+        .into_iter()
+        .map(|mut storable| {
+            storable.delete(date_deleted);
+            storable
+        })
+        .collect()
+}
+
+/// Everything in the trash that was deleted at the same time as its parent, ready to be restored.
+/// Things that were deleted on their own before that stay in the trash.
+async fn restorable_with<S, O, F>(
+    store: &S,
+    date_deleted: Timestamp,
+    is_child: F,
+) -> StorageResult<Vec<O>>
+where
+    S: RecallDeleted<O>,
+    O: SetDeleted + HasId + Clone,
+    F: Fn(&O) -> bool,
+{
+    Ok(store
+        .recall_deleted()
+        .await?
+        .into_iter()
+        .filter(|storable| is_child(storable) && storable.get_date_deleted() == Some(date_deleted))
+        .map(|mut storable| {
+            storable.restore();
+            storable
+        })
+        .collect())
+}
+
+/// Purges everything in the trash that belongs to a parent being purged, however long it has been
+/// there, so nothing is left pointing at a parent that no longer exists
+async fn purge_deleted_with<S, O, F>(
+    store: &mut S,
+    is_child: F,
+    changes: &ChangeSender,
+) -> StorageResult<()>
+where
+    S: RecallDeleted<O> + Purge<O>,
+    O: Searchable + Clone,
+    F: Fn(&O) -> bool,
+{
+    for storable in store.recall_deleted().await? {
+        if is_child(&storable) {
+            let change = Change::new(ChangeAction::Deleted, &storable);
+            store.purge(storable).await?;
+            changes.send(change);
+        }
+    }
+    Ok(())
+}
+
+async fn recall_from_trash<S, O>(store: &S, id: Uuid) -> StorageResult<O>
+where
+    S: RecallDeleted<O>,
+    O: HasId + HasDeleted + Clone,
+{
+    store
+        .recall_deleted()
+        .await?
+        .into_iter()
+        .find(|storable| storable.get_id() == id)
+        .ok_or(StorageError::NotFound)
+}
+
+/// Like `recall_from_trash`, but tells apart something that is still live from something missing
+async fn recall_to_purge<S, O>(store: &S, id: Uuid) -> StorageResult<O>
+where
+    S: RecallDeleted<O> + RecallById<O>,
+    O: HasId + HasDeleted + Clone,
+{
+    match recall_from_trash(store, id).await {
+        Err(StorageError::NotFound) if store.recall_by_id(id).await.is_ok() => {
+            Err(StorageError::NotDeleted)
+        }
+        result => result,
+    }
+}
+
+impl<C, F, V, R, I, Q, P, M> ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
+where
+    C: CompanyStore,
+    F: FlagStore,
+    V: ValueStore,
+    R: RoleStore,
+    I: InterviewStore,
+    Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
+{
+    /// Soft deletes a company along with its roles (and their interviews, questions and
+    /// reminders), flags, values, contacts and reminders. Either all of them are deleted or none
+    /// are.
+    pub async fn delete_company<H: HasId>(
+        &self,
+        company: H,
+        date_deleted: Timestamp,
+    ) -> StorageResult<()> {
+        let company: Company = self.company_store().await.recall_by_id(company).await?;
+        let mut transaction = self.transaction();
+
+        let roles: Vec<Role> = self.role_store().await.recall_by_company(&company).await?;
+        for role in roles {
+            self.stage_role_delete(&mut transaction, role, date_deleted)
+                .await?;
+        }
+        let flags: Vec<Flag> = self.flag_store().await.recall_by_company(&company).await?;
+        transaction.store_all(deleted_at(flags, date_deleted));
+        let values: Vec<Value> = self.value_store().await.recall_by_company(&company).await?;
+        transaction.store_all(deleted_at(values, date_deleted));
+        let contacts: Vec<Contact> = self
+            .contact_store()
+            .await
+            .recall_by_company(&company)
+            .await?;
+        transaction.store_all(deleted_at(contacts, date_deleted));
+        // Including those for its roles
+        let reminders: Vec<Reminder> = self
+            .reminder_store()
+            .await
+            .recall_by_company(&company)
+            .await?;
+        transaction.store_all(deleted_at(reminders, date_deleted));
+        transaction.store_all(deleted_at(vec![company], date_deleted));
+
+        transaction.commit().await
+    }
+
+    /// Soft deletes a role along with its interviews, questions and reminders
+    pub async fn delete_role<H: HasId>(
+        &self,
+        role: H,
+        date_deleted: Timestamp,
+    ) -> StorageResult<()> {
+        let role: Role = self.role_store().await.recall_by_id(role).await?;
+        let mut transaction = self.transaction();
+
+        // Reminders for the role's interviews are for the role as well
+        let reminders: Vec<Reminder> = self
+            .reminder_store()
+            .await
+            .recall_by_query(&Query::new().role(&role))
+            .await?;
+        transaction.store_all(deleted_at(reminders, date_deleted));
+        self.stage_role_delete(&mut transaction, role, date_deleted)
+            .await?;
+
+        transaction.commit().await
+    }
+
+    /// Restores a company from the trash along with everything that was deleted with it
+    pub async fn restore_company<H: HasId>(&self, company: H) -> StorageResult<()> {
+        let mut company: Company =
+            recall_from_trash(&*self.company_store().await, company.get_id()).await?;
+        let Some(date_deleted) = company.date_deleted else {
+            return Ok(());
+        };
+        let company_id = company.id;
+        company.restore();
+        let mut transaction = self.transaction();
+        transaction.store(company);
+
+        let roles = restorable_with(&*self.role_store().await, date_deleted, |role: &Role| {
+            role.company_id == company_id
+        })
+        .await?;
+        for role in roles {
+            self.stage_role_restore(&mut transaction, role, date_deleted)
+                .await?;
+        }
+        let flags = restorable_with(&*self.flag_store().await, date_deleted, |flag: &Flag| {
+            flag.company_id == company_id
+        })
+        .await?;
+        transaction.store_all(flags);
+        let values = restorable_with(&*self.value_store().await, date_deleted, |value: &Value| {
+            value.company_id == company_id
+        })
+        .await?;
+        transaction.store_all(values);
+        let contacts = restorable_with(
+            &*self.contact_store().await,
+            date_deleted,
+            |contact: &Contact| contact.company_id == company_id,
+        )
+        .await?;
+        transaction.store_all(contacts);
+        let reminders = restorable_with(
+            &*self.reminder_store().await,
+            date_deleted,
+            |reminder: &Reminder| reminder.company_id == company_id,
+        )
+        .await?;
+        transaction.store_all(reminders);
+
+        transaction.commit().await
+    }
+
+    /// Restores a role from the trash along with the interviews, questions and reminders deleted
+    /// with it.
+    ///
+    /// The role's company needs to have been restored first.
+    pub async fn restore_role<H: HasId>(&self, role: H) -> StorageResult<()> {
+        let role: Role = recall_from_trash(&*self.role_store().await, role.get_id()).await?;
+        let Some(date_deleted) = role.date_deleted else {
+            return Ok(());
+        };
+        let role_id = role.id;
+        let mut transaction = self.transaction();
+
+        let reminders = restorable_with(
+            &*self.reminder_store().await,
+            date_deleted,
+            |reminder: &Reminder| reminder.role_id == Some(role_id),
+        )
+        .await?;
+        transaction.store_all(reminders);
+        self.stage_role_restore(&mut transaction, role, date_deleted)
+            .await?;
+
+        transaction.commit().await
+    }
+
+    /// Permanently removes a company in the trash along with everything in the trash that belongs
+    /// to it.
+    ///
+    /// A company that isn't in the trash can't be purged, and as a live child can't belong to a
+    /// deleted company, nothing is left without its company.
+    pub async fn purge_company<H: HasId>(&self, company: H) -> StorageResult<()> {
+        let result = self.purge_company_cascade(company).await;
+        self.get_search_index().await.invalidate();
+        result
+    }
+
+    /// Permanently removes a role in the trash along with the interviews, questions and reminders
+    /// in the trash that belong to it
+    pub async fn purge_role<H: HasId>(&self, role: H) -> StorageResult<()> {
+        let result = self.purge_role_cascade(role).await;
+        self.get_search_index().await.invalidate();
+        result
+    }
+
+    /// Stages the role and its interviews and questions as deleted, its reminders are left to the
+    /// caller as a company deletes them all at once
+    async fn stage_role_delete(
+        &self,
+        transaction: &mut Transaction<'_, C, F, V, R, I, Q, P, M>,
+        role: Role,
+        date_deleted: Timestamp,
+    ) -> StorageResult<()> {
+        let interviews: Vec<Interview> = self.interview_store().await.recall_by_role(&role).await?;
+        transaction.store_all(deleted_at(interviews, date_deleted));
+        let questions: Vec<Question> = self.question_store().await.recall_by_role(&role).await?;
+        transaction.store_all(deleted_at(questions, date_deleted));
+        transaction.store_all(deleted_at(vec![role], date_deleted));
+        Ok(())
+    }
+
+    /// The opposite of [`Self::stage_role_delete`], the reminders are again left to the caller
+    async fn stage_role_restore(
+        &self,
+        transaction: &mut Transaction<'_, C, F, V, R, I, Q, P, M>,
+        mut role: Role,
+        date_deleted: Timestamp,
+    ) -> StorageResult<()> {
+        let role_id = role.id;
+        role.restore();
+        transaction.store(role);
+
+        let interviews = restorable_with(
+            &*self.interview_store().await,
+            date_deleted,
+            |interview: &Interview| interview.role_id == role_id,
+        )
+        .await?;
+        transaction.store_all(interviews);
+        let questions = restorable_with(
+            &*self.question_store().await,
+            date_deleted,
+            |question: &Question| question.role_id == role_id,
+        )
+        .await?;
+        transaction.store_all(questions);
+        Ok(())
+    }
+
+    // Purging works on the stores directly, so the search index is invalidated once it's done
+    // rather than being updated as it goes. Changes are still sent for each item.
+    async fn purge_company_cascade<H: HasId>(&self, company: H) -> StorageResult<()> {
+        let company: Company =
+            recall_to_purge(&*self.company_store().await, company.get_id()).await?;
+        let company_id = company.id;
+
+        let roles: Vec<Role> = self
+            .role_store()
+            .await
+            .recall_deleted()
+            .await?
+            .into_iter()
+            .filter(|role| role.company_id == company_id)
+            .collect();
+        for role in roles {
+            self.purge_role_cascade(role).await?;
+        }
+
+        purge_deleted_with(
+            &mut *self.flag_store_mut().await,
+            |flag: &Flag| flag.company_id == company_id,
+            self.get_change_sender(),
+        )
+        .await?;
+        purge_deleted_with(
+            &mut *self.value_store_mut().await,
+            |value: &Value| value.company_id == company_id,
+            self.get_change_sender(),
+        )
+        .await?;
+        purge_deleted_with(
+            &mut *self.contact_store_mut().await,
+            |contact: &Contact| contact.company_id == company_id,
+            self.get_change_sender(),
+        )
+        .await?;
+        purge_deleted_with(
+            &mut *self.reminder_store_mut().await,
+            |reminder: &Reminder| reminder.company_id == company_id,
+            self.get_change_sender(),
+        )
+        .await?;
+
+        let change = Change::new(ChangeAction::Deleted, &company);
+        self.company_store_mut().await.purge(company).await?;
+        self.get_change_sender().send(change);
+        Ok(())
+    }
+
+    async fn purge_role_cascade<H: HasId>(&self, role: H) -> StorageResult<()> {
+        let role: Role = recall_to_purge(&*self.role_store().await, role.get_id()).await?;
+        let role_id = role.id;
+
+        purge_deleted_with(
+            &mut *self.interview_store_mut().await,
+            |interview: &Interview| interview.role_id == role_id,
+            self.get_change_sender(),
+        )
+        .await?;
+        purge_deleted_with(
+            &mut *self.question_store_mut().await,
+            |question: &Question| question.role_id == role_id,
+            self.get_change_sender(),
+        )
+        .await?;
+        purge_deleted_with(
+            &mut *self.reminder_store_mut().await,
+            |reminder: &Reminder| reminder.role_id == Some(role_id),
+            self.get_change_sender(),
+        )
+        .await?;
+
+        let change = Change::new(ChangeAction::Deleted, &role);
+        self.role_store_mut().await.purge(role).await?;
+        self.get_change_sender().send(change);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_helper::{all_without_timestamps, without_timestamps, TestHelper};
+
+    struct Fixture {
+        store: StubThreadSafeGeneralStore,
+        company: Company,
+        role: Role,
+        flag: Flag,
+        value: Value,
+        contact: Contact,
+        interview: Interview,
+        question: Question,
+    }
+
+    async fn fixture() -> Fixture {
+        let mut store = StubThreadSafeGeneralStore::new_stub();
+        let company = Company::new("company");
+        let role = company.create_role("role", Timestamp::now());
+        let flag = company.create_red_flag("flag");
+        let value = company.create_value("value", "description");
+        let contact = company.create_contact("contact");
+        let interview = role.create_interview("interview");
+        let question = role.create_question("question", "answer");
+
+        store.store(company.clone()).await.unwrap();
+        store.store(role.clone()).await.unwrap();
+        store.store(flag.clone()).await.unwrap();
+        store.store(value.clone()).await.unwrap();
+        store.store(contact.clone()).await.unwrap();
+        store.store(interview.clone()).await.unwrap();
+        store.store(question.clone()).await.unwrap();
+
+        Fixture {
+            store,
+            company,
+            role,
+            flag,
+            value,
+            contact,
+            interview,
+            question,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_company_cascades() {
+        let Fixture { store, company, .. } = fixture().await;
+        let date_deleted = Timestamp::from_timestamp(60);
+
+        store.delete_company(&company, date_deleted).await.unwrap();
+
+        let companies: Vec<Company> = store.recall_deleted().await.unwrap();
+        let roles: Vec<Role> = store.recall_deleted().await.unwrap();
+        let flags: Vec<Flag> = store.recall_deleted().await.unwrap();
+        let values: Vec<Value> = store.recall_deleted().await.unwrap();
+        let contacts: Vec<Contact> = store.recall_deleted().await.unwrap();
+        let interviews: Vec<Interview> = store.recall_deleted().await.unwrap();
+        let questions: Vec<Question> = store.recall_deleted().await.unwrap();
+        for date in companies
+            .iter()
+            .map(HasDeleted::get_date_deleted)
+            .chain(roles.iter().map(HasDeleted::get_date_deleted))
+            .chain(flags.iter().map(HasDeleted::get_date_deleted))
+            .chain(values.iter().map(HasDeleted::get_date_deleted))
+            .chain(contacts.iter().map(HasDeleted::get_date_deleted))
+            .chain(interviews.iter().map(HasDeleted::get_date_deleted))
+            .chain(questions.iter().map(HasDeleted::get_date_deleted))
+        {
+            assert_eq!(date, Some(date_deleted));
+        }
+        assert_eq!(
+            [
+                companies.len(),
+                roles.len(),
+                flags.len(),
+                values.len(),
+                contacts.len(),
+                interviews.len(),
+                questions.len()
+            ],
+            [1; 7]
+        );
+
+        let live_roles: Vec<Role> = store.recall_by_company(&company).await.unwrap();
+        assert!(live_roles.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_restore_company_restores_what_was_deleted_with_it() {
+        let Fixture {
+            mut store,
+            company,
+            role,
+            mut flag,
+            value,
+            contact,
+            interview,
+            question,
+        } = fixture().await;
+
+        // Deleted on its own first, so should stay deleted
+        flag.delete(Timestamp::from_timestamp(30));
+        store.store(flag.clone()).await.unwrap();
+
+        store
+            .delete_company(&company, Timestamp::from_timestamp(60))
+            .await
+            .unwrap();
+        store.restore_company(&company).await.unwrap();
+
+        let recalled_company: Company = store.recall_by_id(&company).await.unwrap();
+        let recalled_role: Role = store.recall_by_id(&role).await.unwrap();
+        let recalled_value: Value = store.recall_by_id(&value).await.unwrap();
+        let recalled_contact: Contact = store.recall_by_id(&contact).await.unwrap();
+        let recalled_interview: Interview = store.recall_by_id(&interview).await.unwrap();
+        let recalled_question: Question = store.recall_by_id(&question).await.unwrap();
+        assert_eq!(without_timestamps(recalled_company), company);
+        assert_eq!(without_timestamps(recalled_role), role);
+        assert_eq!(without_timestamps(recalled_value), value);
+        assert_eq!(without_timestamps(recalled_contact), contact);
+        assert_eq!(without_timestamps(recalled_interview), interview);
+        assert_eq!(without_timestamps(recalled_question), question);
+
+        let deleted_flags: Vec<Flag> = store.recall_deleted().await.unwrap();
+        assert_eq!(all_without_timestamps(deleted_flags), vec![flag]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_and_restore_role() {
+        let Fixture {
+            store,
+            company,
+            role,
+            flag,
+            interview,
+            question,
+            ..
+        } = fixture().await;
+
+        store
+            .delete_role(&role, Timestamp::from_timestamp(60))
+            .await
+            .unwrap();
+
+        let interviews: Vec<Interview> = store.recall_by_role(&role).await.unwrap();
+        let questions: Vec<Question> = store.recall_by_role(&role).await.unwrap();
+        let flags: Vec<Flag> = store.recall_by_company(&company).await.unwrap();
+        assert!(interviews.is_empty());
+        assert!(questions.is_empty());
+        assert_eq!(all_without_timestamps(flags), vec![flag]);
+
+        store.restore_role(&role).await.unwrap();
+        let interviews: Vec<Interview> = store.recall_by_role(&role).await.unwrap();
+        let questions: Vec<Question> = store.recall_by_role(&role).await.unwrap();
+        assert_eq!(all_without_timestamps(interviews), vec![interview]);
+        assert_eq!(all_without_timestamps(questions), vec![question]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_and_restore_role_send_changes() {
+        let Fixture {
+            store,
+            role,
+            interview,
+            question,
+            ..
+        } = fixture().await;
+        let mut changes = store.subscribe();
+
+        store
+            .delete_role(&role, Timestamp::from_timestamp(60))
+            .await
+            .unwrap();
+        let mut deleted = vec![];
+        while let Some(change) = changes.try_recv() {
+            assert_eq!(change.action, ChangeAction::Deleted);
+            deleted.push((change.kind, change.id));
+        }
+        // In the order they are committed in, parents first
+        assert_eq!(
+            deleted,
+            vec![
+                (SearchKind::Role, role.id),
+                (SearchKind::Interview, interview.id),
+                (SearchKind::Question, question.id),
+            ]
+        );
+
+        store.restore_role(&role).await.unwrap();
+        let restored = changes.try_recv().unwrap();
+        assert_eq!(restored.action, ChangeAction::Created);
+        assert_eq!(restored.id, role.id);
+    }
+
+    #[tokio::test]
+    async fn test_failed_delete_changes_nothing() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+        let mut store = JsonThreadSafeGeneralStore::new_json(base_path.clone())
+            .await
+            .unwrap();
+        let company = Company::new("company");
+        let role = company.create_role("role", Timestamp::now());
+        let question = role.create_question("question", "answer");
+        store.store(company.clone()).await.unwrap();
+        store.store(role.clone()).await.unwrap();
+        store.store(question.clone()).await.unwrap();
+
+        // The question can't be written over while there is a directory in its place
+        let question_path = base_path
+            .join("question")
+            .join(format!("{}.json", question.id));
+        std::fs::remove_file(&question_path).unwrap();
+        std::fs::create_dir_all(question_path.join("blocker")).unwrap();
+
+        assert!(store
+            .delete_company(&company, Timestamp::from_timestamp(60))
+            .await
+            .is_err());
+        let recalled: Company = store.recall_by_id(&company).await.unwrap();
+        assert!(!recalled.is_deleted());
+        let recalled: Role = store.recall_by_id(&role).await.unwrap();
+        assert!(!recalled.is_deleted());
+    }
+
+    #[tokio::test]
+    async fn test_restore_role_needs_live_company() {
+        let Fixture {
+            store,
+            company,
+            role,
+            ..
+        } = fixture().await;
+
+        store
+            .delete_company(&company, Timestamp::from_timestamp(60))
+            .await
+            .unwrap();
+
+        let error = store.restore_role(&role).await.unwrap_err();
+        assert!(error.is_missing_parent());
+    }
+
+    #[tokio::test]
+    async fn test_delete_and_restore_reminders() {
+        let Fixture {
+            mut store,
+            company,
+            role,
+            interview,
+            ..
+        } = fixture().await;
+        let company_reminder = company.create_reminder("company", Timestamp::now());
+        let role_reminder = role.create_reminder("role", Timestamp::now());
+        let interview_reminder =
+            role.create_reminder_for_interview(&interview, "interview", Timestamp::now());
+        for reminder in [&company_reminder, &role_reminder, &interview_reminder] {
+            store.store(reminder.clone()).await.unwrap();
+        }
+
+        store
+            .delete_role(&role, Timestamp::from_timestamp(30))
+            .await
+            .unwrap();
+        let reminders: Vec<Reminder> = store.recall_by_company(&company).await.unwrap();
+        assert_eq!(
+            all_without_timestamps(reminders),
+            vec![company_reminder.clone()]
+        );
+
+        store.restore_role(&role).await.unwrap();
+        store
+            .delete_company(&company, Timestamp::from_timestamp(60))
+            .await
+            .unwrap();
+        let deleted: Vec<Reminder> = store.recall_deleted().await.unwrap();
+        assert_eq!(deleted.len(), 3);
+
+        store.restore_company(&company).await.unwrap();
+        let mut reminders: Vec<Reminder> = store.recall_by_company(&company).await.unwrap();
+        reminders.sort_by(|a, b| a.name.cmp(&b.name));
+        assert_eq!(
+            all_without_timestamps(reminders),
+            vec![company_reminder, interview_reminder, role_reminder]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reminder_needs_its_interview() {
+        let Fixture {
+            mut store, role, ..
+        } = fixture().await;
+        let orphan = role.create_interview("orphan");
+        let reminder = role.create_reminder_for_interview(&orphan, "reminder", Timestamp::now());
+
+        let error = store.store(reminder.clone()).await.unwrap_err();
+        assert!(error.is_missing_parent());
+
+        store.store(orphan).await.unwrap();
+        store.store(reminder).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_purge_company_cascades() {
+        let Fixture {
+            mut store,
+            company,
+            role,
+            mut flag,
+            ..
+        } = fixture().await;
+        let reminder = role.create_reminder("reminder", Timestamp::now());
+        store.store(reminder).await.unwrap();
+        // Deleted on its own first, it still goes with the company
+        flag.delete(Timestamp::from_timestamp(30));
+        store.store(flag).await.unwrap();
+
+        let error = store.purge_company(&company).await.unwrap_err();
+        assert!(error.is_not_deleted());
+
+        store
+            .delete_company(&company, Timestamp::from_timestamp(60))
+            .await
+            .unwrap();
+        let mut changes = store.subscribe();
+        store.purge_company(&company).await.unwrap();
+
+        let companies: Vec<Company> = store.recall_deleted().await.unwrap();
+        let roles: Vec<Role> = store.recall_deleted().await.unwrap();
+        let flags: Vec<Flag> = store.recall_deleted().await.unwrap();
+        let values: Vec<Value> = store.recall_deleted().await.unwrap();
+        let contacts: Vec<Contact> = store.recall_deleted().await.unwrap();
+        let interviews: Vec<Interview> = store.recall_deleted().await.unwrap();
+        let questions: Vec<Question> = store.recall_deleted().await.unwrap();
+        let reminders: Vec<Reminder> = store.recall_deleted().await.unwrap();
+        assert_eq!(
+            [
+                companies.len(),
+                roles.len(),
+                flags.len(),
+                values.len(),
+                contacts.len(),
+                interviews.len(),
+                questions.len(),
+                reminders.len()
+            ],
+            [0; 8]
+        );
+
+        let mut purged = 0;
+        while let Some(change) = changes.try_recv() {
+            assert_eq!(change.action, ChangeAction::Deleted);
+            purged += 1;
+        }
+        assert_eq!(purged, 8);
+    }
+
+    #[tokio::test]
+    async fn test_purge_role_leaves_the_company() {
+        let Fixture {
+            store,
+            company,
+            role,
+            flag,
+            ..
+        } = fixture().await;
+
+        let error = store.purge_role(&role).await.unwrap_err();
+        assert!(error.is_not_deleted());
+
+        store
+            .delete_role(&role, Timestamp::from_timestamp(60))
+            .await
+            .unwrap();
+        store.purge_role(&role).await.unwrap();
+
+        let roles: Vec<Role> = store.recall_deleted().await.unwrap();
+        let interviews: Vec<Interview> = store.recall_deleted().await.unwrap();
+        let questions: Vec<Question> = store.recall_deleted().await.unwrap();
+        assert!(roles.is_empty());
+        assert!(interviews.is_empty());
+        assert!(questions.is_empty());
+        let flags: Vec<Flag> = store.recall_by_company(&company).await.unwrap();
+        assert_eq!(all_without_timestamps(flags), vec![flag]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_missing_company() {
+        let store = StubThreadSafeGeneralStore::new_stub();
+        let company = Company::new_test().await.unwrap();
+        assert!(store
+            .delete_company(&company, Timestamp::now())
+            .await
+            .is_err());
+        assert!(store.restore_company(&company).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_refuse_children_without_parents() {
+        let mut store = StubThreadSafeGeneralStore::new_stub();
+        let company = Company::new("company");
+        let role = company.create_role("role", Timestamp::now());
+        let question = role.create_question("question", "answer");
+
+        let error = store.store(role.clone()).await.unwrap_err();
+        assert!(error.is_missing_parent());
+        let error = store.store(question.clone()).await.unwrap_err();
+        assert!(error.is_missing_parent());
+
+        store.store(company.clone()).await.unwrap();
+        store.store(role.clone()).await.unwrap();
+        store.store(question).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_deleted_children_only_need_parent_in_trash() {
+        let mut store = StubThreadSafeGeneralStore::new_stub();
+        let mut company = Company::new("company");
+        let mut role = company.create_role("role", Timestamp::now());
+        company.delete(Timestamp::now());
+        store.store(company.clone()).await.unwrap();
+
+        // A live role can't belong to a deleted company
+        assert!(store.store(role.clone()).await.is_err());
+
+        role.delete(Timestamp::now());
+        store.store(role).await.unwrap();
+    }
+}
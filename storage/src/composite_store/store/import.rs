@@ -0,0 +1,446 @@
+use crate::composite_store::{HasChanges, HasSearchIndex, ThreadSafeGeneralStore};
+use crate::storable::*;
+use crate::storage::*;
+use crate::{Change, ChangeAction, ColumnMapping, JobPosting, SpreadsheetKind, SpreadsheetRow};
+use crate::{SpreadsheetTable, StorageError, StorageResult, Timestamp};
+use std::collections::HashMap;
+
+/// What was stored when a job posting was imported
+#[derive(Debug)]
+pub struct ImportedJobPosting {
+    pub company: Company,
+    pub role: Role,
+    /// False when the role was added to a company that was already stored
+    pub is_new_company: bool,
+    /// Why the page itself couldn't be kept with the role. The role is still imported with the
+    /// posting's text, it just has no original HTML.
+    pub html_error: Option<StorageError>,
+}
+
+/// Which company a row of a spreadsheet being imported belongs to
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CompanyMatch {
+    /// A company that is already stored
+    Existing(Company),
+    /// The first row naming a company that isn't stored, importing creates it
+    New,
+    /// The company created by an earlier row
+    Earlier { line: usize },
+}
+
+/// A row of a spreadsheet as it would be imported
+#[derive(Clone, Debug)]
+pub struct SpreadsheetPreviewRow {
+    pub row: SpreadsheetRow,
+    /// None when the row doesn't name a company
+    pub company: Option<CompanyMatch>,
+}
+
+impl SpreadsheetPreviewRow {
+    /// Invalid rows are skipped, as are companies that are already there
+    pub fn will_import(&self, kind: SpreadsheetKind) -> bool {
+        self.row.is_valid()
+            && (kind == SpreadsheetKind::Roles || self.company == Some(CompanyMatch::New))
+    }
+}
+
+/// What was stored when a spreadsheet was imported
+#[derive(Clone, Debug, Default)]
+pub struct ImportedSpreadsheet {
+    pub companies: Vec<Company>,
+    pub roles: Vec<Role>,
+    /// The lines of the rows that weren't imported
+    pub skipped: Vec<usize>,
+}
+
+/// Names are matched the way people tend to type them, ignoring case and surrounding spaces
+fn name_key(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+fn is_same_name(a: &str, b: &str) -> bool {
+    name_key(a) == name_key(b)
+}
+
+impl<C, F, V, R, I, Q, P, M> ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
+where
+    C: CompanyStore,
+    F: FlagStore,
+    V: ValueStore,
+    R: RoleStore,
+    I: InterviewStore,
+    Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
+{
+    /// The live company with this name, if there is one
+    pub async fn find_company_by_name<S: AsRef<str>>(
+        &self,
+        name: S,
+    ) -> StorageResult<Option<Company>> {
+        let name = name.as_ref();
+        let companies: Vec<Company> = self
+            .company_store()
+            .await
+            .recall_by_name(name.trim())
+            .await?;
+        Ok(companies
+            .into_iter()
+            .find(|company| is_same_name(&company.name, name)))
+    }
+
+    /// Creates a role from a job posting saved from a job board, under the company with the same
+    /// name as the one hiring, or a new company if there isn't one. The page is captured with the
+    /// role as its posting.
+    ///
+    /// The role is stored along with the text of its posting, so once that's done the import
+    /// never fails. The page is only attached afterwards, as it needs the role to be there, and
+    /// if that goes wrong the reason is kept in [`ImportedJobPosting::html_error`].
+    pub async fn import_job_posting(
+        &self,
+        job_posting: &JobPosting,
+        html: &str,
+        date_applied: Timestamp,
+    ) -> StorageResult<ImportedJobPosting> {
+        let existing = self
+            .find_company_by_name(&job_posting.hiring_organisation)
+            .await?;
+        let is_new_company = existing.is_none();
+        let company = match existing {
+            Some(company) => company,
+            None => Company::new_from_partial(job_posting.to_partial_company())?,
+        };
+        let mut role =
+            company.create_role_from_partial(job_posting.to_partial_role(date_applied))?;
+        let mut posting = job_posting.to_posting(html);
+        posting.html = None;
+        role.capture_posting(&posting, date_applied);
+
+        let mut transaction = self.transaction();
+        if is_new_company {
+            transaction.store(company.clone());
+        }
+        transaction.store(role.clone());
+        transaction.commit().await?;
+
+        let html_error = self.attach_posting_html(&mut role, html).await.err();
+        Ok(ImportedJobPosting {
+            company,
+            role,
+            is_new_company,
+            html_error,
+        })
+    }
+
+    /// Attaches the original page to a role whose posting was captured without it
+    async fn attach_posting_html(&self, role: &mut Role, html: &str) -> StorageResult<()> {
+        let mut role_store = self.role_store_mut().await;
+        role_store
+            .attach(&*role, Role::POSTING_ATTACHMENT, html.as_bytes())
+            .await?;
+        let mut attached = role.clone();
+        if let Some(snapshot) = &mut attached.posting {
+            snapshot.has_html = true;
+        }
+        role_store.store(attached.clone()).await?;
+        drop(role_store);
+
+        self.get_search_index().await.update(&attached);
+        self.get_change_sender()
+            .send(Change::new(ChangeAction::Updated, &attached));
+        *role = attached;
+        Ok(())
+    }
+
+    /// Reads every row of the spreadsheet and finds the company each one belongs to, rows naming
+    /// the same company share the one that is stored or created by the first of them
+    pub async fn preview_spreadsheet(
+        &self,
+        table: &SpreadsheetTable,
+        mapping: &ColumnMapping,
+    ) -> StorageResult<Vec<SpreadsheetPreviewRow>> {
+        let companies: Vec<Company> = self.company_store().await.recall_by_name("").await?;
+        let mut matches: HashMap<String, CompanyMatch> = companies
+            .into_iter()
+            .map(|company| (name_key(&company.name), CompanyMatch::Existing(company)))
+            .collect();
+
+        let preview = mapping
+            .read_rows(table)
+            .into_iter()
+            .map(|row| {
+                let name = name_key(row.company_name());
+                let company = (!name.is_empty()).then(|| match matches.get(&name) {
+                    Some(company) => company.clone(),
+                    // A row that can't be imported doesn't create the company for later rows
+                    None if !row.is_valid() => CompanyMatch::New,
+                    None => {
+                        let line = row.line;
+                        matches.insert(name, CompanyMatch::Earlier { line });
+                        CompanyMatch::New
+                    }
+                });
+                SpreadsheetPreviewRow { row, company }
+            })
+            .collect();
+        Ok(preview)
+    }
+
+    /// Stores the companies and roles of every row that can be imported, all at once
+    pub async fn import_spreadsheet(
+        &self,
+        table: &SpreadsheetTable,
+        mapping: &ColumnMapping,
+    ) -> StorageResult<ImportedSpreadsheet> {
+        let kind = mapping.kind();
+        let mut imported = ImportedSpreadsheet::default();
+        let mut created: HashMap<usize, Company> = HashMap::new();
+        for preview in self.preview_spreadsheet(table, mapping).await? {
+            if !preview.will_import(kind) {
+                imported.skipped.push(preview.row.line);
+                continue;
+            }
+            let SpreadsheetPreviewRow { row, company } = preview;
+            let company = match company {
+                Some(CompanyMatch::Existing(company)) => company,
+                Some(CompanyMatch::Earlier { line }) => match created.get(&line) {
+                    Some(company) => company.clone(),
+                    None => {
+                        imported.skipped.push(row.line);
+                        continue;
+                    }
+                },
+                _ => {
+                    let company = Company::new_from_partial(row.company)?;
+                    created.insert(row.line, company.clone());
+                    imported.companies.push(company.clone());
+                    company
+                }
+            };
+            if let Some(role) = row.role {
+                let mut role = company.create_role_from_partial(role)?;
+                if let Some(status) = row.status {
+                    role.set_status(status, Timestamp::now());
+                }
+                imported.roles.push(role);
+            }
+        }
+
+        let mut transaction = self.transaction();
+        for company in &imported.companies {
+            transaction.store(company.clone());
+        }
+        for role in &imported.roles {
+            transaction.store(role.clone());
+        }
+        transaction.commit().await?;
+        Ok(imported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::storage::Attachments;
+
+    const GREENHOUSE: &str = include_str!("../../../fixtures/job_postings/greenhouse.html");
+
+    const TRACKER: &str = concat!(
+        "Company,Position,Applied,Stage\n",
+        "acme ,Engineer,2025-03-04,Applied\n",
+        "Globex,Tester,2025-03-05,Offer\n",
+        "GLOBEX,Developer,2025-03-06,Screening\n",
+        "Initech,,2025-03-07,Applied\n",
+    );
+
+    async fn store_with_acme() -> (StubThreadSafeGeneralStore, Company) {
+        let mut store = StubThreadSafeGeneralStore::new_stub();
+        let acme = Company::new("Acme");
+        store.store(acme.clone()).await.unwrap();
+        (store, acme)
+    }
+
+    #[tokio::test]
+    async fn test_find_company_by_name() {
+        let mut store = StubThreadSafeGeneralStore::new_stub();
+        let company = Company::new("Acme Widgets");
+        store.store(Company::new("Acme")).await.unwrap();
+        store.store(company.clone()).await.unwrap();
+
+        let found = store.find_company_by_name(" acme widgets ").await.unwrap();
+        assert_eq!(found.map(|found| found.id), Some(company.id));
+        assert!(store
+            .find_company_by_name("Widgets")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_import_job_posting_creates_company() {
+        let store = StubThreadSafeGeneralStore::new_stub();
+        let job_posting = JobPosting::extract(GREENHOUSE).unwrap();
+        let date_applied = Timestamp::from_string("2025-09-10");
+
+        let imported = store
+            .import_job_posting(&job_posting, GREENHOUSE, date_applied)
+            .await
+            .unwrap();
+        assert!(imported.is_new_company);
+        assert!(imported.html_error.is_none());
+        assert_eq!(imported.company.name, "Acme Widgets");
+
+        let role: Role = store.recall_by_id(&imported.role).await.unwrap();
+        assert_eq!(role.company_id, imported.company.id);
+        assert_eq!(role.name, "Senior Backend Engineer");
+        assert_eq!(role.date_applied, date_applied);
+        assert_eq!(role.salary_min, Some(85_000));
+        assert_eq!(role.currency, "GBP");
+        assert_eq!(role.location, "London, England, GB");
+        assert_eq!(
+            role.posting_url,
+            "https://boards.example.com/acme/jobs/4012"
+        );
+        assert_eq!(role.source, "boards.example.com");
+        assert_eq!(role.date_posted, Some(Timestamp::from_string("2025-09-01")));
+        assert_eq!(imported.role.posting, role.posting);
+        let snapshot = role.posting.unwrap();
+        assert_eq!(snapshot.markdown, job_posting.description);
+        assert!(snapshot.has_html);
+        assert_eq!(
+            store.recall_posting_html(&role.id).await.unwrap(),
+            GREENHOUSE
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_job_posting_matches_company() {
+        let mut store = StubThreadSafeGeneralStore::new_stub();
+        let company = Company::new("ACME WIDGETS");
+        store.store(company.clone()).await.unwrap();
+        let job_posting = JobPosting::extract(GREENHOUSE).unwrap();
+
+        let imported = store
+            .import_job_posting(&job_posting, GREENHOUSE, Timestamp::now())
+            .await
+            .unwrap();
+        assert!(!imported.is_new_company);
+        assert_eq!(imported.company.id, company.id);
+
+        let companies: Vec<Company> = store.recall_by_name("").await.unwrap();
+        assert_eq!(companies.len(), 1);
+        let roles: Vec<Role> = store.recall_by_company(&company).await.unwrap();
+        assert_eq!(roles.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_attach_posting_html_over_left_html() {
+        let mut store = StubThreadSafeGeneralStore::new_stub();
+        let company = Company::new("company");
+        let mut role = company.create_role("role", Timestamp::now());
+        role.capture_posting(&Posting::parse("# Engineer"), Timestamp::now());
+        store.store(company).await.unwrap();
+        store.store(role.clone()).await.unwrap();
+        store
+            .role_store_mut()
+            .await
+            .attach(&role, Role::POSTING_ATTACHMENT, b"<p>Left</p>")
+            .await
+            .unwrap();
+
+        let result = store.attach_posting_html(&mut role, GREENHOUSE).await;
+        assert!(result.unwrap_err().is_already_exists());
+        assert!(!role.posting.as_ref().unwrap().has_html);
+        let recalled: Role = store.recall_by_id(&role).await.unwrap();
+        assert_eq!(recalled.posting, role.posting);
+    }
+
+    #[tokio::test]
+    async fn test_preview_spreadsheet() {
+        let (store, acme) = store_with_acme().await;
+        let table = SpreadsheetTable::parse(TRACKER).unwrap();
+        let mapping = ColumnMapping::guess(SpreadsheetKind::Roles, &table.headers);
+
+        let preview = store.preview_spreadsheet(&table, &mapping).await.unwrap();
+        assert!(
+            matches!(&preview[0].company, Some(CompanyMatch::Existing(company)) if company.id == acme.id)
+        );
+        assert_eq!(preview[1].company, Some(CompanyMatch::New));
+        assert_eq!(preview[2].company, Some(CompanyMatch::Earlier { line: 3 }));
+        assert_eq!(preview[3].company, Some(CompanyMatch::New));
+        assert!(preview[2].will_import(SpreadsheetKind::Roles));
+        assert!(!preview[3].will_import(SpreadsheetKind::Roles));
+        assert!(preview[3]
+            .row
+            .errors
+            .for_field(SpreadsheetHeader("name"))
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_import_spreadsheet_roles() {
+        let (store, acme) = store_with_acme().await;
+        let table = SpreadsheetTable::parse(TRACKER).unwrap();
+        let mapping = ColumnMapping::guess(SpreadsheetKind::Roles, &table.headers);
+
+        let imported = store.import_spreadsheet(&table, &mapping).await.unwrap();
+        assert_eq!(imported.companies.len(), 1);
+        assert_eq!(imported.companies[0].name, "Globex");
+        assert_eq!(imported.roles.len(), 3);
+        assert_eq!(imported.skipped, vec![5]);
+
+        let companies: Vec<Company> = store.recall_by_name("").await.unwrap();
+        assert_eq!(companies.len(), 2);
+        let acme_roles: Vec<Role> = store.recall_by_company(&acme).await.unwrap();
+        assert_eq!(acme_roles.len(), 1);
+        assert_eq!(acme_roles[0].name, "Engineer");
+        let globex_roles: Vec<Role> = store
+            .recall_by_company(&imported.companies[0])
+            .await
+            .unwrap();
+        assert_eq!(globex_roles.len(), 2);
+        let offer = globex_roles
+            .iter()
+            .find(|role| role.status == RoleStatus::Offer)
+            .unwrap();
+        assert_eq!(offer.get_status_history().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_import_spreadsheet_companies() {
+        let (store, _) = store_with_acme().await;
+        let table = SpreadsheetTable::parse("Employer\nACME\nGlobex\nglobex\n").unwrap();
+        let mapping = ColumnMapping::guess(SpreadsheetKind::Companies, &table.headers);
+
+        let imported = store.import_spreadsheet(&table, &mapping).await.unwrap();
+        assert_eq!(imported.companies.len(), 1);
+        assert!(imported.roles.is_empty());
+        assert_eq!(imported.skipped, vec![2, 4]);
+        let companies: Vec<Company> = store.recall_by_name("").await.unwrap();
+        assert_eq!(companies.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_export_and_import_roles() {
+        let (store, acme) = store_with_acme().await;
+        let mut role = acme.create_role("Engineer", Timestamp::from_string("2025-03-04"));
+        role.salary_min = Some(60_000);
+        role.currency = "EUR".to_string();
+        role.location = "Berlin".to_string();
+        let csv =
+            export_roles_csv(std::slice::from_ref(&role), std::slice::from_ref(&acme)).unwrap();
+
+        let table = SpreadsheetTable::parse(csv).unwrap();
+        let mapping = ColumnMapping::guess(SpreadsheetKind::Roles, &table.headers);
+        let imported = store.import_spreadsheet(&table, &mapping).await.unwrap();
+        assert!(imported.companies.is_empty());
+        let copy = &imported.roles[0];
+        assert_ne!(copy.id, role.id);
+        assert_eq!(copy.company_id, acme.id);
+        assert_eq!(copy.name, role.name);
+        assert_eq!(copy.date_applied, role.date_applied);
+        assert_eq!(copy.salary_min, role.salary_min);
+        assert_eq!(copy.currency, role.currency);
+        assert_eq!(copy.location, role.location);
+    }
+}
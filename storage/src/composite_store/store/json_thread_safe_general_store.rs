@@ -1,8 +1,8 @@
 use crate::composite_store::ThreadSafeGeneralStore;
 use crate::prelude::Value;
-use crate::storable::{Company, Flag, Interview, Question, Role};
-use crate::storage::{JsonStore, ScopedJsonStoreFor};
-use anyhow::Result;
+use crate::storable::{Company, Contact, Flag, Interview, Question, Reminder, Role};
+use crate::storage::{JsonStore, LoadReport, ScopedJsonStoreFor};
+use crate::StorageResult;
 use std::path::PathBuf;
 use tokio::join;
 
@@ -13,28 +13,57 @@ pub type JsonThreadSafeGeneralStore = ThreadSafeGeneralStore<
     JsonStore<Role>,
     JsonStore<Interview>,
     JsonStore<Question>,
+    JsonStore<Contact>,
+    JsonStore<Reminder>,
 >;
 
 impl JsonThreadSafeGeneralStore {
-    pub async fn new_json(base_path: PathBuf) -> Result<Self> {
-        let (company_store, flag_store, value_store, role_store, interview_store, question_store) = join!(
+    pub async fn new_json(base_path: PathBuf) -> StorageResult<Self> {
+        let (
+            company_store,
+            flag_store,
+            value_store,
+            role_store,
+            interview_store,
+            question_store,
+            contact_store,
+            reminder_store,
+        ) = join!(
             JsonStore::<Company>::new_scoped(base_path.clone()),
             JsonStore::<Flag>::new_scoped(base_path.clone()),
             JsonStore::<Value>::new_scoped(base_path.clone()),
             JsonStore::<Role>::new_scoped(base_path.clone()),
             JsonStore::<Interview>::new_scoped(base_path.clone()),
             JsonStore::<Question>::new_scoped(base_path.clone()),
+            JsonStore::<Contact>::new_scoped(base_path.clone()),
+            JsonStore::<Reminder>::new_scoped(base_path.clone()),
         );
 
         Ok(Self::new(
-            company_store.map_err(|e| e.context("company_store"))?,
-            flag_store.map_err(|e| e.context("flag_store"))?,
-            value_store.map_err(|e| e.context("value_store"))?,
-            role_store.map_err(|e| e.context("role_store"))?,
-            interview_store.map_err(|e| e.context("interview_store"))?,
-            question_store.map_err(|e| e.context("question_store"))?,
+            company_store?,
+            flag_store?,
+            value_store?,
+            role_store?,
+            interview_store?,
+            question_store?,
+            contact_store?,
+            reminder_store?,
         ))
     }
+
+    /// Files from every store that couldn't be loaded
+    pub async fn load_report(&self) -> LoadReport {
+        let mut report = LoadReport::default();
+        report.merge(self.company_store().await.get_load_report().clone());
+        report.merge(self.flag_store().await.get_load_report().clone());
+        report.merge(self.value_store().await.get_load_report().clone());
+        report.merge(self.role_store().await.get_load_report().clone());
+        report.merge(self.interview_store().await.get_load_report().clone());
+        report.merge(self.question_store().await.get_load_report().clone());
+        report.merge(self.contact_store().await.get_load_report().clone());
+        report.merge(self.reminder_store().await.get_load_report().clone());
+        report
+    }
 }
 
 #[cfg(test)]
@@ -49,4 +78,24 @@ mod tests {
             .await
             .is_ok());
     }
+
+    #[tokio::test]
+    async fn test_load_report() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+        std::fs::create_dir_all(base_path.join("company")).unwrap();
+        std::fs::create_dir_all(base_path.join("role")).unwrap();
+        std::fs::write(base_path.join("company").join("broken.json"), "[").unwrap();
+        std::fs::write(base_path.join("role").join("broken.json"), "{}").unwrap();
+
+        let store = JsonThreadSafeGeneralStore::new_json(base_path)
+            .await
+            .unwrap();
+        let report = store.load_report().await;
+        let stores: Vec<_> = report
+            .get_quarantined()
+            .iter()
+            .map(|file| file.store.as_str())
+            .collect();
+        assert_eq!(stores, vec!["company", "role"]);
+    }
 }
@@ -6,3 +6,20 @@ pub use thread_safe_general_store::*;
 
 mod json_thread_safe_general_store;
 pub use json_thread_safe_general_store::JsonThreadSafeGeneralStore;
+
+#[cfg(feature = "sqlite")]
+mod sqlite_thread_safe_general_store;
+#[cfg(feature = "sqlite")]
+pub use sqlite_thread_safe_general_store::{ImportedJson, SqliteThreadSafeGeneralStore};
+
+mod cascade;
+
+mod posting;
+
+mod import;
+pub use import::{CompanyMatch, ImportedJobPosting, ImportedSpreadsheet, SpreadsheetPreviewRow};
+
+mod search;
+
+mod transaction;
+pub use transaction::Transaction;
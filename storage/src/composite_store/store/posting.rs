@@ -0,0 +1,174 @@
+use crate::composite_store::{HasChanges, HasSearchIndex, ThreadSafeGeneralStore};
+use crate::storable::*;
+use crate::storage::*;
+use crate::{Change, ChangeAction, Posting, StorageError, StorageResult, Timestamp};
+
+impl<C, F, V, R, I, Q, P, M> ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
+where
+    C: CompanyStore,
+    F: FlagStore,
+    V: ValueStore,
+    R: RoleStore,
+    I: InterviewStore,
+    Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
+{
+    /// Keeps a snapshot of the job posting with the role, along with the original HTML if there
+    /// was any.
+    ///
+    /// A role's posting can only be captured once, after that this fails with `AlreadyExists`.
+    pub async fn capture_posting<H: HasId>(
+        &self,
+        role: H,
+        posting: &Posting,
+        date_captured: Timestamp,
+    ) -> StorageResult<Role> {
+        let mut role_store = self.role_store_mut().await;
+        let mut role: Role = role_store.recall_by_id(role).await?;
+        if !role.capture_posting(posting, date_captured) {
+            return Err(StorageError::AlreadyExists);
+        }
+
+        // The HTML goes first so the snapshot never claims a file that isn't there. HTML that's
+        // already there belongs to some other capture, so it's never paired with this snapshot.
+        if let Some(html) = &posting.html {
+            role_store
+                .attach(&role, Role::POSTING_ATTACHMENT, html.as_bytes())
+                .await?;
+        }
+        role_store.store(role.clone()).await?;
+        drop(role_store);
+
+        self.get_search_index().await.update(&role);
+        self.get_change_sender()
+            .send(Change::new(ChangeAction::Updated, &role));
+        Ok(role)
+    }
+
+    /// The original HTML of the role's captured posting
+    pub async fn recall_posting_html<H: HasId>(&self, role: H) -> StorageResult<String> {
+        let html = self
+            .role_store()
+            .await
+            .recall_attachment(role, Role::POSTING_ATTACHMENT)
+            .await?;
+        String::from_utf8(html).map_err(StorageError::integrity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::storage::Attachments;
+
+    const HTML: &str = "<h1>Engineer</h1><p>Write <em>good</em> code</p>";
+
+    async fn store_with_role() -> (StubThreadSafeGeneralStore, Role) {
+        let mut store = StubThreadSafeGeneralStore::new_stub();
+        let company = Company::new("company");
+        let role = company.create_role("role", Timestamp::now());
+        store.store(company).await.unwrap();
+        store.store(role.clone()).await.unwrap();
+        (store, role)
+    }
+
+    #[tokio::test]
+    async fn test_capture_posting() {
+        let (store, role) = store_with_role().await;
+        let mut changes = store.subscribe();
+        let posting = Posting::parse(HTML);
+
+        let captured = store
+            .capture_posting(&role, &posting, Timestamp::from_timestamp(60))
+            .await
+            .unwrap();
+        let snapshot = captured.posting.clone().unwrap();
+        assert_eq!(snapshot.markdown, posting.markdown);
+        assert!(snapshot.has_html);
+
+        let recalled: Role = store.recall_by_id(&role).await.unwrap();
+        assert_eq!(recalled.posting, Some(snapshot));
+        assert_eq!(store.recall_posting_html(&role).await.unwrap(), HTML);
+
+        let change = changes.try_recv().unwrap();
+        assert_eq!(change.action, ChangeAction::Updated);
+        assert_eq!(change.id, role.id);
+    }
+
+    #[tokio::test]
+    async fn test_capture_posting_only_once() {
+        let (store, role) = store_with_role().await;
+        store
+            .capture_posting(&role, &Posting::parse(HTML), Timestamp::now())
+            .await
+            .unwrap();
+
+        let result = store
+            .capture_posting(&role, &Posting::parse("<p>Changed</p>"), Timestamp::now())
+            .await;
+        assert!(result.unwrap_err().is_already_exists());
+        assert_eq!(store.recall_posting_html(&role).await.unwrap(), HTML);
+    }
+
+    #[tokio::test]
+    async fn test_capture_posting_over_left_html() {
+        let (store, role) = store_with_role().await;
+        store
+            .role_store_mut()
+            .await
+            .attach(&role, Role::POSTING_ATTACHMENT, b"<p>Left</p>")
+            .await
+            .unwrap();
+
+        let result = store
+            .capture_posting(&role, &Posting::parse(HTML), Timestamp::now())
+            .await;
+        assert!(result.unwrap_err().is_already_exists());
+        let recalled: Role = store.recall_by_id(&role).await.unwrap();
+        assert_eq!(recalled.posting, None);
+    }
+
+    #[tokio::test]
+    async fn test_capture_markdown_posting() {
+        let (store, role) = store_with_role().await;
+        let captured = store
+            .capture_posting(&role, &Posting::parse("# Engineer"), Timestamp::now())
+            .await
+            .unwrap();
+        assert!(!captured.posting.unwrap().has_html);
+
+        let result = store.recall_posting_html(&role).await;
+        assert!(result.unwrap_err().is_not_found());
+    }
+
+    #[tokio::test]
+    async fn test_capture_posting_keeps_html_next_to_json() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+        let mut store = JsonThreadSafeGeneralStore::new_json(base_path.clone())
+            .await
+            .unwrap();
+        let company = Company::new("company");
+        let role = company.create_role("role", Timestamp::now());
+        store.store(company).await.unwrap();
+        store.store(role.clone()).await.unwrap();
+
+        store
+            .capture_posting(&role, &Posting::parse(HTML), Timestamp::now())
+            .await
+            .unwrap();
+        let path = base_path
+            .join("role")
+            .join(format!("{}.posting.html", role.id));
+        assert_eq!(std::fs::read_to_string(path).unwrap(), HTML);
+
+        // The attachment isn't mistaken for a role when the store is loaded again
+        let store = JsonThreadSafeGeneralStore::new_json(base_path)
+            .await
+            .unwrap();
+        assert!(store.load_report().await.is_empty());
+        let roles: Vec<Role> = store.recall_by_name("").await.unwrap();
+        assert_eq!(roles.len(), 1);
+        assert_eq!(store.recall_posting_html(&role).await.unwrap(), HTML);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::composite_store::{HasSearchIndex, ThreadSafeGeneralStore};
+use crate::storable::{HasName, Searchable};
+use crate::storage::*;
+use crate::{SearchIndex, SearchResult, StorageResult};
+
+/// Every name contains the empty string, so this indexes everything that hasn't been deleted
+async fn index_all<S, O>(index: &mut SearchIndex, store: &S) -> StorageResult<()>
+where
+    S: RecallByName<O>,
+    O: Searchable + HasName + Clone,
+{
+    for storable in store.recall_by_name("").await? {
+        index.update(&storable);
+    }
+    Ok(())
+}
+
+impl<C, F, V, R, I, Q, P, M> ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
+where
+    C: CompanyStore,
+    F: FlagStore,
+    V: ValueStore,
+    R: RoleStore,
+    I: InterviewStore,
+    Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
+{
+    /// Searches the text of everything that hasn't been deleted, best match first.
+    ///
+    /// The index is built from the stores on the first search and kept up to date as things are
+    /// stored after that.
+    pub async fn search<S: AsRef<str>>(&self, query: S) -> StorageResult<Vec<SearchResult>> {
+        let mut index = self.get_search_index().await;
+        if !index.is_built() {
+            index_all(&mut index, &*self.company_store().await).await?;
+            index_all(&mut index, &*self.flag_store().await).await?;
+            index_all(&mut index, &*self.value_store().await).await?;
+            index_all(&mut index, &*self.role_store().await).await?;
+            index_all(&mut index, &*self.interview_store().await).await?;
+            index_all(&mut index, &*self.question_store().await).await?;
+            index_all(&mut index, &*self.contact_store().await).await?;
+            index_all(&mut index, &*self.reminder_store().await).await?;
+            index.set_built();
+        }
+        Ok(index.search(query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn test_search_across_stores() {
+        let mut store = StubThreadSafeGeneralStore::new_stub();
+        let company = Company::new("Ferris Industries");
+        let mut role = company.create_role("Engineer", Timestamp::now());
+        role.description = "Maintaining the crab tank".to_string();
+        let mut interview = role.create_interview("Technical");
+        interview.notes = "Asked about crab husbandry".to_string();
+        let question = role.create_question("Why crabs?", "Sideways is a valid direction");
+        let value = company.create_value("Kindness", "Be kind to crabs");
+
+        store.store(company.clone()).await.unwrap();
+        store.store(role.clone()).await.unwrap();
+        store.store(interview.clone()).await.unwrap();
+        store.store(question.clone()).await.unwrap();
+        store.store(value.clone()).await.unwrap();
+
+        let results = store.search("crab").await.unwrap();
+        let mut found: Vec<_> = results.iter().map(|result| result.kind).collect();
+        found.sort();
+        assert_eq!(
+            found,
+            vec![
+                SearchKind::Role,
+                SearchKind::Interview,
+                SearchKind::Question,
+                SearchKind::Value
+            ]
+        );
+        assert!(results
+            .iter()
+            .all(|result| result.company_id == Some(company.id)));
+    }
+
+    #[tokio::test]
+    async fn test_search_stays_in_sync() {
+        let mut store = StubThreadSafeGeneralStore::new_stub();
+        let mut company = Company::new("Acme");
+        store.store(company.clone()).await.unwrap();
+        assert_eq!(store.search("acme").await.unwrap().len(), 1);
+
+        // Changes after the index has been built are picked up
+        company.name = "Initech".to_string();
+        store.store(company.clone()).await.unwrap();
+        assert!(store.search("acme").await.unwrap().is_empty());
+        assert_eq!(store.search("initech").await.unwrap().len(), 1);
+
+        let role = company.create_role("Initech role", Timestamp::now());
+        store.store(role.clone()).await.unwrap();
+        assert_eq!(store.search("initech").await.unwrap().len(), 2);
+
+        // As are cascades
+        store
+            .delete_company(&company, Timestamp::now())
+            .await
+            .unwrap();
+        assert!(store.search("initech").await.unwrap().is_empty());
+
+        store.restore_company(&company).await.unwrap();
+        assert_eq!(store.search("initech").await.unwrap().len(), 2);
+
+        store.delete_role(&role, Timestamp::now()).await.unwrap();
+        let recalled_role: Role = store.recall_deleted().await.unwrap().remove(0);
+        store.purge(recalled_role).await.unwrap();
+        assert_eq!(store.search("initech").await.unwrap().len(), 1);
+    }
+}
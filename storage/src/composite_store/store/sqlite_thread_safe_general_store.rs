@@ -0,0 +1,415 @@
+use crate::composite_store::{
+    HasChanges, HasSearchIndex, JsonThreadSafeGeneralStore, ThreadSafeGeneralStore,
+};
+use crate::prelude::Value;
+use crate::storable::{
+    Company, Contact, Flag, HasSchemaVersion, Interview, Queryable, Question, Reminder,
+    RestoreRevision, Role, Searchable, SetTimestamps,
+};
+use crate::storage::{
+    Attachments, JsonStore, LoadReport, RecallRevisions, ScopedSqliteStoreFor, SqliteConnection,
+    SqliteImport, SqliteStore, StubStore,
+};
+use crate::{Change, ChangeAction, IoResultExt, StorageResult};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::PathBuf;
+
+const JSON_IMPORTED_KEY: &str = "json_imported";
+
+/// What came across when a JSON store was imported
+#[derive(Debug, Default)]
+pub struct ImportedJson {
+    pub count: usize,
+    /// Files in the JSON store that couldn't be loaded, so weren't imported
+    pub load_report: LoadReport,
+}
+
+pub type SqliteThreadSafeGeneralStore = ThreadSafeGeneralStore<
+    SqliteStore<Company>,
+    SqliteStore<Flag>,
+    SqliteStore<Value>,
+    SqliteStore<Role>,
+    SqliteStore<Interview>,
+    SqliteStore<Question>,
+    SqliteStore<Contact>,
+    SqliteStore<Reminder>,
+>;
+
+/// Stages everything in the JSON store, including deleted items, to be written to the matching
+/// SQLite store
+async fn import_items<O>(
+    from: &JsonStore<O>,
+    to: &SqliteStore<O>,
+    import: &mut SqliteImport,
+    changes: &mut Vec<Change>,
+) -> StorageResult<()>
+where
+    O: Queryable
+        + Searchable
+        + SetTimestamps
+        + HasSchemaVersion
+        + RestoreRevision
+        + Clone
+        + Serialize
+        + DeserializeOwned,
+{
+    let items: Vec<O> = StubStore::items(&from.internal_store).cloned().collect();
+    for item in items {
+        let revisions = from.recall_revisions(&item).await?;
+        to.stage_import(import, item.clone(), &revisions)?;
+        let action = if item.is_deleted() {
+            ChangeAction::Deleted
+        } else {
+            ChangeAction::Created
+        };
+        changes.push(Change::new(action, &item));
+    }
+    Ok(())
+}
+
+/// The original HTML of captured postings lives outside the role documents
+async fn import_posting_attachments(
+    from: &JsonStore<Role>,
+    to: &SqliteStore<Role>,
+    import: &mut SqliteImport,
+) -> StorageResult<()> {
+    let roles: Vec<Role> = StubStore::items(&from.internal_store).cloned().collect();
+    for role in roles {
+        if !role
+            .posting
+            .as_ref()
+            .is_some_and(|posting| posting.has_html)
+        {
+            continue;
+        }
+        match from
+            .recall_attachment(&role, Role::POSTING_ATTACHMENT)
+            .await
+        {
+            Ok(html) => {
+                to.stage_import_attachment(import, &role, Role::POSTING_ATTACHMENT, html)?
+            }
+            Err(e) if e.is_not_found() => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(())
+}
+
+impl SqliteThreadSafeGeneralStore {
+    pub async fn new_sqlite(path: PathBuf) -> StorageResult<Self> {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.at_path(parent)?;
+        }
+        Self::new_with_connection(SqliteConnection::open(path)?)
+    }
+
+    pub fn new_with_connection(connection: SqliteConnection) -> StorageResult<Self> {
+        Ok(Self::new(
+            SqliteStore::new_scoped(connection.clone())?,
+            SqliteStore::new_scoped(connection.clone())?,
+            SqliteStore::new_scoped(connection.clone())?,
+            SqliteStore::new_scoped(connection.clone())?,
+            SqliteStore::new_scoped(connection.clone())?,
+            SqliteStore::new_scoped(connection.clone())?,
+            SqliteStore::new_scoped(connection.clone())?,
+            SqliteStore::new_scoped(connection)?,
+        ))
+    }
+
+    /// Copies everything (including deleted items) out of an existing JSON store.
+    ///
+    /// This only happens once per database, so it's safe to call on every launch. Nothing is
+    /// imported (or reported) once it has been done.
+    pub async fn import_json(&self, json_base_path: PathBuf) -> StorageResult<ImportedJson> {
+        let connection = self.company_store().await.get_connection().clone();
+        if connection.get_metadata(JSON_IMPORTED_KEY).await?.is_some() {
+            return Ok(ImportedJson::default());
+        }
+
+        let mut import = SqliteImport::default();
+        let mut changes = Vec::new();
+        let mut load_report = LoadReport::default();
+        if tokio::fs::try_exists(&json_base_path)
+            .await
+            .at_path(&json_base_path)?
+        {
+            let json_store = JsonThreadSafeGeneralStore::new_json(json_base_path).await?;
+            load_report = json_store.load_report().await;
+            let (import, changes) = (&mut import, &mut changes);
+            import_items(
+                &*json_store.company_store().await,
+                &*self.company_store().await,
+                import,
+                changes,
+            )
+            .await?;
+            import_items(
+                &*json_store.flag_store().await,
+                &*self.flag_store().await,
+                import,
+                changes,
+            )
+            .await?;
+            import_items(
+                &*json_store.value_store().await,
+                &*self.value_store().await,
+                import,
+                changes,
+            )
+            .await?;
+            import_items(
+                &*json_store.role_store().await,
+                &*self.role_store().await,
+                import,
+                changes,
+            )
+            .await?;
+            import_posting_attachments(
+                &*json_store.role_store().await,
+                &*self.role_store().await,
+                import,
+            )
+            .await?;
+            import_items(
+                &*json_store.interview_store().await,
+                &*self.interview_store().await,
+                import,
+                changes,
+            )
+            .await?;
+            import_items(
+                &*json_store.question_store().await,
+                &*self.question_store().await,
+                import,
+                changes,
+            )
+            .await?;
+            import_items(
+                &*json_store.contact_store().await,
+                &*self.contact_store().await,
+                import,
+                changes,
+            )
+            .await?;
+            import_items(
+                &*json_store.reminder_store().await,
+                &*self.reminder_store().await,
+                import,
+                changes,
+            )
+            .await?;
+        }
+
+        // Everything is written along with the flag that says it's been done, so an import that
+        // fails part way is run again from the start next time rather than duplicating anything
+        let count = import.len();
+        connection
+            .import(import, JSON_IMPORTED_KEY, &count.to_string())
+            .await?;
+
+        self.get_search_index().await.invalidate();
+        for change in changes {
+            self.get_change_sender().send(change);
+        }
+        Ok(ImportedJson { count, load_report })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::test_helper::{all_without_timestamps, without_timestamps};
+
+    #[tokio::test]
+    async fn test_new_sqlite() {
+        let path = tempfile::tempdir()
+            .unwrap()
+            .keep()
+            .join("nested")
+            .join("storage.sqlite");
+
+        let mut store = SqliteThreadSafeGeneralStore::new_sqlite(path.clone())
+            .await
+            .unwrap();
+        let company = Company::new("company");
+        store.store(company.clone()).await.unwrap();
+        drop(store);
+
+        let store = SqliteThreadSafeGeneralStore::new_sqlite(path)
+            .await
+            .unwrap();
+        let recalled_company: Company = store.recall_by_id(company.id).await.unwrap();
+        assert_eq!(without_timestamps(recalled_company), company);
+    }
+
+    #[tokio::test]
+    async fn test_import_json() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+        let json_path = base_path.join("storage");
+
+        let company = Company::new("company");
+        // Stored to the minute, as they are in JSON files
+        let mut role = company.create_role("role", Timestamp::from_string("2025-01-02T03:04"));
+        let mut deleted_flag = company.create_red_flag("deleted");
+        deleted_flag.date_deleted = Some(Timestamp::now());
+        let question = role.create_question("question", "answer");
+
+        let mut json_store = JsonThreadSafeGeneralStore::new_json(json_path.clone())
+            .await
+            .unwrap();
+        json_store.store(company.clone()).await.unwrap();
+        json_store.store(role.clone()).await.unwrap();
+        role.name = "renamed role".to_string();
+        json_store.store(role.clone()).await.unwrap();
+        json_store.store(deleted_flag.clone()).await.unwrap();
+        json_store.store(question.clone()).await.unwrap();
+
+        let store = SqliteThreadSafeGeneralStore::new_sqlite(base_path.join("storage.sqlite"))
+            .await
+            .unwrap();
+        assert_eq!(store.import_json(json_path.clone()).await.unwrap().count, 4);
+
+        let recalled_company: Company = store.recall_by_id(company.id).await.unwrap();
+        let recalled_role: Role = store.recall_by_id(role.id).await.unwrap();
+        let recalled_questions: Vec<Question> = store.recall_by_role(role.id).await.unwrap();
+        assert_eq!(without_timestamps(recalled_company), company);
+        assert_eq!(without_timestamps(recalled_role), role);
+        assert_eq!(all_without_timestamps(recalled_questions), vec![question]);
+
+        // Along with their history
+        let revisions = store.recall_revisions(&role).await.unwrap();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].get_changes()[0].field, "name");
+
+        // And can be found
+        let results = store.search("renamed").await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, role.id);
+
+        // Deleted items come across but stay deleted
+        let flags: Vec<Flag> = store.recall_by_company(company.id).await.unwrap();
+        assert!(flags.is_empty());
+
+        // The import only happens once
+        json_store.store(Company::new("added later")).await.unwrap();
+        assert_eq!(store.import_json(json_path).await.unwrap().count, 0);
+        let companies: Vec<Company> = store.recall_by_name("added later").await.unwrap();
+        assert!(companies.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_import_json_with_captured_posting() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+        let json_path = base_path.join("storage");
+        let html = "<h1>Engineer</h1><p>Write code</p>";
+
+        let company = Company::new("company");
+        let role = company.create_role("role", Timestamp::now());
+        let mut json_store = JsonThreadSafeGeneralStore::new_json(json_path.clone())
+            .await
+            .unwrap();
+        json_store.store(company).await.unwrap();
+        json_store.store(role.clone()).await.unwrap();
+        let captured = json_store
+            .capture_posting(
+                &role,
+                &Posting::parse(html),
+                Timestamp::from_string("2025-01-02T03:04"),
+            )
+            .await
+            .unwrap();
+
+        let store = SqliteThreadSafeGeneralStore::new_sqlite(base_path.join("storage.sqlite"))
+            .await
+            .unwrap();
+        assert_eq!(store.import_json(json_path).await.unwrap().count, 2);
+
+        let recalled_role: Role = store.recall_by_id(role.id).await.unwrap();
+        assert_eq!(recalled_role.posting, captured.posting);
+        assert_eq!(store.recall_posting_html(&role).await.unwrap(), html);
+    }
+
+    #[tokio::test]
+    async fn test_import_json_that_fails_can_be_run_again() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+        let json_path = base_path.join("storage");
+
+        let company = Company::new("company");
+        let mut role = company.create_role("role", Timestamp::now());
+        let reminder = company.create_reminder("reminder", Timestamp::now());
+        let mut json_store = JsonThreadSafeGeneralStore::new_json(json_path.clone())
+            .await
+            .unwrap();
+        json_store.store(company.clone()).await.unwrap();
+        json_store.store(role.clone()).await.unwrap();
+        role.name = "renamed role".to_string();
+        json_store.store(role.clone()).await.unwrap();
+        json_store.store(reminder).await.unwrap();
+
+        let store = SqliteThreadSafeGeneralStore::new_sqlite(base_path.join("storage.sqlite"))
+            .await
+            .unwrap();
+        let connection = store.company_store().await.get_connection().clone();
+        connection
+            .lock()
+            .unwrap()
+            .execute_batch(
+                "CREATE TRIGGER no_reminders BEFORE INSERT ON reminder
+                    BEGIN SELECT RAISE(ABORT, 'no reminders'); END;",
+            )
+            .unwrap();
+        assert!(store.import_json(json_path.clone()).await.is_err());
+
+        // The companies and roles written before the reminders are gone again
+        let companies: Vec<Company> = store.recall_by_name("").await.unwrap();
+        assert!(companies.is_empty());
+        assert!(store.recall_revisions(&role).await.unwrap().is_empty());
+
+        connection
+            .lock()
+            .unwrap()
+            .execute_batch("DROP TRIGGER no_reminders;")
+            .unwrap();
+        assert_eq!(store.import_json(json_path).await.unwrap().count, 3);
+        assert_eq!(store.recall_revisions(&role).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_import_json_reports_quarantined_files() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+        let json_path = base_path.join("storage");
+        std::fs::create_dir_all(json_path.join("company")).unwrap();
+        std::fs::write(json_path.join("company").join("broken.json"), "[").unwrap();
+
+        let store = SqliteThreadSafeGeneralStore::new_sqlite(base_path.join("storage.sqlite"))
+            .await
+            .unwrap();
+        let imported = store.import_json(json_path.clone()).await.unwrap();
+        assert_eq!(imported.count, 0);
+        assert_eq!(imported.load_report.get_quarantined().len(), 1);
+        assert_eq!(imported.load_report.get_quarantined()[0].store, "company");
+
+        let imported = store.import_json(json_path).await.unwrap();
+        assert!(imported.load_report.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_import_json_missing_directory() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+        let store = SqliteThreadSafeGeneralStore::new_sqlite(base_path.join("storage.sqlite"))
+            .await
+            .unwrap();
+        assert_eq!(
+            store
+                .import_json(base_path.join("missing"))
+                .await
+                .unwrap()
+                .count,
+            0
+        );
+        assert!(!base_path.join("missing").exists());
+    }
+}
@@ -1,5 +1,5 @@
 use crate::composite_store::ThreadSafeGeneralStore;
-use crate::prelude::{Company, Flag, Interview, Question, Role, Value};
+use crate::prelude::{Company, Contact, Flag, Interview, Question, Reminder, Role, Value};
 use crate::storage::StubStore;
 
 pub type StubThreadSafeGeneralStore = ThreadSafeGeneralStore<
@@ -9,6 +9,8 @@ pub type StubThreadSafeGeneralStore = ThreadSafeGeneralStore<
     StubStore<Role>,
     StubStore<Interview>,
     StubStore<Question>,
+    StubStore<Contact>,
+    StubStore<Reminder>,
 >;
 
 impl StubThreadSafeGeneralStore {
@@ -26,6 +28,8 @@ impl Default for StubThreadSafeGeneralStore {
             StubStore::default(),
             StubStore::default(),
             StubStore::default(),
+            StubStore::default(),
+            StubStore::default(),
         )
     }
 }
@@ -34,7 +38,7 @@ impl Default for StubThreadSafeGeneralStore {
 mod tests {
     use super::*;
     use crate::prelude::*;
-    use crate::test_helper::TestHelper;
+    use crate::test_helper::{without_timestamps, TestHelper};
 
     #[tokio::test]
     async fn test_new_stub() {
@@ -42,6 +46,6 @@ mod tests {
         let company = Company::new_test().await.unwrap();
         stub_store.store(company.clone()).await.unwrap();
         let recalled_company: Company = stub_store.recall_by_id(company.id).await.unwrap();
-        assert_eq!(company, recalled_company);
+        assert_eq!(company, without_timestamps(recalled_company));
     }
 }
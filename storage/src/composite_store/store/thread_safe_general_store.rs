@@ -1,12 +1,16 @@
-use crate::composite_store::HasFutureStoreFor;
+use crate::composite_store::{HasChanges, HasFutureStoreFor, HasSearchIndex};
 use crate::storable::*;
 use crate::storage::*;
-use crate::Sealed;
+use crate::{
+    Change, ChangeAction, ChangeReceiver, ChangeSender, Sealed, SearchIndex, StorageError,
+    StorageResult, Timestamp,
+};
 use std::sync::Arc;
-use tokio::sync::{Mutex, MutexGuard};
+use tokio::sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use uuid::Uuid;
 
 #[derive(Clone)]
-pub struct ThreadSafeGeneralStore<C, F, V, R, I, Q>
+pub struct ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
 where
     C: CompanyStore,
     F: FlagStore,
@@ -14,16 +18,23 @@ where
     R: RoleStore,
     I: InterviewStore,
     Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
 {
-    company_store: Arc<Mutex<C>>,
-    flag_store: Arc<Mutex<F>>,
-    value_store: Arc<Mutex<V>>,
-    role_store: Arc<Mutex<R>>,
-    interview_store: Arc<Mutex<I>>,
-    question_store: Arc<Mutex<Q>>,
+    company_store: Arc<RwLock<C>>,
+    flag_store: Arc<RwLock<F>>,
+    value_store: Arc<RwLock<V>>,
+    role_store: Arc<RwLock<R>>,
+    interview_store: Arc<RwLock<I>>,
+    question_store: Arc<RwLock<Q>>,
+    contact_store: Arc<RwLock<P>>,
+    reminder_store: Arc<RwLock<M>>,
+    search_index: Arc<Mutex<SearchIndex>>,
+    changes: ChangeSender,
+    pub(super) check_parents: bool,
 }
 
-impl<C, F, V, R, I, Q> ThreadSafeGeneralStore<C, F, V, R, I, Q>
+impl<C, F, V, R, I, Q, P, M> ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
 where
     C: CompanyStore,
     F: FlagStore,
@@ -31,7 +42,11 @@ where
     R: RoleStore,
     I: InterviewStore,
     Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
 {
+    // One store for each kind of storable
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         company_store: C,
         flag_store: F,
@@ -39,43 +54,281 @@ where
         role_store: R,
         interview_store: I,
         question_store: Q,
+        contact_store: P,
+        reminder_store: M,
     ) -> Self {
         Self {
-            company_store: Arc::new(Mutex::new(company_store)),
-            flag_store: Arc::new(Mutex::new(flag_store)),
-            value_store: Arc::new(Mutex::new(value_store)),
-            role_store: Arc::new(Mutex::new(role_store)),
-            interview_store: Arc::new(Mutex::new(interview_store)),
-            question_store: Arc::new(Mutex::new(question_store)),
+            company_store: Arc::new(RwLock::new(company_store)),
+            flag_store: Arc::new(RwLock::new(flag_store)),
+            value_store: Arc::new(RwLock::new(value_store)),
+            role_store: Arc::new(RwLock::new(role_store)),
+            interview_store: Arc::new(RwLock::new(interview_store)),
+            question_store: Arc::new(RwLock::new(question_store)),
+            contact_store: Arc::new(RwLock::new(contact_store)),
+            reminder_store: Arc::new(RwLock::new(reminder_store)),
+            search_index: Arc::new(Mutex::new(SearchIndex::default())),
+            changes: ChangeSender::default(),
+            check_parents: true,
         }
     }
 
-    pub async fn company_store<'a>(&'a self) -> MutexGuard<'a, C> {
-        self.company_store.lock().await
+    /// Allows children to be stored without their parents, only useful for tests that don't care
+    /// about the relationships between storables
+    pub fn without_parent_checks(mut self) -> Self {
+        self.check_parents = false;
+        self
     }
 
-    pub async fn flag_store<'a>(&'a self) -> MutexGuard<'a, F> {
-        self.flag_store.lock().await
+    /// Everything that changes in any of the stores from now on
+    pub fn subscribe(&self) -> ChangeReceiver {
+        self.changes.subscribe()
     }
 
-    pub async fn role_store<'a>(&'a self) -> MutexGuard<'a, R> {
-        self.role_store.lock().await
+    /// A live child needs a live parent, a deleted child only needs its parent to still be in the
+    /// trash
+    async fn check_company_exists<O: HasDeleted>(
+        &self,
+        child: &O,
+        company_id: Uuid,
+    ) -> StorageResult<()> {
+        if !self.check_parents
+            || parent_exists(&*self.company_store().await, company_id, child.is_deleted()).await?
+        {
+            return Ok(());
+        }
+        Err(StorageError::MissingParent {
+            parent: "company",
+            id: company_id,
+        })
+    }
+
+    async fn check_role_exists<O: HasDeleted>(
+        &self,
+        child: &O,
+        role_id: Uuid,
+    ) -> StorageResult<()> {
+        if !self.check_parents
+            || parent_exists(&*self.role_store().await, role_id, child.is_deleted()).await?
+        {
+            return Ok(());
+        }
+        Err(StorageError::MissingParent {
+            parent: "role",
+            id: role_id,
+        })
+    }
+
+    async fn check_interview_exists<O: HasDeleted>(
+        &self,
+        child: &O,
+        interview_id: Uuid,
+    ) -> StorageResult<()> {
+        if !self.check_parents
+            || parent_exists(
+                &*self.interview_store().await,
+                interview_id,
+                child.is_deleted(),
+            )
+            .await?
+        {
+            return Ok(());
+        }
+        Err(StorageError::MissingParent {
+            parent: "interview",
+            id: interview_id,
+        })
+    }
+
+    pub async fn company_store<'a>(&'a self) -> RwLockReadGuard<'a, C> {
+        self.company_store.read().await
+    }
+
+    pub async fn company_store_mut<'a>(&'a self) -> RwLockWriteGuard<'a, C> {
+        self.company_store.write().await
+    }
+
+    pub async fn flag_store<'a>(&'a self) -> RwLockReadGuard<'a, F> {
+        self.flag_store.read().await
+    }
+
+    pub async fn flag_store_mut<'a>(&'a self) -> RwLockWriteGuard<'a, F> {
+        self.flag_store.write().await
+    }
+
+    pub async fn role_store<'a>(&'a self) -> RwLockReadGuard<'a, R> {
+        self.role_store.read().await
+    }
+
+    pub async fn role_store_mut<'a>(&'a self) -> RwLockWriteGuard<'a, R> {
+        self.role_store.write().await
+    }
+
+    pub async fn question_store<'a>(&'a self) -> RwLockReadGuard<'a, Q> {
+        self.question_store.read().await
+    }
+
+    pub async fn question_store_mut<'a>(&'a self) -> RwLockWriteGuard<'a, Q> {
+        self.question_store.write().await
+    }
+
+    pub async fn interview_store<'a>(&'a self) -> RwLockReadGuard<'a, I> {
+        self.interview_store.read().await
+    }
+
+    pub async fn interview_store_mut<'a>(&'a self) -> RwLockWriteGuard<'a, I> {
+        self.interview_store.write().await
     }
 
-    pub async fn question_store<'a>(&'a self) -> MutexGuard<'a, Q> {
-        self.question_store.lock().await
+    pub async fn value_store<'a>(&'a self) -> RwLockReadGuard<'a, V> {
+        self.value_store.read().await
     }
 
-    pub async fn interview_store<'a>(&'a self) -> MutexGuard<'a, I> {
-        self.interview_store.lock().await
+    pub async fn value_store_mut<'a>(&'a self) -> RwLockWriteGuard<'a, V> {
+        self.value_store.write().await
+    }
+
+    pub async fn contact_store<'a>(&'a self) -> RwLockReadGuard<'a, P> {
+        self.contact_store.read().await
+    }
+
+    pub async fn contact_store_mut<'a>(&'a self) -> RwLockWriteGuard<'a, P> {
+        self.contact_store.write().await
+    }
+
+    pub async fn reminder_store<'a>(&'a self) -> RwLockReadGuard<'a, M> {
+        self.reminder_store.read().await
+    }
+
+    pub async fn reminder_store_mut<'a>(&'a self) -> RwLockWriteGuard<'a, M> {
+        self.reminder_store.write().await
+    }
+
+    /// Permanently removes everything that was deleted before the cutoff, returning how many
+    /// items were removed
+    pub async fn purge_deleted_before(&self, cutoff: Timestamp) -> StorageResult<usize> {
+        let changes = &self.changes;
+        Ok(
+            purge_store_deleted_before(&mut *self.company_store_mut().await, cutoff, changes)
+                .await?
+                + purge_store_deleted_before(&mut *self.flag_store_mut().await, cutoff, changes)
+                    .await?
+                + purge_store_deleted_before(&mut *self.value_store_mut().await, cutoff, changes)
+                    .await?
+                + purge_store_deleted_before(&mut *self.role_store_mut().await, cutoff, changes)
+                    .await?
+                + purge_store_deleted_before(
+                    &mut *self.interview_store_mut().await,
+                    cutoff,
+                    changes,
+                )
+                .await?
+                + purge_store_deleted_before(
+                    &mut *self.question_store_mut().await,
+                    cutoff,
+                    changes,
+                )
+                .await?
+                + purge_store_deleted_before(&mut *self.contact_store_mut().await, cutoff, changes)
+                    .await?
+                + purge_store_deleted_before(
+                    &mut *self.reminder_store_mut().await,
+                    cutoff,
+                    changes,
+                )
+                .await?,
+        )
+    }
+}
+
+/// Whether there is a version of the storable in the store that hasn't been deleted
+pub(crate) async fn is_live<S, O>(store: &S, storable: &O) -> StorageResult<bool>
+where
+    S: RecallById<O>,
+    O: HasId + HasDeleted + Clone,
+{
+    match store.recall_by_id(storable.get_id()).await {
+        Ok(_) => Ok(true),
+        Err(e) if e.is_not_found() => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+pub(super) async fn parent_exists<S, O>(
+    store: &S,
+    id: Uuid,
+    include_deleted: bool,
+) -> StorageResult<bool>
+where
+    S: RecallById<O> + RecallDeleted<O>,
+    O: HasId + HasDeleted + Clone,
+{
+    match store.recall_by_id(id).await {
+        Ok(_) => return Ok(true),
+        Err(e) if e.is_not_found() => {}
+        Err(e) => return Err(e),
+    }
+    Ok(include_deleted
+        && store
+            .recall_deleted()
+            .await?
+            .iter()
+            .any(|parent| parent.get_id() == id))
+}
+
+async fn purge_store_deleted_before<S, O>(
+    store: &mut S,
+    cutoff: Timestamp,
+    changes: &ChangeSender,
+) -> StorageResult<usize>
+where
+    S: RecallDeleted<O> + Purge<O>,
+    O: Searchable + Clone,
+{
+    let mut purged = 0;
+    for item in store.recall_deleted().await? {
+        if item
+            .get_date_deleted()
+            .is_some_and(|date_deleted| date_deleted < cutoff)
+        {
+            let change = Change::new(ChangeAction::Deleted, &item);
+            store.purge(item).await?;
+            changes.send(change);
+            purged += 1;
+        }
     }
+    Ok(purged)
+}
+
+impl<C, F, V, R, I, Q, P, M> Sealed for ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
+where
+    C: CompanyStore,
+    F: FlagStore,
+    V: ValueStore,
+    R: RoleStore,
+    I: InterviewStore,
+    Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
+{
+}
 
-    pub async fn value_store<'a>(&'a self) -> MutexGuard<'a, V> {
-        self.value_store.lock().await
+impl<C, F, V, R, I, Q, P, M> HasChanges for ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
+where
+    C: CompanyStore,
+    F: FlagStore,
+    V: ValueStore,
+    R: RoleStore,
+    I: InterviewStore,
+    Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
+{
+    fn get_change_sender(&self) -> &ChangeSender {
+        &self.changes
     }
 }
 
-impl<C, F, V, R, I, Q> Sealed for ThreadSafeGeneralStore<C, F, V, R, I, Q>
+impl<C, F, V, R, I, Q, P, M> HasSearchIndex for ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
 where
     C: CompanyStore,
     F: FlagStore,
@@ -83,10 +336,16 @@ where
     R: RoleStore,
     I: InterviewStore,
     Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
 {
+    async fn get_search_index<'a>(&'a self) -> MutexGuard<'a, SearchIndex> {
+        self.search_index.lock().await
+    }
 }
 
-impl<C, F, V, R, I, Q> HasFutureStoreFor<Company> for ThreadSafeGeneralStore<C, F, V, R, I, Q>
+impl<C, F, V, R, I, Q, P, M> HasFutureStoreFor<Company>
+    for ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
 where
     C: CompanyStore,
     F: FlagStore,
@@ -94,18 +353,28 @@ where
     R: RoleStore,
     I: InterviewStore,
     Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
 {
     type Storage = C;
 
-    async fn get_store<'a>(&'a self) -> MutexGuard<'a, Self::Storage>
+    async fn get_store<'a>(&'a self) -> RwLockReadGuard<'a, Self::Storage>
     where
         Self::Storage: 'a,
     {
         self.company_store().await
     }
+
+    async fn get_store_mut<'a>(&'a self) -> RwLockWriteGuard<'a, Self::Storage>
+    where
+        Self::Storage: 'a,
+    {
+        self.company_store_mut().await
+    }
 }
 
-impl<C, F, V, R, I, Q> HasFutureStoreFor<Flag> for ThreadSafeGeneralStore<C, F, V, R, I, Q>
+impl<C, F, V, R, I, Q, P, M> HasFutureStoreFor<Flag>
+    for ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
 where
     C: CompanyStore,
     F: FlagStore,
@@ -113,18 +382,33 @@ where
     R: RoleStore,
     I: InterviewStore,
     Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
 {
     type Storage = F;
 
-    async fn get_store<'a>(&'a self) -> MutexGuard<'a, Self::Storage>
+    async fn get_store<'a>(&'a self) -> RwLockReadGuard<'a, Self::Storage>
     where
         Self::Storage: 'a,
     {
         self.flag_store().await
     }
+
+    async fn get_store_mut<'a>(&'a self) -> RwLockWriteGuard<'a, Self::Storage>
+    where
+        Self::Storage: 'a,
+    {
+        self.flag_store_mut().await
+    }
+
+    async fn check_parent(&self, storable: &Flag) -> StorageResult<()> {
+        self.check_company_exists(storable, storable.get_company_id())
+            .await
+    }
 }
 
-impl<C, F, V, R, I, Q> HasFutureStoreFor<Role> for ThreadSafeGeneralStore<C, F, V, R, I, Q>
+impl<C, F, V, R, I, Q, P, M> HasFutureStoreFor<Role>
+    for ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
 where
     C: CompanyStore,
     F: FlagStore,
@@ -132,18 +416,33 @@ where
     R: RoleStore,
     I: InterviewStore,
     Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
 {
     type Storage = R;
 
-    async fn get_store<'a>(&'a self) -> MutexGuard<'a, Self::Storage>
+    async fn get_store<'a>(&'a self) -> RwLockReadGuard<'a, Self::Storage>
     where
         Self::Storage: 'a,
     {
         self.role_store().await
     }
+
+    async fn get_store_mut<'a>(&'a self) -> RwLockWriteGuard<'a, Self::Storage>
+    where
+        Self::Storage: 'a,
+    {
+        self.role_store_mut().await
+    }
+
+    async fn check_parent(&self, storable: &Role) -> StorageResult<()> {
+        self.check_company_exists(storable, storable.get_company_id())
+            .await
+    }
 }
 
-impl<C, F, V, R, I, Q> HasFutureStoreFor<Interview> for ThreadSafeGeneralStore<C, F, V, R, I, Q>
+impl<C, F, V, R, I, Q, P, M> HasFutureStoreFor<Interview>
+    for ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
 where
     C: CompanyStore,
     F: FlagStore,
@@ -151,18 +450,33 @@ where
     R: RoleStore,
     I: InterviewStore,
     Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
 {
     type Storage = I;
 
-    async fn get_store<'a>(&'a self) -> MutexGuard<'a, Self::Storage>
+    async fn get_store<'a>(&'a self) -> RwLockReadGuard<'a, Self::Storage>
     where
         Self::Storage: 'a,
     {
         self.interview_store().await
     }
+
+    async fn get_store_mut<'a>(&'a self) -> RwLockWriteGuard<'a, Self::Storage>
+    where
+        Self::Storage: 'a,
+    {
+        self.interview_store_mut().await
+    }
+
+    async fn check_parent(&self, storable: &Interview) -> StorageResult<()> {
+        self.check_role_exists(storable, storable.get_role_id())
+            .await
+    }
 }
 
-impl<C, F, V, R, I, Q> HasFutureStoreFor<Question> for ThreadSafeGeneralStore<C, F, V, R, I, Q>
+impl<C, F, V, R, I, Q, P, M> HasFutureStoreFor<Question>
+    for ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
 where
     C: CompanyStore,
     F: FlagStore,
@@ -170,18 +484,33 @@ where
     R: RoleStore,
     I: InterviewStore,
     Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
 {
     type Storage = Q;
 
-    async fn get_store<'a>(&'a self) -> MutexGuard<'a, Self::Storage>
+    async fn get_store<'a>(&'a self) -> RwLockReadGuard<'a, Self::Storage>
     where
         Self::Storage: 'a,
     {
         self.question_store().await
     }
+
+    async fn get_store_mut<'a>(&'a self) -> RwLockWriteGuard<'a, Self::Storage>
+    where
+        Self::Storage: 'a,
+    {
+        self.question_store_mut().await
+    }
+
+    async fn check_parent(&self, storable: &Question) -> StorageResult<()> {
+        self.check_role_exists(storable, storable.get_role_id())
+            .await
+    }
 }
 
-impl<C, F, V, R, I, Q> HasFutureStoreFor<Value> for ThreadSafeGeneralStore<C, F, V, R, I, Q>
+impl<C, F, V, R, I, Q, P, M> HasFutureStoreFor<Value>
+    for ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
 where
     C: CompanyStore,
     F: FlagStore,
@@ -189,15 +518,104 @@ where
     R: RoleStore,
     I: InterviewStore,
     Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
 {
     type Storage = V;
 
-    async fn get_store<'a>(&'a self) -> MutexGuard<'a, Self::Storage>
+    async fn get_store<'a>(&'a self) -> RwLockReadGuard<'a, Self::Storage>
     where
         Self::Storage: 'a,
     {
         self.value_store().await
     }
+
+    async fn get_store_mut<'a>(&'a self) -> RwLockWriteGuard<'a, Self::Storage>
+    where
+        Self::Storage: 'a,
+    {
+        self.value_store_mut().await
+    }
+
+    async fn check_parent(&self, storable: &Value) -> StorageResult<()> {
+        self.check_company_exists(storable, storable.get_company_id())
+            .await
+    }
+}
+
+impl<C, F, V, R, I, Q, P, M> HasFutureStoreFor<Contact>
+    for ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
+where
+    C: CompanyStore,
+    F: FlagStore,
+    V: ValueStore,
+    R: RoleStore,
+    I: InterviewStore,
+    Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
+{
+    type Storage = P;
+
+    async fn get_store<'a>(&'a self) -> RwLockReadGuard<'a, Self::Storage>
+    where
+        Self::Storage: 'a,
+    {
+        self.contact_store().await
+    }
+
+    async fn get_store_mut<'a>(&'a self) -> RwLockWriteGuard<'a, Self::Storage>
+    where
+        Self::Storage: 'a,
+    {
+        self.contact_store_mut().await
+    }
+
+    async fn check_parent(&self, storable: &Contact) -> StorageResult<()> {
+        self.check_company_exists(storable, storable.get_company_id())
+            .await
+    }
+}
+
+impl<C, F, V, R, I, Q, P, M> HasFutureStoreFor<Reminder>
+    for ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
+where
+    C: CompanyStore,
+    F: FlagStore,
+    V: ValueStore,
+    R: RoleStore,
+    I: InterviewStore,
+    Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
+{
+    type Storage = M;
+
+    async fn get_store<'a>(&'a self) -> RwLockReadGuard<'a, Self::Storage>
+    where
+        Self::Storage: 'a,
+    {
+        self.reminder_store().await
+    }
+
+    async fn get_store_mut<'a>(&'a self) -> RwLockWriteGuard<'a, Self::Storage>
+    where
+        Self::Storage: 'a,
+    {
+        self.reminder_store_mut().await
+    }
+
+    async fn check_parent(&self, storable: &Reminder) -> StorageResult<()> {
+        self.check_company_exists(storable, storable.get_company_id())
+            .await?;
+        if let Some(role_id) = storable.role_id {
+            self.check_role_exists(storable, role_id).await?;
+        }
+        if let Some(interview_id) = storable.interview_id {
+            self.check_interview_exists(storable, interview_id).await?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -216,6 +634,8 @@ mod test_helper {
             StubStore<Role>,
             StubStore<Interview>,
             StubStore<Question>,
+            StubStore<Contact>,
+            StubStore<Reminder>,
         >
     {
         #[cfg(test)]
@@ -227,7 +647,11 @@ mod test_helper {
                 StubStore::default(),
                 StubStore::default(),
                 StubStore::default(),
-            );
+                StubStore::default(),
+                StubStore::default(),
+            )
+            // The generic recall tests create storables with made up parents
+            .without_parent_checks();
             Ok(store)
         }
     }
@@ -236,15 +660,20 @@ mod test_helper {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::composite_store::StubThreadSafeGeneralStore;
     use crate::storage::{
-        recall_by_company::test_helper::test_recall_by_company,
+        purge::test_helper::test_purge, recall_by_company::test_helper::test_recall_by_company,
         recall_by_id::test_helper::test_recall_by_id,
         recall_by_name::test_helper::test_recall_by_name,
+        recall_by_query::test_helper::test_recall_by_query,
         recall_by_role::test_helper::test_recall_by_role,
+        recall_deleted::test_helper::test_recall_deleted,
+        recall_revisions::test_helper::test_recall_revisions,
     };
     use crate::test_helper::*;
-    use crate::Timestamp;
+    use crate::{SearchKind, Timestamp};
     use paste::paste;
+    use std::time::Duration;
 
     test_recall_by_id!(ThreadSafeGeneralStore, Company);
     test_recall_by_id!(ThreadSafeGeneralStore, Flag);
@@ -252,17 +681,181 @@ mod tests {
     test_recall_by_id!(ThreadSafeGeneralStore, Role);
     test_recall_by_id!(ThreadSafeGeneralStore, Question);
     test_recall_by_id!(ThreadSafeGeneralStore, Interview);
+    test_recall_by_id!(ThreadSafeGeneralStore, Contact);
+    test_recall_by_id!(ThreadSafeGeneralStore, Reminder);
     test_recall_by_name!(ThreadSafeGeneralStore, Company);
     test_recall_by_name!(ThreadSafeGeneralStore, Flag);
     test_recall_by_name!(ThreadSafeGeneralStore, Value);
     test_recall_by_name!(ThreadSafeGeneralStore, Role);
     test_recall_by_name!(ThreadSafeGeneralStore, Question);
     test_recall_by_name!(ThreadSafeGeneralStore, Interview);
+    test_recall_by_name!(ThreadSafeGeneralStore, Contact);
+    test_recall_by_name!(ThreadSafeGeneralStore, Reminder);
     test_recall_by_company!(ThreadSafeGeneralStore, Flag);
     test_recall_by_company!(ThreadSafeGeneralStore, Value);
     test_recall_by_company!(ThreadSafeGeneralStore, Role);
+    test_recall_by_company!(ThreadSafeGeneralStore, Contact);
+    test_recall_by_company!(ThreadSafeGeneralStore, Reminder);
     test_recall_by_role!(ThreadSafeGeneralStore, Question);
     test_recall_by_role!(ThreadSafeGeneralStore, Interview);
+    test_recall_deleted!(ThreadSafeGeneralStore, Company);
+    test_recall_deleted!(ThreadSafeGeneralStore, Flag);
+    test_recall_deleted!(ThreadSafeGeneralStore, Role);
+    test_recall_deleted!(ThreadSafeGeneralStore, Value);
+    test_recall_deleted!(ThreadSafeGeneralStore, Question);
+    test_recall_deleted!(ThreadSafeGeneralStore, Interview);
+    test_recall_deleted!(ThreadSafeGeneralStore, Contact);
+    test_recall_deleted!(ThreadSafeGeneralStore, Reminder);
+    test_purge!(ThreadSafeGeneralStore, Company);
+    test_purge!(ThreadSafeGeneralStore, Flag);
+    test_purge!(ThreadSafeGeneralStore, Role);
+    test_purge!(ThreadSafeGeneralStore, Value);
+    test_purge!(ThreadSafeGeneralStore, Question);
+    test_purge!(ThreadSafeGeneralStore, Interview);
+    test_purge!(ThreadSafeGeneralStore, Contact);
+    test_purge!(ThreadSafeGeneralStore, Reminder);
+    test_recall_revisions!(ThreadSafeGeneralStore, Company);
+    test_recall_revisions!(ThreadSafeGeneralStore, Flag);
+    test_recall_revisions!(ThreadSafeGeneralStore, Role);
+    test_recall_revisions!(ThreadSafeGeneralStore, Value);
+    test_recall_revisions!(ThreadSafeGeneralStore, Question);
+    test_recall_revisions!(ThreadSafeGeneralStore, Interview);
+    test_recall_revisions!(ThreadSafeGeneralStore, Contact);
+    test_recall_revisions!(ThreadSafeGeneralStore, Reminder);
+    test_recall_by_query!(ThreadSafeGeneralStore, Company);
+    test_recall_by_query!(ThreadSafeGeneralStore, Flag);
+    test_recall_by_query!(ThreadSafeGeneralStore, Role);
+    test_recall_by_query!(ThreadSafeGeneralStore, Value);
+    test_recall_by_query!(ThreadSafeGeneralStore, Question);
+    test_recall_by_query!(ThreadSafeGeneralStore, Interview);
+    test_recall_by_query!(ThreadSafeGeneralStore, Contact);
+    test_recall_by_query!(ThreadSafeGeneralStore, Reminder);
+
+    #[tokio::test]
+    async fn test_purge_deleted_before() {
+        let company = Company::new("company");
+        let mut old_role = company.create_role("old", Timestamp::now());
+        old_role.delete(Timestamp::from_timestamp(1_000));
+        let mut recent_flag = company.create_red_flag("recent");
+        recent_flag.delete(Timestamp::from_timestamp(3_000));
+
+        let mut all_store = ThreadSafeGeneralStore::new_test().await.unwrap();
+        all_store.store(company.clone()).await.unwrap();
+        all_store.store(old_role.clone()).await.unwrap();
+        all_store.store(recent_flag.clone()).await.unwrap();
+
+        let purged = all_store
+            .purge_deleted_before(Timestamp::from_timestamp(2_000))
+            .await
+            .unwrap();
+        assert_eq!(purged, 1);
+
+        let deleted_roles: Vec<Role> = all_store.recall_deleted().await.unwrap();
+        let deleted_flags: Vec<Flag> = all_store.recall_deleted().await.unwrap();
+        let recalled_company: Company = all_store.recall_by_id(&company).await.unwrap();
+        assert!(deleted_roles.is_empty());
+        assert_eq!(all_without_timestamps(deleted_flags), vec![recent_flag]);
+        assert_eq!(without_timestamps(recalled_company), company);
+    }
+
+    #[tokio::test]
+    async fn test_changes() {
+        let mut all_store = ThreadSafeGeneralStore::new_test().await.unwrap();
+        let mut changes = all_store.subscribe();
+        let mut company = Company::new("company");
+
+        all_store.store(company.clone()).await.unwrap();
+        assert_eq!(
+            changes.try_recv(),
+            Some(Change::new(ChangeAction::Created, &company))
+        );
+
+        company.name = "renamed".to_string();
+        all_store.store(company.clone()).await.unwrap();
+        assert_eq!(
+            changes.try_recv(),
+            Some(Change::new(ChangeAction::Updated, &company))
+        );
+
+        company.delete(Timestamp::now());
+        all_store.store(company.clone()).await.unwrap();
+        assert_eq!(
+            changes.try_recv(),
+            Some(Change::new(ChangeAction::Deleted, &company))
+        );
+
+        // Coming back out of the trash
+        company.restore();
+        all_store.store(company.clone()).await.unwrap();
+        assert_eq!(
+            changes.try_recv(),
+            Some(Change::new(ChangeAction::Created, &company))
+        );
+
+        company.delete(Timestamp::now());
+        all_store.store(company.clone()).await.unwrap();
+        changes.try_recv().unwrap();
+        all_store.purge(company.clone()).await.unwrap();
+        let purged = changes.try_recv().unwrap();
+        assert_eq!(purged.action, ChangeAction::Deleted);
+        assert_eq!(purged.kind, SearchKind::Company);
+        assert_eq!(changes.try_recv(), None);
+    }
+
+    #[tokio::test]
+    async fn test_failed_store_sends_no_change() {
+        let mut all_store = StubThreadSafeGeneralStore::new_stub();
+        let mut changes = all_store.subscribe();
+        let orphan = Company::new("company").create_role("role", Timestamp::now());
+
+        assert!(all_store.store(orphan).await.is_err());
+        assert_eq!(changes.try_recv(), None);
+    }
+
+    #[tokio::test]
+    async fn test_reads_share_the_store() {
+        let mut all_store = StubThreadSafeGeneralStore::new_stub();
+        let company = Company::new("company");
+        all_store.store(company.clone()).await.unwrap();
+
+        // Another reader is part way through using the company store
+        let reader = all_store.company_store().await;
+        let reads = async {
+            tokio::join!(
+                RecallById::<Company>::recall_by_id(&all_store, &company),
+                RecallById::<Company>::recall_by_id(&all_store, &company),
+                RecallByName::<Company>::recall_by_name(&all_store, "company"),
+            )
+        };
+        let (first, second, by_name) = tokio::time::timeout(Duration::from_secs(1), reads)
+            .await
+            .expect("Reads waited on each other");
+        assert_eq!(first.unwrap().id, company.id);
+        assert_eq!(second.unwrap().id, company.id);
+        assert_eq!(by_name.unwrap().len(), 1);
+
+        // Writes still wait until nobody is reading
+        let mut writer = all_store.clone();
+        let mut write = Box::pin(writer.store(Company::new("other")));
+        let waited = tokio::time::timeout(Duration::from_millis(50), &mut write).await;
+        assert!(waited.is_err());
+        drop(reader);
+        write.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_shared_reads_are_not_copied() {
+        let mut all_store = StubThreadSafeGeneralStore::new_stub();
+        let company = Company::new("company");
+        let role = company.create_role("role", Timestamp::now());
+        all_store.store(company.clone()).await.unwrap();
+        all_store.store(role.clone()).await.unwrap();
+
+        let first: std::sync::Arc<Role> = all_store.recall_shared_by_id(&role).await.unwrap();
+        let by_company: Vec<std::sync::Arc<Role>> =
+            all_store.recall_shared_by_company(&company).await.unwrap();
+        assert!(std::sync::Arc::ptr_eq(&first, &by_company[0]));
+    }
 
     // ---- The following tests are more to show how the API of ThreadSafeGeneralStore ----
 
@@ -280,6 +873,8 @@ mod tests {
             StubStore::default(),
             StubStore::default(),
             StubStore::default(),
+            StubStore::default(),
+            StubStore::default(),
         );
 
         all_store.store(company.clone()).await.unwrap();
@@ -292,10 +887,10 @@ mod tests {
         let recalled_role: Role = all_store.recall_by_id(&role).await.unwrap();
         let recalled_question: Question = all_store.recall_by_id(&question).await.unwrap();
 
-        assert_eq!(recalled_company, company);
-        assert_eq!(recalled_flag, flag);
-        assert_eq!(recalled_role, role);
-        assert_eq!(recalled_question, question);
+        assert_eq!(without_timestamps(recalled_company), company);
+        assert_eq!(without_timestamps(recalled_flag), flag);
+        assert_eq!(without_timestamps(recalled_role), role);
+        assert_eq!(without_timestamps(recalled_question), question);
     }
 
     #[tokio::test]
@@ -309,6 +904,8 @@ mod tests {
             StubStore::default(),
             StubStore::default(),
             StubStore::default(),
+            StubStore::default(),
+            StubStore::default(),
         );
 
         all_store.store(company.clone()).await.unwrap();
@@ -316,7 +913,7 @@ mod tests {
         let recalled_companies: Vec<Company> =
             all_store.recall_by_name(&company.name).await.unwrap();
 
-        assert!(recalled_companies.contains(&company));
+        assert!(all_without_timestamps(recalled_companies).contains(&company));
     }
 
     #[tokio::test]
@@ -332,16 +929,19 @@ mod tests {
             StubStore::default(),
             StubStore::default(),
             StubStore::default(),
+            StubStore::default(),
+            StubStore::default(),
         );
 
+        all_store.store(company.clone()).await.unwrap();
         all_store.store(flag.clone()).await.unwrap();
         all_store.store(role.clone()).await.unwrap();
 
         let recalled_flags: Vec<Flag> = all_store.recall_by_company(&company).await.unwrap();
         let recalled_roles: Vec<Role> = all_store.recall_by_company(&company).await.unwrap();
 
-        assert!(recalled_flags.contains(&flag));
-        assert!(recalled_roles.contains(&role));
+        assert!(all_without_timestamps(recalled_flags).contains(&flag));
+        assert!(all_without_timestamps(recalled_roles).contains(&role));
     }
 
     #[tokio::test]
@@ -357,12 +957,16 @@ mod tests {
             StubStore::default(),
             StubStore::default(),
             StubStore::default(),
+            StubStore::default(),
+            StubStore::default(),
         );
 
+        all_store.store(company.clone()).await.unwrap();
+        all_store.store(role.clone()).await.unwrap();
         all_store.store(question.clone()).await.unwrap();
 
         let recalled_questions: Vec<Question> = all_store.recall_by_role(&role).await.unwrap();
 
-        assert!(recalled_questions.contains(&question));
+        assert!(all_without_timestamps(recalled_questions).contains(&question));
     }
 }
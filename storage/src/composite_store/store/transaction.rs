@@ -0,0 +1,572 @@
+use super::thread_safe_general_store::{is_live, parent_exists};
+use crate::composite_store::{HasChanges, HasSearchIndex, ThreadSafeGeneralStore};
+use crate::storable::*;
+use crate::storage::*;
+use crate::{Change, StorageError, StorageResult};
+use std::future::Future;
+use tokio::sync::RwLockWriteGuard;
+use uuid::Uuid;
+
+/// Everything a transaction will write, grouped by store
+#[derive(Default)]
+pub struct StagedWrites {
+    companies: Vec<Company>,
+    flags: Vec<Flag>,
+    values: Vec<Value>,
+    roles: Vec<Role>,
+    interviews: Vec<Interview>,
+    questions: Vec<Question>,
+    contacts: Vec<Contact>,
+    reminders: Vec<Reminder>,
+}
+
+/// Storables that can be written as part of a [`Transaction`]
+pub trait Staged: Sized {
+    fn staged(writes: &mut StagedWrites) -> &mut Vec<Self>;
+}
+
+macro_rules! impl_staged {
+    ($storable:ty, $field:ident) => {
+        impl Staged for $storable {
+            fn staged(writes: &mut StagedWrites) -> &mut Vec<Self> {
+                &mut writes.$field
+            }
+        }
+    };
+}
+
+impl_staged!(Company, companies);
+impl_staged!(Flag, flags);
+impl_staged!(Value, values);
+impl_staged!(Role, roles);
+impl_staged!(Interview, interviews);
+impl_staged!(Question, questions);
+impl_staged!(Contact, contacts);
+impl_staged!(Reminder, reminders);
+
+/// Writes to several stores that either all happen or none of them do.
+///
+/// Nothing is written until the transaction is committed, dropping it without committing throws
+/// the staged writes away.
+pub struct Transaction<'a, C, F, V, R, I, Q, P, M>
+where
+    C: CompanyStore,
+    F: FlagStore,
+    V: ValueStore,
+    R: RoleStore,
+    I: InterviewStore,
+    Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
+{
+    store: &'a ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>,
+    writes: StagedWrites,
+}
+
+impl<C, F, V, R, I, Q, P, M> ThreadSafeGeneralStore<C, F, V, R, I, Q, P, M>
+where
+    C: CompanyStore,
+    F: FlagStore,
+    V: ValueStore,
+    R: RoleStore,
+    I: InterviewStore,
+    Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
+{
+    pub fn transaction(&self) -> Transaction<'_, C, F, V, R, I, Q, P, M> {
+        Transaction {
+            store: self,
+            writes: StagedWrites::default(),
+        }
+    }
+}
+
+impl<C, F, V, R, I, Q, P, M> Transaction<'_, C, F, V, R, I, Q, P, M>
+where
+    C: CompanyStore,
+    F: FlagStore,
+    V: ValueStore,
+    R: RoleStore,
+    I: InterviewStore,
+    Q: QuestionStore,
+    P: ContactStore,
+    M: ReminderStore,
+{
+    /// Stages a write, the same storable can be staged more than once
+    pub fn store<O: Staged>(&mut self, storable: O) {
+        O::staged(&mut self.writes).push(storable);
+    }
+
+    /// Stages a write for each of them
+    pub fn store_all<O: Staged>(&mut self, storables: impl IntoIterator<Item = O>) {
+        O::staged(&mut self.writes).extend(storables);
+    }
+
+    /// Makes every staged write, or none of them if any one fails.
+    ///
+    /// The stores that are written to, along with the stores of their parents, stay locked until
+    /// the commit is over. They are always locked in the same order so that commits can't end up
+    /// waiting on each other. Parents are written before their children, so a new company and its
+    /// first role can be committed together.
+    pub async fn commit(self) -> StorageResult<()> {
+        let Self { store, writes } = self;
+        let needs_companies = !writes.companies.is_empty()
+            || !writes.flags.is_empty()
+            || !writes.values.is_empty()
+            || !writes.roles.is_empty()
+            || !writes.contacts.is_empty()
+            || !writes.reminders.is_empty();
+        let needs_roles = !writes.roles.is_empty()
+            || !writes.interviews.is_empty()
+            || !writes.questions.is_empty()
+            || writes
+                .reminders
+                .iter()
+                .any(|reminder| reminder.role_id.is_some());
+        let needs_interviews = !writes.interviews.is_empty()
+            || writes
+                .reminders
+                .iter()
+                .any(|reminder| reminder.interview_id.is_some());
+
+        let mut company_store = lock_if(needs_companies, store.company_store_mut()).await;
+        let mut flag_store = lock_if(!writes.flags.is_empty(), store.flag_store_mut()).await;
+        let mut value_store = lock_if(!writes.values.is_empty(), store.value_store_mut()).await;
+        let mut role_store = lock_if(needs_roles, store.role_store_mut()).await;
+        let mut interview_store = lock_if(needs_interviews, store.interview_store_mut()).await;
+        let mut question_store =
+            lock_if(!writes.questions.is_empty(), store.question_store_mut()).await;
+        let mut contact_store =
+            lock_if(!writes.contacts.is_empty(), store.contact_store_mut()).await;
+        let mut reminder_store =
+            lock_if(!writes.reminders.is_empty(), store.reminder_store_mut()).await;
+
+        let mut company_snapshots = Vec::new();
+        let mut flag_snapshots = Vec::new();
+        let mut value_snapshots = Vec::new();
+        let mut role_snapshots = Vec::new();
+        let mut interview_snapshots = Vec::new();
+        let mut question_snapshots = Vec::new();
+        let mut contact_snapshots = Vec::new();
+        let mut reminder_snapshots = Vec::new();
+        let mut changes = Vec::new();
+
+        let check_parents = store.check_parents;
+        let result = async {
+            if let Some(company_store) = company_store.as_deref_mut() {
+                write_all(
+                    company_store,
+                    &writes.companies,
+                    &mut company_snapshots,
+                    &mut changes,
+                )
+                .await?;
+            }
+            if let Some(flag_store) = flag_store.as_deref_mut() {
+                if check_parents {
+                    check_companies(company_store.as_deref(), &writes.flags).await?;
+                }
+                write_all(flag_store, &writes.flags, &mut flag_snapshots, &mut changes).await?;
+            }
+            if let Some(value_store) = value_store.as_deref_mut() {
+                if check_parents {
+                    check_companies(company_store.as_deref(), &writes.values).await?;
+                }
+                write_all(
+                    value_store,
+                    &writes.values,
+                    &mut value_snapshots,
+                    &mut changes,
+                )
+                .await?;
+            }
+            if let Some(contact_store) = contact_store.as_deref_mut() {
+                if check_parents {
+                    check_companies(company_store.as_deref(), &writes.contacts).await?;
+                }
+                write_all(
+                    contact_store,
+                    &writes.contacts,
+                    &mut contact_snapshots,
+                    &mut changes,
+                )
+                .await?;
+            }
+            if let Some(role_store) = role_store.as_deref_mut() {
+                if check_parents {
+                    check_companies(company_store.as_deref(), &writes.roles).await?;
+                }
+                write_all(role_store, &writes.roles, &mut role_snapshots, &mut changes).await?;
+            }
+            if let Some(interview_store) = interview_store.as_deref_mut() {
+                if check_parents {
+                    check_roles(role_store.as_deref(), &writes.interviews).await?;
+                }
+                write_all(
+                    interview_store,
+                    &writes.interviews,
+                    &mut interview_snapshots,
+                    &mut changes,
+                )
+                .await?;
+            }
+            if let Some(question_store) = question_store.as_deref_mut() {
+                if check_parents {
+                    check_roles(role_store.as_deref(), &writes.questions).await?;
+                }
+                write_all(
+                    question_store,
+                    &writes.questions,
+                    &mut question_snapshots,
+                    &mut changes,
+                )
+                .await?;
+            }
+            if let Some(reminder_store) = reminder_store.as_deref_mut() {
+                if check_parents {
+                    check_companies(company_store.as_deref(), &writes.reminders).await?;
+                    check_reminder_links(
+                        role_store.as_deref(),
+                        interview_store.as_deref(),
+                        &writes.reminders,
+                    )
+                    .await?;
+                }
+                write_all(
+                    reminder_store,
+                    &writes.reminders,
+                    &mut reminder_snapshots,
+                    &mut changes,
+                )
+                .await?;
+            }
+            Ok::<(), StorageError>(())
+        }
+        .await;
+
+        if let Err(error) = result {
+            // Undone in the opposite order to how they were written. One failing to roll back
+            // doesn't stop the rest from being tried.
+            let mut rollback_errors = Vec::new();
+            let errors = &mut rollback_errors;
+            roll_back_all(reminder_store.as_deref_mut(), reminder_snapshots, errors).await;
+            roll_back_all(question_store.as_deref_mut(), question_snapshots, errors).await;
+            roll_back_all(interview_store.as_deref_mut(), interview_snapshots, errors).await;
+            roll_back_all(role_store.as_deref_mut(), role_snapshots, errors).await;
+            roll_back_all(contact_store.as_deref_mut(), contact_snapshots, errors).await;
+            roll_back_all(value_store.as_deref_mut(), value_snapshots, errors).await;
+            roll_back_all(flag_store.as_deref_mut(), flag_snapshots, errors).await;
+            roll_back_all(company_store.as_deref_mut(), company_snapshots, errors).await;
+            if rollback_errors.is_empty() {
+                return Err(error);
+            }
+            return Err(StorageError::RollbackFailed {
+                error: Box::new(error),
+                rollback_errors,
+            });
+        }
+
+        // The search index is only ever locked before the stores, never after
+        drop((
+            company_store,
+            flag_store,
+            value_store,
+            role_store,
+            interview_store,
+            question_store,
+            contact_store,
+            reminder_store,
+        ));
+        let mut index = store.get_search_index().await;
+        update_index(&mut index, &writes.companies);
+        update_index(&mut index, &writes.flags);
+        update_index(&mut index, &writes.values);
+        update_index(&mut index, &writes.roles);
+        update_index(&mut index, &writes.interviews);
+        update_index(&mut index, &writes.questions);
+        update_index(&mut index, &writes.contacts);
+        update_index(&mut index, &writes.reminders);
+        drop(index);
+
+        for change in changes {
+            store.get_change_sender().send(change);
+        }
+        Ok(())
+    }
+}
+
+async fn lock_if<'a, S: 'a>(
+    needed: bool,
+    guard: impl Future<Output = RwLockWriteGuard<'a, S>>,
+) -> Option<RwLockWriteGuard<'a, S>> {
+    if needed {
+        Some(guard.await)
+    } else {
+        None
+    }
+}
+
+async fn write_all<S, O>(
+    store: &mut S,
+    storables: &[O],
+    snapshots: &mut Vec<S::Snapshot>,
+    changes: &mut Vec<Change>,
+) -> StorageResult<()>
+where
+    S: BaseStore<O> + RecallById<O> + Rollback<O>,
+    O: Searchable + Clone,
+{
+    for storable in storables {
+        snapshots.push(store.snapshot(storable.get_id()).await?);
+        let was_live = is_live(store, storable).await?;
+        store.store(storable.clone()).await?;
+        changes.push(Change::stored(storable, was_live));
+    }
+    Ok(())
+}
+
+async fn roll_back_all<S, O>(
+    store: Option<&mut S>,
+    snapshots: Vec<S::Snapshot>,
+    errors: &mut Vec<StorageError>,
+) where
+    S: Rollback<O>,
+    O: HasId + Clone,
+{
+    let Some(store) = store else {
+        return;
+    };
+    for snapshot in snapshots.into_iter().rev() {
+        if let Err(error) = store.roll_back(snapshot).await {
+            errors.push(error);
+        }
+    }
+}
+
+async fn check_companies<S, O>(company_store: Option<&S>, children: &[O]) -> StorageResult<()>
+where
+    S: CompanyStore,
+    O: HasCompany + HasDeleted,
+{
+    check_parents(company_store, children, "company", O::get_company_id).await
+}
+
+async fn check_roles<S, O>(role_store: Option<&S>, children: &[O]) -> StorageResult<()>
+where
+    S: RoleStore,
+    O: HasRole + HasDeleted,
+{
+    check_parents(role_store, children, "role", O::get_role_id).await
+}
+
+/// Reminders only need the role and interview they are for when they are for one
+async fn check_reminder_links<RS, IS>(
+    role_store: Option<&RS>,
+    interview_store: Option<&IS>,
+    reminders: &[Reminder],
+) -> StorageResult<()>
+where
+    RS: RoleStore,
+    IS: InterviewStore,
+{
+    for reminder in reminders {
+        let one = std::slice::from_ref(reminder);
+        if let Some(role_id) = reminder.role_id {
+            check_parents(role_store, one, "role", |_| role_id).await?;
+        }
+        if let Some(interview_id) = reminder.interview_id {
+            check_parents(interview_store, one, "interview", |_| interview_id).await?;
+        }
+    }
+    Ok(())
+}
+
+async fn check_parents<S, T, O>(
+    parents: Option<&S>,
+    children: &[O],
+    parent: &'static str,
+    get_parent_id: impl Fn(&O) -> Uuid,
+) -> StorageResult<()>
+where
+    S: RecallById<T> + RecallDeleted<T>,
+    T: HasId + HasDeleted + Clone,
+    O: HasDeleted,
+{
+    let Some(parents) = parents else {
+        return Ok(());
+    };
+    for child in children {
+        let id = get_parent_id(child);
+        if !parent_exists(parents, id, child.is_deleted()).await? {
+            return Err(StorageError::MissingParent { parent, id });
+        }
+    }
+    Ok(())
+}
+
+fn update_index<O: Searchable>(index: &mut crate::SearchIndex, storables: &[O]) {
+    for storable in storables {
+        index.update(storable);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    macro_rules! test_transaction {
+        ($name:ident, $new_store:expr) => {
+            paste::paste! {
+                #[tokio::test]
+                async fn [< test_commit_ $name >] () {
+                    let store = $new_store;
+                    let mut changes = store.subscribe();
+                    let company = Company::new("company");
+                    let role = company.create_role("role", Timestamp::now());
+                    let interview = role.create_interview("interview");
+
+                    // Staged children before their parents, the commit sorts that out
+                    let mut transaction = store.transaction();
+                    transaction.store(interview.clone());
+                    transaction.store(role.clone());
+                    transaction.store(company.clone());
+                    transaction.commit().await.unwrap();
+
+                    let recalled: Company = store.recall_by_id(&company).await.unwrap();
+                    assert_eq!(recalled.name, company.name);
+                    let recalled: Role = store.recall_by_id(&role).await.unwrap();
+                    assert_eq!(recalled.name, role.name);
+                    let recalled: Interview = store.recall_by_id(&interview).await.unwrap();
+                    assert_eq!(recalled.name, interview.name);
+
+                    let results = store.search("interview").await.unwrap();
+                    assert!(results.iter().any(|result| result.id == interview.id));
+
+                    assert_eq!(changes.try_recv(), Some(Change::new(ChangeAction::Created, &company)));
+                    assert_eq!(changes.try_recv(), Some(Change::new(ChangeAction::Created, &role)));
+                    assert_eq!(changes.try_recv(), Some(Change::new(ChangeAction::Created, &interview)));
+                    assert_eq!(changes.try_recv(), None);
+                }
+
+                #[tokio::test]
+                async fn [< test_failed_commit_rolls_back_ $name >] () {
+                    let mut store = $new_store;
+                    let mut company = Company::new("company");
+                    store.store(company.clone()).await.unwrap();
+                    let mut changes = store.subscribe();
+
+                    let role = company.create_role("role", Timestamp::now());
+                    let orphan = Company::new("other")
+                        .create_role("other", Timestamp::now())
+                        .create_interview("orphan");
+                    company.name = "renamed".to_string();
+
+                    let mut transaction = store.transaction();
+                    transaction.store(company.clone());
+                    transaction.store(role.clone());
+                    transaction.store(orphan);
+                    assert!(transaction.commit().await.is_err());
+
+                    let recalled: Company = store.recall_by_id(&company).await.unwrap();
+                    assert_eq!(recalled.name, "company");
+                    let revisions = store.recall_revisions(&recalled).await.unwrap();
+                    assert!(revisions.is_empty());
+                    let recalled: crate::StorageResult<Role> = store.recall_by_id(&role).await;
+                    assert!(recalled.is_err());
+                    assert!(store.search("renamed").await.unwrap().is_empty());
+                    assert_eq!(changes.try_recv(), None);
+                }
+
+                #[tokio::test]
+                async fn [< test_dropped_transaction_ $name >] () {
+                    let store = $new_store;
+                    let company = Company::new("company");
+
+                    let mut transaction = store.transaction();
+                    transaction.store(company.clone());
+                    drop(transaction);
+
+                    let recalled: crate::StorageResult<Company> = store.recall_by_id(&company).await;
+                    assert!(recalled.is_err());
+                }
+            }
+        };
+    }
+
+    test_transaction!(stub, StubThreadSafeGeneralStore::new_stub());
+    test_transaction!(
+        json,
+        JsonThreadSafeGeneralStore::new_json(tempfile::tempdir().unwrap().keep())
+            .await
+            .unwrap()
+    );
+
+    #[tokio::test]
+    async fn test_failed_roll_back_keeps_going() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+        let mut store = JsonThreadSafeGeneralStore::new_json(base_path.clone())
+            .await
+            .unwrap();
+        let mut company = Company::new("company");
+        let mut role = company.create_role("role", Timestamp::now());
+        store.store(company.clone()).await.unwrap();
+        store.store(role.clone()).await.unwrap();
+
+        // The role's history can't be added to, or cut back, while there is a directory in its
+        // place
+        let history_path = base_path
+            .join("role")
+            .join("history")
+            .join(format!("{}.json", role.id));
+        std::fs::create_dir_all(&history_path).unwrap();
+
+        company.name = "renamed".to_string();
+        role.name = "renamed".to_string();
+        let mut transaction = store.transaction();
+        transaction.store(company.clone());
+        transaction.store(role.clone());
+
+        match transaction.commit().await.unwrap_err() {
+            crate::StorageError::RollbackFailed {
+                error,
+                rollback_errors,
+            } => {
+                assert!(error.is_io());
+                assert_eq!(rollback_errors.len(), 1);
+                assert!(rollback_errors[0].is_io());
+            }
+            other => panic!("Expected the roll back to fail, got {other:?}"),
+        }
+        // The company comes after the role, so was still rolled back
+        let recalled: Company = store.recall_by_id(&company).await.unwrap();
+        assert_eq!(recalled.name, "company");
+    }
+
+    #[tokio::test]
+    async fn test_commit_reminder_checks_its_links() {
+        let store = StubThreadSafeGeneralStore::new_stub();
+        let company = Company::new("company");
+        let role = company.create_role("role", Timestamp::now());
+        let interview = role.create_interview("interview");
+        let reminder = role.create_reminder_for_interview(&interview, "thank", Timestamp::now());
+
+        let mut transaction = store.transaction();
+        transaction.store(company.clone());
+        transaction.store(role.clone());
+        transaction.store(reminder.clone());
+        let error = transaction.commit().await.unwrap_err();
+        assert!(error.is_missing_parent());
+        let recalled: crate::StorageResult<Role> = store.recall_by_id(&role).await;
+        assert!(recalled.is_err());
+
+        let mut transaction = store.transaction();
+        transaction.store(reminder.clone());
+        transaction.store(interview);
+        transaction.store(role);
+        transaction.store(company);
+        transaction.commit().await.unwrap();
+        let recalled: Reminder = store.recall_by_id(&reminder).await.unwrap();
+        assert_eq!(recalled.name, reminder.name);
+    }
+}
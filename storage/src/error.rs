@@ -1,4 +1,12 @@
+use crate::storable::IncompletePartialErrors;
+#[cfg(feature = "sqlite")]
+use rusqlite::ErrorCode;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
+use uuid::Uuid;
+
+pub type StorageResult<T> = Result<T, StorageError>;
 
 #[derive(Debug, Error)]
 pub enum StorageError {
@@ -6,9 +14,65 @@ pub enum StorageError {
     NotFound,
     #[error("Already Exists")]
     AlreadyExists,
+    /// Only something already in the trash can be purged
+    #[error("Not Deleted")]
+    NotDeleted,
+    #[error("The {parent} this belongs to ({id}) does not exist")]
+    MissingParent { parent: &'static str, id: Uuid },
+    #[error("Could not access {}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    /// For SQLite the file is the table the row came from
+    #[error("Could not read {}: {source}", file.display())]
+    Deserialize {
+        file: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error(transparent)]
+    Validation(#[from] IncompletePartialErrors),
+    /// What is stored doesn't fit together, such as a document without a schema version or a
+    /// broken database constraint
+    #[error("The stored data is inconsistent: {0}")]
+    IntegrityViolation(String),
+    /// Something else is using the store, trying again later should work
+    #[error("The store is busy")]
+    Locked,
+    /// A transaction failed part way through, and then some of what it had already written
+    /// couldn't be undone either
+    #[error("{error}, and {} of the writes before it could not be undone", rollback_errors.len())]
+    RollbackFailed {
+        #[source]
+        error: Box<StorageError>,
+        rollback_errors: Vec<StorageError>,
+    },
+    #[cfg(feature = "sqlite")]
+    #[error("Database error: {0}")]
+    Database(#[source] rusqlite::Error),
 }
 
 impl StorageError {
+    pub(crate) fn io<P: Into<PathBuf>>(path: P, source: std::io::Error) -> Self {
+        Self::Io {
+            path: path.into(),
+            source,
+        }
+    }
+
+    pub(crate) fn deserialize<P: Into<PathBuf>>(file: P, source: serde_json::Error) -> Self {
+        Self::Deserialize {
+            file: file.into(),
+            source,
+        }
+    }
+
+    pub(crate) fn integrity<E: Display>(error: E) -> Self {
+        Self::IntegrityViolation(error.to_string())
+    }
+
     pub fn is_not_found(&self) -> bool {
         matches!(self, StorageError::NotFound)
     }
@@ -16,17 +80,87 @@ impl StorageError {
     pub fn is_already_exists(&self) -> bool {
         matches!(self, StorageError::AlreadyExists)
     }
+
+    pub fn is_not_deleted(&self) -> bool {
+        matches!(self, StorageError::NotDeleted)
+    }
+
+    pub fn is_missing_parent(&self) -> bool {
+        matches!(self, StorageError::MissingParent { .. })
+    }
+
+    pub fn is_io(&self) -> bool {
+        matches!(self, StorageError::Io { .. })
+    }
+
+    pub fn is_deserialize(&self) -> bool {
+        matches!(self, StorageError::Deserialize { .. })
+    }
+
+    pub fn is_validation(&self) -> bool {
+        matches!(self, StorageError::Validation(_))
+    }
+
+    /// A missing parent counts, it's the most common way for stored data to stop fitting together
+    pub fn is_integrity_violation(&self) -> bool {
+        matches!(
+            self,
+            StorageError::IntegrityViolation(_) | StorageError::MissingParent { .. }
+        )
+    }
+
+    pub fn is_locked(&self) -> bool {
+        matches!(self, StorageError::Locked)
+    }
+
+    pub fn is_rollback_failed(&self) -> bool {
+        matches!(self, StorageError::RollbackFailed { .. })
+    }
+
+    /// Whether doing the same thing again could work without anything else changing first
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            StorageError::Io { .. } | StorageError::Locked => true,
+            #[cfg(feature = "sqlite")]
+            StorageError::Database(_) => true,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for StorageError {
+    fn from(error: rusqlite::Error) -> Self {
+        match error.sqlite_error_code() {
+            Some(ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked) => Self::Locked,
+            Some(ErrorCode::ConstraintViolation) => Self::integrity(error),
+            _ => Self::Database(error),
+        }
+    }
+}
+
+/// Attaches the path that was being accessed to an IO error
+pub(crate) trait IoResultExt<T> {
+    fn at_path(self, path: &Path) -> StorageResult<T>;
+}
+
+impl<T> IoResultExt<T> for std::io::Result<T> {
+    fn at_path(self, path: &Path) -> StorageResult<T> {
+        self.map_err(|e| StorageError::io(path, e))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::storable::RoleFieldName;
 
     #[test]
     fn test_not_found() {
         let error = StorageError::NotFound;
         assert!(error.is_not_found());
         assert!(!error.is_already_exists());
+        assert!(!error.is_missing_parent());
     }
 
     #[test]
@@ -34,5 +168,73 @@ mod tests {
         let error = StorageError::AlreadyExists;
         assert!(error.is_already_exists());
         assert!(!error.is_not_found());
+        assert!(!error.is_missing_parent());
+    }
+
+    #[test]
+    fn test_missing_parent_is_an_integrity_violation() {
+        let error = StorageError::MissingParent {
+            parent: "role",
+            id: Uuid::new_v4(),
+        };
+        assert!(error.is_integrity_violation());
+        assert!(!error.is_retryable());
+    }
+
+    #[test]
+    fn test_io() {
+        let error = StorageError::io(
+            "company/a.json",
+            std::io::Error::from(std::io::ErrorKind::PermissionDenied),
+        );
+        assert!(error.is_io());
+        assert!(error.is_retryable());
+        assert!(error
+            .to_string()
+            .starts_with("Could not access company/a.json"));
+    }
+
+    #[test]
+    fn test_validation() {
+        let error = StorageError::from(IncompletePartialErrors::field_error(RoleFieldName::Name));
+        assert!(error.is_validation());
+        assert!(!error.is_retryable());
+        assert_eq!(
+            error.to_string(),
+            "Partial was incomplete; Partial Check failed on field `name`"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_busy_database_is_locked() {
+        let busy = rusqlite::Error::SqliteFailure(
+            rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_BUSY),
+            None,
+        );
+        let error = StorageError::from(busy);
+        assert!(error.is_locked());
+        assert!(error.is_retryable());
+
+        let constraint = rusqlite::Error::SqliteFailure(
+            rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_CONSTRAINT),
+            None,
+        );
+        assert!(StorageError::from(constraint).is_integrity_violation());
+    }
+
+    #[test]
+    fn test_missing_parent() {
+        let id = Uuid::new_v4();
+        let error = StorageError::MissingParent {
+            parent: "company",
+            id,
+        };
+        assert!(error.is_missing_parent());
+        assert!(!error.is_not_found());
+        assert_eq!(
+            error.to_string(),
+            format!("The company this belongs to ({id}) does not exist")
+        );
     }
 }
@@ -0,0 +1,428 @@
+use crate::posting::{element_range, find_ignore_case, looks_like_html};
+use crate::storable::{PartialCompany, PartialRole};
+use crate::{OptionalAmount, PayPeriod, Posting, Timestamp};
+use serde_json::Value;
+use thiserror::Error;
+
+/// The `type` of the script elements structured data is embedded in
+const JSON_LD_TYPE: &str = "application/ld+json";
+
+/// How schema.org marks a job that can be done from anywhere
+const TELECOMMUTE: &str = "TELECOMMUTE";
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum JobPostingError {
+    #[error("The page doesn't describe a job posting")]
+    NotFound,
+    #[error("The job posting doesn't say who is hiring")]
+    MissingOrganisation,
+    #[error("The job posting doesn't have a title")]
+    MissingTitle,
+}
+
+/// A job advert read from the schema.org `JobPosting` structured data most job boards embed in
+/// their pages as JSON-LD.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JobPosting {
+    pub title: String,
+    pub hiring_organisation: String,
+    /// Converted to markdown, the structured data usually has it as HTML
+    pub description: String,
+    pub location: String,
+    pub salary_min: Option<u64>,
+    pub salary_max: Option<u64>,
+    pub currency: String,
+    pub pay_period: PayPeriod,
+    pub date_posted: Option<Timestamp>,
+    pub url: String,
+}
+
+impl JobPosting {
+    /// Reads the first `JobPosting` found in the page's JSON-LD, blocks that aren't valid JSON are
+    /// skipped
+    pub fn extract<S: AsRef<str>>(html: S) -> Result<JobPosting, JobPostingError> {
+        let blocks = json_ld_blocks(html.as_ref());
+        let posting = blocks
+            .iter()
+            .find_map(find_job_posting)
+            .ok_or(JobPostingError::NotFound)?;
+
+        let title = text(posting.get("title")).ok_or(JobPostingError::MissingTitle)?;
+        let hiring_organisation =
+            text(posting.get("hiringOrganization")).ok_or(JobPostingError::MissingOrganisation)?;
+        let salary = posting
+            .get("baseSalary")
+            .and_then(salary)
+            .or_else(|| first(posting.get("estimatedSalary")).and_then(salary))
+            .unwrap_or_default();
+
+        Ok(JobPosting {
+            title,
+            hiring_organisation,
+            description: text(posting.get("description"))
+                .map(|description| description_to_markdown(&description))
+                .unwrap_or_default(),
+            location: location(posting),
+            salary_min: salary.min,
+            salary_max: salary.max,
+            currency: salary.currency,
+            pay_period: salary.pay_period,
+            date_posted: text(posting.get("datePosted"))
+                .and_then(|date| Timestamp::parse(date).ok()),
+            url: text(posting.get("url")).unwrap_or_default(),
+        })
+    }
+
+    /// The site the posting came from, eg `boards.example.com`
+    pub fn source(&self) -> String {
+        let without_scheme = self
+            .url
+            .split_once("://")
+            .map_or(self.url.as_str(), |(_, rest)| rest);
+        let host = without_scheme.split(['/', '?', '#']).next().unwrap_or("");
+        host.trim_start_matches("www.").to_string()
+    }
+
+    pub fn to_partial_company(&self) -> PartialCompany {
+        PartialCompany {
+            name: Some(self.hiring_organisation.clone()),
+            ..Default::default()
+        }
+    }
+
+    pub fn to_partial_role(&self, date_applied: Timestamp) -> PartialRole {
+        PartialRole {
+            name: Some(self.title.clone()),
+            description: Some(self.description.clone()),
+            date_applied: Some(date_applied),
+            salary_min: Some(OptionalAmount(self.salary_min)),
+            salary_max: Some(OptionalAmount(self.salary_max)),
+            currency: Some(self.currency.as_str().into()),
+            pay_period: Some(self.pay_period),
+            posting_url: Some(self.url.clone()),
+            source: Some(self.source()),
+            location: Some(self.location.clone()),
+            date_posted: Some(self.date_posted),
+            ..Default::default()
+        }
+    }
+
+    /// The advert to capture with the role, the page it came from is kept as the original
+    pub fn to_posting<S: Into<String>>(&self, html: S) -> Posting {
+        Posting {
+            name: self.title.clone(),
+            markdown: self.description.clone(),
+            html: Some(html.into()),
+        }
+    }
+}
+
+/// Every JSON-LD script in the page that parses
+fn json_ld_blocks(html: &str) -> Vec<Value> {
+    let mut blocks = Vec::new();
+    let mut from = 0;
+    while let Some((start, content_start, content_end)) = element_range(html, "script", from) {
+        let opening_tag = &html[start..content_start];
+        if find_ignore_case(opening_tag, JSON_LD_TYPE, 0).is_some() {
+            let content = html[content_start..content_end].trim();
+            let content = content
+                .strip_prefix("<![CDATA[")
+                .and_then(|content| content.strip_suffix("]]>"))
+                .unwrap_or(content);
+            if let Ok(block) = serde_json::from_str(content) {
+                blocks.push(block);
+            }
+        }
+        from = content_end;
+    }
+    blocks
+}
+
+fn is_job_posting(value: &Value) -> bool {
+    let is_job_posting_type = |kind: &Value| {
+        kind.as_str()
+            .is_some_and(|kind| kind.ends_with("JobPosting"))
+    };
+    match value.get("@type") {
+        Some(Value::Array(kinds)) => kinds.iter().any(is_job_posting_type),
+        Some(kind) => is_job_posting_type(kind),
+        None => false,
+    }
+}
+
+/// Postings can be the block itself, in a list of blocks, in an `@graph` or nested in a page
+fn find_job_posting(value: &Value) -> Option<&Value> {
+    if is_job_posting(value) {
+        return Some(value);
+    }
+    match value {
+        Value::Array(values) => values.iter().find_map(find_job_posting),
+        Value::Object(object) => object.values().find_map(find_job_posting),
+        _ => None,
+    }
+}
+
+fn first(value: Option<&Value>) -> Option<&Value> {
+    match value? {
+        Value::Array(values) => values.first(),
+        value => Some(value),
+    }
+}
+
+/// Plain text from a string, or from the name of a thing such as an organisation or country
+fn text(value: Option<&Value>) -> Option<String> {
+    let text = match value? {
+        Value::String(text) => text.trim().to_string(),
+        Value::Number(number) => number.to_string(),
+        Value::Array(values) => return values.iter().find_map(|value| text(Some(value))),
+        Value::Object(object) => return text(object.get("name")),
+        _ => return None,
+    };
+    (!text.is_empty()).then_some(text)
+}
+
+fn number(value: Option<&Value>) -> Option<u64> {
+    let number = match value? {
+        Value::Number(number) => number.as_f64()?,
+        Value::String(text) => text.replace([',', ' '], "").parse().ok()?,
+        _ => return None,
+    };
+    (number.is_finite() && number >= 0.0).then(|| number.round() as u64)
+}
+
+/// Some boards escape the HTML a second time, so the tags only show up once it's decoded
+fn description_to_markdown(description: &str) -> String {
+    let description = if !looks_like_html(description) && description.contains("&lt;") {
+        html2md::parse_html(description)
+            .replace("\\<", "<")
+            .replace("\\>", ">")
+    } else {
+        description.to_string()
+    };
+    Posting::parse(description).markdown
+}
+
+fn address(place: &Value) -> Option<String> {
+    let address = place.get("address").unwrap_or(place);
+    if address.is_string() {
+        return text(Some(address));
+    }
+    let mut parts: Vec<String> = Vec::new();
+    for field in ["addressLocality", "addressRegion", "addressCountry"] {
+        if let Some(part) = text(address.get(field)) {
+            if !parts.contains(&part) {
+                parts.push(part);
+            }
+        }
+    }
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+fn location(posting: &Value) -> String {
+    let mut locations: Vec<String> = match posting.get("jobLocation") {
+        Some(Value::Array(places)) => places.iter().filter_map(address).collect(),
+        Some(place) => address(place).into_iter().collect(),
+        None => Vec::new(),
+    };
+    let is_remote = text(posting.get("jobLocationType"))
+        .is_some_and(|kind| kind.eq_ignore_ascii_case(TELECOMMUTE));
+    if is_remote {
+        let remote = match text(posting.get("applicantLocationRequirements")) {
+            Some(requirement) => format!("Remote ({requirement})"),
+            None => "Remote".to_string(),
+        };
+        locations.insert(0, remote);
+    }
+    locations.join("; ")
+}
+
+#[derive(Default)]
+struct Salary {
+    min: Option<u64>,
+    max: Option<u64>,
+    currency: String,
+    pay_period: PayPeriod,
+}
+
+/// A schema.org `MonetaryAmount`, weekly and monthly amounts are turned into annual ones as roles
+/// don't have a period for them
+fn salary(amount: &Value) -> Option<Salary> {
+    let currency = text(amount.get("currency")).unwrap_or_default();
+    let value = amount.get("value");
+    let (min, max, unit) = match value {
+        Some(Value::Object(quantity)) => {
+            let exact = number(quantity.get("value"));
+            (
+                number(quantity.get("minValue")).or(exact),
+                number(quantity.get("maxValue")).or(exact),
+                text(quantity.get("unitText")),
+            )
+        }
+        value => {
+            let exact = number(value).or_else(|| number(amount.get("minValue")));
+            (
+                exact,
+                number(amount.get("maxValue")).or(exact),
+                text(amount.get("unitText")),
+            )
+        }
+    };
+
+    let (pay_period, multiplier) = match unit.as_deref().map(str::to_uppercase).as_deref() {
+        Some("HOUR") => (PayPeriod::Hourly, 1),
+        Some("DAY") => (PayPeriod::Daily, 1),
+        Some("WEEK") => (PayPeriod::Annual, 52),
+        Some("MONTH") => (PayPeriod::Annual, 12),
+        _ => (PayPeriod::Annual, 1),
+    };
+    // An amount too big to be annualised can't be a real salary, so it's left out
+    let min = min.and_then(|amount| amount.checked_mul(multiplier));
+    let max = max.and_then(|amount| amount.checked_mul(multiplier));
+    if min.is_none() && max.is_none() {
+        return None;
+    }
+    Some(Salary {
+        min,
+        max,
+        currency: currency.to_uppercase(),
+        pay_period,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::markdown_to_html;
+    use crate::storable::CheckPartialComplete;
+
+    const GREENHOUSE: &str = include_str!("../fixtures/job_postings/greenhouse.html");
+    const GRAPH: &str = include_str!("../fixtures/job_postings/graph.html");
+    const REMOTE_HOURLY: &str = include_str!("../fixtures/job_postings/remote_hourly.html");
+    const ESCAPED: &str = include_str!("../fixtures/job_postings/escaped_description.html");
+    const NO_POSTING: &str = include_str!("../fixtures/job_postings/no_posting.html");
+
+    #[test]
+    fn test_extract_single_block() {
+        let posting = JobPosting::extract(GREENHOUSE).unwrap();
+        assert_eq!(posting.title, "Senior Backend Engineer");
+        assert_eq!(posting.hiring_organisation, "Acme Widgets");
+        assert!(posting.description.contains("**Rust**"));
+        assert!(posting.description.contains("* Postgres"));
+        assert_eq!(posting.location, "London, England, GB");
+        assert_eq!(posting.salary_min, Some(85_000));
+        assert_eq!(posting.salary_max, Some(100_000));
+        assert_eq!(posting.currency, "GBP");
+        assert_eq!(posting.pay_period, PayPeriod::Annual);
+        assert_eq!(
+            posting.date_posted,
+            Some(Timestamp::from_string("2025-09-01"))
+        );
+        assert_eq!(posting.url, "https://boards.example.com/acme/jobs/4012");
+        assert_eq!(posting.source(), "boards.example.com");
+    }
+
+    #[test]
+    fn test_extract_from_graph() {
+        let posting = JobPosting::extract(GRAPH).unwrap();
+        assert_eq!(posting.title, "Data Engineer");
+        assert_eq!(posting.hiring_organisation, "Northwind Analytics");
+        assert_eq!(posting.location, "Berlin, DE; Munich, DE");
+        // Monthly salaries are annualised
+        assert_eq!(posting.salary_min, Some(60_000));
+        assert_eq!(posting.salary_max, Some(72_000));
+        assert_eq!(posting.currency, "EUR");
+        assert_eq!(posting.source(), "careers.northwind.example");
+    }
+
+    #[test]
+    fn test_extract_remote_hourly() {
+        let posting = JobPosting::extract(REMOTE_HOURLY).unwrap();
+        assert_eq!(posting.title, "Contract QA Tester");
+        assert_eq!(posting.hiring_organisation, "Globex");
+        assert_eq!(posting.location, "Remote (United States)");
+        assert_eq!(posting.salary_min, Some(45));
+        assert_eq!(posting.salary_max, Some(45));
+        assert_eq!(posting.currency, "USD");
+        assert_eq!(posting.pay_period, PayPeriod::Hourly);
+        assert_eq!(posting.date_posted, None);
+    }
+
+    #[test]
+    fn test_extract_escaped_description() {
+        let posting = JobPosting::extract(ESCAPED).unwrap();
+        assert_eq!(posting.title, "Frontend Developer");
+        assert!(posting.description.contains("**React**"));
+        assert!(!posting.description.contains("&lt;"));
+        assert!(!posting.description.contains("<p>"));
+        assert_eq!(posting.salary_min, None);
+        assert_eq!(posting.currency, "");
+    }
+
+    #[test]
+    fn test_extract_without_posting() {
+        assert_eq!(
+            JobPosting::extract(NO_POSTING),
+            Err(JobPostingError::NotFound)
+        );
+        assert_eq!(
+            JobPosting::extract("<p>Not a job</p>"),
+            Err(JobPostingError::NotFound)
+        );
+    }
+
+    #[test]
+    fn test_extract_without_organisation() {
+        let html = r#"<script type="application/ld+json">{"@type": "JobPosting", "title": "Cook"}</script>"#;
+        assert_eq!(
+            JobPosting::extract(html),
+            Err(JobPostingError::MissingOrganisation)
+        );
+    }
+
+    #[test]
+    fn test_extract_salary_too_big_to_annualise() {
+        let html = r#"<script type="application/ld+json">{
+            "@type": "JobPosting",
+            "title": "Cook",
+            "hiringOrganization": {"name": "Diner"},
+            "baseSalary": {
+                "currency": "GBP",
+                "value": {"minValue": 1e30, "maxValue": 2000, "unitText": "MONTH"}
+            }
+        }</script>"#;
+        let posting = JobPosting::extract(html).unwrap();
+        assert_eq!(posting.salary_min, None);
+        assert_eq!(posting.salary_max, Some(24_000));
+    }
+
+    #[test]
+    fn test_extract_description_is_safe_to_show() {
+        let html = r#"<script type="application/ld+json">{
+            "@type": "JobPosting",
+            "title": "Cook",
+            "hiringOrganization": {"name": "Diner"},
+            "description": "<p>Cook <b>breakfast</b></p><iframe src=\"https://evil.example\">Framed</iframe><img src=x onerror=\"alert(1)\"><a href=\"javascript:alert(1)\">Apply</a>"
+        }</script>"#;
+        let posting = JobPosting::extract(html).unwrap();
+        assert!(!posting.description.contains("Framed"));
+
+        let shown = markdown_to_html(&posting.description);
+        assert!(shown.contains("<strong>breakfast</strong>"));
+        assert!(shown.contains("Apply"));
+        assert!(!shown.contains("<iframe"));
+        assert!(!shown.contains("onerror"));
+        assert!(!shown.contains("javascript:"));
+    }
+
+    #[test]
+    fn test_to_partial_role() {
+        let posting = JobPosting::extract(GREENHOUSE).unwrap();
+        let date_applied = Timestamp::from_string("2025-09-10");
+        let partial = posting.to_partial_role(date_applied);
+        assert!(partial.check_complete().is_ok());
+        assert_eq!(partial.name.as_deref(), Some("Senior Backend Engineer"));
+        assert_eq!(partial.date_applied, Some(date_applied));
+        assert_eq!(partial.source.as_deref(), Some("boards.example.com"));
+        assert_eq!(partial.location.as_deref(), Some("London, England, GB"));
+    }
+}
@@ -11,6 +11,39 @@ pub use time::*;
 mod error;
 pub use error::*;
 
+mod compensation;
+pub use compensation::*;
+
+mod markdown;
+pub use markdown::*;
+
+mod posting;
+pub use posting::*;
+
+mod job_posting;
+pub use job_posting::*;
+
+mod spreadsheet;
+pub use spreadsheet::*;
+
+mod trash_settings;
+pub use trash_settings::*;
+
+mod zone_settings;
+pub use zone_settings::*;
+
+mod revision;
+pub use revision::*;
+
+mod search;
+pub use search::*;
+
+mod query;
+pub use query::*;
+
+mod change;
+pub use change::*;
+
 mod composite_store;
 mod storable;
 mod storage;
@@ -24,27 +57,56 @@ mod test_helper;
 trait Sealed {}
 
 // Re-export
+pub use chrono_tz::{Tz, TZ_VARIANTS};
 pub use partially::Partial;
 
 pub mod prelude {
+    pub use crate::change::{Change, ChangeAction, ChangeReceiver};
+    pub use crate::compensation::{ExchangeRates, NormalisedCompensation, PayPeriod};
     pub use crate::composite_store::{
-        HasFutureStoreFor, JsonThreadSafeGeneralStore, StubThreadSafeGeneralStore,
-        ThreadSafeGeneralStore,
+        CompanyMatch, HasFutureStoreFor, ImportedJobPosting, ImportedSpreadsheet,
+        JsonThreadSafeGeneralStore, SpreadsheetPreviewRow, StubThreadSafeGeneralStore,
+        ThreadSafeGeneralStore, Transaction,
     };
-    pub use crate::error::StorageError;
+    #[cfg(feature = "sqlite")]
+    pub use crate::composite_store::{ImportedJson, SqliteThreadSafeGeneralStore};
+    pub use crate::error::{StorageError, StorageResult};
+    pub use crate::job_posting::{JobPosting, JobPostingError};
     pub use crate::logging::{
         json_log_fetcher::JsonLogFetcher, stub_log_fetcher::StubLogFetcher, LogFetcher,
     };
+    pub use crate::markdown::markdown_to_html;
+    pub use crate::posting::{Posting, PostingSnapshot};
+    pub use crate::query::{Query, SortBy, SortOrder};
+    pub use crate::revision::{FieldChange, Revision};
+    pub use crate::search::{
+        group_by_kind, SearchDocument, SearchIndex, SearchKind, SearchResult, SnippetPart,
+    };
+    pub use crate::spreadsheet::{
+        export_csv, export_roles_csv, ColumnMapping, SpreadsheetColumns, SpreadsheetError,
+        SpreadsheetField, SpreadsheetHeader, SpreadsheetKind, SpreadsheetPreset, SpreadsheetRow,
+        SpreadsheetTable,
+    };
     pub use crate::storable::{
-        ApplyPartial, CheckPartialComplete, Company, CompanyFieldName, Flag, FlagColor,
-        FlagFieldName, HasCompany, HasDeleted, HasId, HasName, HasRole, Interview,
-        InterviewFieldName, PartialCompany, PartialFlag, PartialInterview, PartialQuestion,
-        PartialRole, PartialValue, Question, QuestionFieldName, Role, RoleFieldName, Value,
-        ValueFieldName,
+        ApplyPartial, CheckPartialComplete, Company, CompanyFieldName, Contact, ContactFieldName,
+        FieldError, FieldKey, Flag, FlagColor, FlagFieldName, HasCompany, HasDeleted, HasId,
+        HasName, HasRole, HasSchemaVersion, HasTimestamps, IncompletePartialErrors, Interview,
+        InterviewFieldName, PartialCompany, PartialContact, PartialFlag, PartialInterview,
+        PartialQuestion, PartialReminder, PartialRole, PartialValue, Queryable, Question,
+        QuestionFieldName, Recurrence, Reminder, ReminderFieldName, RestoreRevision, Role,
+        RoleFieldName, RoleStatus, RoleStatusTransition, Searchable, SetDeleted,
+        ValidationErrorKind, Value, ValueFieldName,
     };
     pub use crate::storage::{
-        BaseStore, CompanyStore, FlagStore, JsonStore, RecallByCompany, RecallById, RecallByName,
-        RecallByRole, RoleStore, ScopedJsonStoreFor,
+        BaseStore, CompanyStore, ContactStore, FlagStore, JsonStore, LoadReport, Purge,
+        QuarantinedFile, RecallByCompany, RecallById, RecallByName, RecallByQuery, RecallByRole,
+        RecallDeleted, RecallDue, RecallRevisions, ReminderStore, RoleStore, ScopedJsonStoreFor,
+        StubStore,
     };
-    pub use crate::time::Timestamp;
+    #[cfg(feature = "sqlite")]
+    pub use crate::storage::{ScopedSqliteStoreFor, SqliteConnection, SqliteStore};
+    pub use crate::time::{DateOrder, Timestamp, TimestampParseError};
+    pub use crate::trash_settings::TrashSettings;
+    pub use crate::zone_settings::ZoneSettings;
+    pub use chrono_tz::Tz;
 }
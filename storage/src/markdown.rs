@@ -0,0 +1,102 @@
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+
+/// Schemes a link or image can point at, anything else (`javascript:`, `data:` and so on) could run
+/// in the page it's shown in
+const SAFE_SCHEMES: [&str; 3] = ["http", "https", "mailto"];
+
+/// Turns markdown into HTML that is safe to show, even when the markdown came from someone else's
+/// page, such as a job advert.
+///
+/// Raw HTML in the markdown is left out, and links and images that point anywhere other than a
+/// web page or an email address are shown as their text alone.
+pub fn markdown_to_html(markdown: &str) -> String {
+    // Whether each open link or image was kept, so its end is dropped along with its start
+    let mut kept = Vec::new();
+    let events = Parser::new(markdown).filter(|event| match event {
+        Event::Html(_) | Event::InlineHtml(_) => false,
+        Event::Start(Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. }) => {
+            let is_safe = is_safe_url(dest_url);
+            kept.push(is_safe);
+            is_safe
+        }
+        Event::End(TagEnd::Link | TagEnd::Image) => kept.pop().unwrap_or(false),
+        _ => true,
+    });
+    let mut html = String::new();
+    pulldown_cmark::html::push_html(&mut html, events);
+    html
+}
+
+/// Relative links have no scheme, so are kept. Browsers ignore whitespace and control characters
+/// in a scheme, so they're ignored here too.
+fn is_safe_url(url: &str) -> bool {
+    let url: String = url
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_control())
+        .collect();
+    let Some((scheme, _)) = url.split_once(':') else {
+        return true;
+    };
+    if scheme.contains(['/', '?', '#']) {
+        return true;
+    }
+    SAFE_SCHEMES
+        .iter()
+        .any(|safe| scheme.eq_ignore_ascii_case(safe))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_to_html() {
+        let html =
+            markdown_to_html("# Engineer\n\nWrite **good** code, [apply](https://example.com)");
+        assert_eq!(
+            html,
+            "<h1>Engineer</h1>\n<p>Write <strong>good</strong> code, \
+             <a href=\"https://example.com\">apply</a></p>\n"
+        );
+    }
+
+    #[test]
+    fn test_raw_html_is_left_out() {
+        let html = markdown_to_html(
+            "<iframe src=\"javascript:alert(1)\"></iframe>\n\nText <img src=x onerror=alert(1)>",
+        );
+        assert!(!html.contains("<iframe"), "{html}");
+        assert!(!html.contains("<img"), "{html}");
+        assert!(html.contains("Text"));
+    }
+
+    #[test]
+    fn test_unsafe_links_keep_their_text() {
+        for markdown in [
+            "[x](javascript:alert(1))",
+            "[x](JavaScript:alert(1))",
+            "[x](java%09script:alert(1))",
+            "[x](<java\tscript:alert(1)>)",
+            "[x](data:text/html;base64,PHNjcmlwdD4=)",
+            "![x](javascript:alert(1))",
+        ] {
+            let html = markdown_to_html(markdown);
+            assert!(!html.contains("href"), "{markdown}: {html}");
+            assert!(!html.contains("src"), "{markdown}: {html}");
+            assert!(html.contains('x'), "{markdown}: {html}");
+        }
+    }
+
+    #[test]
+    fn test_safe_links_are_kept() {
+        for (markdown, url) in [
+            ("[x](http://example.com)", "http://example.com"),
+            ("[x](mailto:jobs@example.com)", "mailto:jobs@example.com"),
+            ("[x](/jobs/1)", "/jobs/1"),
+            ("[x](#apply)", "#apply"),
+        ] {
+            let html = markdown_to_html(markdown);
+            assert!(html.contains(&format!("href=\"{url}\"")), "{html}");
+        }
+    }
+}
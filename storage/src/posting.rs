@@ -0,0 +1,271 @@
+use crate::storable::PartialRole;
+use crate::Timestamp;
+use serde::{Deserialize, Serialize};
+
+/// Elements whose content is never part of the advert's text, along with the ones that embed
+/// active content from elsewhere
+const IGNORED_ELEMENTS: [&str; 10] = [
+    "script", "style", "noscript", "template", "iframe", "frameset", "frame", "object", "embed",
+    "applet",
+];
+
+/// A job advert pasted in as either HTML or markdown.
+///
+/// HTML is converted to markdown so it can be shown like the rest of the role, the original is
+/// kept so it can be stored next to the role when the posting is captured.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Posting {
+    /// The first heading of the advert, or its title or first line if it doesn't have one
+    pub name: String,
+    pub markdown: String,
+    pub html: Option<String>,
+}
+
+impl Posting {
+    pub fn parse<S: AsRef<str>>(text: S) -> Posting {
+        let text = text.as_ref().trim();
+        if !looks_like_html(text) {
+            return Posting {
+                name: first_heading(text)
+                    .or_else(|| first_line(text))
+                    .unwrap_or_default(),
+                markdown: text.to_string(),
+                html: None,
+            };
+        }
+
+        let markdown = html2md::parse_html(&remove_ignored_elements(body_of(text)))
+            .trim()
+            .to_string();
+        Posting {
+            name: first_heading(&markdown)
+                .or_else(|| element_text(text, "title"))
+                .or_else(|| first_line(&markdown))
+                .unwrap_or_default(),
+            markdown,
+            html: Some(text.to_string()),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.markdown.is_empty()
+    }
+
+    /// The name and description a role created from this posting starts with
+    pub fn to_partial_role(&self) -> PartialRole {
+        PartialRole {
+            name: Some(self.name.clone()),
+            description: Some(self.markdown.clone()),
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn to_snapshot(&self, date_captured: Timestamp) -> PostingSnapshot {
+        PostingSnapshot {
+            markdown: self.markdown.clone(),
+            has_html: self.html.is_some(),
+            date_captured,
+        }
+    }
+}
+
+/// A job posting as it was when it was captured, it never changes after that.
+///
+/// The original HTML (if there was any) isn't part of the snapshot, it is stored as an attachment
+/// of the role named [`crate::prelude::Role::POSTING_ATTACHMENT`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PostingSnapshot {
+    pub markdown: String,
+    pub has_html: bool,
+    pub date_captured: Timestamp,
+}
+
+/// Something that opens or closes a tag, a `<` on its own is just as likely to be a comparison
+pub(crate) fn looks_like_html(text: &str) -> bool {
+    text.match_indices('<').any(|(i, _)| {
+        let rest = &text[i + 1..];
+        rest.starts_with(|c: char| c.is_ascii_alphabetic() || c == '!' || c == '/')
+            && rest.contains('>')
+    })
+}
+
+/// Byte offset of the first match, ignoring ASCII case, lowercasing could move non-ASCII offsets
+pub(crate) fn find_ignore_case(haystack: &str, needle: &str, from: usize) -> Option<usize> {
+    haystack
+        .get(from..)?
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+        .map(|position| position + from)
+}
+
+/// Where the first matching element opens, along with where its content starts and ends. An
+/// element that is never closed runs to the end.
+pub(crate) fn element_range(html: &str, tag: &str, from: usize) -> Option<(usize, usize, usize)> {
+    let open = format!("<{tag}");
+    let mut start = from;
+    loop {
+        let found = find_ignore_case(html, &open, start)?;
+        let after = html.as_bytes().get(found + open.len()).copied();
+        // Don't match `<br` when looking for `<b` and similar
+        if matches!(after, Some(b'>' | b' ' | b'\t' | b'\n' | b'\r' | b'/')) {
+            let content_start = html[found..].find('>')? + found + 1;
+            let close = format!("</{tag}");
+            let Some(content_end) = find_ignore_case(html, &close, content_start) else {
+                return Some((found, content_start, html.len()));
+            };
+            return Some((found, content_start, content_end));
+        }
+        start = found + open.len();
+    }
+}
+
+fn body_of(html: &str) -> &str {
+    match element_range(html, "body", 0) {
+        Some((_, start, end)) => &html[start..end],
+        None => html,
+    }
+}
+
+fn remove_ignored_elements(html: &str) -> String {
+    let mut html = html.to_string();
+    for tag in IGNORED_ELEMENTS {
+        while let Some((start, _, content_end)) = element_range(&html, tag, 0) {
+            let end = html[content_end..]
+                .find('>')
+                .map_or(html.len(), |close| content_end + close + 1);
+            html.replace_range(start..end, "");
+        }
+    }
+    html
+}
+
+fn element_text(html: &str, tag: &str) -> Option<String> {
+    let (_, start, end) = element_range(html, tag, 0)?;
+    let text = html2md::parse_html(&html[start..end]);
+    clean_name(&text)
+}
+
+fn first_heading(markdown: &str) -> Option<String> {
+    let lines: Vec<&str> = markdown.lines().map(str::trim).collect();
+    lines.iter().enumerate().find_map(|(i, line)| {
+        let hashes = line.chars().take_while(|c| *c == '#').count();
+        if (1..=6).contains(&hashes) && line[hashes..].starts_with(' ') {
+            return clean_name(line[hashes..].trim_end_matches('#'));
+        }
+        let underline = lines.get(i + 1)?;
+        let is_underline = !underline.is_empty()
+            && (underline.chars().all(|c| c == '=') || underline.chars().all(|c| c == '-'));
+        if is_underline && !line.is_empty() {
+            return clean_name(line);
+        }
+        None
+    })
+}
+
+fn first_line(markdown: &str) -> Option<String> {
+    markdown.lines().find_map(clean_name)
+}
+
+/// Strips the markdown emphasis from a line so it can be used as a name
+fn clean_name(line: &str) -> Option<String> {
+    let name = line
+        .replace(['*', '_', '`'], "")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!name.is_empty()).then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_markdown() {
+        let posting = Posting::parse("\n# Senior **Rust** Engineer\n\nWe are hiring.\n");
+        assert_eq!(posting.name, "Senior Rust Engineer");
+        assert_eq!(
+            posting.markdown,
+            "# Senior **Rust** Engineer\n\nWe are hiring."
+        );
+        assert_eq!(posting.html, None);
+    }
+
+    #[test]
+    fn test_parse_plain_text_uses_first_line() {
+        let posting = Posting::parse("Backend Developer\nRemote, full time");
+        assert_eq!(posting.name, "Backend Developer");
+        assert_eq!(posting.html, None);
+    }
+
+    #[test]
+    fn test_parse_less_than_is_not_html() {
+        let posting = Posting::parse("Salary < 50k\nRemote");
+        assert_eq!(posting.html, None);
+    }
+
+    #[test]
+    fn test_parse_html() {
+        let html = r#"<!DOCTYPE html><html><head><title>Jobs at Acme</title>
+            <style>body { color: red }</style></head>
+            <body><script>var tracking = 1;</script><h1>Senior Rust Engineer</h1>
+            <p>We are <strong>hiring</strong> &amp; growing.</p>
+            <ul><li>Rust</li><li>SQL</li></ul></body></html>"#;
+        let posting = Posting::parse(html);
+        assert_eq!(posting.name, "Senior Rust Engineer");
+        assert!(posting.markdown.contains("We are **hiring** & growing."));
+        assert!(posting.markdown.contains("* Rust"));
+        assert!(!posting.markdown.contains("tracking"));
+        assert!(!posting.markdown.contains("color"));
+        assert!(!posting.markdown.contains("Jobs at Acme"));
+        assert_eq!(posting.html.as_deref(), Some(html));
+    }
+
+    #[test]
+    fn test_parse_html_without_heading_uses_title() {
+        let posting = Posting::parse(
+            "<html><head><title>Data Analyst</title></head><body><p>Apply now</p></body></html>",
+        );
+        assert_eq!(posting.name, "Data Analyst");
+        assert_eq!(posting.markdown, "Apply now");
+    }
+
+    #[test]
+    fn test_parsed_html_is_safe_to_show() {
+        let posting = Posting::parse(concat!(
+            "<h1>Engineer</h1>",
+            "<iframe src=\"javascript:alert(1)\"></iframe>",
+            "<p><a href=\"javascript:alert(2)\">Apply</a></p>",
+        ));
+        let html = crate::markdown_to_html(&posting.markdown);
+        assert!(!html.contains("<iframe"), "{html}");
+        assert!(!html.contains("javascript:"), "{html}");
+        assert!(html.contains("Apply"), "{html}");
+    }
+
+    #[test]
+    fn test_parse_html_fragment() {
+        let posting = Posting::parse("<p><b>Platform Engineer</b></p><p>Berlin<br>Hybrid</p>");
+        assert_eq!(posting.name, "Platform Engineer");
+        assert!(posting.html.is_some());
+    }
+
+    #[test]
+    fn test_to_partial_role() {
+        let posting = Posting::parse("# Tester\n\nManual and automated");
+        let partial = posting.to_partial_role();
+        assert_eq!(partial.name.as_deref(), Some("Tester"));
+        assert_eq!(
+            partial.description.as_deref(),
+            Some("# Tester\n\nManual and automated")
+        );
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let posting = Posting::parse("   ");
+        assert!(posting.is_empty());
+        assert_eq!(posting.name, "");
+    }
+}
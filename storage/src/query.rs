@@ -0,0 +1,336 @@
+use crate::storable::{HasId, Queryable};
+use crate::{ExchangeRates, Timestamp};
+use std::cmp::Ordering;
+use uuid::Uuid;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum SortBy {
+    /// Case insensitive
+    #[default]
+    Name,
+    /// See [`Queryable::get_date`]
+    Date,
+    DateCreated,
+    /// Falls back to when it was created for things that have never been edited
+    DateUpdated,
+    /// See [`Queryable::get_compensation`], using [`Query::exchange_rates`] to compare currencies
+    Compensation,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+/// Filters, sorts and pages the items in a store.
+///
+/// Every filter that is set has to match. Deleted items are left out unless asked for and the
+/// order is whatever the store has unless a sort is given.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Query {
+    pub(crate) name_contains: Option<String>,
+    pub(crate) company_id: Option<Uuid>,
+    pub(crate) role_id: Option<Uuid>,
+    pub(crate) date_from: Option<Timestamp>,
+    pub(crate) date_to: Option<Timestamp>,
+    pub(crate) include_deleted: bool,
+    pub(crate) sort: Option<(SortBy, SortOrder)>,
+    pub(crate) exchange_rates: ExchangeRates,
+    pub(crate) offset: usize,
+    pub(crate) limit: Option<usize>,
+}
+
+impl Query {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Case insensitive, like [`crate::storage::RecallByName`]
+    pub fn name_contains<S: AsRef<str>>(mut self, name: S) -> Self {
+        self.name_contains = Some(name.as_ref().to_lowercase());
+        self
+    }
+
+    /// Only things that belong to the company, companies themselves never match
+    pub fn company<I: HasId>(mut self, company: I) -> Self {
+        self.company_id = Some(company.get_id());
+        self
+    }
+
+    /// Only things that belong to the role
+    pub fn role<I: HasId>(mut self, role: I) -> Self {
+        self.role_id = Some(role.get_id());
+        self
+    }
+
+    /// Only things dated on or after this, things without a date never match
+    pub fn date_from(mut self, date_from: Timestamp) -> Self {
+        self.date_from = Some(date_from);
+        self
+    }
+
+    /// Only things dated before this, things without a date never match
+    pub fn date_to(mut self, date_to: Timestamp) -> Self {
+        self.date_to = Some(date_to);
+        self
+    }
+
+    pub fn include_deleted(mut self) -> Self {
+        self.include_deleted = true;
+        self
+    }
+
+    /// Things without the date being sorted by come first when ascending
+    pub fn sort_by(mut self, sort_by: SortBy, order: SortOrder) -> Self {
+        self.sort = Some((sort_by, order));
+        self
+    }
+
+    /// The rates compensation is converted with when sorting by it, the default has no rates so
+    /// only compensation in its base currency can be compared
+    pub fn exchange_rates(mut self, exchange_rates: ExchangeRates) -> Self {
+        self.exchange_rates = exchange_rates;
+        self
+    }
+
+    /// Skips this many matches, after sorting
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Returns at most this many matches, after sorting
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn matches<O: Queryable>(&self, storable: &O) -> bool {
+        if !self.include_deleted && storable.is_deleted() {
+            return false;
+        }
+        if let Some(name) = &self.name_contains {
+            if !storable.get_name().to_lowercase().contains(name) {
+                return false;
+            }
+        }
+        if self.company_id.is_some() && storable.get_parent_company_id() != self.company_id {
+            return false;
+        }
+        if self.role_id.is_some() && storable.get_parent_role_id() != self.role_id {
+            return false;
+        }
+        if self.date_from.is_some() || self.date_to.is_some() {
+            let Some(date) = storable.get_date() else {
+                return false;
+            };
+            if self.date_from.is_some_and(|date_from| date < date_from)
+                || self.date_to.is_some_and(|date_to| date >= date_to)
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Runs the query over everything in a store
+    pub fn apply<'a, O, I>(&self, storables: I) -> Vec<O>
+    where
+        O: Queryable + Clone + 'a,
+        I: IntoIterator<Item = &'a O>,
+    {
+        let mut matches: Vec<&O> = storables
+            .into_iter()
+            .filter(|storable| self.matches(*storable))
+            .collect();
+        if let Some((sort_by, order)) = self.sort {
+            matches.sort_by(|a, b| {
+                let ordering = compare(sort_by, &self.exchange_rates, *a, *b);
+                match order {
+                    SortOrder::Ascending => ordering,
+                    SortOrder::Descending => ordering.reverse(),
+                }
+            });
+        }
+        matches
+            .into_iter()
+            .skip(self.offset)
+            .take(self.limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect()
+    }
+}
+
+fn compare<O: Queryable>(
+    sort_by: SortBy,
+    exchange_rates: &ExchangeRates,
+    a: &O,
+    b: &O,
+) -> Ordering {
+    match sort_by {
+        SortBy::Name => a
+            .get_name()
+            .to_lowercase()
+            .cmp(&b.get_name().to_lowercase()),
+        SortBy::Date => a.get_date().cmp(&b.get_date()),
+        SortBy::DateCreated => a.get_date_created().cmp(&b.get_date_created()),
+        SortBy::DateUpdated => a
+            .get_date_updated()
+            .or_else(|| a.get_date_created())
+            .cmp(&b.get_date_updated().or_else(|| b.get_date_created())),
+        SortBy::Compensation => a
+            .get_compensation(exchange_rates)
+            .cmp(&b.get_compensation(exchange_rates)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storable::{Company, Interview, Role};
+
+    fn names<O: Queryable>(storables: &[O]) -> Vec<&str> {
+        storables
+            .iter()
+            .map(|storable| storable.get_name())
+            .collect()
+    }
+
+    #[test]
+    fn test_name_contains_and_deleted() {
+        let mut deleted = Company::new("Acme Deleted");
+        deleted.date_deleted = Some(Timestamp::now());
+        let companies = vec![Company::new("ACME"), Company::new("Initech"), deleted];
+
+        let found = Query::new().name_contains("acme").apply(&companies);
+        assert_eq!(names(&found), vec!["ACME"]);
+
+        let found = Query::new()
+            .name_contains("acme")
+            .include_deleted()
+            .apply(&companies);
+        assert_eq!(names(&found), vec!["ACME", "Acme Deleted"]);
+    }
+
+    #[test]
+    fn test_parents() {
+        let company = Company::new("company");
+        let role = company.create_role("role", Timestamp::now());
+        let other_role = Role::new(uuid::Uuid::new_v4(), "other role", Timestamp::now());
+        let roles = vec![role.clone(), other_role];
+        let interviews = vec![
+            role.create_interview("interview"),
+            Interview::new(uuid::Uuid::new_v4(), "other interview"),
+        ];
+
+        assert_eq!(
+            names(&Query::new().company(&company).apply(&roles)),
+            vec!["role"]
+        );
+        assert_eq!(
+            names(&Query::new().role(&role).apply(&interviews)),
+            vec!["interview"]
+        );
+        // Interviews only know their role
+        assert!(Query::new().company(&company).apply(&interviews).is_empty());
+        assert!(Query::new()
+            .company(&company)
+            .apply(std::slice::from_ref(&company))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_date_range() {
+        let company = Company::new("company");
+        let roles: Vec<Role> = [10, 20, 30]
+            .into_iter()
+            .map(|seconds| {
+                company.create_role(
+                    format!("role {seconds}"),
+                    Timestamp::from_timestamp(seconds),
+                )
+            })
+            .collect();
+
+        let found = Query::new()
+            .date_from(Timestamp::from_timestamp(20))
+            .date_to(Timestamp::from_timestamp(30))
+            .apply(&roles);
+        assert_eq!(names(&found), vec!["role 20"]);
+
+        // Without a date there's nothing to compare
+        let undated = vec![Interview::new(uuid::Uuid::new_v4(), "undated")];
+        assert!(Query::new()
+            .date_from(Timestamp::from_timestamp(0))
+            .apply(&undated)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_sort_and_page() {
+        let companies = vec![
+            Company::new("b"),
+            Company::new("C"),
+            Company::new("a"),
+            Company::new("d"),
+        ];
+
+        let found = Query::new()
+            .sort_by(SortBy::Name, SortOrder::Ascending)
+            .apply(&companies);
+        assert_eq!(names(&found), vec!["a", "b", "C", "d"]);
+
+        let found = Query::new()
+            .sort_by(SortBy::Name, SortOrder::Descending)
+            .offset(1)
+            .limit(2)
+            .apply(&companies);
+        assert_eq!(names(&found), vec!["C", "b"]);
+
+        let found = Query::new().offset(10).apply(&companies);
+        assert!(found.is_empty());
+    }
+
+    #[test]
+    fn test_sort_by_date_updated() {
+        let mut created = Company::new("created");
+        created.date_created = Some(Timestamp::from_timestamp(20));
+        let mut updated = Company::new("updated");
+        updated.date_created = Some(Timestamp::from_timestamp(10));
+        updated.date_updated = Some(Timestamp::from_timestamp(30));
+        let never_stored = Company::new("never stored");
+
+        let found = Query::new()
+            .sort_by(SortBy::DateUpdated, SortOrder::Descending)
+            .apply(&[created, never_stored, updated]);
+        assert_eq!(names(&found), vec!["updated", "created", "never stored"]);
+    }
+
+    #[test]
+    fn test_sort_by_compensation() {
+        let company = Company::new("company");
+        let role = |name: &str, salary: Option<u64>, currency: &str| {
+            let mut role = company.create_role(name, Timestamp::now());
+            role.salary_min = salary;
+            role.currency = currency.to_string();
+            role
+        };
+        let roles = vec![
+            role("dollars", Some(100_000), "USD"),
+            role("unknown", None, ""),
+            role("pounds", Some(90_000), "GBP"),
+            role("euros", Some(95_000), "EUR"),
+        ];
+        let mut exchange_rates = ExchangeRates::new("GBP");
+        exchange_rates.set_rate("USD", 0.8);
+
+        // Euros have no rate, so they sort as if the pay wasn't known
+        let found = Query::new()
+            .sort_by(SortBy::Compensation, SortOrder::Descending)
+            .exchange_rates(exchange_rates)
+            .apply(&roles);
+        assert_eq!(names(&found), vec!["pounds", "dollars", "unknown", "euros"]);
+    }
+}
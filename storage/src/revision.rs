@@ -0,0 +1,144 @@
+use crate::storable::{migrate, set_schema_version, HasSchemaVersion};
+use crate::{StorageError, StorageResult, Timestamp};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Map;
+use std::collections::BTreeSet;
+
+/// A single top level field that was changed by an edit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: serde_json::Value,
+    pub after: serde_json::Value,
+}
+
+/// How a storable looked before it was edited, along with what the edit changed.
+///
+/// The earlier version is kept as a document tagged with its schema version so that it can still
+/// be restored after the storable has been migrated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Revision {
+    date: Timestamp,
+    changes: Vec<FieldChange>,
+    previous: serde_json::Value,
+}
+
+impl Revision {
+    /// Compares both versions field by field, returns `None` if nothing changed
+    pub fn between<O: Serialize + HasSchemaVersion>(
+        previous: &O,
+        next: &O,
+        date: Timestamp,
+    ) -> StorageResult<Option<Self>> {
+        let mut previous = serde_json::to_value(previous).map_err(StorageError::integrity)?;
+        let next = serde_json::to_value(next).map_err(StorageError::integrity)?;
+        let changes = diff(&previous, &next);
+        if changes.is_empty() {
+            return Ok(None);
+        }
+        set_schema_version::<O>(&mut previous).map_err(StorageError::integrity)?;
+
+        Ok(Some(Self {
+            date,
+            changes,
+            previous,
+        }))
+    }
+
+    pub fn get_date(&self) -> Timestamp {
+        self.date
+    }
+
+    pub fn get_changes(&self) -> &[FieldChange] {
+        &self.changes
+    }
+
+    /// The storable as it was before this edit, migrated to the current schema version
+    pub fn get_previous<O: HasSchemaVersion + DeserializeOwned>(&self) -> StorageResult<O> {
+        let mut document = self.previous.clone();
+        migrate::<O>(&mut document).map_err(StorageError::integrity)?;
+        serde_json::from_value(document).map_err(StorageError::integrity)
+    }
+}
+
+/// Every top level field whose value differs, a field missing from one side is treated as null
+fn diff(before: &serde_json::Value, after: &serde_json::Value) -> Vec<FieldChange> {
+    let empty = Map::new();
+    let before = before.as_object().unwrap_or(&empty);
+    let after = after.as_object().unwrap_or(&empty);
+
+    before
+        .keys()
+        .chain(after.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter_map(|field| {
+            let before = before.get(field).cloned().unwrap_or_default();
+            let after = after.get(field).cloned().unwrap_or_default();
+            (before != after).then(|| FieldChange {
+                field: field.clone(),
+                before,
+                after,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storable::{Company, Role};
+    use serde_json::json;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_between() {
+        let original = Company::new("company");
+        let mut edited = original.clone();
+        edited.name = "new name".to_string();
+
+        let date = Timestamp::now();
+        let revision = Revision::between(&original, &edited, date)
+            .unwrap()
+            .unwrap();
+        assert_eq!(revision.get_date(), date);
+        assert_eq!(
+            revision.get_changes(),
+            &[FieldChange {
+                field: "name".to_string(),
+                before: json!("company"),
+                after: json!("new name"),
+            }]
+        );
+        assert_eq!(revision.get_previous::<Company>().unwrap(), original);
+    }
+
+    #[test]
+    fn test_between_unchanged() {
+        let company = Company::new("company");
+        let revision = Revision::between(&company, &company.clone(), Timestamp::now()).unwrap();
+        assert!(revision.is_none());
+    }
+
+    #[test]
+    fn test_get_previous_migrates() {
+        // A revision recorded before roles had a status
+        let id = Uuid::new_v4();
+        let revision = Revision {
+            date: Timestamp::now(),
+            changes: Vec::new(),
+            previous: json!({
+                "id": id,
+                "company_id": Uuid::new_v4(),
+                "name": "role",
+                "description": "",
+                "date_applied": "2025-07-28T00:00",
+                "date_deleted": null,
+            }),
+        };
+
+        let role: Role = revision.get_previous().unwrap();
+        assert_eq!(role.id, id);
+    }
+}
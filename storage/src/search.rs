@@ -0,0 +1,511 @@
+use crate::storable::Searchable;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::ops::Range;
+use uuid::Uuid;
+
+/// Matches in the title count for more than matches in the rest of the text
+const TITLE_WEIGHT: f64 = 2.0;
+/// A word that only starts with the search term counts for less than the whole word
+const PREFIX_WEIGHT: f64 = 0.5;
+const SNIPPET_WORDS_BEFORE: usize = 5;
+const SNIPPET_WORDS_AFTER: usize = 15;
+
+/// The kinds of storable, search results are grouped by them and changes are labelled with them
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SearchKind {
+    Company,
+    Role,
+    Interview,
+    Question,
+    Flag,
+    Value,
+    Contact,
+    Reminder,
+}
+
+impl fmt::Display for SearchKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SearchKind::Company => write!(f, "Companies"),
+            SearchKind::Role => write!(f, "Roles"),
+            SearchKind::Interview => write!(f, "Interviews"),
+            SearchKind::Question => write!(f, "Questions"),
+            SearchKind::Flag => write!(f, "Flags"),
+            SearchKind::Value => write!(f, "Values"),
+            SearchKind::Contact => write!(f, "Contacts"),
+            SearchKind::Reminder => write!(f, "Reminders"),
+        }
+    }
+}
+
+/// The searchable text of a storable along with enough to link back to it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchDocument {
+    kind: SearchKind,
+    id: Uuid,
+    company_id: Option<Uuid>,
+    role_id: Option<Uuid>,
+    title: String,
+    text: Vec<String>,
+}
+
+impl SearchDocument {
+    pub fn new<S: Into<String>>(kind: SearchKind, id: Uuid, title: S) -> Self {
+        Self {
+            kind,
+            id,
+            company_id: None,
+            role_id: None,
+            title: title.into(),
+            text: Vec::new(),
+        }
+    }
+
+    pub fn with_company(mut self, company_id: Uuid) -> Self {
+        self.company_id = Some(company_id);
+        self
+    }
+
+    pub fn with_role(mut self, role_id: Uuid) -> Self {
+        self.role_id = Some(role_id);
+        self
+    }
+
+    pub fn with_text<S: Into<String>>(mut self, text: S) -> Self {
+        self.text.push(text.into());
+        self
+    }
+
+    /// Every word along with how much it counts for
+    fn weighted_words(&self) -> HashMap<String, f64> {
+        let mut words = HashMap::new();
+        for (_, word) in tokenize(&self.title) {
+            *words.entry(word).or_default() += TITLE_WEIGHT;
+        }
+        for text in &self.text {
+            for (_, word) in tokenize(text) {
+                *words.entry(word).or_default() += 1.0;
+            }
+        }
+        words
+    }
+}
+
+/// A piece of a snippet, highlighted if it matched the search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnippetPart {
+    pub text: String,
+    pub highlighted: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult {
+    pub kind: SearchKind,
+    pub id: Uuid,
+    /// Filled in from the role for interviews and questions
+    pub company_id: Option<Uuid>,
+    pub role_id: Option<Uuid>,
+    pub title: String,
+    /// The text around the first match outside of the title, empty if only the title matched
+    pub snippet: Vec<SnippetPart>,
+    pub score: f64,
+}
+
+/// An inverted index from each word to the storables that contain it.
+///
+/// Only live storables are indexed, deleting a storable removes it from the index.
+#[derive(Debug, Default)]
+pub struct SearchIndex {
+    built: bool,
+    documents: HashMap<Uuid, SearchDocument>,
+    words: BTreeMap<String, HashMap<Uuid, f64>>,
+}
+
+impl SearchIndex {
+    /// False until the index has been filled from the stores, or after it has been invalidated
+    pub fn is_built(&self) -> bool {
+        self.built
+    }
+
+    pub(crate) fn set_built(&mut self) {
+        self.built = true;
+    }
+
+    /// Empties the index so that it gets rebuilt from the stores before the next search
+    pub fn invalidate(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// Indexes the storable, replacing what was indexed for it before
+    pub fn update<O: Searchable>(&mut self, storable: &O) {
+        self.remove(storable.get_id());
+        if !storable.is_deleted() {
+            self.insert(storable.to_search_document());
+        }
+    }
+
+    pub fn remove(&mut self, id: Uuid) {
+        let Some(document) = self.documents.remove(&id) else {
+            return;
+        };
+        for word in document.weighted_words().into_keys() {
+            if let Some(ids) = self.words.get_mut(&word) {
+                ids.remove(&id);
+                if ids.is_empty() {
+                    self.words.remove(&word);
+                }
+            }
+        }
+    }
+
+    fn insert(&mut self, document: SearchDocument) {
+        for (word, weight) in document.weighted_words() {
+            self.words
+                .entry(word)
+                .or_default()
+                .insert(document.id, weight);
+        }
+        self.documents.insert(document.id, document);
+    }
+
+    /// Everything that contains every word of the query, best match first.
+    ///
+    /// The last word can be incomplete, so words are also matched by what they start with.
+    pub fn search<Q: AsRef<str>>(&self, query: Q) -> Vec<SearchResult> {
+        let query_words: Vec<String> = tokenize(query.as_ref())
+            .map(|(_, word)| word)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        if query_words.is_empty() {
+            return Vec::new();
+        }
+
+        let mut scores: Option<HashMap<Uuid, f64>> = None;
+        for query_word in &query_words {
+            let word_scores = self.score_word(query_word);
+            scores = Some(match scores {
+                None => word_scores,
+                Some(scores) => scores
+                    .into_iter()
+                    .filter_map(|(id, score)| word_scores.get(&id).map(|extra| (id, score + extra)))
+                    .collect(),
+            });
+        }
+
+        let mut results: Vec<SearchResult> = scores
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(id, score)| {
+                let document = self.documents.get(&id)?;
+                Some(SearchResult {
+                    kind: document.kind,
+                    id,
+                    company_id: document.company_id.or_else(|| {
+                        document
+                            .role_id
+                            .and_then(|role_id| self.documents.get(&role_id))
+                            .and_then(|role| role.company_id)
+                    }),
+                    role_id: document.role_id,
+                    title: document.title.clone(),
+                    snippet: create_snippet(document, &query_words),
+                    score,
+                })
+            })
+            .collect();
+        results.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.title.cmp(&b.title))
+        });
+        results
+    }
+
+    /// How well each document matches a single word, rarer words count for more
+    fn score_word(&self, query_word: &str) -> HashMap<Uuid, f64> {
+        let document_count = self.documents.len() as f64;
+        let mut scores: HashMap<Uuid, f64> = HashMap::new();
+        for (word, ids) in self
+            .words
+            .range(query_word.to_string()..)
+            .take_while(|(word, _)| word.starts_with(query_word))
+        {
+            let rarity = (1.0 + document_count / ids.len() as f64).ln();
+            let exactness = if word == query_word {
+                1.0
+            } else {
+                PREFIX_WEIGHT
+            };
+            for (id, weight) in ids {
+                *scores.entry(*id).or_default() += weight * rarity * exactness;
+            }
+        }
+        scores
+    }
+}
+
+/// Results in groups of the same kind, the group with the best match first
+pub fn group_by_kind(results: Vec<SearchResult>) -> Vec<(SearchKind, Vec<SearchResult>)> {
+    let mut groups: Vec<(SearchKind, Vec<SearchResult>)> = Vec::new();
+    for result in results {
+        match groups.iter_mut().find(|(kind, _)| *kind == result.kind) {
+            Some((_, group)) => group.push(result),
+            None => groups.push((result.kind, vec![result])),
+        }
+    }
+    groups
+}
+
+/// Lowercase words along with where they are in the text
+fn tokenize(text: &str) -> impl Iterator<Item = (Range<usize>, String)> + '_ {
+    let mut start = None;
+    text.char_indices()
+        .chain(std::iter::once((text.len(), ' ')))
+        .filter_map(
+            move |(index, character)| match (start, character.is_alphanumeric()) {
+                (None, true) => {
+                    start = Some(index);
+                    None
+                }
+                (Some(word_start), false) => {
+                    start = None;
+                    Some((word_start..index, text[word_start..index].to_lowercase()))
+                }
+                _ => None,
+            },
+        )
+}
+
+fn create_snippet(document: &SearchDocument, query_words: &[String]) -> Vec<SnippetPart> {
+    let is_match = |word: &str| query_words.iter().any(|query| word.starts_with(query));
+
+    for text in &document.text {
+        let words: Vec<(Range<usize>, String)> = tokenize(text).collect();
+        let Some(first_match) = words.iter().position(|(_, word)| is_match(word)) else {
+            continue;
+        };
+        let start = first_match.saturating_sub(SNIPPET_WORDS_BEFORE);
+        let end = (first_match + SNIPPET_WORDS_AFTER).min(words.len());
+
+        let mut parts = Vec::new();
+        let mut plain = if start > 0 {
+            "…".to_string()
+        } else {
+            String::new()
+        };
+        let mut cursor = words[start].0.start;
+        for (range, word) in &words[start..end] {
+            if !is_match(word) {
+                continue;
+            }
+            plain.push_str(&text[cursor..range.start]);
+            if !plain.is_empty() {
+                parts.push(SnippetPart {
+                    text: std::mem::take(&mut plain),
+                    highlighted: false,
+                });
+            }
+            parts.push(SnippetPart {
+                text: text[range.clone()].to_string(),
+                highlighted: true,
+            });
+            cursor = range.end;
+        }
+        plain.push_str(&text[cursor..words[end - 1].0.end]);
+        if end < words.len() {
+            plain.push('…');
+        }
+        if !plain.is_empty() {
+            parts.push(SnippetPart {
+                text: plain,
+                highlighted: false,
+            });
+        }
+        return parts;
+    }
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storable::{Company, Interview, Question, Role};
+    use crate::Timestamp;
+
+    fn highlighted(snippet: &[SnippetPart]) -> Vec<&str> {
+        snippet
+            .iter()
+            .filter(|part| part.highlighted)
+            .map(|part| part.text.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_tokenize() {
+        let words: Vec<_> = tokenize("Rust, async-Rust & C++ 2025").collect();
+        assert_eq!(
+            words,
+            vec![
+                (0..4, "rust".to_string()),
+                (6..11, "async".to_string()),
+                (12..16, "rust".to_string()),
+                (19..20, "c".to_string()),
+                (23..27, "2025".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_search_ranks_title_matches_first() {
+        let company = Company::new("Rust Corp");
+        let mut role = company.create_role("Backend engineer", Timestamp::now());
+        role.description = "Writing Rust services".to_string();
+
+        let mut index = SearchIndex::default();
+        index.update(&company);
+        index.update(&role);
+
+        let results = index.search("rust");
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].kind, SearchKind::Company);
+        assert!(results[0].snippet.is_empty());
+        assert_eq!(results[1].kind, SearchKind::Role);
+        assert_eq!(results[1].company_id, Some(company.id));
+        assert_eq!(highlighted(&results[1].snippet), vec!["Rust"]);
+    }
+
+    #[test]
+    fn test_search_needs_every_word() {
+        let company = Company::new("company");
+        let role = company.create_role("role", Timestamp::now());
+        let first = role.create_question("first", "Talked about rust and testing");
+        let second = role.create_question("second", "Talked about rust");
+
+        let mut index = SearchIndex::default();
+        index.update(&role);
+        index.update(&first);
+        index.update(&second);
+
+        let results = index.search("RUST test");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, first.id);
+        assert_eq!(results[0].role_id, Some(role.id));
+        // Found through the role, even though the company itself wasn't indexed
+        assert_eq!(results[0].company_id, Some(company.id));
+        assert_eq!(highlighted(&results[0].snippet), vec!["rust", "testing"]);
+    }
+
+    #[test]
+    fn test_search_prefers_whole_words() {
+        let company = Company::new("company");
+        let role = company.create_role("role", Timestamp::now());
+        let mut whole = Interview::new(&role, "whole");
+        whole.notes = "pair programming".to_string();
+        let mut prefix = Interview::new(&role, "prefix");
+        prefix.notes = "pairing".to_string();
+
+        let mut index = SearchIndex::default();
+        index.update(&prefix);
+        index.update(&whole);
+
+        let results = index.search("pair");
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].id, whole.id);
+        assert_eq!(results[1].id, prefix.id);
+    }
+
+    #[test]
+    fn test_update_and_remove() {
+        let mut company = Company::new("Acme");
+        let mut index = SearchIndex::default();
+        index.update(&company);
+        assert_eq!(index.search("acme").len(), 1);
+
+        company.name = "Initech".to_string();
+        index.update(&company);
+        assert!(index.search("acme").is_empty());
+        assert_eq!(index.search("initech").len(), 1);
+
+        company.date_deleted = Some(Timestamp::now());
+        index.update(&company);
+        assert!(index.search("initech").is_empty());
+        assert!(index.is_empty());
+
+        company.date_deleted = None;
+        index.update(&company);
+        index.remove(company.id);
+        assert!(index.is_empty());
+        assert!(index.words.is_empty());
+    }
+
+    #[test]
+    fn test_snippet_is_trimmed_around_the_match() {
+        let question = Question::new(
+            uuid::Uuid::new_v4(),
+            "question",
+            "one two three four five six seven eight nine ten eleven twelve thirteen fourteen \
+            fifteen sixteen seventeen eighteen nineteen twenty needle after",
+        );
+        let mut index = SearchIndex::default();
+        index.update(&question);
+
+        let results = index.search("needle");
+        let snippet = &results[0].snippet;
+        assert_eq!(
+            snippet,
+            &vec![
+                SnippetPart {
+                    text: "…sixteen seventeen eighteen nineteen twenty ".to_string(),
+                    highlighted: false,
+                },
+                SnippetPart {
+                    text: "needle".to_string(),
+                    highlighted: true,
+                },
+                SnippetPart {
+                    text: " after".to_string(),
+                    highlighted: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_by_kind() {
+        let company = Company::new("shared");
+        let role = company.create_role("shared", Timestamp::now());
+        let other_role = company.create_role("shared role", Timestamp::now());
+
+        let mut index = SearchIndex::default();
+        index.update(&company);
+        index.update(&role);
+        index.update(&other_role);
+
+        let groups = group_by_kind(index.search("shared"));
+        let kinds: Vec<_> = groups
+            .iter()
+            .map(|(kind, results)| (*kind, results.len()))
+            .collect();
+        assert_eq!(kinds.len(), 2);
+        assert!(kinds.contains(&(SearchKind::Company, 1)));
+        assert!(kinds.contains(&(SearchKind::Role, 2)));
+    }
+
+    #[test]
+    fn test_empty_query() {
+        let mut index = SearchIndex::default();
+        index.update(&Role::new(uuid::Uuid::new_v4(), "role", Timestamp::now()));
+        assert!(index.search("").is_empty());
+        assert!(index.search(" - ").is_empty());
+    }
+}
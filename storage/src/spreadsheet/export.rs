@@ -0,0 +1,170 @@
+use crate::spreadsheet::{SpreadsheetError, SpreadsheetField, SpreadsheetKind};
+use crate::storable::*;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+
+/// What a spreadsheet takes to be the start of a formula
+const FORMULA_PREFIXES: [char; 4] = ['=', '+', '-', '@'];
+
+/// A storable that can be written out as a row of a spreadsheet, with a column for each field
+pub trait SpreadsheetColumns: Serialize {
+    fn columns() -> Vec<&'static str>;
+}
+
+macro_rules! impl_spreadsheet_columns {
+    ($($storable:ty),+) => {
+        $(
+            impl SpreadsheetColumns for $storable {
+                fn columns() -> Vec<&'static str> {
+                    <$storable>::as_field_name_array()
+                        .iter()
+                        .map(|field| field.name())
+                        .collect()
+                }
+            }
+        )+
+    };
+}
+
+impl_spreadsheet_columns!(Company, Contact, Flag, Interview, Question, Reminder, Role, Value);
+
+/// Every field of every item, using the field names as the headers
+pub fn export_csv<O: SpreadsheetColumns>(items: &[O]) -> Result<String, SpreadsheetError> {
+    let columns = O::columns();
+    let rows = items
+        .iter()
+        .map(|item| row(&columns, item))
+        .collect::<Result<Vec<_>, _>>()?;
+    write_csv(&columns, rows)
+}
+
+/// Roles along with the name of the company each belongs to, so they can be imported again
+pub fn export_roles_csv(roles: &[Role], companies: &[Company]) -> Result<String, SpreadsheetError> {
+    let company_names: HashMap<_, _> = companies
+        .iter()
+        .map(|company| (company.id, company.name.as_str()))
+        .collect();
+    let company_header =
+        SpreadsheetKind::Roles.header(SpreadsheetField::Company(CompanyFieldName::Name));
+
+    let role_columns = Role::columns();
+    let mut columns = vec![company_header];
+    columns.extend(&role_columns);
+    let rows = roles
+        .iter()
+        .map(|role| {
+            let company_name = company_names.get(&role.company_id).copied();
+            let mut cells = vec![text_cell(company_name.unwrap_or_default())];
+            cells.extend(row(&role_columns, role)?);
+            Ok(cells)
+        })
+        .collect::<Result<Vec<_>, SpreadsheetError>>()?;
+    write_csv(&columns, rows)
+}
+
+fn row<O: Serialize>(columns: &[&str], item: &O) -> Result<Vec<String>, SpreadsheetError> {
+    let value = serde_json::to_value(item).map_err(|e| SpreadsheetError::Write(e.to_string()))?;
+    Ok(columns
+        .iter()
+        .map(|column| cell(value.get(column)))
+        .collect())
+}
+
+/// Lists and nested values, such as a role's status history, are kept as JSON
+fn cell(value: Option<&JsonValue>) -> String {
+    match value {
+        None | Some(JsonValue::Null) => String::new(),
+        Some(JsonValue::String(text)) => text_cell(text),
+        Some(value) => value.to_string(),
+    }
+}
+
+/// Text that would be taken for a formula is started with a `'`, so opening the file shows it
+/// rather than running it
+fn text_cell(text: &str) -> String {
+    if text.starts_with(FORMULA_PREFIXES) {
+        format!("'{text}")
+    } else {
+        text.to_string()
+    }
+}
+
+fn write_csv(columns: &[&str], rows: Vec<Vec<String>>) -> Result<String, SpreadsheetError> {
+    let write_error = |e: csv::Error| SpreadsheetError::Write(e.to_string());
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(columns).map_err(write_error)?;
+    for row in rows {
+        writer.write_record(row).map_err(write_error)?;
+    }
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| SpreadsheetError::Write(e.to_string()))?;
+    String::from_utf8(bytes).map_err(|e| SpreadsheetError::Write(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spreadsheet::SpreadsheetTable;
+    use crate::Timestamp;
+
+    #[test]
+    fn test_export_companies() {
+        let mut company = Company::new("Acme, Inc");
+        company.date_created = Some(Timestamp::from_string("2025-01-02 03:04"));
+
+        let csv = export_csv(&[company.clone()]).unwrap();
+        let table = SpreadsheetTable::parse(csv).unwrap();
+        assert_eq!(
+            table.headers,
+            vec!["id", "name", "date_deleted", "date_created", "date_updated"]
+        );
+        assert_eq!(table.cell(0, 0), company.id.to_string());
+        assert_eq!(table.cell(0, 1), "Acme, Inc");
+        assert_eq!(table.cell(0, 2), "");
+        assert_eq!(table.cell(0, 3), "2025-01-02T03:04");
+    }
+
+    #[test]
+    fn test_export_roles() {
+        let company = Company::new("Acme");
+        let mut role = company.create_role("Engineer", Timestamp::from_string("2025-02-03"));
+        role.salary_min = Some(50_000);
+        role.set_status(RoleStatus::Screening, Timestamp::from_string("2025-02-10"));
+        let orphan = Company::new("Gone").create_role("Tester", Timestamp::now());
+
+        let csv = export_roles_csv(&[role, orphan], &[company]).unwrap();
+        let table = SpreadsheetTable::parse(csv).unwrap();
+        let column = |name: &str| table.headers.iter().position(|h| h == name).unwrap();
+        assert_eq!(table.headers[0], "company");
+        assert_eq!(table.cell(0, 0), "Acme");
+        assert_eq!(table.cell(0, column("name")), "Engineer");
+        assert_eq!(table.cell(0, column("salary_min")), "50000");
+        assert_eq!(table.cell(0, column("status")), "screening");
+        assert_eq!(
+            table.cell(0, column("status_history")),
+            r#"[{"date_changed":"2025-02-10T00:00","from":"applied","to":"screening"}]"#
+        );
+        assert_eq!(table.cell(1, 0), "");
+        assert_eq!(table.cell(1, column("name")), "Tester");
+    }
+
+    #[test]
+    fn test_export_formulas_as_text() {
+        let company = Company::new("=HYPERLINK(\"https://evil.example\")");
+        let mut role = company.create_role("+1 Engineer", Timestamp::now());
+        role.equity = "-0.5%".to_string();
+        role.source = "@board".to_string();
+        role.location = "Remote - UK".to_string();
+
+        let csv = export_roles_csv(&[role], &[company]).unwrap();
+        let table = SpreadsheetTable::parse(csv).unwrap();
+        let column = |name: &str| table.headers.iter().position(|h| h == name).unwrap();
+        assert_eq!(table.cell(0, 0), "'=HYPERLINK(\"https://evil.example\")");
+        assert_eq!(table.cell(0, column("name")), "'+1 Engineer");
+        assert_eq!(table.cell(0, column("equity")), "'-0.5%");
+        assert_eq!(table.cell(0, column("source")), "'@board");
+        assert_eq!(table.cell(0, column("location")), "Remote - UK");
+    }
+}
@@ -0,0 +1,393 @@
+use crate::spreadsheet::{
+    SpreadsheetField, SpreadsheetHeader, SpreadsheetKind, SpreadsheetPreset, SpreadsheetTable,
+};
+use crate::storable::*;
+use crate::{DateOrder, PayPeriod, Timestamp};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::str::FromStr;
+
+/// Which field, if any, each column of a spreadsheet is read into
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnMapping {
+    kind: SpreadsheetKind,
+    columns: Vec<Option<SpreadsheetField>>,
+    date_order: Option<DateOrder>,
+}
+
+impl ColumnMapping {
+    /// Each column goes into the field the preset recognises its heading as, a field is only
+    /// filled from the first column that names it. Dates are read in the preset's order.
+    pub fn from_preset(
+        kind: SpreadsheetKind,
+        headers: &[String],
+        preset: &SpreadsheetPreset,
+    ) -> ColumnMapping {
+        let mut mapping = ColumnMapping {
+            kind,
+            columns: vec![None; headers.len()],
+            date_order: preset.date_order,
+        };
+        for (column, header) in headers.iter().enumerate() {
+            if let Some(field) = preset.field_for(kind, header) {
+                if mapping.column_for(field).is_none() {
+                    mapping.columns[column] = Some(field);
+                }
+            }
+        }
+        mapping
+    }
+
+    /// Uses the preset that recognises the most headings
+    pub fn guess(kind: SpreadsheetKind, headers: &[String]) -> ColumnMapping {
+        ColumnMapping::from_preset(kind, headers, SpreadsheetPreset::detect(kind, headers))
+    }
+
+    pub fn kind(&self) -> SpreadsheetKind {
+        self.kind
+    }
+
+    pub fn get(&self, column: usize) -> Option<SpreadsheetField> {
+        self.columns.get(column).copied().flatten()
+    }
+
+    /// Reads the column into the field, any other column that was going into it is left out
+    pub fn set(&mut self, column: usize, field: Option<SpreadsheetField>) {
+        if column >= self.columns.len() {
+            self.columns.resize(column + 1, None);
+        }
+        if let Some(field) = field {
+            self.columns
+                .iter_mut()
+                .filter(|mapped| **mapped == Some(field))
+                .for_each(|mapped| *mapped = None);
+        }
+        self.columns[column] = field;
+    }
+
+    pub fn date_order(&self) -> Option<DateOrder> {
+        self.date_order
+    }
+
+    /// Without an order, dates that read as a different day either way round are reported
+    pub fn set_date_order(&mut self, date_order: Option<DateOrder>) {
+        self.date_order = date_order;
+    }
+
+    pub fn column_for(&self, field: SpreadsheetField) -> Option<usize> {
+        self.columns
+            .iter()
+            .position(|mapped| *mapped == Some(field))
+    }
+
+    /// Every row with what it would create, the rows are numbered by line with the headings on
+    /// line 1
+    pub fn read_rows(&self, table: &SpreadsheetTable) -> Vec<SpreadsheetRow> {
+        (0..table.rows.len())
+            .map(|row| self.read_row(table, row))
+            .collect()
+    }
+
+    fn read_row(&self, table: &SpreadsheetTable, row: usize) -> SpreadsheetRow {
+        let mut errors = IncompletePartialErrors::default();
+        let mut company = Map::new();
+        let mut role = Map::new();
+        let mut status = None;
+        for (column, field) in self.columns.iter().enumerate() {
+            let Some(field) = field else {
+                continue;
+            };
+            let cell = table.cell(row, column);
+            if cell.is_empty() {
+                continue;
+            }
+            let (fields, value) = match field {
+                SpreadsheetField::Company(_) => (&mut company, cell.to_string()),
+                // Not part of the partial role, so it's set once the role exists
+                SpreadsheetField::Role(RoleFieldName::Status) => {
+                    match RoleStatus::from_str(cell) {
+                        Ok(parsed) => status = Some(parsed),
+                        Err(e) => {
+                            errors.push_invalid(SpreadsheetHeader(self.kind.header(*field)), e)
+                        }
+                    }
+                    continue;
+                }
+                SpreadsheetField::Role(RoleFieldName::DateApplied | RoleFieldName::DatePosted) => {
+                    match Timestamp::parse_in_order(cell, self.date_order) {
+                        Ok(date) => (&mut role, date.to_string()),
+                        Err(e) => {
+                            errors.push_invalid(SpreadsheetHeader(self.kind.header(*field)), e);
+                            continue;
+                        }
+                    }
+                }
+                SpreadsheetField::Role(field) => (&mut role, normalise_cell(*field, cell)),
+            };
+            fields.insert(field.name().to_string(), Value::String(value));
+        }
+
+        let company_header = |field: &str| {
+            CompanyFieldName::by_name(field)
+                .map(|field| self.kind.header(SpreadsheetField::Company(field)))
+        };
+        let company: PartialCompany = read_partial(company, &mut errors, company_header);
+        check(&company, &mut errors, company_header);
+
+        let role = (self.kind == SpreadsheetKind::Roles).then(|| {
+            let role_header = |field: &str| {
+                RoleFieldName::by_name(field)
+                    .map(|field| self.kind.header(SpreadsheetField::Role(field)))
+            };
+            let role: PartialRole = read_partial(role, &mut errors, role_header);
+            check(&role, &mut errors, role_header);
+            role
+        });
+
+        SpreadsheetRow {
+            line: row + 2,
+            company,
+            role,
+            status,
+            errors,
+        }
+    }
+}
+
+/// A row of a spreadsheet read into the company, and for roles the role, it describes
+#[derive(Clone, Debug)]
+pub struct SpreadsheetRow {
+    pub line: usize,
+    pub company: PartialCompany,
+    pub role: Option<PartialRole>,
+    /// Changed through [`Role::set_status`] after the role is created, so it's in the history
+    pub status: Option<RoleStatus>,
+    /// Tied to the heading of the field they're for, eg `company` rather than the company's `name`
+    pub errors: IncompletePartialErrors,
+}
+
+impl SpreadsheetRow {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn company_name(&self) -> &str {
+        self.company.name.as_deref().unwrap_or_default().trim()
+    }
+}
+
+/// Spreadsheets tend to spell out the pay period and format amounts
+fn normalise_cell(field: RoleFieldName, cell: &str) -> String {
+    match field {
+        RoleFieldName::PayPeriod => {
+            let period = cell.to_lowercase();
+            let period = period.trim_start_matches("per ");
+            let period = match period {
+                "hour" | "hr" => PayPeriod::Hourly.as_str(),
+                "day" => PayPeriod::Daily.as_str(),
+                "year" | "yearly" | "annually" | "annum" | "yr" => PayPeriod::Annual.as_str(),
+                period => period,
+            };
+            period.to_string()
+        }
+        RoleFieldName::SalaryMin | RoleFieldName::SalaryMax | RoleFieldName::Bonus => {
+            // Leave letters alone, `50k` isn't 50
+            let amount = cell.trim_start_matches(|c: char| !c.is_alphanumeric());
+            match amount.split_once('.') {
+                Some((whole, pence)) if pence.chars().all(|c| c.is_ascii_digit()) => {
+                    whole.to_string()
+                }
+                _ => amount.to_string(),
+            }
+        }
+        _ => cell.to_string(),
+    }
+}
+
+/// Cells that can't be read as their field are left out and reported against the column's heading
+fn read_partial<P, H>(
+    mut fields: Map<String, Value>,
+    errors: &mut IncompletePartialErrors,
+    header: H,
+) -> P
+where
+    P: DeserializeOwned + Default,
+    H: Fn(&str) -> Option<&'static str>,
+{
+    let names: Vec<String> = fields.keys().cloned().collect();
+    for name in names {
+        let Some(value) = fields.get(&name).cloned() else {
+            continue;
+        };
+        let single = Map::from_iter([(name.clone(), value.clone())]);
+        if let Err(e) = serde_json::from_value::<P>(Value::Object(single)) {
+            fields.remove(&name);
+            let message = format!("`{value}` isn't a valid {name}: {e}");
+            match header(&name) {
+                Some(header) => errors.push_invalid(SpreadsheetHeader(header), message),
+                None => errors.push(message),
+            }
+        }
+    }
+    serde_json::from_value(Value::Object(fields)).unwrap_or_else(|e| {
+        errors.push(e);
+        P::default()
+    })
+}
+
+/// Runs the partial's own checks, with the errors tied to the headings instead of the fields
+fn check<P, H>(partial: &P, errors: &mut IncompletePartialErrors, header: H)
+where
+    P: CheckPartialComplete,
+    H: Fn(&str) -> Option<&'static str>,
+{
+    let Err(incomplete) = partial.check_complete() else {
+        return;
+    };
+    for error in incomplete.field_errors() {
+        match error.field().and_then(&header) {
+            Some(header) => {
+                let message = error.message().replace(
+                    &format!("`{}`", error.field().unwrap_or_default()),
+                    &format!("`{header}`"),
+                );
+                errors.push_field(SpreadsheetHeader(header), error.kind(), message)
+            }
+            None => errors.push(error.message()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OptionalAmount;
+
+    fn table(csv: &str) -> SpreadsheetTable {
+        SpreadsheetTable::parse(csv).unwrap()
+    }
+
+    #[test]
+    fn test_guess() {
+        let table = table("Company,Position,Notes,Rating,Job Title\n");
+        let mapping = ColumnMapping::guess(SpreadsheetKind::Roles, &table.headers);
+        assert_eq!(
+            mapping.get(0),
+            Some(SpreadsheetField::Company(CompanyFieldName::Name))
+        );
+        assert_eq!(
+            mapping.get(1),
+            Some(SpreadsheetField::Role(RoleFieldName::Name))
+        );
+        assert_eq!(
+            mapping.get(2),
+            Some(SpreadsheetField::Role(RoleFieldName::Description))
+        );
+        assert_eq!(mapping.get(3), None);
+        // Only the first column for a field is used
+        assert_eq!(mapping.get(4), None);
+    }
+
+    #[test]
+    fn test_set_moves_field() {
+        let table = table("Company,Position,Job Title\n");
+        let mut mapping = ColumnMapping::guess(SpreadsheetKind::Roles, &table.headers);
+        let name = SpreadsheetField::Role(RoleFieldName::Name);
+        mapping.set(2, Some(name));
+        assert_eq!(mapping.get(1), None);
+        assert_eq!(mapping.column_for(name), Some(2));
+    }
+
+    #[test]
+    fn test_read_rows() {
+        let table = table(concat!(
+            "Company,Job Title,Applied,Stage,Salary,Currency,Pay Period\n",
+            "Acme,Engineer,2025-03-04,Technical,\"£50,000.00\",gbp,Per Year\n",
+        ));
+        let mapping = ColumnMapping::guess(SpreadsheetKind::Roles, &table.headers);
+        let rows = mapping.read_rows(&table);
+        assert_eq!(rows.len(), 1);
+
+        let row = &rows[0];
+        assert!(row.is_valid(), "{}", row.errors);
+        assert_eq!(row.line, 2);
+        assert_eq!(row.company_name(), "Acme");
+        let role = row.role.clone().unwrap();
+        assert_eq!(role.name.as_deref(), Some("Engineer"));
+        assert_eq!(
+            role.date_applied,
+            Some(Timestamp::from_string("2025-03-04"))
+        );
+        assert_eq!(row.status, Some(RoleStatus::Technical));
+        assert_eq!(role.salary_min, Some(OptionalAmount(Some(50_000))));
+        assert_eq!(role.pay_period, Some(PayPeriod::Annual));
+    }
+
+    #[test]
+    fn test_read_rows_in_date_order() {
+        let tracker = table(concat!(
+            "Company,Job Title,Applied\n",
+            "Acme,Engineer,03/04/2025\n",
+        ));
+        let mut mapping = ColumnMapping::guess(SpreadsheetKind::Roles, &tracker.headers);
+        assert_eq!(mapping.date_order(), None);
+        let row = &mapping.read_rows(&tracker)[0];
+        let date = row.errors.for_field(SpreadsheetHeader("date_applied"));
+        assert_eq!(date.unwrap().kind(), ValidationErrorKind::Invalid);
+
+        mapping.set_date_order(Some(DateOrder::DayFirst));
+        let row = &mapping.read_rows(&tracker)[0];
+        assert!(row.is_valid(), "{}", row.errors);
+        assert_eq!(
+            row.role.as_ref().unwrap().date_applied,
+            Some(Timestamp::from_string("2025-04-03"))
+        );
+
+        let teal = table(concat!(
+            "Company,Job Position,Date Applied\n",
+            "Acme,Engineer,03/04/2025\n",
+        ));
+        let mapping = ColumnMapping::guess(SpreadsheetKind::Roles, &teal.headers);
+        let row = &mapping.read_rows(&teal)[0];
+        assert_eq!(
+            row.role.as_ref().unwrap().date_applied,
+            Some(Timestamp::from_string("2025-03-04"))
+        );
+    }
+
+    #[test]
+    fn test_read_rows_reports_errors_by_heading() {
+        let table = table(concat!(
+            "company,name,date_applied,salary_min,status\n",
+            ",Engineer,2025-03-04,50k,Hired\n",
+        ));
+        let mapping = ColumnMapping::guess(SpreadsheetKind::Roles, &table.headers);
+        let row = &mapping.read_rows(&table)[0];
+        assert!(!row.is_valid());
+
+        let company = row.errors.for_field(SpreadsheetHeader("company")).unwrap();
+        assert_eq!(company.kind(), ValidationErrorKind::Missing);
+        assert_eq!(company.message(), "`company` is missing");
+        assert!(row
+            .errors
+            .for_field(SpreadsheetHeader("salary_min"))
+            .is_some());
+        assert!(row.errors.for_field(SpreadsheetHeader("status")).is_some());
+        assert!(row.errors.for_field(SpreadsheetHeader("name")).is_none());
+        assert_eq!(row.role.as_ref().unwrap().name.as_deref(), Some("Engineer"));
+    }
+
+    #[test]
+    fn test_read_company_rows() {
+        let table = table("name,id\nAcme,1\n  ,2\n");
+        let mapping = ColumnMapping::guess(SpreadsheetKind::Companies, &table.headers);
+        let rows = mapping.read_rows(&table);
+        assert!(rows[0].is_valid());
+        assert_eq!(rows[0].company_name(), "Acme");
+        assert!(rows[0].role.is_none());
+        assert!(rows[1]
+            .errors
+            .for_field(SpreadsheetHeader("name"))
+            .is_some());
+    }
+}
@@ -0,0 +1,155 @@
+use crate::storable::{CompanyFieldName, FieldKey, RoleFieldName};
+use thiserror::Error;
+
+mod export;
+pub use export::*;
+
+mod mapping;
+pub use mapping::*;
+
+mod preset;
+pub use preset::*;
+
+mod table;
+pub use table::*;
+
+#[derive(Debug, Error)]
+pub enum SpreadsheetError {
+    #[error("Could not read the CSV: {0}")]
+    Read(#[source] csv::Error),
+    #[error("Could not write the CSV: {0}")]
+    Write(String),
+    #[error("The CSV doesn't have a header row")]
+    MissingHeaders,
+}
+
+/// A column heading, for errors about cells that aren't tied to a field of a storable, such as the
+/// company a role belongs to
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SpreadsheetHeader(pub &'static str);
+
+impl FieldKey for SpreadsheetHeader {
+    fn key(&self) -> &'static str {
+        self.0
+    }
+}
+
+/// What each row of a spreadsheet is, roles name the company they belong to in one of their
+/// columns
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SpreadsheetKind {
+    Companies,
+    Roles,
+}
+
+impl SpreadsheetKind {
+    pub const ALL: [SpreadsheetKind; 2] = [SpreadsheetKind::Companies, SpreadsheetKind::Roles];
+
+    /// The fields a column can be read into, the first is the company's name
+    pub fn fields(&self) -> &'static [SpreadsheetField] {
+        match self {
+            SpreadsheetKind::Companies => &COMPANY_FIELDS,
+            SpreadsheetKind::Roles => &ROLE_FIELDS,
+        }
+    }
+
+    /// The column heading a field is exported under, which is always recognised when importing
+    pub fn header(&self, field: SpreadsheetField) -> &'static str {
+        match (self, field) {
+            (SpreadsheetKind::Roles, SpreadsheetField::Company(_)) => COMPANY_HEADER,
+            (_, field) => field.name(),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SpreadsheetKind::Companies => "companies",
+            SpreadsheetKind::Roles => "roles",
+        }
+    }
+}
+
+/// The column of an exported role that names its company
+const COMPANY_HEADER: &str = "company";
+
+const COMPANY_FIELDS: [SpreadsheetField; 1] = [SpreadsheetField::Company(CompanyFieldName::Name)];
+
+const ROLE_FIELDS: [SpreadsheetField; 15] = [
+    SpreadsheetField::Company(CompanyFieldName::Name),
+    SpreadsheetField::Role(RoleFieldName::Name),
+    SpreadsheetField::Role(RoleFieldName::Description),
+    SpreadsheetField::Role(RoleFieldName::DateApplied),
+    SpreadsheetField::Role(RoleFieldName::Status),
+    SpreadsheetField::Role(RoleFieldName::SalaryMin),
+    SpreadsheetField::Role(RoleFieldName::SalaryMax),
+    SpreadsheetField::Role(RoleFieldName::Currency),
+    SpreadsheetField::Role(RoleFieldName::PayPeriod),
+    SpreadsheetField::Role(RoleFieldName::Equity),
+    SpreadsheetField::Role(RoleFieldName::Bonus),
+    SpreadsheetField::Role(RoleFieldName::Location),
+    SpreadsheetField::Role(RoleFieldName::PostingUrl),
+    SpreadsheetField::Role(RoleFieldName::Source),
+    SpreadsheetField::Role(RoleFieldName::DatePosted),
+];
+
+/// A field of a company or role that a spreadsheet column can be read into
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SpreadsheetField {
+    Company(CompanyFieldName),
+    Role(RoleFieldName),
+}
+
+impl SpreadsheetField {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SpreadsheetField::Company(field) => field.name(),
+            SpreadsheetField::Role(field) => field.name(),
+        }
+    }
+
+    /// How the field is described when choosing which column goes where
+    pub fn label(&self) -> String {
+        match self {
+            SpreadsheetField::Company(_) => "Company".to_string(),
+            SpreadsheetField::Role(RoleFieldName::Name) => "Role".to_string(),
+            SpreadsheetField::Role(RoleFieldName::PostingUrl) => "Posting URL".to_string(),
+            field => {
+                let name = field.name().replace('_', " ");
+                let mut chars = name.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header() {
+        let company = SpreadsheetField::Company(CompanyFieldName::Name);
+        assert_eq!(SpreadsheetKind::Companies.header(company), "name");
+        assert_eq!(SpreadsheetKind::Roles.header(company), "company");
+        assert_eq!(
+            SpreadsheetKind::Roles.header(SpreadsheetField::Role(RoleFieldName::DateApplied)),
+            "date_applied"
+        );
+    }
+
+    #[test]
+    fn test_label() {
+        assert_eq!(
+            SpreadsheetField::Company(CompanyFieldName::Name).label(),
+            "Company"
+        );
+        assert_eq!(SpreadsheetField::Role(RoleFieldName::Name).label(), "Role");
+        assert_eq!(
+            SpreadsheetField::Role(RoleFieldName::DateApplied).label(),
+            "Date applied"
+        );
+    }
+}
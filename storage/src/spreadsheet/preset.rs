@@ -0,0 +1,222 @@
+use crate::spreadsheet::{SpreadsheetField, SpreadsheetKind};
+use crate::storable::{CompanyFieldName, RoleFieldName};
+use crate::DateOrder;
+
+use SpreadsheetField::{Company, Role};
+
+static PRESETS: [SpreadsheetPreset; 4] = SpreadsheetPreset::ALL;
+
+/// Column headings other tools use for the fields, used to guess which column goes where. The
+/// headings the fields are exported under are always recognised, whichever preset is used.
+///
+/// A tool writes its dates one way round, a preset without a date order is for spreadsheets that
+/// could have been written either way, so dates such as 03/04/2025 are refused.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SpreadsheetPreset {
+    pub name: &'static str,
+    pub date_order: Option<DateOrder>,
+    columns: &'static [(&'static str, SpreadsheetField)],
+}
+
+impl SpreadsheetPreset {
+    /// A spreadsheet exported from here
+    pub const EXPORTED: SpreadsheetPreset = SpreadsheetPreset {
+        name: "Exported from here",
+        // Exported dates are ISO 8601, which only reads one way
+        date_order: None,
+        columns: &[],
+    };
+
+    /// The headings people tend to give their own tracking spreadsheets, and that job tracking
+    /// tools tend to export
+    pub const JOB_TRACKER: SpreadsheetPreset = SpreadsheetPreset {
+        name: "Spreadsheet or job tracker",
+        date_order: None,
+        columns: &[
+            ("Company", Company(CompanyFieldName::Name)),
+            ("Company Name", Company(CompanyFieldName::Name)),
+            ("Employer", Company(CompanyFieldName::Name)),
+            ("Organisation", Company(CompanyFieldName::Name)),
+            ("Organization", Company(CompanyFieldName::Name)),
+            ("Job Title", Role(RoleFieldName::Name)),
+            ("Title", Role(RoleFieldName::Name)),
+            ("Position", Role(RoleFieldName::Name)),
+            ("Role", Role(RoleFieldName::Name)),
+            ("Job", Role(RoleFieldName::Name)),
+            ("Job Description", Role(RoleFieldName::Description)),
+            ("Notes", Role(RoleFieldName::Description)),
+            ("Applied", Role(RoleFieldName::DateApplied)),
+            ("Applied On", Role(RoleFieldName::DateApplied)),
+            ("Date", Role(RoleFieldName::DateApplied)),
+            ("Status", Role(RoleFieldName::Status)),
+            ("Stage", Role(RoleFieldName::Status)),
+            ("Salary", Role(RoleFieldName::SalaryMin)),
+            ("Min Salary", Role(RoleFieldName::SalaryMin)),
+            ("Minimum Salary", Role(RoleFieldName::SalaryMin)),
+            ("Salary From", Role(RoleFieldName::SalaryMin)),
+            ("Max Salary", Role(RoleFieldName::SalaryMax)),
+            ("Maximum Salary", Role(RoleFieldName::SalaryMax)),
+            ("Salary To", Role(RoleFieldName::SalaryMax)),
+            ("Salary Currency", Role(RoleFieldName::Currency)),
+            ("Pay Period", Role(RoleFieldName::PayPeriod)),
+            ("Salary Period", Role(RoleFieldName::PayPeriod)),
+            ("City", Role(RoleFieldName::Location)),
+            ("URL", Role(RoleFieldName::PostingUrl)),
+            ("Link", Role(RoleFieldName::PostingUrl)),
+            ("Job URL", Role(RoleFieldName::PostingUrl)),
+            ("Job Link", Role(RoleFieldName::PostingUrl)),
+            ("Job Board", Role(RoleFieldName::Source)),
+            ("Found On", Role(RoleFieldName::Source)),
+            ("Posted", Role(RoleFieldName::DatePosted)),
+            ("Posted On", Role(RoleFieldName::DatePosted)),
+        ],
+    };
+
+    /// A board export from Huntr
+    pub const HUNTR: SpreadsheetPreset = SpreadsheetPreset {
+        name: "Huntr",
+        date_order: Some(DateOrder::MonthFirst),
+        columns: &[
+            ("Company", Company(CompanyFieldName::Name)),
+            ("Title", Role(RoleFieldName::Name)),
+            ("Description", Role(RoleFieldName::Description)),
+            ("List", Role(RoleFieldName::Status)),
+            ("Date Applied", Role(RoleFieldName::DateApplied)),
+            ("Date Posted", Role(RoleFieldName::DatePosted)),
+            ("Salary", Role(RoleFieldName::SalaryMin)),
+            ("Location", Role(RoleFieldName::Location)),
+            ("URL", Role(RoleFieldName::PostingUrl)),
+        ],
+    };
+
+    /// A job tracker export from Teal
+    pub const TEAL: SpreadsheetPreset = SpreadsheetPreset {
+        name: "Teal",
+        date_order: Some(DateOrder::MonthFirst),
+        columns: &[
+            ("Company", Company(CompanyFieldName::Name)),
+            ("Job Position", Role(RoleFieldName::Name)),
+            ("Job Description", Role(RoleFieldName::Description)),
+            ("Status", Role(RoleFieldName::Status)),
+            ("Date Applied", Role(RoleFieldName::DateApplied)),
+            ("Min Salary", Role(RoleFieldName::SalaryMin)),
+            ("Max Salary", Role(RoleFieldName::SalaryMax)),
+            ("Location", Role(RoleFieldName::Location)),
+            ("Job URL", Role(RoleFieldName::PostingUrl)),
+        ],
+    };
+
+    pub const ALL: [SpreadsheetPreset; 4] = [
+        SpreadsheetPreset::EXPORTED,
+        SpreadsheetPreset::JOB_TRACKER,
+        SpreadsheetPreset::HUNTR,
+        SpreadsheetPreset::TEAL,
+    ];
+
+    /// The field a column with this heading is read into, if the heading is recognised
+    pub fn field_for(&self, kind: SpreadsheetKind, header: &str) -> Option<SpreadsheetField> {
+        let header = normalise(header);
+        let fields = kind.fields();
+        fields
+            .iter()
+            .copied()
+            .find(|field| normalise(kind.header(*field)) == header)
+            .or_else(|| {
+                self.columns
+                    .iter()
+                    .find(|(name, field)| normalise(name) == header && fields.contains(field))
+                    .map(|(_, field)| *field)
+            })
+    }
+
+    /// The preset that recognises the most headings, the one for this app's own exports if none
+    /// of them do any better
+    pub fn detect(kind: SpreadsheetKind, headers: &[String]) -> &'static SpreadsheetPreset {
+        let recognised = |preset: &SpreadsheetPreset| {
+            headers
+                .iter()
+                .filter(|header| preset.field_for(kind, header).is_some())
+                .count()
+        };
+        let mut best = &PRESETS[0];
+        for preset in &PRESETS[1..] {
+            if recognised(preset) > recognised(best) {
+                best = preset;
+            }
+        }
+        best
+    }
+
+    pub fn by_name(name: &str) -> Option<&'static SpreadsheetPreset> {
+        PRESETS.iter().find(|preset| preset.name == name)
+    }
+}
+
+/// Headings are matched ignoring case, spacing and punctuation, so `Date Applied` is `date_applied`
+fn normalise(header: &str) -> String {
+    header
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_for_exported_headers() {
+        let preset = &SpreadsheetPreset::EXPORTED;
+        assert_eq!(
+            preset.field_for(SpreadsheetKind::Roles, "Date Applied"),
+            Some(Role(RoleFieldName::DateApplied))
+        );
+        assert_eq!(
+            preset.field_for(SpreadsheetKind::Roles, "company"),
+            Some(Company(CompanyFieldName::Name))
+        );
+        assert_eq!(
+            preset.field_for(SpreadsheetKind::Companies, "name"),
+            Some(Company(CompanyFieldName::Name))
+        );
+        assert_eq!(preset.field_for(SpreadsheetKind::Roles, "id"), None);
+        assert_eq!(preset.field_for(SpreadsheetKind::Roles, "Job Title"), None);
+    }
+
+    #[test]
+    fn test_field_for_only_fields_of_kind() {
+        let preset = &SpreadsheetPreset::JOB_TRACKER;
+        assert_eq!(
+            preset.field_for(SpreadsheetKind::Roles, "Job Title"),
+            Some(Role(RoleFieldName::Name))
+        );
+        assert_eq!(
+            preset.field_for(SpreadsheetKind::Companies, "Employer"),
+            Some(Company(CompanyFieldName::Name))
+        );
+        assert_eq!(
+            preset.field_for(SpreadsheetKind::Companies, "Job Title"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_detect() {
+        let headers = |headers: &[&str]| headers.iter().map(|h| h.to_string()).collect::<Vec<_>>();
+        let exported = headers(&["company", "id", "name", "date_applied"]);
+        assert_eq!(
+            SpreadsheetPreset::detect(SpreadsheetKind::Roles, &exported).name,
+            SpreadsheetPreset::EXPORTED.name
+        );
+        let tracker = headers(&["Company", "Position", "Stage", "Link"]);
+        assert_eq!(
+            SpreadsheetPreset::detect(SpreadsheetKind::Roles, &tracker).name,
+            SpreadsheetPreset::JOB_TRACKER.name
+        );
+        let teal = headers(&["Company", "Job Position", "Status", "Date Applied"]);
+        let detected = SpreadsheetPreset::detect(SpreadsheetKind::Roles, &teal);
+        assert_eq!(detected.name, SpreadsheetPreset::TEAL.name);
+        assert_eq!(detected.date_order, Some(DateOrder::MonthFirst));
+    }
+}
@@ -0,0 +1,71 @@
+use crate::spreadsheet::SpreadsheetError;
+
+/// The cells of a CSV file, as they were in the file apart from surrounding spaces
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SpreadsheetTable {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl SpreadsheetTable {
+    /// Rows that are shorter or longer than the header row are kept as they are, blank rows are
+    /// dropped
+    pub fn parse<S: AsRef<str>>(csv: S) -> Result<SpreadsheetTable, SpreadsheetError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(csv.as_ref().as_bytes());
+        let headers: Vec<String> = reader
+            .headers()
+            .map_err(SpreadsheetError::Read)?
+            .iter()
+            .map(str::to_string)
+            .collect();
+        if headers.iter().all(String::is_empty) {
+            return Err(SpreadsheetError::MissingHeaders);
+        }
+
+        let mut rows = Vec::new();
+        for record in reader.records() {
+            let record = record.map_err(SpreadsheetError::Read)?;
+            if record.iter().any(|cell| !cell.is_empty()) {
+                rows.push(record.iter().map(str::to_string).collect());
+            }
+        }
+        Ok(SpreadsheetTable { headers, rows })
+    }
+
+    /// The cell in the column, a short row is treated as having empty cells at the end
+    pub fn cell(&self, row: usize, column: usize) -> &str {
+        self.rows
+            .get(row)
+            .and_then(|row| row.get(column))
+            .map_or("", String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let table = SpreadsheetTable::parse(
+            "\u{feff}Company, Job Title\n Acme ,\"Engineer, Senior\"\n\n,\nGlobex\n",
+        )
+        .unwrap();
+        assert_eq!(table.headers, vec!["Company", "Job Title"]);
+        assert_eq!(table.rows.len(), 2);
+        assert_eq!(table.cell(0, 0), "Acme");
+        assert_eq!(table.cell(0, 1), "Engineer, Senior");
+        assert_eq!(table.cell(1, 0), "Globex");
+        assert_eq!(table.cell(1, 1), "");
+        assert_eq!(table.cell(2, 0), "");
+    }
+
+    #[test]
+    fn test_parse_without_headers() {
+        let result = SpreadsheetTable::parse("");
+        assert!(matches!(result, Err(SpreadsheetError::MissingHeaders)));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::search::{SearchDocument, SearchKind};
 use crate::storable::*;
 use crate::Timestamp;
 use partially::Partial;
@@ -9,12 +10,18 @@ use field_types::FieldName;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Partial)]
 #[cfg_attr(feature = "field_names", derive(FieldName))]
-#[partially(derive(Deserialize, Default))]
+#[partially(derive(Debug, Clone, Deserialize, Default))]
 pub struct Company {
     #[partially(omit)]
     pub id: Uuid,
     pub name: String,
     pub date_deleted: Option<Timestamp>,
+    #[serde(default)]
+    #[partially(omit)]
+    pub date_created: Option<Timestamp>,
+    #[serde(default)]
+    #[partially(omit)]
+    pub date_updated: Option<Timestamp>,
 }
 
 impl Company {
@@ -23,6 +30,8 @@ impl Company {
             id: Uuid::new_v4(),
             name: name.into(),
             date_deleted: None,
+            date_created: None,
+            date_updated: None,
         }
     }
 
@@ -33,8 +42,10 @@ impl Company {
             id: Uuid::new_v4(),
             name: partial
                 .name
-                .ok_or_else(|| IncompletePartialErrors::field_error("name"))?,
+                .ok_or_else(|| IncompletePartialErrors::field_error(CompanyFieldName::Name))?,
             date_deleted: partial.date_deleted.unwrap_or_default(),
+            date_created: None,
+            date_updated: None,
         })
     }
 
@@ -74,13 +85,48 @@ impl Company {
     ) -> Result<Value, IncompletePartialErrors> {
         Value::new_from_partial(self, value)
     }
+
+    pub fn create_contact<S: Into<String>>(&self, name: S) -> Contact {
+        Contact::new(self, name)
+    }
+
+    pub fn create_contact_from_partial(
+        &self,
+        contact: PartialContact,
+    ) -> Result<Contact, IncompletePartialErrors> {
+        Contact::new_from_partial(self, contact)
+    }
+
+    pub fn create_reminder<S: Into<String>>(&self, name: S, due: Timestamp) -> Reminder {
+        Reminder::new(self, name, due)
+    }
+
+    pub fn create_reminder_from_partial(
+        &self,
+        reminder: PartialReminder,
+    ) -> Result<Reminder, IncompletePartialErrors> {
+        Reminder::new_from_partial(self, reminder)
+    }
 }
 
 impl_has_id!(Company);
 impl_has_name!(Company);
 impl_has_deleted!(Company);
+impl_has_timestamps!(Company);
+impl_has_schema_version!(Company, [add_timestamps]);
+impl_restore_revision!(Company);
 
-impl_is_partial_complete_optional_name_only!(PartialCompany);
+impl Searchable for Company {
+    const KIND: SearchKind = SearchKind::Company;
+
+    fn to_search_document(&self) -> SearchDocument {
+        SearchDocument::new(Self::KIND, self.id, &self.name)
+    }
+}
+
+impl Queryable for Company {}
+
+impl_is_partial_complete_optional_name_only!(PartialCompany, CompanyFieldName);
 
 #[cfg(test)]
 mod test_helper {
@@ -105,6 +151,7 @@ mod tests {
     test_has_id!(Company);
     test_has_name!(Company);
     test_has_deleted!(Company);
+    test_has_timestamps!(Company);
 
     #[test]
     fn test_new_from_partial() {
@@ -146,6 +193,7 @@ mod tests {
             description: None,
             date_applied: Some(Timestamp::now()),
             date_deleted: None,
+            ..Default::default()
         };
         let role = company.create_role_from_partial(partial_role).unwrap();
         assert_eq!(role.name, name);
@@ -200,6 +248,29 @@ mod tests {
         assert_eq!(value.name, name);
     }
 
+    #[test]
+    fn test_create_contact() {
+        let company = Company::new("company");
+        let contact = company.create_contact("contact");
+        assert_eq!(contact.get_company_id(), company.get_id());
+        assert_eq!(contact.name, "contact");
+    }
+
+    #[test]
+    fn create_contact_from_partial() {
+        let name = "Contact name".to_string();
+        let company = Company::new("company");
+        let partial_contact = PartialContact {
+            name: Some(name.clone()),
+            ..Default::default()
+        };
+        let contact = company
+            .create_contact_from_partial(partial_contact)
+            .unwrap();
+        assert_eq!(contact.name, name);
+        assert_eq!(contact.get_company_id(), company.get_id());
+    }
+
     #[test]
     fn test_modify_with_hashmap() {
         let mut company = Company::new("Original name");
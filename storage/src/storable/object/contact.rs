@@ -0,0 +1,212 @@
+use crate::search::{SearchDocument, SearchKind};
+use crate::storable::*;
+use crate::Timestamp;
+use partially::Partial;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "field_names")]
+use field_types::FieldName;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Partial)]
+#[cfg_attr(feature = "field_names", derive(FieldName))]
+#[partially(derive(Deserialize, Default), attribute(serde(default)))]
+pub struct Contact {
+    #[partially(omit)]
+    pub id: Uuid,
+    #[partially(omit)]
+    pub company_id: Uuid,
+    pub name: String,
+    pub title: String,
+    pub email: String,
+    pub phone: String,
+    pub linkedin_url: String,
+    pub notes: String,
+    pub date_deleted: Option<Timestamp>,
+    #[serde(default)]
+    #[partially(omit)]
+    pub date_created: Option<Timestamp>,
+    #[serde(default)]
+    #[partially(omit)]
+    pub date_updated: Option<Timestamp>,
+}
+
+impl Contact {
+    pub fn new<C: HasId, S: Into<String>>(company: C, name: S) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            company_id: company.get_id(),
+            name: name.into(),
+            title: String::new(),
+            email: String::new(),
+            phone: String::new(),
+            linkedin_url: String::new(),
+            notes: String::new(),
+            date_deleted: None,
+            date_created: None,
+            date_updated: None,
+        }
+    }
+
+    pub fn new_from_partial<C: HasId>(
+        company: C,
+        partial: PartialContact,
+    ) -> Result<Contact, IncompletePartialErrors> {
+        partial.check_complete()?;
+
+        Ok(Contact {
+            id: Uuid::new_v4(),
+            company_id: company.get_id(),
+            name: partial
+                .name
+                .ok_or_else(|| IncompletePartialErrors::field_error(ContactFieldName::Name))?,
+            title: partial.title.unwrap_or_default(),
+            email: partial.email.unwrap_or_default(),
+            phone: partial.phone.unwrap_or_default(),
+            linkedin_url: partial.linkedin_url.unwrap_or_default(),
+            notes: partial.notes.unwrap_or_default(),
+            date_deleted: partial.date_deleted.unwrap_or_default(),
+            date_created: None,
+            date_updated: None,
+        })
+    }
+}
+
+impl_has_id!(Contact);
+impl_has_name!(Contact);
+impl_has_company!(Contact);
+impl_has_deleted!(Contact);
+impl_has_timestamps!(Contact);
+impl_has_schema_version!(Contact, [add_timestamps]);
+impl_restore_revision!(Contact);
+
+impl Searchable for Contact {
+    const KIND: SearchKind = SearchKind::Contact;
+
+    fn to_search_document(&self) -> SearchDocument {
+        SearchDocument::new(Self::KIND, self.id, &self.name)
+            .with_company(self.company_id)
+            .with_text(&self.title)
+            .with_text(&self.email)
+            .with_text(&self.notes)
+    }
+}
+
+impl Queryable for Contact {
+    fn get_parent_company_id(&self) -> Option<Uuid> {
+        Some(self.company_id)
+    }
+}
+
+impl_is_partial_complete_optional_name_only!(PartialContact, ContactFieldName);
+
+#[cfg(test)]
+mod test_helper {
+    use super::*;
+    use crate::test_helper::TestHelper;
+    use uuid::Uuid;
+
+    impl TestHelper for Contact {
+        async fn new_test() -> anyhow::Result<Self> {
+            Ok(Contact::new(Uuid::new_v4(), "Contact"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::TestHelper;
+    use paste::paste;
+    use serde::de::IntoDeserializer;
+    use std::collections::HashMap;
+
+    test_has_id!(Contact);
+    test_has_name!(Contact);
+    test_has_company!(Contact);
+    test_has_deleted!(Contact);
+    test_has_timestamps!(Contact);
+
+    #[test]
+    fn test_new_from_partial() {
+        let partial_contact = PartialContact {
+            name: Some("Jo Recruiter".to_string()),
+            email: Some("jo@example.com".to_string()),
+            ..Default::default()
+        };
+        let company_id = Uuid::new_v4();
+        let contact = Contact::new_from_partial(company_id, partial_contact).unwrap();
+        assert_eq!(contact.company_id, company_id);
+        assert_eq!(contact.name, "Jo Recruiter");
+        assert_eq!(contact.email, "jo@example.com");
+        assert_eq!(contact.phone, "");
+    }
+
+    #[test]
+    fn test_modify_with_hashmap() {
+        let mut contact = Contact::new(Uuid::new_v4(), "Contact name");
+        let original_id = contact.id;
+        let original_company = contact.company_id;
+
+        let mut hash_map: HashMap<String, serde_json::Value> = HashMap::new();
+        hash_map.insert("id".to_string(), Uuid::new_v4().to_string().into());
+        hash_map.insert("company_id".to_string(), Uuid::new_v4().to_string().into());
+        hash_map.insert("name".to_string(), "New name".into());
+        hash_map.insert("title".to_string(), "Hiring Manager".into());
+        hash_map.insert("email".to_string(), "new@example.com".into());
+        hash_map.insert("phone".to_string(), "+44 1234 567890".into());
+        hash_map.insert(
+            "linkedin_url".to_string(),
+            "https://www.linkedin.com/in/example".into(),
+        );
+        hash_map.insert("notes".to_string(), "New notes".into());
+        hash_map.insert("date_deleted".to_string(), "2025-07-28T00:00".into());
+
+        let partial_contact = PartialContact::deserialize(hash_map.into_deserializer()).unwrap();
+        contact.apply(partial_contact);
+
+        assert_eq!(contact.id, original_id);
+        assert_eq!(contact.company_id, original_company);
+        assert_eq!(contact.name, "New name".to_string());
+        assert_eq!(contact.title, "Hiring Manager".to_string());
+        assert_eq!(contact.email, "new@example.com".to_string());
+        assert_eq!(contact.phone, "+44 1234 567890".to_string());
+        assert_eq!(
+            contact.linkedin_url,
+            "https://www.linkedin.com/in/example".to_string()
+        );
+        assert_eq!(contact.notes, "New notes".to_string());
+        assert_eq!(
+            contact.date_deleted,
+            Some(Timestamp::from_string("2025-07-28T00:00"))
+        );
+    }
+
+    #[test]
+    fn test_partial_contact_is_complete_complete_contact() {
+        let complete_contact = PartialContact {
+            name: Some("Test Contact".to_string()),
+            ..Default::default()
+        };
+        assert!(complete_contact.check_complete().is_ok());
+    }
+
+    #[test]
+    fn test_partial_contact_is_complete_missing_name() {
+        let missing_name = PartialContact::default();
+        let error = missing_name.check_complete().unwrap_err();
+        let errors = error.get_errors();
+        assert!(errors.contains(&String::from("`name` is missing")));
+    }
+
+    #[test]
+    fn test_partial_contact_is_complete_empty_name() {
+        let empty_name = PartialContact {
+            name: Some(String::new()),
+            ..Default::default()
+        };
+        let error = empty_name.check_complete().unwrap_err();
+        let errors = error.get_errors();
+        assert!(errors.contains(&String::from("`name` is empty")));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::search::{SearchDocument, SearchKind};
 use crate::storable::*;
 use crate::Timestamp;
 use partially::Partial;
@@ -38,6 +39,12 @@ pub struct Flag {
     pub flag_color: FlagColor,
     pub name: String,
     pub date_deleted: Option<Timestamp>,
+    #[serde(default)]
+    #[partially(omit)]
+    pub date_created: Option<Timestamp>,
+    #[serde(default)]
+    #[partially(omit)]
+    pub date_updated: Option<Timestamp>,
 }
 
 impl Flag {
@@ -48,6 +55,8 @@ impl Flag {
             flag_color: FlagColor::Green,
             name: name.into(),
             date_deleted: None,
+            date_created: None,
+            date_updated: None,
         }
     }
 
@@ -58,6 +67,8 @@ impl Flag {
             flag_color: FlagColor::Red,
             name: name.into(),
             date_deleted: None,
+            date_created: None,
+            date_updated: None,
         }
     }
 
@@ -72,11 +83,13 @@ impl Flag {
             company_id: company.get_id(),
             flag_color: partial
                 .flag_color
-                .ok_or_else(|| IncompletePartialErrors::field_error("flag_color"))?,
+                .ok_or_else(|| IncompletePartialErrors::field_error(FlagFieldName::FlagColor))?,
             name: partial
                 .name
-                .ok_or_else(|| IncompletePartialErrors::field_error("name"))?,
+                .ok_or_else(|| IncompletePartialErrors::field_error(FlagFieldName::Name))?,
             date_deleted: partial.date_deleted.flatten(),
+            date_created: None,
+            date_updated: None,
         })
     }
 }
@@ -85,6 +98,23 @@ impl_has_id!(Flag);
 impl_has_name!(Flag);
 impl_has_company!(Flag);
 impl_has_deleted!(Flag);
+impl_has_timestamps!(Flag);
+impl_has_schema_version!(Flag, [add_timestamps]);
+impl_restore_revision!(Flag);
+
+impl Searchable for Flag {
+    const KIND: SearchKind = SearchKind::Flag;
+
+    fn to_search_document(&self) -> SearchDocument {
+        SearchDocument::new(Self::KIND, self.id, &self.name).with_company(self.company_id)
+    }
+}
+
+impl Queryable for Flag {
+    fn get_parent_company_id(&self) -> Option<Uuid> {
+        Some(self.company_id)
+    }
+}
 
 impl CheckPartialComplete for PartialFlag {
     fn check_complete(&self) -> Result<(), IncompletePartialErrors> {
@@ -93,14 +123,14 @@ impl CheckPartialComplete for PartialFlag {
         // Name must be present and not empty
         match self.name.as_ref().map(|name| !name.is_empty()) {
             Some(true) => {}
-            Some(false) => errors.push("`name` is empty"),
-            None => errors.push("`name` is missing"),
+            Some(false) => errors.push_empty(FlagFieldName::Name),
+            None => errors.push_missing(FlagFieldName::Name),
         }
 
         // Flag color must be present
         match self.flag_color {
             Some(_) => {}
-            None => errors.push("`flag_color` is missing"),
+            None => errors.push_missing(FlagFieldName::FlagColor),
         }
 
         errors.into()
@@ -132,6 +162,7 @@ mod tests {
     test_has_name!(Flag);
     test_has_company!(Flag);
     test_has_deleted!(Flag);
+    test_has_timestamps!(Flag);
 
     #[test]
     fn test_create_green_flag() {
@@ -258,4 +289,26 @@ mod tests {
         assert!(errors.contains(&"`name` is missing".to_string()));
         assert!(errors.contains(&"`flag_color` is missing".to_string()));
     }
+
+    #[test]
+    fn test_partial_flag_errors_are_keyed_by_field() {
+        let flag = PartialFlag {
+            flag_color: None,
+            name: Some(String::new()),
+            date_deleted: None,
+        };
+
+        let error = flag.check_complete().unwrap_err();
+        assert_eq!(
+            error.for_field(FlagFieldName::Name).map(FieldError::kind),
+            Some(ValidationErrorKind::Empty)
+        );
+        assert_eq!(
+            error
+                .for_field(FlagFieldName::FlagColor)
+                .map(FieldError::kind),
+            Some(ValidationErrorKind::Missing)
+        );
+        assert!(error.for_field(FlagFieldName::DateDeleted).is_none());
+    }
 }
@@ -1,7 +1,9 @@
+use crate::search::{SearchDocument, SearchKind};
 use crate::storable::*;
 use crate::Timestamp;
 use partially::Partial;
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Map};
 use uuid::Uuid;
 
 #[cfg(feature = "field_names")]
@@ -18,8 +20,18 @@ pub struct Interview {
     pub name: String,
     pub notes: String,
     pub host: String,
+    // Linked through `add_contact` and `remove_contact` rather than through the partial
+    #[serde(default)]
+    #[partially(omit)]
+    pub contact_ids: Vec<Uuid>,
     pub date_time: Option<Timestamp>,
     pub date_deleted: Option<Timestamp>,
+    #[serde(default)]
+    #[partially(omit)]
+    pub date_created: Option<Timestamp>,
+    #[serde(default)]
+    #[partially(omit)]
+    pub date_updated: Option<Timestamp>,
 }
 
 impl Interview {
@@ -30,8 +42,11 @@ impl Interview {
             name: name.into(),
             notes: String::new(),
             host: String::new(),
+            contact_ids: Vec::new(),
             date_time: None,
             date_deleted: None,
+            date_created: None,
+            date_updated: None,
         }
     }
 
@@ -46,21 +61,76 @@ impl Interview {
             role_id: role.get_id(),
             name: partial
                 .name
-                .ok_or_else(|| IncompletePartialErrors::field_error("name"))?,
+                .ok_or_else(|| IncompletePartialErrors::field_error(InterviewFieldName::Name))?,
             notes: partial.notes.unwrap_or_default(),
             host: partial.host.unwrap_or_default(),
+            contact_ids: Vec::new(),
             date_time: partial.date_time.unwrap_or_default(),
             date_deleted: partial.date_deleted.unwrap_or_default(),
+            date_created: None,
+            date_updated: None,
         })
     }
+
+    /// Links a contact to the interview, returns false if they were already linked
+    pub fn add_contact<C: HasId>(&mut self, contact: C) -> bool {
+        let contact_id = contact.get_id();
+        if self.contact_ids.contains(&contact_id) {
+            return false;
+        }
+        self.contact_ids.push(contact_id);
+        true
+    }
+
+    /// Unlinks a contact from the interview, returns false if they weren't linked
+    pub fn remove_contact<C: HasId>(&mut self, contact: C) -> bool {
+        let contact_id = contact.get_id();
+        let original_len = self.contact_ids.len();
+        self.contact_ids.retain(|id| *id != contact_id);
+        self.contact_ids.len() != original_len
+    }
+
+    pub fn has_contact<C: HasId>(&self, contact: C) -> bool {
+        self.contact_ids.contains(&contact.get_id())
+    }
 }
 
 impl_has_id!(Interview);
 impl_has_name!(Interview);
 impl_has_role!(Interview);
 impl_has_deleted!(Interview);
+impl_has_timestamps!(Interview);
+impl_has_schema_version!(Interview, [add_contact_ids, add_timestamps]);
+impl_restore_revision!(Interview);
+
+impl Searchable for Interview {
+    const KIND: SearchKind = SearchKind::Interview;
+
+    fn to_search_document(&self) -> SearchDocument {
+        SearchDocument::new(Self::KIND, self.id, &self.name)
+            .with_role(self.role_id)
+            .with_text(&self.notes)
+            .with_text(&self.host)
+    }
+}
+
+impl Queryable for Interview {
+    fn get_parent_role_id(&self) -> Option<Uuid> {
+        Some(self.role_id)
+    }
+
+    fn get_date(&self) -> Option<Timestamp> {
+        self.date_time
+    }
+}
 
-impl_is_partial_complete_optional_name_only!(PartialInterview);
+/// Version 1 added linked contacts
+fn add_contact_ids(interview: &mut Map<String, serde_json::Value>) -> anyhow::Result<()> {
+    interview.entry("contact_ids").or_insert_with(|| json!([]));
+    Ok(())
+}
+
+impl_is_partial_complete_optional_name_only!(PartialInterview, InterviewFieldName);
 
 #[cfg(test)]
 mod test_helper {
@@ -87,6 +157,7 @@ mod tests {
     test_has_name!(Interview);
     test_has_role!(Interview);
     test_has_deleted!(Interview);
+    test_has_timestamps!(Interview);
 
     #[test]
     fn test_modify_with_hashmap() {
@@ -122,6 +193,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_add_and_remove_contact() {
+        let mut interview = Interview::new(Uuid::new_v4(), "Interview");
+        let contact = Contact::new(Uuid::new_v4(), "Contact");
+
+        assert!(!interview.has_contact(&contact));
+        assert!(interview.add_contact(&contact));
+        assert!(!interview.add_contact(&contact));
+        assert!(interview.has_contact(&contact));
+        assert_eq!(interview.contact_ids, vec![contact.id]);
+
+        assert!(interview.remove_contact(&contact));
+        assert!(!interview.remove_contact(&contact));
+        assert!(interview.contact_ids.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_interview_without_contacts() {
+        let json = format!(
+            r#"{{"id":"{}","role_id":"{}","name":"Interview","notes":"","host":"Host","date_time":null,"date_deleted":null}}"#,
+            Uuid::new_v4(),
+            Uuid::new_v4()
+        );
+        let interview: Interview = serde_json::from_str(&json).unwrap();
+        assert!(interview.contact_ids.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_interview_from_unversioned() {
+        let mut document = json!({
+            "id": Uuid::new_v4(),
+            "role_id": Uuid::new_v4(),
+            "name": "Interview",
+            "notes": "",
+            "host": "Host",
+            "date_time": null,
+            "date_deleted": null,
+        });
+        assert_eq!(migrate::<Interview>(&mut document).unwrap(), 0);
+        assert_eq!(document["schema_version"], json!(Interview::SCHEMA_VERSION));
+        assert_eq!(document["contact_ids"], json!([]));
+
+        let interview: Interview = serde_json::from_value(document).unwrap();
+        assert!(interview.contact_ids.is_empty());
+    }
+
     #[test]
     fn test_partial_interview_is_complete_complete_interview() {
         let interview = PartialInterview {
@@ -15,3 +15,9 @@ pub use interview::*;
 
 mod value;
 pub use value::*;
+
+mod contact;
+pub use contact::*;
+
+mod reminder;
+pub use reminder::*;
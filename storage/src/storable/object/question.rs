@@ -1,3 +1,4 @@
+use crate::search::{SearchDocument, SearchKind};
 use crate::storable::*;
 use crate::Timestamp;
 use partially::Partial;
@@ -18,6 +19,12 @@ pub struct Question {
     pub name: String,
     pub answer: String,
     pub date_deleted: Option<Timestamp>,
+    #[serde(default)]
+    #[partially(omit)]
+    pub date_created: Option<Timestamp>,
+    #[serde(default)]
+    #[partially(omit)]
+    pub date_updated: Option<Timestamp>,
 }
 
 impl Question {
@@ -28,6 +35,8 @@ impl Question {
             name: name.into(),
             answer: answer.into(),
             date_deleted: None,
+            date_created: None,
+            date_updated: None,
         }
     }
 
@@ -42,9 +51,11 @@ impl Question {
             role_id: role.get_id(),
             name: partial
                 .name
-                .ok_or_else(|| IncompletePartialErrors::field_error("name"))?,
+                .ok_or_else(|| IncompletePartialErrors::field_error(QuestionFieldName::Name))?,
             answer: partial.answer.unwrap_or_default(),
             date_deleted: partial.date_deleted.unwrap_or_default(),
+            date_created: None,
+            date_updated: None,
         })
     }
 }
@@ -53,8 +64,27 @@ impl_has_id!(Question);
 impl_has_name!(Question);
 impl_has_role!(Question);
 impl_has_deleted!(Question);
+impl_has_timestamps!(Question);
+impl_has_schema_version!(Question, [add_timestamps]);
+impl_restore_revision!(Question);
+
+impl Searchable for Question {
+    const KIND: SearchKind = SearchKind::Question;
+
+    fn to_search_document(&self) -> SearchDocument {
+        SearchDocument::new(Self::KIND, self.id, &self.name)
+            .with_role(self.role_id)
+            .with_text(&self.answer)
+    }
+}
+
+impl Queryable for Question {
+    fn get_parent_role_id(&self) -> Option<Uuid> {
+        Some(self.role_id)
+    }
+}
 
-impl_is_partial_complete_optional_name_only!(PartialQuestion);
+impl_is_partial_complete_optional_name_only!(PartialQuestion, QuestionFieldName);
 
 #[cfg(test)]
 mod test_helper {
@@ -81,6 +111,7 @@ mod tests {
     test_has_name!(Question);
     test_has_role!(Question);
     test_has_deleted!(Question);
+    test_has_timestamps!(Question);
 
     #[test]
     fn test_modify_with_hashmap() {
@@ -0,0 +1,421 @@
+use crate::search::{SearchDocument, SearchKind};
+use crate::storable::*;
+use crate::Timestamp;
+use partially::Partial;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+#[cfg(feature = "field_names")]
+use field_types::FieldName;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Recurrence {
+    #[default]
+    Never,
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl Recurrence {
+    pub const ALL: [Recurrence; 4] = [
+        Recurrence::Never,
+        Recurrence::Daily,
+        Recurrence::Weekly,
+        Recurrence::Monthly,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Recurrence::Never => "never",
+            Recurrence::Daily => "daily",
+            Recurrence::Weekly => "weekly",
+            Recurrence::Monthly => "monthly",
+        }
+    }
+
+    /// When the `count`th repeat after `due` falls, `None` if it never repeats
+    pub fn repeat(&self, due: Timestamp, count: u32) -> Option<Timestamp> {
+        match self {
+            Recurrence::Never => None,
+            Recurrence::Daily => Some(due.add_days(count.into())),
+            Recurrence::Weekly => Some(due.add_days(u64::from(count) * 7)),
+            Recurrence::Monthly => Some(due.add_months(count)),
+        }
+    }
+}
+
+impl FromStr for Recurrence {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Recurrence::ALL
+            .into_iter()
+            .find(|recurrence| recurrence.as_str() == s.to_lowercase())
+            .ok_or_else(|| format!("Invalid recurrence '{s}'"))
+    }
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Recurrence::Never => write!(f, "Never"),
+            Recurrence::Daily => write!(f, "Daily"),
+            Recurrence::Weekly => write!(f, "Weekly"),
+            Recurrence::Monthly => write!(f, "Monthly"),
+        }
+    }
+}
+
+/// Something to follow up on by a date, always for a company and narrowed to one of its roles or
+/// one of that role's interviews when it's about them
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Partial)]
+#[cfg_attr(feature = "field_names", derive(FieldName))]
+#[partially(derive(Deserialize, Default), attribute(serde(default)))]
+pub struct Reminder {
+    #[partially(omit)]
+    pub id: Uuid,
+    #[partially(omit)]
+    pub company_id: Uuid,
+    #[partially(omit)]
+    pub role_id: Option<Uuid>,
+    #[partially(omit)]
+    pub interview_id: Option<Uuid>,
+    pub name: String,
+    pub notes: String,
+    pub due: Timestamp,
+    pub recurrence: Recurrence,
+    pub date_completed: Option<Timestamp>,
+    pub date_deleted: Option<Timestamp>,
+    #[serde(default)]
+    #[partially(omit)]
+    pub date_created: Option<Timestamp>,
+    #[serde(default)]
+    #[partially(omit)]
+    pub date_updated: Option<Timestamp>,
+}
+
+impl Reminder {
+    pub fn new<C: HasId, S: Into<String>>(company: C, name: S, due: Timestamp) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            company_id: company.get_id(),
+            role_id: None,
+            interview_id: None,
+            name: name.into(),
+            notes: String::new(),
+            due,
+            recurrence: Recurrence::Never,
+            date_completed: None,
+            date_deleted: None,
+            date_created: None,
+            date_updated: None,
+        }
+    }
+
+    pub fn new_for_role<R: HasId + HasCompany, S: Into<String>>(
+        role: R,
+        name: S,
+        due: Timestamp,
+    ) -> Self {
+        Self {
+            role_id: Some(role.get_id()),
+            ..Reminder::new(role.get_company_id(), name, due)
+        }
+    }
+
+    /// Interviews don't know their company, so it comes from the role they belong to
+    pub fn new_for_interview<R: HasId + HasCompany, I: HasId + HasRole, S: Into<String>>(
+        role: R,
+        interview: I,
+        name: S,
+        due: Timestamp,
+    ) -> Self {
+        debug_assert_eq!(interview.get_role_id(), role.get_id());
+        Self {
+            interview_id: Some(interview.get_id()),
+            ..Reminder::new_for_role(role, name, due)
+        }
+    }
+
+    pub fn new_from_partial<C: HasId>(
+        company: C,
+        partial: PartialReminder,
+    ) -> Result<Reminder, IncompletePartialErrors> {
+        partial.check_complete()?;
+
+        Ok(Reminder {
+            id: Uuid::new_v4(),
+            company_id: company.get_id(),
+            role_id: None,
+            interview_id: None,
+            name: partial
+                .name
+                .ok_or_else(|| IncompletePartialErrors::field_error(ReminderFieldName::Name))?,
+            notes: partial.notes.unwrap_or_default(),
+            due: partial
+                .due
+                .ok_or_else(|| IncompletePartialErrors::field_error(ReminderFieldName::Due))?,
+            recurrence: partial.recurrence.unwrap_or_default(),
+            date_completed: partial.date_completed.unwrap_or_default(),
+            date_deleted: partial.date_deleted.unwrap_or_default(),
+            date_created: None,
+            date_updated: None,
+        })
+    }
+
+    pub fn new_for_role_from_partial<R: HasId + HasCompany>(
+        role: R,
+        partial: PartialReminder,
+    ) -> Result<Reminder, IncompletePartialErrors> {
+        Ok(Reminder {
+            role_id: Some(role.get_id()),
+            ..Reminder::new_from_partial(role.get_company_id(), partial)?
+        })
+    }
+
+    /// Marks the reminder as done. One that repeats isn't ever done, it moves on to the first
+    /// time it's due after `date` instead.
+    pub fn complete(&mut self, date: Timestamp) {
+        let mut count = 1;
+        while let Some(next) = self.recurrence.repeat(self.due, count) {
+            if next > date {
+                self.due = next;
+                return;
+            }
+            count += 1;
+        }
+        self.date_completed = Some(date);
+    }
+
+    pub fn reopen(&mut self) {
+        self.date_completed = None;
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.date_completed.is_some()
+    }
+
+    pub fn is_overdue(&self, now: Timestamp) -> bool {
+        !self.is_complete() && self.due < now
+    }
+}
+
+impl_has_id!(Reminder);
+impl_has_name!(Reminder);
+impl_has_company!(Reminder);
+impl_has_deleted!(Reminder);
+impl_has_timestamps!(Reminder);
+impl_has_schema_version!(Reminder);
+impl_restore_revision!(Reminder);
+
+impl Searchable for Reminder {
+    const KIND: SearchKind = SearchKind::Reminder;
+
+    fn to_search_document(&self) -> SearchDocument {
+        let document = SearchDocument::new(Self::KIND, self.id, &self.name)
+            .with_company(self.company_id)
+            .with_text(&self.notes);
+        match self.role_id {
+            Some(role_id) => document.with_role(role_id),
+            None => document,
+        }
+    }
+}
+
+impl Queryable for Reminder {
+    fn get_parent_company_id(&self) -> Option<Uuid> {
+        Some(self.company_id)
+    }
+
+    fn get_parent_role_id(&self) -> Option<Uuid> {
+        self.role_id
+    }
+
+    fn get_date(&self) -> Option<Timestamp> {
+        Some(self.due)
+    }
+}
+
+impl CheckPartialComplete for PartialReminder {
+    fn check_complete(&self) -> Result<(), IncompletePartialErrors> {
+        let mut errors = IncompletePartialErrors::with_capacity(2);
+
+        match self.name.as_ref().map(|name| name.is_empty()) {
+            None => errors.push_missing(ReminderFieldName::Name),
+            Some(true) => errors.push_empty(ReminderFieldName::Name),
+            Some(false) => {}
+        }
+
+        if self.due.is_none() {
+            errors.push_missing(ReminderFieldName::Due);
+        }
+
+        errors.into()
+    }
+}
+
+#[cfg(test)]
+mod test_helper {
+    use super::*;
+    use crate::test_helper::TestHelper;
+    use uuid::Uuid;
+
+    impl TestHelper for Reminder {
+        async fn new_test() -> anyhow::Result<Self> {
+            Ok(Reminder::new(
+                Uuid::new_v4(),
+                "Reminder",
+                Timestamp::from_string("2025-07-28T09:30"),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::TestHelper;
+    use paste::paste;
+    use serde::de::IntoDeserializer;
+    use std::collections::HashMap;
+
+    test_has_id!(Reminder);
+    test_has_name!(Reminder);
+    test_has_company!(Reminder);
+    test_has_deleted!(Reminder);
+    test_has_timestamps!(Reminder);
+
+    #[test]
+    fn test_new_for_interview() {
+        let role = Role::new(Uuid::new_v4(), "Role", Timestamp::now());
+        let interview = role.create_interview("Interview");
+        let reminder =
+            role.create_reminder_for_interview(&interview, "Send thanks", Timestamp::now());
+        assert_eq!(reminder.company_id, role.company_id);
+        assert_eq!(reminder.role_id, Some(role.id));
+        assert_eq!(reminder.interview_id, Some(interview.id));
+    }
+
+    #[test]
+    fn test_new_from_partial() {
+        let partial_reminder = PartialReminder {
+            name: Some("Chase recruiter".to_string()),
+            due: Some(Timestamp::from_string("2025-08-12T09:00")),
+            recurrence: Some(Recurrence::Weekly),
+            ..Default::default()
+        };
+        let company_id = Uuid::new_v4();
+        let reminder = Reminder::new_from_partial(company_id, partial_reminder).unwrap();
+        assert_eq!(reminder.company_id, company_id);
+        assert_eq!(reminder.role_id, None);
+        assert_eq!(reminder.name, "Chase recruiter");
+        assert_eq!(reminder.recurrence, Recurrence::Weekly);
+        assert!(!reminder.is_complete());
+    }
+
+    #[test]
+    fn test_modify_with_hashmap() {
+        let mut reminder = Reminder::new(Uuid::new_v4(), "Reminder name", Timestamp::now());
+        let original_id = reminder.id;
+        let original_company = reminder.company_id;
+
+        let mut hash_map: HashMap<String, serde_json::Value> = HashMap::new();
+        hash_map.insert("id".to_string(), Uuid::new_v4().to_string().into());
+        hash_map.insert("company_id".to_string(), Uuid::new_v4().to_string().into());
+        hash_map.insert("name".to_string(), "New name".into());
+        hash_map.insert("notes".to_string(), "New notes".into());
+        hash_map.insert("due".to_string(), "2025-07-28T09:00".into());
+        hash_map.insert("recurrence".to_string(), "monthly".into());
+        hash_map.insert("date_completed".to_string(), "2025-07-29T00:00".into());
+
+        let partial_reminder = PartialReminder::deserialize(hash_map.into_deserializer()).unwrap();
+        reminder.apply(partial_reminder);
+
+        assert_eq!(reminder.id, original_id);
+        assert_eq!(reminder.company_id, original_company);
+        assert_eq!(reminder.name, "New name".to_string());
+        assert_eq!(reminder.notes, "New notes".to_string());
+        assert_eq!(reminder.due, Timestamp::from_string("2025-07-28T09:00"));
+        assert_eq!(reminder.recurrence, Recurrence::Monthly);
+        assert_eq!(
+            reminder.date_completed,
+            Some(Timestamp::from_string("2025-07-29T00:00"))
+        );
+    }
+
+    #[test]
+    fn test_complete() {
+        let due = Timestamp::from_string("2025-08-12T09:00");
+        let mut reminder = Reminder::new(Uuid::new_v4(), "Reminder", due);
+        assert!(reminder.is_overdue(Timestamp::from_string("2025-08-13T00:00")));
+
+        reminder.complete(Timestamp::from_string("2025-08-13T00:00"));
+        assert!(reminder.is_complete());
+        assert!(!reminder.is_overdue(Timestamp::from_string("2025-08-13T00:00")));
+        assert_eq!(reminder.due, due);
+
+        reminder.reopen();
+        assert!(!reminder.is_complete());
+    }
+
+    #[test]
+    fn test_complete_recurring() {
+        let mut reminder = Reminder::new(
+            Uuid::new_v4(),
+            "Reminder",
+            Timestamp::from_string("2025-01-31T09:00"),
+        );
+        reminder.recurrence = Recurrence::Monthly;
+
+        // Done on time moves it on a month
+        reminder.complete(Timestamp::from_string("2025-01-31T10:00"));
+        assert!(!reminder.is_complete());
+        assert_eq!(reminder.due, Timestamp::from_string("2025-02-28T09:00"));
+
+        // Done late skips the months that were missed
+        reminder.due = Timestamp::from_string("2025-01-31T09:00");
+        reminder.complete(Timestamp::from_string("2025-04-01T00:00"));
+        assert_eq!(reminder.due, Timestamp::from_string("2025-04-30T09:00"));
+
+        reminder.recurrence = Recurrence::Weekly;
+        reminder.complete(Timestamp::from_string("2025-04-30T08:00"));
+        assert_eq!(reminder.due, Timestamp::from_string("2025-05-07T09:00"));
+    }
+
+    #[test]
+    fn test_recurrence_from_str() {
+        for recurrence in Recurrence::ALL {
+            assert_eq!(recurrence.as_str().parse(), Ok(recurrence));
+        }
+        assert!("fortnightly".parse::<Recurrence>().is_err());
+    }
+
+    #[test]
+    fn test_partial_reminder_is_complete_missing_fields() {
+        let error = PartialReminder::default().check_complete().unwrap_err();
+        let errors = error.get_errors();
+        assert!(errors.contains(&String::from("`name` is missing")));
+        assert!(errors.contains(&String::from("`due` is missing")));
+        assert_eq!(
+            error
+                .for_field(ReminderFieldName::Due)
+                .map(FieldError::kind),
+            Some(ValidationErrorKind::Missing)
+        );
+    }
+
+    #[test]
+    fn test_partial_reminder_is_complete_empty_name() {
+        let empty_name = PartialReminder {
+            name: Some(String::new()),
+            due: Some(Timestamp::now()),
+            ..Default::default()
+        };
+        let error = empty_name.check_complete().unwrap_err();
+        assert_eq!(error.get_errors(), vec![String::from("`name` is empty")]);
+    }
+}
@@ -1,16 +1,93 @@
 use crate::prelude::Interview;
+use crate::search::{SearchDocument, SearchKind};
 use crate::storable::*;
-use crate::Timestamp;
+use crate::{
+    looks_like_currency_code, CurrencyCode, ExchangeRates, NormalisedCompensation, OptionalAmount,
+};
+use crate::{PayPeriod, Posting, PostingSnapshot, Timestamp};
 use partially::Partial;
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Map};
+use std::fmt;
+use std::str::FromStr;
 use uuid::Uuid;
 
 #[cfg(feature = "field_names")]
 use field_types::FieldName;
 
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoleStatus {
+    #[default]
+    Applied,
+    Screening,
+    Technical,
+    Offer,
+    Rejected,
+    Withdrawn,
+}
+
+impl RoleStatus {
+    pub const ALL: [RoleStatus; 6] = [
+        RoleStatus::Applied,
+        RoleStatus::Screening,
+        RoleStatus::Technical,
+        RoleStatus::Offer,
+        RoleStatus::Rejected,
+        RoleStatus::Withdrawn,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RoleStatus::Applied => "applied",
+            RoleStatus::Screening => "screening",
+            RoleStatus::Technical => "technical",
+            RoleStatus::Offer => "offer",
+            RoleStatus::Rejected => "rejected",
+            RoleStatus::Withdrawn => "withdrawn",
+        }
+    }
+
+    /// Rejected and withdrawn applications are no longer moving through the pipeline
+    pub fn is_closed(&self) -> bool {
+        matches!(self, RoleStatus::Rejected | RoleStatus::Withdrawn)
+    }
+}
+
+impl FromStr for RoleStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RoleStatus::ALL
+            .into_iter()
+            .find(|status| status.as_str() == s.to_lowercase())
+            .ok_or_else(|| format!("Invalid status '{s}'"))
+    }
+}
+
+impl fmt::Display for RoleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoleStatus::Applied => write!(f, "Applied"),
+            RoleStatus::Screening => write!(f, "Screening"),
+            RoleStatus::Technical => write!(f, "Technical"),
+            RoleStatus::Offer => write!(f, "Offer"),
+            RoleStatus::Rejected => write!(f, "Rejected"),
+            RoleStatus::Withdrawn => write!(f, "Withdrawn"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoleStatusTransition {
+    pub from: RoleStatus,
+    pub to: RoleStatus,
+    pub date_changed: Timestamp,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Partial)]
 #[cfg_attr(feature = "field_names", derive(FieldName))]
-#[partially(derive(Deserialize, Default))]
+#[partially(derive(Debug, Clone, Deserialize, Default))]
 pub struct Role {
     #[partially(omit)]
     pub id: Uuid,
@@ -19,10 +96,56 @@ pub struct Role {
     pub name: String,
     pub description: String,
     pub date_applied: Timestamp,
+    #[serde(default)]
+    #[partially(as_type = "Option<OptionalAmount>")]
+    pub salary_min: Option<u64>,
+    #[serde(default)]
+    #[partially(as_type = "Option<OptionalAmount>")]
+    pub salary_max: Option<u64>,
+    #[serde(default)]
+    #[partially(as_type = "Option<CurrencyCode>")]
+    pub currency: String,
+    #[serde(default)]
+    pub pay_period: PayPeriod,
+    #[serde(default)]
+    pub equity: String,
+    #[serde(default)]
+    #[partially(as_type = "Option<OptionalAmount>")]
+    pub bonus: Option<u64>,
+    // Only ever changed through `set_status`, so every change is in the history
+    #[serde(default)]
+    #[partially(omit)]
+    pub status: RoleStatus,
+    // Only ever appended to through `set_status`, so it isn't exposed through the partial
+    #[serde(default)]
+    #[partially(omit)]
+    pub status_history: Vec<RoleStatusTransition>,
+    #[serde(default)]
+    pub posting_url: String,
+    /// Where the role was found, such as a job board or the person who referred it
+    #[serde(default)]
+    pub source: String,
+    #[serde(default)]
+    pub location: String,
+    #[serde(default)]
+    pub date_posted: Option<Timestamp>,
+    // Only ever set once through `capture_posting`, so it isn't exposed through the partial
+    #[serde(default)]
+    #[partially(omit)]
+    pub posting: Option<PostingSnapshot>,
     pub date_deleted: Option<Timestamp>,
+    #[serde(default)]
+    #[partially(omit)]
+    pub date_created: Option<Timestamp>,
+    #[serde(default)]
+    #[partially(omit)]
+    pub date_updated: Option<Timestamp>,
 }
 
 impl Role {
+    /// The name the original HTML of a captured posting is attached to the role with
+    pub const POSTING_ATTACHMENT: &'static str = "posting.html";
+
     pub fn new<C: HasId, S: Into<String>>(company: C, name: S, date_applied: Timestamp) -> Role {
         Role {
             id: Uuid::new_v4(),
@@ -30,7 +153,22 @@ impl Role {
             name: name.into(),
             description: "".to_string(),
             date_applied,
+            salary_min: None,
+            salary_max: None,
+            currency: String::new(),
+            pay_period: PayPeriod::default(),
+            equity: String::new(),
+            bonus: None,
+            status: RoleStatus::default(),
+            status_history: Vec::new(),
+            posting_url: String::new(),
+            source: String::new(),
+            location: String::new(),
+            date_posted: None,
+            posting: None,
             date_deleted: None,
+            date_created: None,
+            date_updated: None,
         }
     }
 
@@ -45,15 +183,79 @@ impl Role {
             company_id: company.get_id(),
             name: partial
                 .name
-                .ok_or_else(|| IncompletePartialErrors::field_error("name"))?,
+                .ok_or_else(|| IncompletePartialErrors::field_error(RoleFieldName::Name))?,
             description: partial.description.unwrap_or_default(),
             date_applied: partial
                 .date_applied
-                .ok_or_else(|| IncompletePartialErrors::field_error("date_applied"))?,
+                .ok_or_else(|| IncompletePartialErrors::field_error(RoleFieldName::DateApplied))?,
+            salary_min: partial.salary_min.and_then(Into::into),
+            salary_max: partial.salary_max.and_then(Into::into),
+            currency: partial.currency.map(String::from).unwrap_or_default(),
+            pay_period: partial.pay_period.unwrap_or_default(),
+            equity: partial.equity.unwrap_or_default(),
+            bonus: partial.bonus.and_then(Into::into),
+            status: RoleStatus::default(),
+            status_history: Vec::new(),
+            posting_url: partial.posting_url.unwrap_or_default(),
+            source: partial.source.unwrap_or_default(),
+            location: partial.location.unwrap_or_default(),
+            date_posted: partial.date_posted.unwrap_or_default(),
+            posting: None,
             date_deleted: partial.date_deleted.unwrap_or_default(),
+            date_created: None,
+            date_updated: None,
         })
     }
 
+    /// Moves the role to a new status, recording the transition in the status history.
+    ///
+    /// Returns false (and records nothing) if the role is already in the given status.
+    pub fn set_status(&mut self, status: RoleStatus, date_changed: Timestamp) -> bool {
+        if self.status == status {
+            return false;
+        }
+        self.status_history.push(RoleStatusTransition {
+            from: self.status,
+            to: status,
+            date_changed,
+        });
+        self.status = status;
+        true
+    }
+
+    pub fn get_status_history(&self) -> &[RoleStatusTransition] {
+        &self.status_history
+    }
+
+    /// Keeps a snapshot of the job posting so it's still around once the advert is taken down.
+    ///
+    /// Returns false (and keeps the original snapshot) if a posting has already been captured.
+    pub fn capture_posting(&mut self, posting: &Posting, date_captured: Timestamp) -> bool {
+        if self.posting.is_some() {
+            return false;
+        }
+        self.posting = Some(posting.to_snapshot(date_captured));
+        true
+    }
+
+    pub fn has_compensation(&self) -> bool {
+        self.salary_min.is_some() || self.salary_max.is_some()
+    }
+
+    /// The role's compensation as an annual figure in the base currency of the given rates
+    pub fn normalised_compensation(
+        &self,
+        exchange_rates: &ExchangeRates,
+    ) -> Option<NormalisedCompensation> {
+        exchange_rates.normalise(
+            self.salary_min,
+            self.salary_max,
+            self.bonus,
+            self.pay_period,
+            &self.currency,
+        )
+    }
+
     pub fn create_question<N: Into<String>, A: Into<String>>(
         &self,
         name: N,
@@ -79,32 +281,164 @@ impl Role {
     ) -> Result<Interview, IncompletePartialErrors> {
         Interview::new_from_partial(self, interview)
     }
+
+    pub fn create_reminder<S: Into<String>>(&self, name: S, due: Timestamp) -> Reminder {
+        Reminder::new_for_role(self, name, due)
+    }
+
+    pub fn create_reminder_from_partial(
+        &self,
+        reminder: PartialReminder,
+    ) -> Result<Reminder, IncompletePartialErrors> {
+        Reminder::new_for_role_from_partial(self, reminder)
+    }
+
+    pub fn create_reminder_for_interview<I: HasId + HasRole, S: Into<String>>(
+        &self,
+        interview: I,
+        name: S,
+        due: Timestamp,
+    ) -> Reminder {
+        Reminder::new_for_interview(self, interview, name, due)
+    }
 }
 
 impl_has_id!(Role);
 impl_has_name!(Role);
 impl_has_company!(Role);
 impl_has_deleted!(Role);
+impl_has_timestamps!(Role);
+impl_has_schema_version!(
+    Role,
+    [
+        add_status_and_compensation,
+        add_timestamps,
+        add_posting,
+        add_location_and_date_posted
+    ]
+);
+
+impl RestoreRevision for Role {
+    /// The status history is kept, going back to an earlier status is a status change like any
+    /// other. So is a captured posting, its HTML stays on disk and it can't be captured again.
+    fn restore_over(&self, mut previous: Role, date_restored: Timestamp) -> Role {
+        let status = previous.status;
+        previous.status = self.status;
+        previous.status_history = self.status_history.clone();
+        previous.posting = self.posting.clone();
+        previous.set_status(status, date_restored);
+        previous
+    }
+}
+
+impl Searchable for Role {
+    const KIND: SearchKind = SearchKind::Role;
+
+    fn to_search_document(&self) -> SearchDocument {
+        SearchDocument::new(Self::KIND, self.id, &self.name)
+            .with_company(self.company_id)
+            .with_text(&self.description)
+    }
+}
+
+impl Queryable for Role {
+    fn get_parent_company_id(&self) -> Option<Uuid> {
+        Some(self.company_id)
+    }
+
+    fn get_date(&self) -> Option<Timestamp> {
+        Some(self.date_applied)
+    }
+
+    fn get_compensation(&self, exchange_rates: &ExchangeRates) -> Option<NormalisedCompensation> {
+        self.normalised_compensation(exchange_rates)
+    }
+}
+
+/// Version 1 added the status pipeline and compensation
+fn add_status_and_compensation(role: &mut Map<String, serde_json::Value>) -> anyhow::Result<()> {
+    role.entry("status")
+        .or_insert_with(|| json!(RoleStatus::default()));
+    role.entry("status_history").or_insert_with(|| json!([]));
+    for field in ["salary_min", "salary_max", "bonus"] {
+        role.entry(field).or_insert(serde_json::Value::Null);
+    }
+    role.entry("currency").or_insert_with(|| json!(""));
+    role.entry("pay_period")
+        .or_insert_with(|| json!(PayPeriod::default()));
+    role.entry("equity").or_insert_with(|| json!(""));
+    Ok(())
+}
+
+/// Version 3 added the posting URL, source and snapshot
+fn add_posting(role: &mut Map<String, serde_json::Value>) -> anyhow::Result<()> {
+    role.entry("posting_url").or_insert_with(|| json!(""));
+    role.entry("source").or_insert_with(|| json!(""));
+    role.entry("posting").or_insert(serde_json::Value::Null);
+    Ok(())
+}
+
+/// Version 4 added where the role is based and when it was advertised
+fn add_location_and_date_posted(role: &mut Map<String, serde_json::Value>) -> anyhow::Result<()> {
+    role.entry("location").or_insert_with(|| json!(""));
+    role.entry("date_posted").or_insert(serde_json::Value::Null);
+    Ok(())
+}
 
 impl CheckPartialComplete for PartialRole {
     fn check_complete(&self) -> Result<(), IncompletePartialErrors> {
         let mut errors = IncompletePartialErrors::with_capacity(2);
 
         match self.name.as_ref().map(|name| name.is_empty()) {
-            None => errors.push("`name` is missing".to_string()),
-            Some(true) => errors.push("`name` is empty".to_string()),
+            None => errors.push_missing(RoleFieldName::Name),
+            Some(true) => errors.push_empty(RoleFieldName::Name),
             Some(false) => {}
         }
 
         match self.date_applied.map(|t| (t, t.looks_valid())) {
-            None => errors.push("`date_applied` is missing".to_string()),
-            Some((t, false)) => errors.push(format!(
-                "`date_applied` appears to be invalid: {}",
-                t.format("%Y-%m-%d %H:%M:%S")
-            )),
+            None => errors.push_missing(RoleFieldName::DateApplied),
+            Some((t, false)) => errors.push_invalid(
+                RoleFieldName::DateApplied,
+                format!(
+                    "`date_applied` appears to be invalid: {}",
+                    t.format("%Y-%m-%d %H:%M:%S")
+                ),
+            ),
             Some((_, true)) => {}
         }
 
+        let salary_min = self.salary_min.and_then(|amount| amount.0);
+        let salary_max = self.salary_max.and_then(|amount| amount.0);
+        if let (Some(min), Some(max)) = (salary_min, salary_max) {
+            if min > max {
+                errors.push_invalid(
+                    RoleFieldName::SalaryMin,
+                    format!("`salary_min` ({min}) is greater than `salary_max` ({max})"),
+                );
+            }
+        }
+
+        let currency_required = "`currency` is required when a salary is given";
+        match self.currency.as_deref() {
+            Some(currency) if !currency.is_empty() && !looks_like_currency_code(currency) => errors
+                .push_invalid(
+                    RoleFieldName::Currency,
+                    format!("`currency` should be a three letter code, eg USD, got: {currency}"),
+                ),
+            Some(currency) if currency.is_empty() && salary_min.or(salary_max).is_some() => errors
+                .push_field(
+                    RoleFieldName::Currency,
+                    ValidationErrorKind::Empty,
+                    currency_required,
+                ),
+            None if salary_min.or(salary_max).is_some() => errors.push_field(
+                RoleFieldName::Currency,
+                ValidationErrorKind::Missing,
+                currency_required,
+            ),
+            _ => {}
+        }
+
         errors.into()
     }
 }
@@ -117,7 +451,11 @@ mod test_helper {
 
     impl TestHelper for Role {
         async fn new_test() -> anyhow::Result<Self> {
-            Ok(Role::new(Uuid::new_v4(), "Role", Timestamp::now()))
+            Ok(Role::new(
+                Uuid::new_v4(),
+                "Role",
+                Timestamp::from_string("2025-07-28T09:30"),
+            ))
         }
     }
 }
@@ -134,6 +472,7 @@ mod tests {
     test_has_name!(Role);
     test_has_company!(Role);
     test_has_deleted!(Role);
+    test_has_timestamps!(Role);
 
     #[test]
     fn test_create_question() {
@@ -194,6 +533,20 @@ mod tests {
         hash_map.insert("name".to_string(), "New name".into());
         hash_map.insert("description".to_string(), "New description".into());
         hash_map.insert("date_applied".to_string(), "2025-07-28T00:00".into());
+        hash_map.insert("status".to_string(), "technical".into());
+        hash_map.insert("salary_min".to_string(), "50,000".into());
+        hash_map.insert("salary_max".to_string(), "".into());
+        hash_map.insert("currency".to_string(), "gbp".into());
+        hash_map.insert("pay_period".to_string(), "annual".into());
+        hash_map.insert("equity".to_string(), "0.1% over 4 years".into());
+        hash_map.insert("bonus".to_string(), 5000.into());
+        hash_map.insert(
+            "posting_url".to_string(),
+            "https://example.com/jobs/1".into(),
+        );
+        hash_map.insert("source".to_string(), "Referral".into());
+        hash_map.insert("location".to_string(), "Leeds".into());
+        hash_map.insert("posting".to_string(), "Not part of the partial".into());
         hash_map.insert("date_deleted".to_string(), "2026-07-28T00:00".into());
 
         let partial_role = PartialRole::deserialize(hash_map.into_deserializer()).unwrap();
@@ -207,12 +560,313 @@ mod tests {
             role.date_applied,
             Timestamp::from_string("2025-07-28T00:00")
         );
+        // The status only changes through `set_status`
+        assert_eq!(role.status, RoleStatus::Applied);
+        assert_eq!(role.salary_min, Some(50_000));
+        assert_eq!(role.salary_max, None);
+        assert_eq!(role.currency, "GBP".to_string());
+        assert_eq!(role.pay_period, PayPeriod::Annual);
+        assert_eq!(role.equity, "0.1% over 4 years".to_string());
+        assert_eq!(role.bonus, Some(5000));
+        assert_eq!(role.posting_url, "https://example.com/jobs/1".to_string());
+        assert_eq!(role.source, "Referral".to_string());
+        assert_eq!(role.location, "Leeds".to_string());
+        assert_eq!(role.posting, None);
         assert_eq!(
             role.date_deleted,
             Some(Timestamp::from_string("2026-07-28T00:00"))
         );
     }
 
+    #[test]
+    fn test_new_role_status() {
+        let role = Role::new(Uuid::new_v4(), "role", Timestamp::now());
+        assert_eq!(role.status, RoleStatus::Applied);
+        assert!(role.get_status_history().is_empty());
+    }
+
+    #[test]
+    fn test_set_status() {
+        let mut role = Role::new(Uuid::new_v4(), "role", Timestamp::from_timestamp(1));
+
+        assert!(role.set_status(RoleStatus::Screening, Timestamp::from_timestamp(2)));
+        assert!(role.set_status(RoleStatus::Technical, Timestamp::from_timestamp(3)));
+        assert_eq!(role.status, RoleStatus::Technical);
+        assert_eq!(
+            role.get_status_history(),
+            &[
+                RoleStatusTransition {
+                    from: RoleStatus::Applied,
+                    to: RoleStatus::Screening,
+                    date_changed: Timestamp::from_timestamp(2),
+                },
+                RoleStatusTransition {
+                    from: RoleStatus::Screening,
+                    to: RoleStatus::Technical,
+                    date_changed: Timestamp::from_timestamp(3),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_status_unchanged() {
+        let mut role = Role::new(Uuid::new_v4(), "role", Timestamp::from_timestamp(1));
+        assert!(!role.set_status(RoleStatus::Applied, Timestamp::from_timestamp(2)));
+        assert!(role.get_status_history().is_empty());
+    }
+
+    #[test]
+    fn test_restore_over_records_status_change() {
+        let mut previous = Role::new(Uuid::new_v4(), "role", Timestamp::from_timestamp(1));
+        previous.set_status(RoleStatus::Screening, Timestamp::from_timestamp(2));
+        let mut current = previous.clone();
+        current.name = "renamed".to_string();
+        current.set_status(RoleStatus::Offer, Timestamp::from_timestamp(3));
+
+        let restored = current.restore_over(previous, Timestamp::from_timestamp(4));
+        assert_eq!(restored.name, "role");
+        assert_eq!(restored.status, RoleStatus::Screening);
+        assert_eq!(
+            restored.get_status_history(),
+            &[
+                RoleStatusTransition {
+                    from: RoleStatus::Applied,
+                    to: RoleStatus::Screening,
+                    date_changed: Timestamp::from_timestamp(2),
+                },
+                RoleStatusTransition {
+                    from: RoleStatus::Screening,
+                    to: RoleStatus::Offer,
+                    date_changed: Timestamp::from_timestamp(3),
+                },
+                RoleStatusTransition {
+                    from: RoleStatus::Offer,
+                    to: RoleStatus::Screening,
+                    date_changed: Timestamp::from_timestamp(4),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_restore_over_keeps_posting() {
+        let previous = Role::new(Uuid::new_v4(), "role", Timestamp::from_timestamp(1));
+        let mut current = previous.clone();
+        current.capture_posting(&Posting::parse("# Engineer"), Timestamp::from_timestamp(2));
+
+        let restored = current.restore_over(previous, Timestamp::from_timestamp(3));
+        assert_eq!(restored.posting, current.posting);
+    }
+
+    #[test]
+    fn test_role_status_from_str() {
+        for status in RoleStatus::ALL {
+            assert_eq!(RoleStatus::from_str(status.as_str()), Ok(status));
+        }
+        assert_eq!(RoleStatus::from_str("OFFER"), Ok(RoleStatus::Offer));
+        assert!(RoleStatus::from_str("hired").is_err());
+    }
+
+    #[test]
+    fn test_role_status_is_closed() {
+        assert!(RoleStatus::Rejected.is_closed());
+        assert!(RoleStatus::Withdrawn.is_closed());
+        assert!(!RoleStatus::Offer.is_closed());
+    }
+
+    #[test]
+    fn test_deserialize_role_without_status() {
+        let json = format!(
+            r#"{{"id":"{}","company_id":"{}","name":"Role","description":"","date_applied":"2025-07-28T00:00","date_deleted":null}}"#,
+            Uuid::new_v4(),
+            Uuid::new_v4()
+        );
+        let role: Role = serde_json::from_str(&json).unwrap();
+        assert_eq!(role.status, RoleStatus::Applied);
+        assert!(role.get_status_history().is_empty());
+    }
+
+    #[test]
+    fn test_migrate_role_from_unversioned() {
+        let mut document = json!({
+            "id": Uuid::new_v4(),
+            "company_id": Uuid::new_v4(),
+            "name": "Role",
+            "description": "",
+            "date_applied": "2025-07-28T00:00",
+            "date_deleted": null,
+        });
+        assert_eq!(migrate::<Role>(&mut document).unwrap(), 0);
+        assert_eq!(document["schema_version"], json!(Role::SCHEMA_VERSION));
+        assert_eq!(document["status"], json!("applied"));
+        assert_eq!(document["pay_period"], json!("annual"));
+
+        let role: Role = serde_json::from_value(document).unwrap();
+        assert_eq!(role.status, RoleStatus::Applied);
+        assert!(role.get_status_history().is_empty());
+        assert!(!role.has_compensation());
+    }
+
+    #[test]
+    fn test_migrate_role_adds_posting() {
+        let mut document = json!({
+            "id": Uuid::new_v4(),
+            "company_id": Uuid::new_v4(),
+            "name": "Role",
+            "description": "",
+            "date_applied": "2025-07-28T00:00",
+            "date_deleted": null,
+            "schema_version": 2,
+        });
+        assert_eq!(migrate::<Role>(&mut document).unwrap(), 2);
+        assert_eq!(document["posting_url"], json!(""));
+        assert_eq!(document["posting"], serde_json::Value::Null);
+        assert_eq!(document["location"], json!(""));
+        assert_eq!(document["date_posted"], serde_json::Value::Null);
+
+        let role: Role = serde_json::from_value(document).unwrap();
+        assert!(role.source.is_empty());
+        assert!(role.posting.is_none());
+    }
+
+    #[test]
+    fn test_capture_posting() {
+        let mut role = Role::new(Uuid::new_v4(), "role", Timestamp::from_timestamp(60));
+        let posting = Posting::parse("<h1>Engineer</h1><p>Write code</p>");
+        assert!(role.capture_posting(&posting, Timestamp::from_timestamp(120)));
+
+        let snapshot = role.posting.clone().unwrap();
+        assert_eq!(snapshot.markdown, posting.markdown);
+        assert!(snapshot.has_html);
+        assert_eq!(snapshot.date_captured, Timestamp::from_timestamp(120));
+
+        let replacement = Posting::parse("# Something else");
+        assert!(!role.capture_posting(&replacement, Timestamp::from_timestamp(180)));
+        assert_eq!(role.posting, Some(snapshot));
+    }
+
+    #[test]
+    fn test_new_from_posting() {
+        let posting = Posting::parse("# Engineer\n\nWrite code");
+        let partial = PartialRole {
+            date_applied: Some(Timestamp::now()),
+            source: Some("Job board".to_string()),
+            ..posting.to_partial_role()
+        };
+        let role = Role::new_from_partial(Uuid::new_v4(), partial).unwrap();
+        assert_eq!(role.name, "Engineer");
+        assert_eq!(role.description, "# Engineer\n\nWrite code");
+        assert_eq!(role.source, "Job board");
+    }
+
+    #[test]
+    fn test_status_history_round_trip() {
+        let mut role = Role::new(Uuid::new_v4(), "role", Timestamp::from_timestamp(60));
+        role.set_status(RoleStatus::Offer, Timestamp::from_timestamp(120));
+        let json = serde_json::to_string(&role).unwrap();
+        let recalled: Role = serde_json::from_str(&json).unwrap();
+        assert_eq!(recalled, role);
+    }
+
+    #[test]
+    fn test_new_from_partial_with_compensation() {
+        let partial = PartialRole {
+            name: Some("Test role".to_string()),
+            date_applied: Some(Timestamp::now()),
+            salary_min: Some(OptionalAmount(Some(400))),
+            salary_max: Some(OptionalAmount(Some(500))),
+            currency: Some("eur".into()),
+            pay_period: Some(PayPeriod::Daily),
+            ..Default::default()
+        };
+        let role = Role::new_from_partial(Uuid::new_v4(), partial).unwrap();
+        assert_eq!(role.salary_min, Some(400));
+        assert_eq!(role.salary_max, Some(500));
+        assert_eq!(role.currency, "EUR");
+        assert_eq!(role.pay_period, PayPeriod::Daily);
+        assert!(role.has_compensation());
+    }
+
+    #[test]
+    fn test_normalised_compensation() {
+        let mut role = Role::new(Uuid::new_v4(), "role", Timestamp::now());
+        let mut rates = ExchangeRates::new("GBP");
+        rates.set_rate("EUR", 0.85);
+        assert!(role.normalised_compensation(&rates).is_none());
+
+        role.salary_min = Some(400);
+        role.salary_max = Some(500);
+        role.currency = "EUR".to_string();
+        role.pay_period = PayPeriod::Daily;
+        let normalised = role.normalised_compensation(&rates).unwrap();
+        assert_eq!(normalised.currency, "GBP");
+        assert_eq!(normalised.salary_min, 88_400);
+        assert_eq!(normalised.salary_max, 110_500);
+    }
+
+    #[test]
+    fn test_partial_role_is_complete_salary_range_inverted() {
+        let role = PartialRole {
+            name: Some("Test role".to_string()),
+            date_applied: Some(Timestamp::now()),
+            salary_min: Some(OptionalAmount(Some(60_000))),
+            salary_max: Some(OptionalAmount(Some(50_000))),
+            currency: Some("GBP".into()),
+            ..Default::default()
+        };
+
+        let error = role.check_complete().unwrap_err();
+        assert_eq!(
+            error.get_errors(),
+            vec!["`salary_min` (60000) is greater than `salary_max` (50000)".to_string()]
+        );
+        assert_eq!(
+            error
+                .for_field(RoleFieldName::SalaryMin)
+                .map(FieldError::kind),
+            Some(ValidationErrorKind::Invalid)
+        );
+    }
+
+    #[test]
+    fn test_partial_role_is_complete_invalid_currency() {
+        let role = PartialRole {
+            name: Some("Test role".to_string()),
+            date_applied: Some(Timestamp::now()),
+            currency: Some("£".into()),
+            ..Default::default()
+        };
+
+        let error = role.check_complete().unwrap_err();
+        assert_eq!(
+            error.get_errors(),
+            vec!["`currency` should be a three letter code, eg USD, got: £".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_partial_role_is_complete_salary_without_currency() {
+        let role = PartialRole {
+            name: Some("Test role".to_string()),
+            date_applied: Some(Timestamp::now()),
+            salary_max: Some(OptionalAmount(Some(50_000))),
+            ..Default::default()
+        };
+
+        let error = role.check_complete().unwrap_err();
+        assert_eq!(
+            error.get_errors(),
+            vec!["`currency` is required when a salary is given".to_string()]
+        );
+        assert_eq!(
+            error
+                .for_field(RoleFieldName::Currency)
+                .map(FieldError::kind),
+            Some(ValidationErrorKind::Missing)
+        );
+    }
+
     #[test]
     fn test_partial_role_is_complete_complete_role() {
         let role = PartialRole {
@@ -220,6 +874,7 @@ mod tests {
             description: None,
             date_applied: Some(Timestamp::now()),
             date_deleted: None,
+            ..Default::default()
         };
         assert!(role.check_complete().is_ok())
     }
@@ -231,6 +886,7 @@ mod tests {
             description: None,
             date_applied: Some(Timestamp::now()),
             date_deleted: None,
+            ..Default::default()
         };
 
         let error = role.check_complete().unwrap_err();
@@ -246,6 +902,7 @@ mod tests {
             description: None,
             date_applied: Some(Timestamp::now()),
             date_deleted: None,
+            ..Default::default()
         };
 
         let error = role.check_complete().unwrap_err();
@@ -261,6 +918,7 @@ mod tests {
             description: None,
             date_applied: None,
             date_deleted: None,
+            ..Default::default()
         };
 
         let error = role.check_complete().unwrap_err();
@@ -276,6 +934,7 @@ mod tests {
             description: None,
             date_applied: Some(Timestamp::from_timestamp(0)),
             date_deleted: None,
+            ..Default::default()
         };
 
         let error = role.check_complete().unwrap_err();
@@ -283,7 +942,7 @@ mod tests {
         assert_eq!(errors.len(), 1);
         assert_eq!(
             errors,
-            &vec!["`date_applied` appears to be invalid: 1970-01-01 00:00:00".to_string()]
+            vec!["`date_applied` appears to be invalid: 1970-01-01 00:00:00".to_string()]
         );
 
         assert!(errors
@@ -1,3 +1,4 @@
+use crate::search::{SearchDocument, SearchKind};
 use crate::storable::*;
 use crate::Timestamp;
 use partially::Partial;
@@ -18,6 +19,12 @@ pub struct Value {
     pub name: String,
     pub description: String,
     pub date_deleted: Option<Timestamp>,
+    #[serde(default)]
+    #[partially(omit)]
+    pub date_created: Option<Timestamp>,
+    #[serde(default)]
+    #[partially(omit)]
+    pub date_updated: Option<Timestamp>,
 }
 
 impl Value {
@@ -32,6 +39,8 @@ impl Value {
             name: name.into(),
             description: description.into(),
             date_deleted: None,
+            date_created: None,
+            date_updated: None,
         }
     }
 
@@ -46,9 +55,11 @@ impl Value {
             company_id: company.get_id(),
             name: partial
                 .name
-                .ok_or_else(|| IncompletePartialErrors::field_error("name"))?,
+                .ok_or_else(|| IncompletePartialErrors::field_error(ValueFieldName::Name))?,
             description: partial.description.unwrap_or_default(),
             date_deleted: partial.date_deleted.unwrap_or_default(),
+            date_created: None,
+            date_updated: None,
         })
     }
 }
@@ -57,8 +68,27 @@ impl_has_id!(Value);
 impl_has_name!(Value);
 impl_has_company!(Value);
 impl_has_deleted!(Value);
+impl_has_timestamps!(Value);
+impl_has_schema_version!(Value, [add_timestamps]);
+impl_restore_revision!(Value);
+
+impl Searchable for Value {
+    const KIND: SearchKind = SearchKind::Value;
+
+    fn to_search_document(&self) -> SearchDocument {
+        SearchDocument::new(Self::KIND, self.id, &self.name)
+            .with_company(self.company_id)
+            .with_text(&self.description)
+    }
+}
+
+impl Queryable for Value {
+    fn get_parent_company_id(&self) -> Option<Uuid> {
+        Some(self.company_id)
+    }
+}
 
-impl_is_partial_complete_optional_name_only!(PartialValue);
+impl_is_partial_complete_optional_name_only!(PartialValue, ValueFieldName);
 
 #[cfg(test)]
 mod test_helper {
@@ -85,6 +115,7 @@ mod tests {
     test_has_name!(Value);
     test_has_company!(Value);
     test_has_deleted!(Value);
+    test_has_timestamps!(Value);
 
     #[test]
     fn test_modify_with_hashmap() {
@@ -3,9 +3,79 @@ use std::error::Error;
 use std::fmt;
 use std::fmt::Display;
 
-#[derive(Debug, Default)]
+/// Something that names a field of a storable, the generated `*FieldName` enums or a
+/// [`SpreadsheetHeader`](crate::prelude::SpreadsheetHeader) for a column that isn't one
+pub trait FieldKey {
+    fn key(&self) -> &'static str;
+}
+
+macro_rules! impl_field_key {
+    ($($field_name:ty),+) => {
+        $(
+            impl FieldKey for $field_name {
+                fn key(&self) -> &'static str {
+                    self.name()
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "field_names")]
+impl_field_key!(
+    crate::storable::CompanyFieldName,
+    crate::storable::ContactFieldName,
+    crate::storable::FlagFieldName,
+    crate::storable::InterviewFieldName,
+    crate::storable::QuestionFieldName,
+    crate::storable::ReminderFieldName,
+    crate::storable::RoleFieldName,
+    crate::storable::ValueFieldName
+);
+
+/// Why a field didn't pass the check
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ValidationErrorKind {
+    Missing,
+    Empty,
+    Invalid,
+}
+
+/// A single failed check, tied to the field that caused it when there is one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    field: Option<&'static str>,
+    kind: ValidationErrorKind,
+    message: String,
+}
+
+impl FieldError {
+    pub fn field(&self) -> Option<&'static str> {
+        self.field
+    }
+
+    pub fn kind(&self) -> ValidationErrorKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn is_for<F: FieldKey>(&self, field: F) -> bool {
+        self.field == Some(field.key())
+    }
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct IncompletePartialErrors {
-    errors: Vec<String>,
+    errors: Vec<FieldError>,
 }
 
 impl IncompletePartialErrors {
@@ -15,18 +85,69 @@ impl IncompletePartialErrors {
         }
     }
 
-    pub fn field_error<S: Display>(field: S) -> Self {
+    pub fn field_error<F: FieldKey>(field: F) -> Self {
         let mut errors = IncompletePartialErrors::with_capacity(1);
-        errors.push(format!("Partial Check failed on field `{field}`"));
+        let message = format!("Partial Check failed on field `{}`", field.key());
+        errors.push_field(field, ValidationErrorKind::Missing, message);
         errors
     }
 
-    pub fn get_errors(&self) -> &Vec<String> {
+    /// The messages of every error, in the order they were found
+    pub fn get_errors(&self) -> Vec<String> {
+        self.errors.iter().map(ToString::to_string).collect()
+    }
+
+    pub fn field_errors(&self) -> &[FieldError] {
         &self.errors
     }
 
+    /// The first error found on the field, if it failed
+    pub fn for_field<F: FieldKey>(&self, field: F) -> Option<&FieldError> {
+        let key = field.key();
+        self.errors.iter().find(|error| error.field == Some(key))
+    }
+
+    /// Adds an error that isn't tied to any one field
     pub fn push<S: ToString>(&mut self, error: S) {
-        self.errors.push(error.to_string())
+        self.errors.push(FieldError {
+            field: None,
+            kind: ValidationErrorKind::Invalid,
+            message: error.to_string(),
+        })
+    }
+
+    pub fn push_field<F: FieldKey, S: ToString>(
+        &mut self,
+        field: F,
+        kind: ValidationErrorKind,
+        message: S,
+    ) {
+        self.errors.push(FieldError {
+            field: Some(field.key()),
+            kind,
+            message: message.to_string(),
+        })
+    }
+
+    pub fn push_missing<F: FieldKey>(&mut self, field: F) {
+        let message = format!("`{}` is missing", field.key());
+        self.push_field(field, ValidationErrorKind::Missing, message)
+    }
+
+    pub fn push_empty<F: FieldKey>(&mut self, field: F) {
+        let message = format!("`{}` is empty", field.key());
+        self.push_field(field, ValidationErrorKind::Empty, message)
+    }
+
+    pub fn push_invalid<F: FieldKey, S: ToString>(&mut self, field: F, message: S) {
+        self.push_field(field, ValidationErrorKind::Invalid, message)
+    }
+
+    /// Drops the missing fields, a partial applied as an edit leaves those fields as they were
+    pub fn ignoring_missing(mut self) -> Self {
+        self.errors
+            .retain(|error| error.kind != ValidationErrorKind::Missing);
+        self
     }
 
     pub fn is_empty(&self) -> bool {
@@ -45,14 +166,16 @@ impl From<IncompletePartialErrors> for Result<(), IncompletePartialErrors> {
 
 impl From<Vec<String>> for IncompletePartialErrors {
     fn from(errors: Vec<String>) -> Self {
-        Self { errors }
+        let mut incomplete = Self::with_capacity(errors.len());
+        errors.into_iter().for_each(|error| incomplete.push(error));
+        incomplete
     }
 }
 
 impl fmt::Display for IncompletePartialErrors {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Partial was incomplete; ")?;
-        let mut iter = self.errors.iter().map(String::as_str).peekable();
+        let mut iter = self.errors.iter().map(FieldError::message).peekable();
         let first_message = iter.next().unwrap_or("but no errors returned");
         write!(f, "{first_message}")?;
         while let Some(message) = iter.next() {
@@ -73,15 +196,15 @@ pub trait CheckPartialComplete: Partial {
 }
 
 macro_rules! impl_is_partial_complete_optional_name_only {
-    ($storable:ty) => {
+    ($storable:ty, $field_name:ident) => {
         impl CheckPartialComplete for $storable {
             fn check_complete(&self) -> Result<(), IncompletePartialErrors> {
                 let mut errors = IncompletePartialErrors::with_capacity(1);
                 // Name must be present and not empty
                 match self.name.as_ref().map(|name| !name.is_empty()) {
                     Some(true) => {}
-                    Some(false) => errors.push("`name` is empty"),
-                    None => errors.push("`name` is missing"),
+                    Some(false) => errors.push_empty($field_name::Name),
+                    None => errors.push_missing($field_name::Name),
                 }
                 errors.into()
             }
@@ -93,6 +216,7 @@ pub(crate) use impl_is_partial_complete_optional_name_only;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::storable::{InterviewFieldName, RoleFieldName};
 
     #[test]
     fn test_incomplete_partial_error_to_result_no_errors() {
@@ -111,7 +235,7 @@ mod tests {
 
     #[test]
     fn test_incomplete_partial_error_no_errors() {
-        let error = IncompletePartialErrors { errors: vec![] };
+        let error = IncompletePartialErrors::default();
         assert_eq!(
             format!("{error}"),
             "Partial was incomplete; but no errors returned"
@@ -120,17 +244,16 @@ mod tests {
 
     #[test]
     fn test_incomplete_partial_error_single() {
-        let error = IncompletePartialErrors {
-            errors: vec!["Single error".to_string()],
-        };
+        let error = IncompletePartialErrors::from(vec!["Single error".to_string()]);
         assert_eq!(format!("{error}"), "Partial was incomplete; Single error");
     }
 
     #[test]
     fn test_incomplete_partial_error_double() {
-        let error = IncompletePartialErrors {
-            errors: vec!["First error".to_string(), "Second error".to_string()],
-        };
+        let error = IncompletePartialErrors::from(vec![
+            "First error".to_string(),
+            "Second error".to_string(),
+        ]);
         assert_eq!(
             format!("{error}"),
             "Partial was incomplete; First error, and Second error"
@@ -139,13 +262,11 @@ mod tests {
 
     #[test]
     fn test_incomplete_partial_error_triple() {
-        let error = IncompletePartialErrors {
-            errors: vec![
-                "First error".to_string(),
-                "Second error".to_string(),
-                "Third error".to_string(),
-            ],
-        };
+        let error = IncompletePartialErrors::from(vec![
+            "First error".to_string(),
+            "Second error".to_string(),
+            "Third error".to_string(),
+        ]);
         assert_eq!(
             format!("{error}"),
             "Partial was incomplete; First error, Second error, and Third error"
@@ -154,7 +275,7 @@ mod tests {
 
     #[test]
     fn test_field_error() {
-        let error = IncompletePartialErrors::field_error("name");
+        let error = IncompletePartialErrors::field_error(RoleFieldName::Name);
         assert_eq!(
             format!("{error}"),
             "Partial was incomplete; Partial Check failed on field `name`"
@@ -168,10 +289,8 @@ mod tests {
             "Second error".to_string(),
             "Third error".to_string(),
         ];
-        let error = IncompletePartialErrors {
-            errors: errors.clone(),
-        };
-        assert_eq!(error.get_errors(), &errors);
+        let error = IncompletePartialErrors::from(errors.clone());
+        assert_eq!(error.get_errors(), errors);
     }
 
     #[test]
@@ -181,7 +300,7 @@ mod tests {
         error.push("Second error");
         assert_eq!(
             error.get_errors(),
-            &vec!["First error".to_string(), "Second error".to_string()]
+            vec!["First error".to_string(), "Second error".to_string()]
         );
     }
 
@@ -193,6 +312,53 @@ mod tests {
             "Third error".to_string(),
         ];
         let error = IncompletePartialErrors::from(errors.clone());
-        assert_eq!(error.get_errors(), &errors);
+        assert_eq!(error.get_errors(), errors);
+        assert!(error.field_errors().iter().all(|e| e.field().is_none()));
+    }
+
+    #[test]
+    fn test_push_field() {
+        let mut error = IncompletePartialErrors::with_capacity(3);
+        error.push_missing(RoleFieldName::Name);
+        error.push_empty(InterviewFieldName::Host);
+        error.push_invalid(
+            RoleFieldName::Currency,
+            "`currency` should be a three letter code",
+        );
+
+        let name = error.for_field(RoleFieldName::Name).unwrap();
+        assert_eq!(name.kind(), ValidationErrorKind::Missing);
+        assert_eq!(name.message(), "`name` is missing");
+        assert_eq!(
+            error.for_field(InterviewFieldName::Host).unwrap().kind(),
+            ValidationErrorKind::Empty
+        );
+        assert_eq!(
+            error.for_field(RoleFieldName::Currency).unwrap().message(),
+            "`currency` should be a three letter code"
+        );
+        assert!(error.for_field(RoleFieldName::Location).is_none());
+    }
+
+    #[test]
+    fn test_for_field_name() {
+        let mut error = IncompletePartialErrors::with_capacity(1);
+        error.push_empty(RoleFieldName::Name);
+        // Fields with the same name are the same field, whichever storable they are from
+        assert!(error.for_field(InterviewFieldName::Name).is_some());
+        assert!(error.field_errors()[0].is_for(RoleFieldName::Name));
+        assert!(error.for_field(RoleFieldName::DateApplied).is_none());
+    }
+
+    #[test]
+    fn test_ignoring_missing() {
+        let mut error = IncompletePartialErrors::with_capacity(2);
+        error.push_missing(RoleFieldName::DateApplied);
+        error.push_empty(RoleFieldName::Name);
+        let error = error.ignoring_missing();
+        assert_eq!(error.get_errors(), vec!["`name` is empty".to_string()]);
+        assert!(IncompletePartialErrors::field_error(RoleFieldName::Name)
+            .ignoring_missing()
+            .is_empty());
     }
 }
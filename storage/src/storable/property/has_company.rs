@@ -24,6 +24,9 @@ macro_rules! impl_has_company {
 }
 pub(crate) use impl_has_company;
 
+#[cfg(test)]
+pub(crate) use test_helper::test_has_company;
+
 #[cfg(test)]
 pub mod test_helper {
     macro_rules! test_has_company {
@@ -40,6 +43,3 @@ pub mod test_helper {
     }
     pub(crate) use test_has_company;
 }
-
-#[cfg(test)]
-pub(crate) use test_helper::test_has_company;
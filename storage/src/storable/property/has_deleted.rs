@@ -1,5 +1,9 @@
+use crate::Timestamp;
+
 pub trait HasDeleted {
     fn is_deleted(&self) -> bool;
+
+    fn get_date_deleted(&self) -> Option<Timestamp>;
 }
 impl<T> HasDeleted for &T
 where
@@ -8,6 +12,22 @@ where
     fn is_deleted(&self) -> bool {
         (*self).is_deleted()
     }
+
+    fn get_date_deleted(&self) -> Option<Timestamp> {
+        (*self).get_date_deleted()
+    }
+}
+
+pub trait SetDeleted: HasDeleted {
+    fn set_date_deleted(&mut self, date_deleted: Option<Timestamp>);
+
+    fn delete(&mut self, date_deleted: Timestamp) {
+        self.set_date_deleted(Some(date_deleted));
+    }
+
+    fn restore(&mut self) {
+        self.set_date_deleted(None);
+    }
 }
 
 macro_rules! impl_has_deleted {
@@ -16,11 +36,24 @@ macro_rules! impl_has_deleted {
             fn is_deleted(&self) -> bool {
                 self.date_deleted.is_some()
             }
+
+            fn get_date_deleted(&self) -> Option<Timestamp> {
+                self.date_deleted
+            }
+        }
+
+        impl SetDeleted for $storable {
+            fn set_date_deleted(&mut self, date_deleted: Option<Timestamp>) {
+                self.date_deleted = date_deleted;
+            }
         }
     };
 }
 pub(crate) use impl_has_deleted;
 
+#[cfg(test)]
+pub(crate) use test_helper::test_has_deleted;
+
 #[cfg(test)]
 pub mod test_helper {
     macro_rules! test_has_deleted {
@@ -31,14 +64,18 @@ pub mod test_helper {
                     use crate::Timestamp;
                     let mut storable = $storable::new_test().await.expect("Could not create storable");
                     assert!(!storable.is_deleted());
-                    storable.date_deleted = Some(Timestamp::now());
+                    assert_eq!(storable.get_date_deleted(), None);
+
+                    let date_deleted = Timestamp::now();
+                    storable.delete(date_deleted);
                     assert!(storable.is_deleted());
+                    assert_eq!(storable.get_date_deleted(), Some(date_deleted));
+
+                    storable.restore();
+                    assert!(!storable.is_deleted());
                 }
             }
         };
     }
     pub(crate) use test_has_deleted;
 }
-
-#[cfg(test)]
-pub(crate) use test_helper::test_has_deleted;
@@ -30,6 +30,9 @@ macro_rules! impl_has_id {
 }
 pub(crate) use impl_has_id;
 
+#[cfg(test)]
+pub(crate) use test_helper::test_has_id;
+
 #[cfg(test)]
 pub mod test_helper {
     macro_rules! test_has_id {
@@ -46,6 +49,3 @@ pub mod test_helper {
     }
     pub(crate) use test_has_id;
 }
-
-#[cfg(test)]
-pub(crate) use test_helper::test_has_id;
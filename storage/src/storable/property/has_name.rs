@@ -22,6 +22,9 @@ macro_rules! impl_has_name {
 }
 pub(crate) use impl_has_name;
 
+#[cfg(test)]
+pub(crate) use test_helper::test_has_name;
+
 #[cfg(test)]
 pub mod test_helper {
     macro_rules! test_has_name {
@@ -37,6 +40,3 @@ pub mod test_helper {
     }
     pub(crate) use test_has_name;
 }
-
-#[cfg(test)]
-pub(crate) use test_helper::test_has_name;
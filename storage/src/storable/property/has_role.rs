@@ -24,6 +24,9 @@ macro_rules! impl_has_role {
 }
 pub(crate) use impl_has_role;
 
+#[cfg(test)]
+pub(crate) use test_helper::test_has_role;
+
 #[cfg(test)]
 pub mod test_helper {
     macro_rules! test_has_role {
@@ -40,6 +43,3 @@ pub mod test_helper {
     }
     pub(crate) use test_has_role;
 }
-
-#[cfg(test)]
-pub(crate) use test_helper::test_has_role;
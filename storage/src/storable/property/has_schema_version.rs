@@ -0,0 +1,145 @@
+use anyhow::{bail, Result};
+use serde_json::Map;
+
+/// The key the schema version is stored under in each persisted document
+pub const SCHEMA_VERSION_KEY: &str = "schema_version";
+
+/// Upgrades a stored document from one schema version to the next
+pub type Migration = fn(&mut Map<String, serde_json::Value>) -> Result<()>;
+
+pub trait HasSchemaVersion {
+    /// `MIGRATIONS[n]` upgrades a document at version `n` to version `n + 1`.
+    ///
+    /// Only ever append to this list, the position of each migration is its version.
+    const MIGRATIONS: &'static [Migration];
+
+    const SCHEMA_VERSION: u64 = Self::MIGRATIONS.len() as u64;
+}
+
+macro_rules! impl_has_schema_version {
+    ($storable:ty) => {
+        impl_has_schema_version!($storable, []);
+    };
+    ($storable:ty, [$($migration:path),* $(,)?]) => {
+        impl HasSchemaVersion for $storable {
+            const MIGRATIONS: &'static [Migration] = &[$($migration),*];
+        }
+    };
+}
+pub(crate) use impl_has_schema_version;
+
+/// Documents saved before versioning existed have no version and are treated as version 0
+pub fn get_schema_version(document: &serde_json::Value) -> Result<u64> {
+    match document.get(SCHEMA_VERSION_KEY) {
+        None => Ok(0),
+        Some(version) => version
+            .as_u64()
+            .ok_or_else(|| anyhow::anyhow!("Invalid schema version: {version}")),
+    }
+}
+
+pub fn set_schema_version<O: HasSchemaVersion>(document: &mut serde_json::Value) -> Result<()> {
+    let Some(object) = document.as_object_mut() else {
+        bail!("Can not set the schema version of a document that is not an object");
+    };
+    object.insert(SCHEMA_VERSION_KEY.to_string(), O::SCHEMA_VERSION.into());
+    Ok(())
+}
+
+/// Runs each migration needed to bring the document up to the current schema version.
+///
+/// Returns the version the document was at before migrating.
+pub fn migrate<O: HasSchemaVersion>(document: &mut serde_json::Value) -> Result<u64> {
+    let original_version = get_schema_version(document)?;
+    if original_version > O::SCHEMA_VERSION {
+        bail!(
+            "Schema version {original_version} is newer than {}, was this saved by a newer version of the app?",
+            O::SCHEMA_VERSION
+        );
+    }
+
+    let Some(object) = document.as_object_mut() else {
+        bail!("Can not migrate a document that is not an object");
+    };
+    for migration in &O::MIGRATIONS[original_version as usize..] {
+        migration(object)?;
+    }
+    set_schema_version::<O>(document)?;
+
+    Ok(original_version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    struct Versioned;
+
+    fn add_status(document: &mut Map<String, serde_json::Value>) -> Result<()> {
+        document.entry("status").or_insert_with(|| "applied".into());
+        Ok(())
+    }
+
+    fn rename_title(document: &mut Map<String, serde_json::Value>) -> Result<()> {
+        if let Some(title) = document.remove("title") {
+            document.insert("name".to_string(), title);
+        }
+        Ok(())
+    }
+
+    impl HasSchemaVersion for Versioned {
+        const MIGRATIONS: &'static [Migration] = &[add_status, rename_title];
+    }
+
+    #[test]
+    fn test_schema_version() {
+        assert_eq!(Versioned::SCHEMA_VERSION, 2);
+    }
+
+    #[test]
+    fn test_get_schema_version() {
+        assert_eq!(get_schema_version(&json!({})).unwrap(), 0);
+        assert_eq!(
+            get_schema_version(&json!({"schema_version": 3})).unwrap(),
+            3
+        );
+        assert!(get_schema_version(&json!({"schema_version": "three"})).is_err());
+    }
+
+    #[test]
+    fn test_migrate_from_unversioned() {
+        let mut document = json!({"title": "Role"});
+        assert_eq!(migrate::<Versioned>(&mut document).unwrap(), 0);
+        assert_eq!(
+            document,
+            json!({"name": "Role", "status": "applied", "schema_version": 2})
+        );
+    }
+
+    #[test]
+    fn test_migrate_only_runs_newer_migrations() {
+        let mut document = json!({"title": "Role", "schema_version": 1});
+        assert_eq!(migrate::<Versioned>(&mut document).unwrap(), 1);
+        assert_eq!(document, json!({"name": "Role", "schema_version": 2}));
+    }
+
+    #[test]
+    fn test_migrate_current_is_unchanged() {
+        let mut document = json!({"title": "Role", "schema_version": 2});
+        assert_eq!(migrate::<Versioned>(&mut document).unwrap(), 2);
+        assert_eq!(document, json!({"title": "Role", "schema_version": 2}));
+    }
+
+    #[test]
+    fn test_migrate_newer_fails() {
+        let mut document = json!({"schema_version": 3});
+        assert!(migrate::<Versioned>(&mut document).is_err());
+    }
+
+    #[test]
+    fn test_migrate_non_object_fails() {
+        let mut document = json!(["not", "an", "object"]);
+        assert!(migrate::<Versioned>(&mut document).is_err());
+    }
+}
@@ -0,0 +1,95 @@
+use crate::Timestamp;
+use serde_json::Map;
+
+/// When a storable was first stored and when it was last changed.
+///
+/// These are set by the store, so anything that hasn't been stored yet has neither.
+pub trait HasTimestamps {
+    fn get_date_created(&self) -> Option<Timestamp>;
+
+    fn get_date_updated(&self) -> Option<Timestamp>;
+}
+
+impl<T> HasTimestamps for &T
+where
+    T: HasTimestamps,
+{
+    fn get_date_created(&self) -> Option<Timestamp> {
+        (*self).get_date_created()
+    }
+
+    fn get_date_updated(&self) -> Option<Timestamp> {
+        (*self).get_date_updated()
+    }
+}
+
+/// Only the stores should be changing these
+pub(crate) trait SetTimestamps: HasTimestamps {
+    fn set_date_created(&mut self, date_created: Option<Timestamp>);
+
+    fn set_date_updated(&mut self, date_updated: Option<Timestamp>);
+}
+
+macro_rules! impl_has_timestamps {
+    ($storable:ty) => {
+        impl HasTimestamps for $storable {
+            fn get_date_created(&self) -> Option<Timestamp> {
+                self.date_created
+            }
+
+            fn get_date_updated(&self) -> Option<Timestamp> {
+                self.date_updated
+            }
+        }
+
+        impl SetTimestamps for $storable {
+            fn set_date_created(&mut self, date_created: Option<Timestamp>) {
+                self.date_created = date_created;
+            }
+
+            fn set_date_updated(&mut self, date_updated: Option<Timestamp>) {
+                self.date_updated = date_updated;
+            }
+        }
+    };
+}
+pub(crate) use impl_has_timestamps;
+
+/// Shared by every storable, the stores fill these in when the documents are next loaded
+pub(crate) fn add_timestamps(storable: &mut Map<String, serde_json::Value>) -> anyhow::Result<()> {
+    storable
+        .entry("date_created")
+        .or_insert(serde_json::Value::Null);
+    storable
+        .entry("date_updated")
+        .or_insert(serde_json::Value::Null);
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) use test_helper::test_has_timestamps;
+
+#[cfg(test)]
+pub mod test_helper {
+    macro_rules! test_has_timestamps {
+        ($storable:ty) => {
+            paste! {
+                #[tokio::test]
+                async fn [< test_has_timestamps_ $storable:snake >] () {
+                    use crate::Timestamp;
+                    let mut storable = $storable::new_test().await.expect("Could not create storable");
+                    assert_eq!(storable.get_date_created(), None);
+                    assert_eq!(storable.get_date_updated(), None);
+
+                    let date_created = Timestamp::from_timestamp(1_000);
+                    let date_updated = Timestamp::from_timestamp(2_000);
+                    storable.set_date_created(Some(date_created));
+                    storable.set_date_updated(Some(date_updated));
+                    assert_eq!(storable.get_date_created(), Some(date_created));
+                    assert_eq!(storable.get_date_updated(), Some(date_updated));
+                }
+            }
+        };
+    }
+    pub(crate) use test_has_timestamps;
+}
@@ -18,3 +18,18 @@ pub use apply_partial::*;
 
 mod check_partial_complete;
 pub use check_partial_complete::*;
+
+mod has_schema_version;
+pub use has_schema_version::*;
+
+mod has_timestamps;
+pub use has_timestamps::*;
+
+mod restore_revision;
+pub use restore_revision::*;
+
+mod searchable;
+pub use searchable::*;
+
+mod queryable;
+pub use queryable::*;
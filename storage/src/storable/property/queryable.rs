@@ -0,0 +1,24 @@
+use crate::storable::{HasDeleted, HasId, HasName, HasTimestamps};
+use crate::{ExchangeRates, NormalisedCompensation, Timestamp};
+use uuid::Uuid;
+
+/// Storables that can be filtered and sorted with a [`crate::Query`]
+pub trait Queryable: HasId + HasName + HasDeleted + HasTimestamps {
+    fn get_parent_company_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn get_parent_role_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    /// The date the storable is about, when it was created unless it has a date of its own
+    fn get_date(&self) -> Option<Timestamp> {
+        self.get_date_created()
+    }
+
+    /// What the storable pays, only roles have compensation
+    fn get_compensation(&self, _exchange_rates: &ExchangeRates) -> Option<NormalisedCompensation> {
+        None
+    }
+}
@@ -0,0 +1,20 @@
+use crate::Timestamp;
+
+/// How an earlier version of an item is put back over the current one.
+///
+/// Most items simply go back to how they were, anything that is only ever added to (such as a
+/// role's status history) carries on from the current version instead.
+pub trait RestoreRevision: Sized {
+    fn restore_over(&self, previous: Self, _date_restored: Timestamp) -> Self {
+        previous
+    }
+}
+
+macro_rules! impl_restore_revision {
+    ($($storable:ty),+) => {
+        $(
+            impl RestoreRevision for $storable {}
+        )+
+    };
+}
+pub(crate) use impl_restore_revision;
@@ -0,0 +1,9 @@
+use crate::search::{SearchDocument, SearchKind};
+use crate::storable::{HasDeleted, HasId};
+
+/// Storables that can be found through the search index
+pub trait Searchable: HasId + HasDeleted {
+    const KIND: SearchKind;
+
+    fn to_search_document(&self) -> SearchDocument;
+}
@@ -1,46 +1,145 @@
 use crate::prelude::Interview;
 use crate::storable::{
-    Company, Flag, HasCompany, HasDeleted, HasId, HasName, HasRole, Question, Role, Value,
+    migrate, set_schema_version, Company, Contact, Flag, HasCompany, HasDeleted, HasId, HasName,
+    HasRole, HasSchemaVersion, Queryable, Question, Reminder, RestoreRevision, Role, SetTimestamps,
+    Value,
 };
 use crate::storage::{
-    BaseStore, CompanyStore, FlagStore, InterviewStore, QuestionStore, RecallByCompany, RecallById,
-    RecallByName, RecallByRole, RoleStore, StubStore, ValueStore,
+    check_attachment_name, prepare_store, Attachments, BaseStore, CompanyStore, ContactStore,
+    FlagStore, InterviewStore, LoadReport, Purge, QuarantinedFile, QuestionStore, RecallByCompany,
+    RecallById, RecallByName, RecallByQuery, RecallByRole, RecallDeleted, RecallRevisions,
+    ReminderStore, RoleStore, Rollback, StoreChange, StubSnapshot, StubStore, ValueStore,
 };
-use anyhow::Result;
+use crate::{IoResultExt, Query, Revision, StorageError, StorageResult, Timestamp};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use serde_json::json;
 use std::ffi::OsStr;
-use std::path::PathBuf;
-use tokio::fs::{create_dir_all, read, read_dir};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::fs::{
+    copy, create_dir_all, metadata, read, read_dir, remove_file, rename, File, OpenOptions,
+};
+use tokio::io::AsyncWriteExt;
+use uuid::Uuid;
+
+/// Files are written here first, then renamed over the real file once they are complete
+const TEMP_EXTENSION: &str = "tmp";
+
+/// Where the original files are kept when they are migrated to a newer schema version
+const BACKUP_DIRECTORY: &str = "backup";
+
+/// Earlier versions of each item are kept here, one file per item with a revision on each line so
+/// that recording another only has to append it
+const HISTORY_DIRECTORY: &str = "history";
 
 #[derive(Clone)]
 pub struct JsonStore<O> {
     base_path: PathBuf,
     pub(crate) internal_store: StubStore<O>,
+    load_report: LoadReport,
 }
 
 impl<O> JsonStore<O>
 where
-    O: Clone + HasId + Serialize + DeserializeOwned,
+    O: Clone + Queryable + SetTimestamps + HasSchemaVersion + Serialize + DeserializeOwned,
 {
-    pub async fn new(base_path: PathBuf) -> Result<Self> {
-        let mut internal_store = StubStore::default();
-        create_dir_all(&base_path).await?;
-        let mut dir = read_dir(&base_path).await?;
+    pub async fn new(base_path: PathBuf) -> StorageResult<Self> {
+        create_dir_all(&base_path).await.at_path(&base_path)?;
+        let mut store = Self {
+            base_path,
+            internal_store: StubStore::default(),
+            load_report: LoadReport::default(),
+        };
+
+        remove_temp_files(&store.base_path).await?;
+        remove_temp_files(&store.base_path.join(HISTORY_DIRECTORY)).await?;
+
+        // Files quarantined on earlier launches are still waiting to be looked at
+        let store_name = store.store_name();
+        for file in QuarantinedFile::find(&store_name, &store.base_path).await? {
+            store.load_report.add_quarantined(file);
+        }
 
-        while let Some(entry) = dir.next_entry().await? {
+        let mut dir = read_dir(&store.base_path).await.at_path(&store.base_path)?;
+        while let Some(entry) = dir.next_entry().await.at_path(&store.base_path)? {
             if entry.path().is_file() && entry.path().extension() == Some(OsStr::new("json")) {
-                let file_date = read(&entry.path()).await?;
-                let item: O = serde_json::from_slice(&file_date)?;
-                internal_store.store(item).await?;
+                if let Some(item) = store.load_file(&entry.path()).await? {
+                    store.internal_store.store(item).await?;
+                }
             }
         }
 
-        Ok(Self {
-            base_path,
-            internal_store,
-        })
+        Ok(store)
+    }
+
+    fn store_name(&self) -> String {
+        let store_name = self.base_path.file_name().unwrap_or_default();
+        store_name.to_string_lossy().to_string()
+    }
+
+    /// Files that couldn't be loaded, whether that was when the store was created or earlier
+    pub fn get_load_report(&self) -> &LoadReport {
+        &self.load_report
+    }
+
+    /// Reads a file, migrating it to the current schema version if it is older.
+    ///
+    /// Migrated files are rewritten after the original is copied into the backup directory. Files
+    /// that can not be deserialised are quarantined and `None` is returned. Anything saved before
+    /// timestamps were recorded is treated as created and last updated when its file was written.
+    async fn load_file(&mut self, path: &Path) -> StorageResult<Option<O>> {
+        let file_data = read(path).await.at_path(path)?;
+        let (mut item, original_version) = match Self::parse_file(&file_data) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                let file = QuarantinedFile::quarantine(self.store_name(), path, e).await?;
+                self.load_report.add_quarantined(file);
+                return Ok(None);
+            }
+        };
+
+        let is_missing_timestamps =
+            item.get_date_created().is_none() || item.get_date_updated().is_none();
+        if is_missing_timestamps {
+            let modified = metadata(path)
+                .await
+                .and_then(|metadata| metadata.modified())
+                .at_path(path)?;
+            let modified = Timestamp::from_system_time(modified);
+            item.set_date_created(Some(item.get_date_created().unwrap_or(modified)));
+            item.set_date_updated(Some(item.get_date_updated().unwrap_or(modified)));
+        }
+
+        if original_version < O::SCHEMA_VERSION {
+            let backup_path = self.create_backup_filename(path, original_version);
+            let backup_directory = self.base_path.join(BACKUP_DIRECTORY);
+            create_dir_all(&backup_directory)
+                .await
+                .at_path(&backup_directory)?;
+            copy(path, &backup_path).await.at_path(&backup_path)?;
+        }
+        if original_version < O::SCHEMA_VERSION || is_missing_timestamps {
+            self.write_file(&item).await?;
+        }
+
+        Ok(Some(item))
+    }
+
+    /// Anything wrong with a file ends up quarantined, so the error is only ever reported
+    fn parse_file(file_data: &[u8]) -> anyhow::Result<(O, u64)> {
+        let mut document: serde_json::Value = serde_json::from_slice(file_data)?;
+        let original_version = migrate::<O>(&mut document)?;
+        let item: O = serde_json::from_value(document)?;
+        Ok((item, original_version))
+    }
+
+    fn create_backup_filename(&self, path: &Path, version: u64) -> PathBuf {
+        let mut buf = self.base_path.join(BACKUP_DIRECTORY);
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        buf.push(format!("{stem}.v{version}.json"));
+        buf
     }
 
     pub fn create_filename(&self, data: &O) -> PathBuf {
@@ -50,69 +149,249 @@ where
         buf
     }
 
-    async fn write_file(&self, data: &O) -> Result<()> {
-        let path = self.create_filename(data);
-        tokio::fs::write(path, json!(data).to_string().as_bytes()).await?;
+    #[cfg(test)]
+    fn create_temp_filename(&self, data: &O) -> PathBuf {
+        create_temp_filename(&self.create_filename(data))
+    }
+
+    /// Attachments sit next to the item's own file, named after it
+    fn create_attachment_filename(&self, id: Uuid, name: &str) -> PathBuf {
+        self.base_path.join(format!("{id}.{name}"))
+    }
+
+    async fn remove_attachments(&self, id: Uuid) -> StorageResult<()> {
+        let prefix = format!("{id}.");
+        let mut dir = read_dir(&self.base_path).await.at_path(&self.base_path)?;
+        while let Some(entry) = dir.next_entry().await.at_path(&self.base_path)? {
+            let file_name = entry.file_name();
+            let is_attachment = file_name
+                .to_str()
+                .and_then(|file_name| file_name.strip_prefix(&prefix))
+                .is_some_and(|name| check_attachment_name(name).is_ok());
+            if is_attachment && entry.path().is_file() {
+                remove_file(entry.path()).await.at_path(&entry.path())?;
+            }
+        }
         Ok(())
     }
+
+    fn create_history_filename(&self, id: Uuid) -> PathBuf {
+        let mut buf = self.base_path.join(HISTORY_DIRECTORY);
+        buf.push(id.to_string());
+        buf.set_extension("json");
+        buf
+    }
+
+    async fn write_file(&self, data: &O) -> StorageResult<()> {
+        let mut document = json!(data);
+        set_schema_version::<O>(&mut document).map_err(StorageError::integrity)?;
+        write_atomically(&self.create_filename(data), document.to_string().as_bytes()).await
+    }
+
+    /// Every recorded revision of an item, an item that has never been edited has no file
+    async fn read_history(&self, id: Uuid) -> StorageResult<Vec<Revision>> {
+        let path = self.create_history_filename(id);
+        let data = match read(&path).await {
+            Ok(data) => data,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(StorageError::io(path, e)),
+        };
+        data.split(|byte| *byte == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                serde_json::from_slice(line).map_err(|e| StorageError::deserialize(&path, e))
+            })
+            .collect()
+    }
+
+    async fn add_revision(&self, id: Uuid, revision: Revision) -> StorageResult<()> {
+        let history_directory = self.base_path.join(HISTORY_DIRECTORY);
+        create_dir_all(&history_directory)
+            .await
+            .at_path(&history_directory)?;
+        let path = self.create_history_filename(id);
+        let mut line = serde_json::to_vec(&revision).map_err(StorageError::integrity)?;
+        line.push(b'\n');
+        let mut file = OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(&path)
+            .await
+            .at_path(&path)?;
+        file.write_all(&line).await.at_path(&path)?;
+        file.sync_all().await.at_path(&path)
+    }
+
+    /// The size of an item's history file, which is where it can be cut back to in a roll back
+    async fn history_length(&self, id: Uuid) -> StorageResult<u64> {
+        let path = self.create_history_filename(id);
+        match metadata(&path).await {
+            Ok(metadata) => Ok(metadata.len()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(0),
+            Err(e) => Err(StorageError::io(path, e)),
+        }
+    }
+
+    async fn truncate_history(&self, id: Uuid, length: u64) -> StorageResult<()> {
+        let path = self.create_history_filename(id);
+        if length == 0 {
+            return remove_file_if_exists(&path).await;
+        }
+        let file = OpenOptions::new()
+            .write(true)
+            .open(&path)
+            .await
+            .at_path(&path)?;
+        file.set_len(length).await.at_path(&path)?;
+        file.sync_all().await.at_path(&path)
+    }
+}
+
+/// How an item was stored in a [`JsonStore`], see [`Rollback`]
+#[derive(Clone)]
+pub struct JsonSnapshot<T> {
+    stored: StubSnapshot<T>,
+    history_length: u64,
+}
+
+fn create_temp_filename(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{TEMP_EXTENSION}"));
+    PathBuf::from(name)
+}
+
+/// Writes the whole file somewhere else first so that a crash part way through can never leave a
+/// truncated file in place of the last good one.
+pub(crate) async fn write_atomically(path: &Path, data: &[u8]) -> StorageResult<()> {
+    let temp_path = create_temp_filename(path);
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(&temp_path)
+        .await
+        .at_path(&temp_path)?;
+    file.write_all(data).await.at_path(&temp_path)?;
+    file.sync_all().await.at_path(&temp_path)?;
+    drop(file);
+    rename(&temp_path, path).await.at_path(path)?;
+
+    // Make sure the rename itself survives a power cut
+    #[cfg(unix)]
+    if let Some(directory) = path.parent() {
+        File::open(directory)
+            .await
+            .at_path(directory)?
+            .sync_all()
+            .await
+            .at_path(directory)?;
+    }
+
+    Ok(())
+}
+
+async fn remove_file_if_exists(path: &Path) -> StorageResult<()> {
+    match remove_file(path).await {
+        Err(e) if e.kind() != ErrorKind::NotFound => Err(StorageError::io(path, e)),
+        _ => Ok(()),
+    }
+}
+
+/// Removes anything left behind by a write that never finished, the real files are still intact
+async fn remove_temp_files(directory: &Path) -> StorageResult<()> {
+    let mut dir = match read_dir(directory).await {
+        Ok(dir) => dir,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(StorageError::io(directory, e)),
+    };
+    while let Some(entry) = dir.next_entry().await.at_path(directory)? {
+        if entry.path().is_file() && entry.path().extension() == Some(OsStr::new(TEMP_EXTENSION)) {
+            remove_file(entry.path()).await.at_path(&entry.path())?;
+        }
+    }
+    Ok(())
 }
 
 pub trait ScopedJsonStoreFor
 where
     Self: Sized,
 {
-    async fn new_scoped(base_path: PathBuf) -> Result<Self>;
+    async fn new_scoped(base_path: PathBuf) -> StorageResult<Self>;
 }
 
 impl ScopedJsonStoreFor for JsonStore<Company> {
-    async fn new_scoped(mut base_path: PathBuf) -> Result<Self> {
+    async fn new_scoped(mut base_path: PathBuf) -> StorageResult<Self> {
         base_path.push("company");
         Self::new(base_path).await
     }
 }
 
 impl ScopedJsonStoreFor for JsonStore<Flag> {
-    async fn new_scoped(mut base_path: PathBuf) -> Result<Self> {
+    async fn new_scoped(mut base_path: PathBuf) -> StorageResult<Self> {
         base_path.push("flag");
         Self::new(base_path).await
     }
 }
 
 impl ScopedJsonStoreFor for JsonStore<Value> {
-    async fn new_scoped(mut base_path: PathBuf) -> Result<Self> {
+    async fn new_scoped(mut base_path: PathBuf) -> StorageResult<Self> {
         base_path.push("value");
         Self::new(base_path).await
     }
 }
 
 impl ScopedJsonStoreFor for JsonStore<Role> {
-    async fn new_scoped(mut base_path: PathBuf) -> Result<Self> {
+    async fn new_scoped(mut base_path: PathBuf) -> StorageResult<Self> {
         base_path.push("role");
         Self::new(base_path).await
     }
 }
 
 impl ScopedJsonStoreFor for JsonStore<Interview> {
-    async fn new_scoped(mut base_path: PathBuf) -> Result<Self> {
+    async fn new_scoped(mut base_path: PathBuf) -> StorageResult<Self> {
         base_path.push("interview");
         Self::new(base_path).await
     }
 }
 
 impl ScopedJsonStoreFor for JsonStore<Question> {
-    async fn new_scoped(mut base_path: PathBuf) -> Result<Self> {
+    async fn new_scoped(mut base_path: PathBuf) -> StorageResult<Self> {
         base_path.push("question");
         Self::new(base_path).await
     }
 }
 
+impl ScopedJsonStoreFor for JsonStore<Contact> {
+    async fn new_scoped(mut base_path: PathBuf) -> StorageResult<Self> {
+        base_path.push("contact");
+        Self::new(base_path).await
+    }
+}
+
+impl ScopedJsonStoreFor for JsonStore<Reminder> {
+    async fn new_scoped(mut base_path: PathBuf) -> StorageResult<Self> {
+        base_path.push("reminder");
+        Self::new(base_path).await
+    }
+}
+
 impl<O> BaseStore<O> for JsonStore<O>
 where
-    O: HasId + Clone + Serialize + DeserializeOwned,
+    O: Queryable + SetTimestamps + HasSchemaVersion + Clone + Serialize + DeserializeOwned,
 {
-    async fn store(&mut self, storable: O) -> anyhow::Result<()> {
+    async fn store(&mut self, mut storable: O) -> StorageResult<()> {
+        let previous = self.internal_store.get(storable.get_id());
+        let change = prepare_store(&mut storable, previous, Timestamp::now())?;
+        if let StoreChange::Unchanged = change {
+            return Ok(());
+        }
+
         self.write_file(&storable).await?;
-        self.internal_store.store(storable).await?;
+        self.internal_store.replace(storable.clone());
+        // The item itself is kept even if its history can't be, but that's still a failure
+        if let StoreChange::Updated(revision) = change {
+            self.add_revision(storable.get_id(), revision).await?;
+        }
         Ok(())
     }
 }
@@ -121,36 +400,161 @@ impl<O> RecallById<O> for JsonStore<O>
 where
     O: HasId + HasDeleted + Clone + Serialize + DeserializeOwned,
 {
-    async fn recall_by_id<I: HasId>(&self, id: I) -> anyhow::Result<O> {
+    async fn recall_by_id<I: HasId>(&self, id: I) -> StorageResult<O> {
         self.internal_store.recall_by_id(id).await
     }
+
+    async fn recall_shared_by_id<I: HasId>(&self, id: I) -> StorageResult<Arc<O>> {
+        self.internal_store.recall_shared_by_id(id).await
+    }
 }
 
 impl<T> RecallByName<T> for JsonStore<T>
 where
     T: HasName + HasDeleted + Clone + Serialize + DeserializeOwned,
 {
-    async fn recall_by_name<N: AsRef<str>>(&self, name: N) -> anyhow::Result<Vec<T>> {
+    async fn recall_by_name<N: AsRef<str>>(&self, name: N) -> StorageResult<Vec<T>> {
         self.internal_store.recall_by_name(name).await
     }
 }
 
 impl<T> RecallByCompany<T> for JsonStore<T>
 where
-    T: HasCompany + HasDeleted + Clone + Serialize + DeserializeOwned,
+    T: HasCompany + HasId + HasDeleted + Clone + Serialize + DeserializeOwned,
 {
-    async fn recall_by_company<C: HasId>(&self, company: C) -> anyhow::Result<Vec<T>> {
+    async fn recall_by_company<C: HasId>(&self, company: C) -> StorageResult<Vec<T>> {
         self.internal_store.recall_by_company(company).await
     }
+
+    async fn recall_shared_by_company<C: HasId>(&self, company: C) -> StorageResult<Vec<Arc<T>>> {
+        self.internal_store.recall_shared_by_company(company).await
+    }
 }
 
 impl<T> RecallByRole<T> for JsonStore<T>
 where
-    T: HasRole + HasDeleted + Clone + Serialize + DeserializeOwned,
+    T: HasRole + HasId + HasDeleted + Clone + Serialize + DeserializeOwned,
 {
-    async fn recall_by_role<R: HasId>(&self, role: R) -> anyhow::Result<Vec<T>> {
+    async fn recall_by_role<R: HasId>(&self, role: R) -> StorageResult<Vec<T>> {
         self.internal_store.recall_by_role(role).await
     }
+
+    async fn recall_shared_by_role<R: HasId>(&self, role: R) -> StorageResult<Vec<Arc<T>>> {
+        self.internal_store.recall_shared_by_role(role).await
+    }
+}
+
+impl<T> RecallDeleted<T> for JsonStore<T>
+where
+    T: HasId + HasDeleted + Clone + Serialize + DeserializeOwned,
+{
+    async fn recall_deleted(&self) -> StorageResult<Vec<T>> {
+        self.internal_store.recall_deleted().await
+    }
+}
+
+impl<T> Purge<T> for JsonStore<T>
+where
+    T: Queryable + SetTimestamps + HasSchemaVersion + Clone + Serialize + DeserializeOwned,
+{
+    async fn purge(&mut self, storable: T) -> StorageResult<()> {
+        match self.internal_store.get(&storable) {
+            None => return Err(StorageError::NotFound),
+            Some(stored) if !stored.is_deleted() => return Err(StorageError::NotDeleted),
+            Some(_) => {}
+        }
+        // The file goes first, so if that fails the item is still there to try again
+        let path = self.create_filename(&storable);
+        remove_file(&path).await.at_path(&path)?;
+        let id = storable.get_id();
+        self.internal_store.purge(storable).await?;
+        remove_file_if_exists(&self.create_history_filename(id)).await?;
+        self.remove_attachments(id).await
+    }
+}
+
+impl<T> Attachments<T> for JsonStore<T>
+where
+    T: Queryable + SetTimestamps + HasSchemaVersion + Clone + Serialize + DeserializeOwned,
+{
+    async fn attach<I: HasId>(
+        &mut self,
+        storable: I,
+        name: &str,
+        data: &[u8],
+    ) -> StorageResult<()> {
+        check_attachment_name(name)?;
+        let id = storable.get_id();
+        if self.internal_store.get(id).is_none() {
+            return Err(StorageError::NotFound);
+        }
+        let path = self.create_attachment_filename(id, name);
+        if tokio::fs::try_exists(&path).await.at_path(&path)? {
+            return Err(StorageError::AlreadyExists);
+        }
+        write_atomically(&path, data).await
+    }
+
+    async fn recall_attachment<I: HasId>(&self, storable: I, name: &str) -> StorageResult<Vec<u8>> {
+        check_attachment_name(name)?;
+        let path = self.create_attachment_filename(storable.get_id(), name);
+        match read(&path).await {
+            Ok(data) => Ok(data),
+            Err(e) if e.kind() == ErrorKind::NotFound => Err(StorageError::NotFound),
+            Err(e) => Err(StorageError::io(path, e)),
+        }
+    }
+}
+
+impl<T> RecallRevisions<T> for JsonStore<T>
+where
+    T: Queryable
+        + SetTimestamps
+        + HasSchemaVersion
+        + RestoreRevision
+        + Clone
+        + Serialize
+        + DeserializeOwned,
+{
+    async fn recall_revisions(&self, storable: &T) -> StorageResult<Vec<Revision>> {
+        self.read_history(storable.get_id()).await
+    }
+}
+
+impl<T> RecallByQuery<T> for JsonStore<T>
+where
+    T: Queryable + Clone + Serialize + DeserializeOwned,
+{
+    async fn recall_by_query(&self, query: &Query) -> StorageResult<Vec<T>> {
+        self.internal_store.recall_by_query(query).await
+    }
+}
+
+impl<T> Rollback<T> for JsonStore<T>
+where
+    T: Queryable + SetTimestamps + HasSchemaVersion + Clone + Serialize + DeserializeOwned,
+{
+    type Snapshot = JsonSnapshot<T>;
+
+    async fn snapshot(&self, id: Uuid) -> StorageResult<JsonSnapshot<T>> {
+        Ok(JsonSnapshot {
+            stored: self.internal_store.snapshot(id).await?,
+            history_length: self.history_length(id).await?,
+        })
+    }
+
+    async fn roll_back(&mut self, snapshot: JsonSnapshot<T>) -> StorageResult<()> {
+        let id = snapshot.stored.id;
+        match (&snapshot.stored.stored, self.internal_store.get(id)) {
+            (Some((_, stored_item)), _) => self.write_file(stored_item).await?,
+            (None, Some(current)) => remove_file_if_exists(&self.create_filename(current)).await?,
+            (None, None) => {}
+        }
+
+        self.truncate_history(id, snapshot.history_length).await?;
+
+        self.internal_store.roll_back(snapshot.stored).await
+    }
 }
 
 impl CompanyStore for JsonStore<Company> {}
@@ -160,6 +564,8 @@ impl QuestionStore for JsonStore<Question> {}
 impl InterviewStore for JsonStore<Interview> {}
 
 impl ValueStore for JsonStore<Value> {}
+impl ContactStore for JsonStore<Contact> {}
+impl ReminderStore for JsonStore<Reminder> {}
 
 #[cfg(test)]
 mod test_helper {
@@ -169,12 +575,12 @@ mod test_helper {
     #[cfg(test)]
     impl<O> TestHelper for JsonStore<O>
     where
-        O: HasId + Clone + Serialize + DeserializeOwned,
+        O: Queryable + SetTimestamps + HasSchemaVersion + Clone + Serialize + DeserializeOwned,
     {
         #[cfg(test)]
-        async fn new_test() -> Result<Self> {
+        async fn new_test() -> anyhow::Result<Self> {
             let base_path = tempfile::tempdir()?;
-            Self::new(base_path.keep()).await
+            Ok(Self::new(base_path.keep()).await?)
         }
     }
 }
@@ -182,16 +588,23 @@ mod test_helper {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::SetDeleted;
+    use crate::storable::RoleStatus;
     use crate::storage::{
+        attachments::test_helper::test_attachments, purge::test_helper::test_purge,
         recall_by_company::test_helper::test_recall_by_company,
         recall_by_id::test_helper::test_recall_by_id,
         recall_by_name::test_helper::test_recall_by_name,
+        recall_by_query::test_helper::test_recall_by_query,
         recall_by_role::test_helper::test_recall_by_role,
+        recall_deleted::test_helper::test_recall_deleted,
+        recall_revisions::test_helper::test_recall_revisions, rollback::test_helper::test_rollback,
     };
     use crate::test_helper::*;
     use paste::paste;
     use std::fs::File;
     use std::io::Write;
+    use uuid::Uuid;
 
     test_recall_by_id!(JsonStore, Company);
     test_recall_by_id!(JsonStore, Flag);
@@ -199,17 +612,64 @@ mod tests {
     test_recall_by_id!(JsonStore, Value);
     test_recall_by_id!(JsonStore, Question);
     test_recall_by_id!(JsonStore, Interview);
+    test_recall_by_id!(JsonStore, Contact);
+    test_recall_by_id!(JsonStore, Reminder);
     test_recall_by_name!(JsonStore, Company);
     test_recall_by_name!(JsonStore, Flag);
     test_recall_by_name!(JsonStore, Role);
     test_recall_by_name!(JsonStore, Value);
     test_recall_by_name!(JsonStore, Question);
     test_recall_by_name!(JsonStore, Interview);
+    test_recall_by_name!(JsonStore, Contact);
+    test_recall_by_name!(JsonStore, Reminder);
     test_recall_by_company!(JsonStore, Flag);
     test_recall_by_company!(JsonStore, Role);
     test_recall_by_company!(JsonStore, Value);
+    test_recall_by_company!(JsonStore, Contact);
+    test_recall_by_company!(JsonStore, Reminder);
     test_recall_by_role!(JsonStore, Question);
     test_recall_by_role!(JsonStore, Interview);
+    test_recall_deleted!(JsonStore, Company);
+    test_recall_deleted!(JsonStore, Flag);
+    test_recall_deleted!(JsonStore, Role);
+    test_recall_deleted!(JsonStore, Value);
+    test_recall_deleted!(JsonStore, Question);
+    test_recall_deleted!(JsonStore, Interview);
+    test_recall_deleted!(JsonStore, Contact);
+    test_recall_deleted!(JsonStore, Reminder);
+    test_purge!(JsonStore, Company);
+    test_purge!(JsonStore, Flag);
+    test_purge!(JsonStore, Role);
+    test_purge!(JsonStore, Value);
+    test_purge!(JsonStore, Question);
+    test_purge!(JsonStore, Interview);
+    test_purge!(JsonStore, Contact);
+    test_purge!(JsonStore, Reminder);
+    test_recall_revisions!(JsonStore, Company);
+    test_recall_revisions!(JsonStore, Flag);
+    test_recall_revisions!(JsonStore, Role);
+    test_recall_revisions!(JsonStore, Value);
+    test_recall_revisions!(JsonStore, Question);
+    test_recall_revisions!(JsonStore, Interview);
+    test_recall_revisions!(JsonStore, Contact);
+    test_recall_revisions!(JsonStore, Reminder);
+    test_recall_by_query!(JsonStore, Company);
+    test_recall_by_query!(JsonStore, Flag);
+    test_recall_by_query!(JsonStore, Role);
+    test_recall_by_query!(JsonStore, Value);
+    test_recall_by_query!(JsonStore, Question);
+    test_recall_by_query!(JsonStore, Interview);
+    test_recall_by_query!(JsonStore, Contact);
+    test_recall_by_query!(JsonStore, Reminder);
+    test_rollback!(JsonStore, Company);
+    test_rollback!(JsonStore, Flag);
+    test_rollback!(JsonStore, Role);
+    test_rollback!(JsonStore, Value);
+    test_rollback!(JsonStore, Question);
+    test_rollback!(JsonStore, Interview);
+    test_rollback!(JsonStore, Contact);
+    test_rollback!(JsonStore, Reminder);
+    test_attachments!(JsonStore, Role);
 
     #[tokio::test]
     async fn test_load_from_file() {
@@ -232,7 +692,321 @@ mod tests {
         let loaded_store = JsonStore::<Company>::new(base_path).await.unwrap();
         let recalled_company = loaded_store.recall_by_id(&company.get_id()).await.unwrap();
 
-        assert_eq!(recalled_company, company);
+        assert_eq!(without_timestamps(recalled_company), company);
+    }
+
+    #[tokio::test]
+    async fn test_files_are_versioned() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+
+        let role = Role::new(Uuid::new_v4(), "role", Timestamp::now());
+        let mut store = JsonStore::new(base_path.clone()).await.unwrap();
+        store.store(role.clone()).await.unwrap();
+
+        let file_data = std::fs::read(store.create_filename(&role)).unwrap();
+        let document: serde_json::Value = serde_json::from_slice(&file_data).unwrap();
+        assert_eq!(document["schema_version"], json!(Role::SCHEMA_VERSION));
+    }
+
+    #[tokio::test]
+    async fn test_migrate_on_load() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+
+        // A role saved before versioning, status or compensation existed
+        let id = Uuid::new_v4();
+        let original = json!({
+            "id": id,
+            "company_id": Uuid::new_v4(),
+            "name": "role",
+            "description": "",
+            "date_applied": "2025-07-28T00:00",
+            "date_deleted": null,
+        })
+        .to_string();
+        let file_name = base_path.join(format!("{id}.json"));
+        std::fs::write(&file_name, &original).unwrap();
+
+        let modified = std::fs::metadata(&file_name).unwrap().modified().unwrap();
+
+        let store = JsonStore::<Role>::new(base_path.clone()).await.unwrap();
+        let role = store.recall_by_id(id).await.unwrap();
+        assert_eq!(role.status, RoleStatus::Applied);
+
+        // Timestamps are backfilled from when the file was last written
+        let modified = Timestamp::from_system_time(modified);
+        assert_eq!(role.date_created, Some(modified));
+        assert_eq!(role.date_updated, Some(modified));
+
+        // The original is backed up
+        let backup =
+            std::fs::read_to_string(base_path.join("backup").join(format!("{id}.v0.json")))
+                .unwrap();
+        assert_eq!(backup, original);
+
+        // The file is rewritten at the current version
+        let file_data = std::fs::read(&file_name).unwrap();
+        let document: serde_json::Value = serde_json::from_slice(&file_data).unwrap();
+        assert_eq!(document["schema_version"], json!(Role::SCHEMA_VERSION));
+        assert_eq!(document["status"], json!("applied"));
+
+        // Loading again leaves everything alone, timestamps are written to the minute
+        std::fs::remove_dir_all(base_path.join("backup")).unwrap();
+        let store = JsonStore::<Role>::new(base_path.clone()).await.unwrap();
+        assert_eq!(
+            json!(store.recall_by_id(id).await.unwrap() as Role),
+            json!(role)
+        );
+        assert!(!base_path.join("backup").exists());
+    }
+
+    #[tokio::test]
+    async fn test_store_sets_timestamps() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+
+        let mut company = Company::new("company");
+        let mut store = JsonStore::new(base_path.clone()).await.unwrap();
+        store.store(company.clone()).await.unwrap();
+        let created = store.recall_by_id(company.id).await.unwrap();
+        assert!(created.date_created.is_some());
+        assert_eq!(created.date_created, created.date_updated);
+
+        // Whatever the caller sets is ignored on an update
+        company.name = "new name".to_string();
+        company.date_created = Some(Timestamp::from_timestamp(0));
+        store.store(company.clone()).await.unwrap();
+        let updated = store.recall_by_id(company.id).await.unwrap();
+        assert_eq!(updated.date_created, created.date_created);
+        assert!(updated.date_updated >= created.date_updated);
+
+        // And survives a reload, to the minute it's written with
+        let store = JsonStore::<Company>::new(base_path).await.unwrap();
+        assert_eq!(
+            json!(store.recall_by_id(company.id).await.unwrap() as Company),
+            json!(updated)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_newer_schema_version_is_quarantined() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+
+        let mut document = json!(Company::new("company"));
+        document["schema_version"] = json!(Company::SCHEMA_VERSION + 1);
+        std::fs::write(base_path.join("company.json"), document.to_string()).unwrap();
+
+        let store = JsonStore::<Company>::new(base_path).await.unwrap();
+        let quarantined = store.get_load_report().get_quarantined();
+        assert_eq!(quarantined.len(), 1);
+        assert!(quarantined[0].error.contains("newer version"));
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_file_is_quarantined() {
+        let base_path = tempfile::tempdir().unwrap().keep().join("company");
+
+        let company = Company::new("company");
+        let mut initial_store = JsonStore::new(base_path.clone()).await.unwrap();
+        initial_store.store(company.clone()).await.unwrap();
+        assert!(initial_store.get_load_report().is_empty());
+        drop(initial_store);
+
+        let broken_path = base_path.join("broken.json");
+        std::fs::write(&broken_path, "{\"id\": \"not finished").unwrap();
+
+        // Everything else still loads
+        let store = JsonStore::<Company>::new(base_path.clone()).await.unwrap();
+        assert_eq!(
+            without_timestamps(store.recall_by_id(company.id).await.unwrap()),
+            company
+        );
+
+        let quarantined = store.get_load_report().get_quarantined();
+        assert_eq!(quarantined.len(), 1);
+        assert_eq!(quarantined[0].store, "company");
+        assert_eq!(quarantined[0].original_path, broken_path);
+        assert_eq!(
+            quarantined[0].quarantine_path.parent(),
+            Some(&*base_path.join("quarantine"))
+        );
+        assert!(!broken_path.exists());
+        assert!(quarantined[0].quarantine_path.exists());
+
+        // Quarantined files are not loaded again, but are still reported
+        let quarantined = quarantined.to_vec();
+        let store = JsonStore::<Company>::new(base_path.clone()).await.unwrap();
+        assert_eq!(store.get_load_report().get_quarantined(), quarantined);
+        assert_eq!(store.recall_by_name("").await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_purge_removes_file() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+
+        let mut company = Company::new("company");
+        let mut store = JsonStore::new(base_path.clone()).await.unwrap();
+        company.delete(Timestamp::now());
+        store.store(company.clone()).await.unwrap();
+        store.purge(company.clone()).await.unwrap();
+        assert!(!store.create_filename(&company).exists());
+
+        let store = JsonStore::<Company>::new(base_path).await.unwrap();
+        assert!(store.recall_by_id(company.id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_revisions_are_kept_on_disk() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+
+        let mut company = Company::new("company");
+        let mut store = JsonStore::new(base_path.clone()).await.unwrap();
+        store.store(company.clone()).await.unwrap();
+        company.name = "new name".to_string();
+        store.store(company.clone()).await.unwrap();
+        drop(store);
+
+        let mut store = JsonStore::<Company>::new(base_path.clone()).await.unwrap();
+        let revisions = store.recall_revisions(&company).await.unwrap();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].get_changes()[0].after, json!("new name"));
+
+        // History isn't loaded as if it were a company
+        assert!(store.get_load_report().is_empty());
+
+        company.delete(Timestamp::now());
+        store.store(company.clone()).await.unwrap();
+        store.purge(company.clone()).await.unwrap();
+        assert!(!store.create_history_filename(company.id).exists());
+    }
+
+    #[tokio::test]
+    async fn test_revisions_are_appended() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+
+        let mut company = Company::new("company");
+        let mut store = JsonStore::new(base_path.clone()).await.unwrap();
+        store.store(company.clone()).await.unwrap();
+        for name in ["first", "second"] {
+            company.name = name.to_string();
+            store.store(company.clone()).await.unwrap();
+        }
+
+        let history = std::fs::read_to_string(store.create_history_filename(company.id)).unwrap();
+        assert_eq!(history.lines().count(), 2);
+
+        // Rolling back cuts the file back to where it was
+        let snapshot = store.snapshot(company.id).await.unwrap();
+        company.name = "third".to_string();
+        store.store(company.clone()).await.unwrap();
+        store.roll_back(snapshot).await.unwrap();
+        let revisions = store.recall_revisions(&company).await.unwrap();
+        assert_eq!(revisions.len(), 2);
+        assert_eq!(revisions[1].get_changes()[0].after, json!("second"));
+    }
+
+    #[tokio::test]
+    async fn test_history_failure_is_reported_but_keeps_the_write() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+
+        let mut company = Company::new("company");
+        let mut store = JsonStore::new(base_path.clone()).await.unwrap();
+        store.store(company.clone()).await.unwrap();
+        // A directory where the history file should be means it can't be written
+        let history_path = store.create_history_filename(company.id);
+        std::fs::create_dir_all(&history_path).unwrap();
+
+        company.name = "new name".to_string();
+        let error = store.store(company.clone()).await.unwrap_err();
+        assert!(error.is_io());
+
+        let store = JsonStore::<Company>::new(base_path).await.unwrap();
+        let recalled: Company = store.recall_by_id(company.id).await.unwrap();
+        assert_eq!(recalled.name, "new name");
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_history_names_the_file() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+
+        let company = Company::new("company");
+        let mut store = JsonStore::new(base_path.clone()).await.unwrap();
+        store.store(company.clone()).await.unwrap();
+        let history_path = store.create_history_filename(company.id);
+        std::fs::create_dir_all(history_path.parent().unwrap()).unwrap();
+        std::fs::write(&history_path, "[").unwrap();
+
+        match store.recall_revisions(&company).await {
+            Err(StorageError::Deserialize { file, .. }) => assert_eq!(file, history_path),
+            other => panic!("Expected a deserialize error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unreadable_directory_names_the_path() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+        let file_path = base_path.join("not_a_directory");
+        std::fs::write(&file_path, "").unwrap();
+
+        match JsonStore::<Company>::new(file_path.clone()).await {
+            Err(StorageError::Io { path, .. }) => assert_eq!(path, file_path),
+            Err(other) => panic!("Expected an IO error, got {other:?}"),
+            Ok(_) => panic!("Expected an IO error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_leaves_no_temp_file() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+
+        let company = Company::new("company");
+        let mut store = JsonStore::new(base_path.clone()).await.unwrap();
+        store.store(company.clone()).await.unwrap();
+
+        assert!(store.create_filename(&company).exists());
+        assert!(!store.create_temp_filename(&company).exists());
+    }
+
+    #[tokio::test]
+    async fn test_interrupted_first_write() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+
+        let company = Company::new("company");
+        let store = JsonStore::new(base_path.clone()).await.unwrap();
+
+        // Simulate a crash part way through writing a new company
+        let temp_path = store.create_temp_filename(&company);
+        let json = json!(company).to_string();
+        std::fs::write(&temp_path, &json[..json.len() / 2]).unwrap();
+        drop(store);
+
+        let store = JsonStore::<Company>::new(base_path).await.unwrap();
+        assert!(store.recall_by_id(company.id).await.is_err());
+        assert!(store.get_load_report().is_empty());
+        assert!(!temp_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_interrupted_update_keeps_last_good_file() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+
+        let mut company = Company::new("company");
+        let mut store = JsonStore::new(base_path.clone()).await.unwrap();
+        store.store(company.clone()).await.unwrap();
+
+        // Simulate a crash part way through writing an update
+        let original = company.clone();
+        company.name = "new name".to_string();
+        let temp_path = store.create_temp_filename(&company);
+        let json = json!(company).to_string();
+        std::fs::write(&temp_path, &json[..json.len() / 2]).unwrap();
+        drop(store);
+
+        let store = JsonStore::<Company>::new(base_path).await.unwrap();
+        assert_eq!(
+            without_timestamps(store.recall_by_id(company.id).await.unwrap()),
+            original
+        );
+        assert!(store.get_load_report().is_empty());
+        assert!(!temp_path.exists());
     }
 
     #[tokio::test]
@@ -258,4 +1032,12 @@ mod tests {
         let role_store = JsonStore::<Role>::new_scoped(base_path).await.unwrap();
         assert!(role_store.base_path.ends_with("role"));
     }
+
+    #[tokio::test]
+    async fn test_contact_scoped() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+
+        let contact_store = JsonStore::<Contact>::new_scoped(base_path).await.unwrap();
+        assert!(contact_store.base_path.ends_with("contact"));
+    }
 }
@@ -0,0 +1,251 @@
+use crate::{IoResultExt, StorageResult, Timestamp};
+use std::ffi::OsStr;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use tokio::fs::{create_dir_all, read_dir, read_to_string, remove_file, rename, try_exists, write};
+
+/// Where files that can not be loaded are moved to, inside each store's directory
+pub const QUARANTINE_DIRECTORY: &str = "quarantine";
+
+/// Kept next to each quarantined file, holding why it couldn't be loaded
+const ERROR_EXTENSION: &str = "error";
+
+/// A file that could not be loaded and was moved out of the way so the rest of the store could be
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuarantinedFile {
+    pub store: String,
+    pub original_path: PathBuf,
+    pub quarantine_path: PathBuf,
+    pub error: String,
+}
+
+impl QuarantinedFile {
+    /// Moves a file into the quarantine directory next to it.
+    ///
+    /// The file is named after when it was quarantined, eg `{id}.20250728T093000.json`, so one
+    /// quarantined on an earlier launch is never overwritten.
+    pub(crate) async fn quarantine<S: Into<String>, E: ToString>(
+        store: S,
+        original_path: &Path,
+        error: E,
+    ) -> StorageResult<Self> {
+        let directory = original_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default()
+            .join(QUARANTINE_DIRECTORY);
+        create_dir_all(&directory).await.at_path(&directory)?;
+
+        let stem = original_path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy();
+        let extension = original_path
+            .extension()
+            .unwrap_or_default()
+            .to_string_lossy();
+        let date = Timestamp::now().format("%Y%m%dT%H%M%S").to_string();
+        let mut quarantine_path = directory.join(format!("{stem}.{date}.{extension}"));
+        let mut copy = 1;
+        while try_exists(&quarantine_path)
+            .await
+            .at_path(&quarantine_path)?
+        {
+            copy += 1;
+            quarantine_path = directory.join(format!("{stem}.{date}-{copy}.{extension}"));
+        }
+        rename(original_path, &quarantine_path)
+            .await
+            .at_path(original_path)?;
+
+        let error = error.to_string();
+        let error_path = error_filename(&quarantine_path);
+        write(&error_path, &error).await.at_path(&error_path)?;
+
+        Ok(Self {
+            store: store.into(),
+            original_path: original_path.to_path_buf(),
+            quarantine_path,
+            error,
+        })
+    }
+
+    /// Every file in a store's quarantine directory, including those quarantined on earlier
+    /// launches
+    pub(crate) async fn find<S: AsRef<str>>(
+        store: S,
+        store_directory: &Path,
+    ) -> StorageResult<Vec<Self>> {
+        let directory = store_directory.join(QUARANTINE_DIRECTORY);
+        let mut dir = match read_dir(&directory).await {
+            Ok(dir) => dir,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e).at_path(&directory),
+        };
+
+        let mut found = Vec::new();
+        while let Some(entry) = dir.next_entry().await.at_path(&directory)? {
+            let quarantine_path = entry.path();
+            if !quarantine_path.is_file()
+                || quarantine_path.extension() == Some(OsStr::new(ERROR_EXTENSION))
+            {
+                continue;
+            }
+            let error_path = error_filename(&quarantine_path);
+            let error = match read_to_string(&error_path).await {
+                Ok(error) => error,
+                Err(e) if e.kind() == ErrorKind::NotFound => "Unknown".to_string(),
+                Err(e) => return Err(e).at_path(&error_path),
+            };
+            found.push(Self {
+                store: store.as_ref().to_string(),
+                original_path: store_directory.join(original_filename(&quarantine_path)),
+                quarantine_path,
+                error,
+            });
+        }
+        found.sort_by(|a, b| a.quarantine_path.cmp(&b.quarantine_path));
+        Ok(found)
+    }
+
+    /// Moves the file back to where it came from, it will be loaded again next time the store is
+    /// created
+    pub async fn restore(&self) -> StorageResult<()> {
+        rename(&self.quarantine_path, &self.original_path)
+            .await
+            .at_path(&self.quarantine_path)?;
+        let error_path = error_filename(&self.quarantine_path);
+        match remove_file(&error_path).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(e).at_path(&error_path),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn error_filename(quarantine_path: &Path) -> PathBuf {
+    let mut name = quarantine_path.file_name().unwrap_or_default().to_owned();
+    name.push(".");
+    name.push(ERROR_EXTENSION);
+    quarantine_path.with_file_name(name)
+}
+
+/// Takes off the date the file was quarantined, anything without one keeps its name
+fn original_filename(quarantine_path: &Path) -> String {
+    let stem = quarantine_path
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy();
+    let stem = stem.rsplit_once('.').map_or(&*stem, |(stem, _)| stem);
+    match quarantine_path.extension() {
+        Some(extension) => format!("{stem}.{}", extension.to_string_lossy()),
+        None => stem.to_string(),
+    }
+}
+
+/// Everything that went wrong, but didn't stop a store from loading
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LoadReport {
+    quarantined: Vec<QuarantinedFile>,
+}
+
+impl LoadReport {
+    pub fn is_empty(&self) -> bool {
+        self.quarantined.is_empty()
+    }
+
+    pub fn get_quarantined(&self) -> &[QuarantinedFile] {
+        &self.quarantined
+    }
+
+    pub(crate) fn add_quarantined(&mut self, file: QuarantinedFile) {
+        self.quarantined.push(file);
+    }
+
+    pub fn merge(&mut self, other: LoadReport) {
+        self.quarantined.extend(other.quarantined);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_quarantine_and_restore() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+        let original_path = base_path.join("broken.json");
+        std::fs::write(&original_path, "{").unwrap();
+
+        let file = QuarantinedFile::quarantine("company", &original_path, "bad json")
+            .await
+            .unwrap();
+        assert_eq!(file.store, "company");
+        assert_eq!(file.error, "bad json");
+        assert_eq!(
+            file.quarantine_path.parent(),
+            Some(&*base_path.join("quarantine"))
+        );
+        assert!(!original_path.exists());
+        assert!(file.quarantine_path.exists());
+
+        file.restore().await.unwrap();
+        assert!(original_path.exists());
+        assert!(!file.quarantine_path.exists());
+        assert!(QuarantinedFile::find("company", &base_path)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_quarantine_keeps_earlier_files() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+        let original_path = base_path.join("broken.json");
+
+        std::fs::write(&original_path, "{").unwrap();
+        let first = QuarantinedFile::quarantine("company", &original_path, "bad json")
+            .await
+            .unwrap();
+        std::fs::write(&original_path, "[").unwrap();
+        let second = QuarantinedFile::quarantine("company", &original_path, "still bad")
+            .await
+            .unwrap();
+        assert_ne!(first.quarantine_path, second.quarantine_path);
+        assert_eq!(
+            std::fs::read_to_string(&first.quarantine_path).unwrap(),
+            "{"
+        );
+
+        let found = QuarantinedFile::find("company", &base_path).await.unwrap();
+        assert_eq!(found.len(), 2);
+        assert!(found.contains(&first));
+        assert!(found.contains(&second));
+    }
+
+    #[tokio::test]
+    async fn test_find_without_quarantine() {
+        let base_path = tempfile::tempdir().unwrap().keep();
+        let found = QuarantinedFile::find("company", &base_path).await.unwrap();
+        assert!(found.is_empty());
+    }
+
+    #[test]
+    fn test_merge() {
+        let file = QuarantinedFile {
+            store: "company".to_string(),
+            original_path: PathBuf::from("company/a.json"),
+            quarantine_path: PathBuf::from("company/quarantine/a.json"),
+            error: "bad json".to_string(),
+        };
+
+        let mut report = LoadReport::default();
+        assert!(report.is_empty());
+
+        let mut other = LoadReport::default();
+        other.add_quarantined(file.clone());
+        report.merge(other);
+
+        assert!(!report.is_empty());
+        assert_eq!(report.get_quarantined(), &[file]);
+    }
+}
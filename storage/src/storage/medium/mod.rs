@@ -1,5 +1,13 @@
 mod stub_storage;
 pub use stub_storage::*;
 
+mod load_report;
+pub use load_report::*;
+
 mod json_storage;
 pub use json_storage::*;
+
+#[cfg(feature = "sqlite")]
+mod sqlite_storage;
+#[cfg(feature = "sqlite")]
+pub use sqlite_storage::*;
@@ -0,0 +1,919 @@
+use crate::prelude::Interview;
+use crate::storable::{
+    Company, Contact, Flag, HasCompany, HasDeleted, HasId, HasName, HasRole, HasSchemaVersion,
+    Queryable, Question, Reminder, RestoreRevision, Role, SetTimestamps, Value,
+};
+use crate::storage::{
+    check_attachment_name, prepare_store, Attachments, BaseStore, CompanyStore, ContactStore,
+    FlagStore, InterviewStore, Purge, QuestionStore, RecallByCompany, RecallById, RecallByName,
+    RecallByQuery, RecallByRole, RecallDeleted, RecallRevisions, ReminderStore, RoleStore,
+    Rollback, StoreChange, ValueStore,
+};
+use crate::{Query, Revision, StorageError, StorageResult, Timestamp};
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+use uuid::Uuid;
+
+/// A single SQLite connection shared between every store backed by the same database
+#[derive(Clone)]
+pub struct SqliteConnection(Arc<Mutex<Connection>>);
+
+impl SqliteConnection {
+    pub fn open<P: AsRef<Path>>(path: P) -> StorageResult<Self> {
+        Ok(Self::from(Connection::open(path)?))
+    }
+
+    pub fn open_in_memory() -> StorageResult<Self> {
+        Ok(Self::from(Connection::open_in_memory()?))
+    }
+
+    pub(crate) fn lock(&self) -> StorageResult<MutexGuard<'_, Connection>> {
+        self.0
+            .lock()
+            .map_err(|_| StorageError::integrity("SQLite connection was poisoned"))
+    }
+
+    /// Runs the work on the blocking thread pool, so waiting on the connection or the disk doesn't
+    /// hold up everything else on the async runtime
+    pub(crate) async fn run<T, F>(&self, work: F) -> StorageResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Connection) -> StorageResult<T> + Send + 'static,
+    {
+        let connection = self.clone();
+        tokio::task::spawn_blocking(move || work(&*connection.lock()?))
+            .await
+            .map_err(StorageError::integrity)?
+    }
+
+    fn create_metadata_table(connection: &Connection) -> StorageResult<()> {
+        connection.execute_batch(
+            "CREATE TABLE IF NOT EXISTS metadata (key TEXT PRIMARY KEY NOT NULL, value TEXT NOT NULL);",
+        )?;
+        Ok(())
+    }
+
+    pub(crate) async fn get_metadata(&self, key: &str) -> StorageResult<Option<String>> {
+        let key = key.to_string();
+        self.run(move |connection| {
+            Self::create_metadata_table(connection)?;
+            Ok(connection
+                .query_row(
+                    "SELECT value FROM metadata WHERE key = ?1",
+                    params![key],
+                    |row| row.get(0),
+                )
+                .optional()?)
+        })
+        .await
+    }
+
+    fn set_metadata(connection: &Connection, key: &str, value: &str) -> StorageResult<()> {
+        Self::create_metadata_table(connection)?;
+        connection.execute(
+            "INSERT INTO metadata (key, value) VALUES (?1, ?2)
+                ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+            params![key, value],
+        )?;
+        Ok(())
+    }
+
+    /// Writes everything that was staged and sets the metadata in one transaction, so an import
+    /// that fails part way leaves nothing behind and can simply be run again
+    pub(crate) async fn import(
+        &self,
+        import: SqliteImport,
+        key: &str,
+        value: &str,
+    ) -> StorageResult<()> {
+        let (key, value) = (key.to_string(), value.to_string());
+        self.run(move |connection| {
+            let transaction = connection.unchecked_transaction()?;
+            for item in import.items {
+                for revision in &item.revisions {
+                    insert_revision(&transaction, item.table, &item.id, revision)?;
+                }
+                upsert(&transaction, item.table, &item.id, &item.data)?;
+            }
+            for attachment in import.attachments {
+                let table = attachment.table;
+                transaction.execute(
+                    &format!(
+                        "INSERT OR IGNORE INTO {table}_attachment (item_id, name, data)
+                            VALUES (?1, ?2, ?3)"
+                    ),
+                    params![attachment.id, attachment.name, attachment.data],
+                )?;
+            }
+            Self::set_metadata(&transaction, &key, &value)?;
+            transaction.commit()?;
+            Ok(())
+        })
+        .await
+    }
+}
+
+impl From<Connection> for SqliteConnection {
+    fn from(connection: Connection) -> Self {
+        Self(Arc::new(Mutex::new(connection)))
+    }
+}
+
+/// Stores each storable as a JSON document in its own table, keyed by id.
+///
+/// Parent ids and the deleted date are read out of the document with `json_extract` so the
+/// table doesn't need to change shape when a storable gains new fields. Earlier versions of each
+/// item are kept in a second table, `{table}_revision`.
+#[derive(Clone)]
+pub struct SqliteStore<O> {
+    connection: SqliteConnection,
+    table: &'static str,
+    _storable: PhantomData<O>,
+}
+
+impl<O> SqliteStore<O>
+where
+    O: Clone + HasId + Serialize + DeserializeOwned,
+{
+    pub fn new(connection: SqliteConnection, table: &'static str) -> StorageResult<Self> {
+        connection.lock()?.execute_batch(&format!(
+            "CREATE TABLE IF NOT EXISTS {table} (id TEXT PRIMARY KEY NOT NULL, data TEXT NOT NULL);
+            CREATE TABLE IF NOT EXISTS {table}_revision (item_id TEXT NOT NULL, data TEXT NOT NULL);
+            CREATE INDEX IF NOT EXISTS {table}_revision_item_id ON {table}_revision (item_id);
+            CREATE TABLE IF NOT EXISTS {table}_attachment (item_id TEXT NOT NULL, name TEXT NOT NULL, data BLOB NOT NULL, PRIMARY KEY (item_id, name));"
+        ))?;
+
+        Ok(Self {
+            connection,
+            table,
+            _storable: PhantomData,
+        })
+    }
+
+    /// Adds an index over a field of the stored documents
+    fn with_index(self, field: &str) -> StorageResult<Self> {
+        let table = self.table;
+        self.connection.lock()?.execute_batch(&format!(
+            "CREATE INDEX IF NOT EXISTS {table}_{field} ON {table} (json_extract(data, '$.{field}'));"
+        ))?;
+        Ok(self)
+    }
+
+    pub(crate) fn get_connection(&self) -> &SqliteConnection {
+        &self.connection
+    }
+}
+
+fn insert_revision(
+    connection: &Connection,
+    table: &str,
+    id: &str,
+    data: &str,
+) -> StorageResult<()> {
+    connection.execute(
+        &format!("INSERT INTO {table}_revision (item_id, data) VALUES (?1, ?2)"),
+        params![id, data],
+    )?;
+    Ok(())
+}
+
+/// Updating in place keeps the row where it was
+fn upsert(connection: &Connection, table: &str, id: &str, data: &str) -> StorageResult<()> {
+    connection.execute(
+        &format!(
+            "INSERT INTO {table} (id, data) VALUES (?1, ?2)
+                ON CONFLICT(id) DO UPDATE SET data = excluded.data"
+        ),
+        params![id, data],
+    )?;
+    Ok(())
+}
+
+/// An item copied in from another store, along with its earlier versions
+struct ImportedItem {
+    table: &'static str,
+    id: String,
+    data: String,
+    revisions: Vec<String>,
+}
+
+struct ImportedAttachment {
+    table: &'static str,
+    id: String,
+    name: String,
+    data: Vec<u8>,
+}
+
+/// Everything being copied into the database from another store, staged by each of the stores
+/// so it can all be written at once with [`SqliteConnection::import`]
+#[derive(Default)]
+pub(crate) struct SqliteImport {
+    items: Vec<ImportedItem>,
+    attachments: Vec<ImportedAttachment>,
+}
+
+impl SqliteImport {
+    /// How many items have been staged, not counting their revisions or attachments
+    pub(crate) fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
+impl<O> SqliteStore<O>
+where
+    O: HasId + SetTimestamps + HasSchemaVersion + Clone + Serialize + DeserializeOwned,
+{
+    /// Stages an item and its earlier versions, stamped the same way storing it as something new
+    /// would be
+    pub(crate) fn stage_import(
+        &self,
+        import: &mut SqliteImport,
+        mut item: O,
+        revisions: &[Revision],
+    ) -> StorageResult<()> {
+        prepare_store(&mut item, None, Timestamp::now())?;
+        import.items.push(ImportedItem {
+            table: self.table,
+            id: item.get_id().to_string(),
+            data: serde_json::to_string(&item).map_err(StorageError::integrity)?,
+            revisions: revisions
+                .iter()
+                .map(|revision| serde_json::to_string(revision).map_err(StorageError::integrity))
+                .collect::<StorageResult<_>>()?,
+        });
+        Ok(())
+    }
+
+    pub(crate) fn stage_import_attachment<I: HasId>(
+        &self,
+        import: &mut SqliteImport,
+        item: I,
+        name: &str,
+        data: Vec<u8>,
+    ) -> StorageResult<()> {
+        check_attachment_name(name)?;
+        import.attachments.push(ImportedAttachment {
+            table: self.table,
+            id: item.get_id().to_string(),
+            name: name.to_string(),
+            data,
+        });
+        Ok(())
+    }
+}
+
+impl<O> SqliteStore<O>
+where
+    O: DeserializeOwned,
+{
+    /// Recalls every live (not deleted) item matching the given condition
+    async fn recall_where(&self, condition: &str, params: Vec<String>) -> StorageResult<Vec<O>> {
+        self.select_where(
+            &format!("json_extract(data, '$.date_deleted') IS NULL AND {condition}"),
+            params,
+        )
+        .await
+    }
+
+    /// Recalls every item matching the given condition, whether it's deleted or not
+    async fn select_where(&self, condition: &str, params: Vec<String>) -> StorageResult<Vec<O>> {
+        let table = self.table;
+        let query = format!("SELECT data FROM {table} WHERE {condition}");
+        let rows = self
+            .connection
+            .run(move |connection| select_data(connection, &query, params));
+        deserialize_rows(table, rows.await?)
+    }
+}
+
+fn select_data(
+    connection: &Connection,
+    query: &str,
+    params: Vec<String>,
+) -> StorageResult<Vec<String>> {
+    let mut statement = connection.prepare(query)?;
+    let rows = statement.query_map(params_from_iter(params), |row| row.get::<_, String>(0))?;
+    Ok(rows.collect::<Result<_, _>>()?)
+}
+
+fn deserialize_rows<O: DeserializeOwned>(table: &str, rows: Vec<String>) -> StorageResult<Vec<O>> {
+    rows.iter()
+        .map(|data| serde_json::from_str(data).map_err(|e| StorageError::deserialize(table, e)))
+        .collect()
+}
+
+pub trait ScopedSqliteStoreFor
+where
+    Self: Sized,
+{
+    fn new_scoped(connection: SqliteConnection) -> StorageResult<Self>;
+}
+
+impl ScopedSqliteStoreFor for SqliteStore<Company> {
+    fn new_scoped(connection: SqliteConnection) -> StorageResult<Self> {
+        Self::new(connection, "company")
+    }
+}
+
+impl ScopedSqliteStoreFor for SqliteStore<Flag> {
+    fn new_scoped(connection: SqliteConnection) -> StorageResult<Self> {
+        Self::new(connection, "flag")?.with_index("company_id")
+    }
+}
+
+impl ScopedSqliteStoreFor for SqliteStore<Value> {
+    fn new_scoped(connection: SqliteConnection) -> StorageResult<Self> {
+        Self::new(connection, "value")?.with_index("company_id")
+    }
+}
+
+impl ScopedSqliteStoreFor for SqliteStore<Role> {
+    fn new_scoped(connection: SqliteConnection) -> StorageResult<Self> {
+        Self::new(connection, "role")?.with_index("company_id")
+    }
+}
+
+impl ScopedSqliteStoreFor for SqliteStore<Interview> {
+    fn new_scoped(connection: SqliteConnection) -> StorageResult<Self> {
+        Self::new(connection, "interview")?.with_index("role_id")
+    }
+}
+
+impl ScopedSqliteStoreFor for SqliteStore<Question> {
+    fn new_scoped(connection: SqliteConnection) -> StorageResult<Self> {
+        Self::new(connection, "question")?.with_index("role_id")
+    }
+}
+
+impl ScopedSqliteStoreFor for SqliteStore<Contact> {
+    fn new_scoped(connection: SqliteConnection) -> StorageResult<Self> {
+        Self::new(connection, "contact")?.with_index("company_id")
+    }
+}
+
+impl ScopedSqliteStoreFor for SqliteStore<Reminder> {
+    fn new_scoped(connection: SqliteConnection) -> StorageResult<Self> {
+        Self::new(connection, "reminder")?.with_index("company_id")
+    }
+}
+
+impl<O> BaseStore<O> for SqliteStore<O>
+where
+    O: HasId + SetTimestamps + HasSchemaVersion + Clone + Serialize + DeserializeOwned,
+{
+    async fn store(&mut self, mut storable: O) -> StorageResult<()> {
+        let table = self.table;
+        let id = storable.get_id().to_string();
+        let previous = self.select_where("id = ?1", vec![id.clone()]).await?.pop();
+        let revision = match prepare_store(&mut storable, previous.as_ref(), Timestamp::now())? {
+            StoreChange::Unchanged => return Ok(()),
+            StoreChange::Created => None,
+            StoreChange::Updated(revision) => {
+                Some(serde_json::to_string(&revision).map_err(StorageError::integrity)?)
+            }
+        };
+
+        let data = serde_json::to_string(&storable).map_err(StorageError::integrity)?;
+        self.connection
+            .run(move |connection| {
+                // The revision is only kept if the new version is too
+                let transaction = connection.unchecked_transaction()?;
+                if let Some(revision) = revision {
+                    insert_revision(&transaction, table, &id, &revision)?;
+                }
+                upsert(&transaction, table, &id, &data)?;
+                transaction.commit()?;
+                Ok(())
+            })
+            .await
+    }
+}
+
+impl<O> RecallById<O> for SqliteStore<O>
+where
+    O: HasId + HasDeleted + Clone + Serialize + DeserializeOwned,
+{
+    async fn recall_by_id<I: HasId>(&self, id: I) -> StorageResult<O> {
+        self.recall_where("id = ?1", vec![id.get_id().to_string()])
+            .await?
+            .pop()
+            .ok_or(StorageError::NotFound)
+    }
+}
+
+impl<T> RecallByName<T> for SqliteStore<T>
+where
+    T: HasName + HasDeleted + Clone + Serialize + DeserializeOwned,
+{
+    async fn recall_by_name<N: AsRef<str>>(&self, name: N) -> StorageResult<Vec<T>> {
+        // SQLite's lower() only handles ASCII so do the matching here to behave like the others
+        let search_string = name.as_ref().to_lowercase();
+        Ok(self
+            .recall_where("1", Vec::new())
+            .await?
+            .into_iter()
+            .filter(|item| item.get_name().to_lowercase().contains(&search_string))
+            .collect())
+    }
+}
+
+impl<T> RecallByCompany<T> for SqliteStore<T>
+where
+    T: HasCompany + HasDeleted + Clone + Serialize + DeserializeOwned,
+{
+    async fn recall_by_company<C: HasId>(&self, company: C) -> StorageResult<Vec<T>> {
+        self.recall_where(
+            "json_extract(data, '$.company_id') = ?1",
+            vec![company.get_id().to_string()],
+        )
+        .await
+    }
+}
+
+impl<T> RecallByRole<T> for SqliteStore<T>
+where
+    T: HasRole + HasDeleted + Clone + Serialize + DeserializeOwned,
+{
+    async fn recall_by_role<R: HasId>(&self, role: R) -> StorageResult<Vec<T>> {
+        self.recall_where(
+            "json_extract(data, '$.role_id') = ?1",
+            vec![role.get_id().to_string()],
+        )
+        .await
+    }
+}
+
+impl<T> RecallDeleted<T> for SqliteStore<T>
+where
+    T: HasId + HasDeleted + Clone + Serialize + DeserializeOwned,
+{
+    async fn recall_deleted(&self) -> StorageResult<Vec<T>> {
+        self.select_where(
+            "json_extract(data, '$.date_deleted') IS NOT NULL",
+            Vec::new(),
+        )
+        .await
+    }
+}
+
+impl<T> Purge<T> for SqliteStore<T>
+where
+    T: HasId + Clone + Serialize + DeserializeOwned,
+{
+    async fn purge(&mut self, storable: T) -> StorageResult<()> {
+        let table = self.table;
+        let id = storable.get_id().to_string();
+        self.connection
+            .run(move |connection| {
+                let is_deleted: bool = connection
+                    .query_row(
+                        &format!(
+                            "SELECT json_extract(data, '$.date_deleted') IS NOT NULL
+                                FROM {table} WHERE id = ?1"
+                        ),
+                        params![id],
+                        |row| row.get(0),
+                    )
+                    .optional()?
+                    .ok_or(StorageError::NotFound)?;
+                if !is_deleted {
+                    return Err(StorageError::NotDeleted);
+                }
+                // Nothing is left behind without the rest
+                let transaction = connection.unchecked_transaction()?;
+                transaction.execute(&format!("DELETE FROM {table} WHERE id = ?1"), params![id])?;
+                transaction.execute(
+                    &format!("DELETE FROM {table}_revision WHERE item_id = ?1"),
+                    params![id],
+                )?;
+                transaction.execute(
+                    &format!("DELETE FROM {table}_attachment WHERE item_id = ?1"),
+                    params![id],
+                )?;
+                transaction.commit()?;
+                Ok(())
+            })
+            .await
+    }
+}
+
+impl<T> Attachments<T> for SqliteStore<T>
+where
+    T: HasId + Clone,
+{
+    async fn attach<I: HasId>(
+        &mut self,
+        storable: I,
+        name: &str,
+        data: &[u8],
+    ) -> StorageResult<()> {
+        check_attachment_name(name)?;
+        let table = self.table;
+        let id = storable.get_id().to_string();
+        let (name, data) = (name.to_string(), data.to_vec());
+        self.connection
+            .run(move |connection| {
+                let exists = connection
+                    .query_row(
+                        &format!("SELECT 1 FROM {table} WHERE id = ?1"),
+                        params![id],
+                        |_| Ok(()),
+                    )
+                    .optional()?;
+                if exists.is_none() {
+                    return Err(StorageError::NotFound);
+                }
+                let inserted = connection.execute(
+                    &format!(
+                        "INSERT OR IGNORE INTO {table}_attachment (item_id, name, data)
+                            VALUES (?1, ?2, ?3)"
+                    ),
+                    params![id, name, data],
+                )?;
+                if inserted == 0 {
+                    return Err(StorageError::AlreadyExists);
+                }
+                Ok(())
+            })
+            .await
+    }
+
+    async fn recall_attachment<I: HasId>(&self, storable: I, name: &str) -> StorageResult<Vec<u8>> {
+        let table = self.table;
+        let (id, name) = (storable.get_id().to_string(), name.to_string());
+        self.connection
+            .run(move |connection| {
+                connection
+                    .query_row(
+                        &format!(
+                            "SELECT data FROM {table}_attachment WHERE item_id = ?1 AND name = ?2"
+                        ),
+                        params![id, name],
+                        |row| row.get(0),
+                    )
+                    .optional()?
+                    .ok_or(StorageError::NotFound)
+            })
+            .await
+    }
+}
+
+impl<T> RecallRevisions<T> for SqliteStore<T>
+where
+    T: HasId
+        + HasDeleted
+        + SetTimestamps
+        + HasSchemaVersion
+        + RestoreRevision
+        + Clone
+        + Serialize
+        + DeserializeOwned,
+{
+    async fn recall_revisions(&self, storable: &T) -> StorageResult<Vec<Revision>> {
+        let table = self.table;
+        let query = format!("SELECT data FROM {table}_revision WHERE item_id = ?1 ORDER BY rowid");
+        let params = vec![storable.get_id().to_string()];
+        let rows = self
+            .connection
+            .run(move |connection| select_data(connection, &query, params));
+        deserialize_rows(table, rows.await?)
+    }
+}
+
+impl<T> RecallByQuery<T> for SqliteStore<T>
+where
+    T: Queryable + Clone + Serialize + DeserializeOwned,
+{
+    async fn recall_by_query(&self, query: &Query) -> StorageResult<Vec<T>> {
+        // Narrow things down with the indexed fields, the rest is done the same way as the others
+        let mut conditions = vec!["1".to_string()];
+        let mut parameters = Vec::new();
+        if !query.include_deleted {
+            conditions.push("json_extract(data, '$.date_deleted') IS NULL".to_string());
+        }
+        if let Some(company_id) = query.company_id {
+            parameters.push(company_id.to_string());
+            conditions.push(format!(
+                "json_extract(data, '$.company_id') = ?{}",
+                parameters.len()
+            ));
+        }
+        if let Some(role_id) = query.role_id {
+            parameters.push(role_id.to_string());
+            conditions.push(format!(
+                "json_extract(data, '$.role_id') = ?{}",
+                parameters.len()
+            ));
+        }
+
+        let candidates: Vec<T> = self
+            .select_where(&conditions.join(" AND "), parameters)
+            .await?;
+        Ok(query.apply(&candidates))
+    }
+}
+
+/// How an item was stored in a [`SqliteStore`], see [`Rollback`]
+#[derive(Clone)]
+pub struct SqliteSnapshot {
+    id: Uuid,
+    data: Option<String>,
+    revision_count: i64,
+}
+
+impl<T> Rollback<T> for SqliteStore<T>
+where
+    T: HasId + Clone,
+{
+    type Snapshot = SqliteSnapshot;
+
+    async fn snapshot(&self, id: Uuid) -> StorageResult<SqliteSnapshot> {
+        let table = self.table;
+        self.connection
+            .run(move |connection| {
+                let data = connection
+                    .query_row(
+                        &format!("SELECT data FROM {table} WHERE id = ?1"),
+                        params![id.to_string()],
+                        |row| row.get(0),
+                    )
+                    .optional()?;
+                let revision_count = connection.query_row(
+                    &format!("SELECT COUNT(*) FROM {table}_revision WHERE item_id = ?1"),
+                    params![id.to_string()],
+                    |row| row.get(0),
+                )?;
+                Ok(SqliteSnapshot {
+                    id,
+                    data,
+                    revision_count,
+                })
+            })
+            .await
+    }
+
+    async fn roll_back(&mut self, snapshot: SqliteSnapshot) -> StorageResult<()> {
+        let table = self.table;
+        let id = snapshot.id.to_string();
+        self.connection
+            .run(move |connection| {
+                let transaction = connection.unchecked_transaction()?;
+                match snapshot.data {
+                    Some(data) => upsert(&transaction, table, &id, &data)?,
+                    None => {
+                        transaction
+                            .execute(&format!("DELETE FROM {table} WHERE id = ?1"), params![id])?;
+                    }
+                };
+                transaction.execute(
+                    &format!(
+                        "DELETE FROM {table}_revision WHERE item_id = ?1 AND rowid NOT IN (
+                            SELECT rowid FROM {table}_revision WHERE item_id = ?1
+                                ORDER BY rowid LIMIT ?2
+                        )"
+                    ),
+                    params![id, snapshot.revision_count],
+                )?;
+                transaction.commit()?;
+                Ok(())
+            })
+            .await
+    }
+}
+
+impl CompanyStore for SqliteStore<Company> {}
+impl RoleStore for SqliteStore<Role> {}
+impl FlagStore for SqliteStore<Flag> {}
+impl QuestionStore for SqliteStore<Question> {}
+impl InterviewStore for SqliteStore<Interview> {}
+impl ValueStore for SqliteStore<Value> {}
+impl ContactStore for SqliteStore<Contact> {}
+impl ReminderStore for SqliteStore<Reminder> {}
+
+#[cfg(test)]
+mod test_helper {
+    use super::*;
+    use crate::test_helper::TestHelper;
+
+    #[cfg(test)]
+    impl<O> TestHelper for SqliteStore<O>
+    where
+        O: HasId + Clone + Serialize + DeserializeOwned,
+    {
+        #[cfg(test)]
+        async fn new_test() -> anyhow::Result<Self> {
+            Ok(Self::new(SqliteConnection::open_in_memory()?, "storable")?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{
+        attachments::test_helper::test_attachments, purge::test_helper::test_purge,
+        recall_by_company::test_helper::test_recall_by_company,
+        recall_by_id::test_helper::test_recall_by_id,
+        recall_by_name::test_helper::test_recall_by_name,
+        recall_by_query::test_helper::test_recall_by_query,
+        recall_by_role::test_helper::test_recall_by_role,
+        recall_deleted::test_helper::test_recall_deleted,
+        recall_revisions::test_helper::test_recall_revisions, rollback::test_helper::test_rollback,
+    };
+    use crate::test_helper::*;
+    use crate::Timestamp;
+    use paste::paste;
+
+    test_recall_by_id!(SqliteStore, Company);
+    test_recall_by_id!(SqliteStore, Flag);
+    test_recall_by_id!(SqliteStore, Role);
+    test_recall_by_id!(SqliteStore, Value);
+    test_recall_by_id!(SqliteStore, Question);
+    test_recall_by_id!(SqliteStore, Interview);
+    test_recall_by_id!(SqliteStore, Contact);
+    test_recall_by_id!(SqliteStore, Reminder);
+    test_recall_by_name!(SqliteStore, Company);
+    test_recall_by_name!(SqliteStore, Flag);
+    test_recall_by_name!(SqliteStore, Role);
+    test_recall_by_name!(SqliteStore, Value);
+    test_recall_by_name!(SqliteStore, Question);
+    test_recall_by_name!(SqliteStore, Interview);
+    test_recall_by_name!(SqliteStore, Contact);
+    test_recall_by_name!(SqliteStore, Reminder);
+    test_recall_by_company!(SqliteStore, Flag);
+    test_recall_by_company!(SqliteStore, Role);
+    test_recall_by_company!(SqliteStore, Value);
+    test_recall_by_company!(SqliteStore, Contact);
+    test_recall_by_company!(SqliteStore, Reminder);
+    test_recall_by_role!(SqliteStore, Question);
+    test_recall_by_role!(SqliteStore, Interview);
+    test_recall_deleted!(SqliteStore, Company);
+    test_recall_deleted!(SqliteStore, Flag);
+    test_recall_deleted!(SqliteStore, Role);
+    test_recall_deleted!(SqliteStore, Value);
+    test_recall_deleted!(SqliteStore, Question);
+    test_recall_deleted!(SqliteStore, Interview);
+    test_recall_deleted!(SqliteStore, Contact);
+    test_recall_deleted!(SqliteStore, Reminder);
+    test_purge!(SqliteStore, Company);
+    test_purge!(SqliteStore, Flag);
+    test_purge!(SqliteStore, Role);
+    test_purge!(SqliteStore, Value);
+    test_purge!(SqliteStore, Question);
+    test_purge!(SqliteStore, Interview);
+    test_purge!(SqliteStore, Contact);
+    test_purge!(SqliteStore, Reminder);
+    test_recall_revisions!(SqliteStore, Company);
+    test_recall_revisions!(SqliteStore, Flag);
+    test_recall_revisions!(SqliteStore, Role);
+    test_recall_revisions!(SqliteStore, Value);
+    test_recall_revisions!(SqliteStore, Question);
+    test_recall_revisions!(SqliteStore, Interview);
+    test_recall_revisions!(SqliteStore, Contact);
+    test_recall_revisions!(SqliteStore, Reminder);
+    test_recall_by_query!(SqliteStore, Company);
+    test_recall_by_query!(SqliteStore, Flag);
+    test_recall_by_query!(SqliteStore, Role);
+    test_recall_by_query!(SqliteStore, Value);
+    test_recall_by_query!(SqliteStore, Question);
+    test_recall_by_query!(SqliteStore, Interview);
+    test_recall_by_query!(SqliteStore, Contact);
+    test_recall_by_query!(SqliteStore, Reminder);
+    test_rollback!(SqliteStore, Company);
+    test_rollback!(SqliteStore, Flag);
+    test_rollback!(SqliteStore, Role);
+    test_rollback!(SqliteStore, Value);
+    test_rollback!(SqliteStore, Question);
+    test_rollback!(SqliteStore, Interview);
+    test_rollback!(SqliteStore, Contact);
+    test_rollback!(SqliteStore, Reminder);
+    test_attachments!(SqliteStore, Role);
+
+    #[tokio::test]
+    async fn test_load_from_file() {
+        let path = tempfile::tempdir().unwrap().keep().join("storage.sqlite");
+
+        let company = Company::new("company");
+
+        let mut initial_store =
+            SqliteStore::<Company>::new_scoped(SqliteConnection::open(&path).unwrap()).unwrap();
+        initial_store.store(company.clone()).await.unwrap();
+        drop(initial_store);
+
+        let loaded_store =
+            SqliteStore::<Company>::new_scoped(SqliteConnection::open(&path).unwrap()).unwrap();
+        let recalled_company = loaded_store.recall_by_id(&company.get_id()).await.unwrap();
+
+        assert_eq!(without_timestamps(recalled_company), company);
+    }
+
+    #[tokio::test]
+    async fn test_store_replaces_existing() {
+        let connection = SqliteConnection::open_in_memory().unwrap();
+        let mut store = SqliteStore::<Role>::new_scoped(connection).unwrap();
+
+        let date_applied = Timestamp::from_string("2025-07-28T09:30");
+        let mut role = Role::new(uuid::Uuid::new_v4(), "role", date_applied);
+        store.store(role.clone()).await.unwrap();
+        role.name = "renamed".to_string();
+        store.store(role.clone()).await.unwrap();
+
+        let roles: Vec<Role> = store.recall_by_company(role.company_id).await.unwrap();
+        assert_eq!(all_without_timestamps(roles), vec![role]);
+    }
+
+    #[tokio::test]
+    async fn test_stores_share_connection() {
+        let connection = SqliteConnection::open_in_memory().unwrap();
+        let mut company_store = SqliteStore::<Company>::new_scoped(connection.clone()).unwrap();
+        let mut flag_store = SqliteStore::<Flag>::new_scoped(connection).unwrap();
+
+        let company = Company::new("company");
+        let flag = company.create_green_flag("flag");
+        company_store.store(company.clone()).await.unwrap();
+        flag_store.store(flag.clone()).await.unwrap();
+
+        // Tables are separate so an id from one store isn't found in the other
+        let result: StorageResult<Flag> = flag_store.recall_by_id(company.id).await;
+        assert!(result.is_err());
+        assert_eq!(
+            without_timestamps(flag_store.recall_by_id(flag.id).await.unwrap()),
+            flag
+        );
+    }
+
+    #[tokio::test]
+    async fn test_metadata() {
+        let connection = SqliteConnection::open_in_memory().unwrap();
+        assert_eq!(connection.get_metadata("key").await.unwrap(), None);
+        let empty = SqliteImport::default;
+        connection.import(empty(), "key", "value").await.unwrap();
+        connection
+            .import(empty(), "key", "new value")
+            .await
+            .unwrap();
+        assert_eq!(
+            connection.get_metadata("key").await.unwrap(),
+            Some("new value".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_store_keeps_no_revision_when_the_update_fails() {
+        let connection = SqliteConnection::open_in_memory().unwrap();
+        let mut store = SqliteStore::<Company>::new_scoped(connection.clone()).unwrap();
+        let mut company = Company::new("company");
+        store.store(company.clone()).await.unwrap();
+
+        connection
+            .lock()
+            .unwrap()
+            .execute_batch(
+                "CREATE TRIGGER no_updates BEFORE UPDATE ON company
+                    BEGIN SELECT RAISE(ABORT, 'no updates'); END;",
+            )
+            .unwrap();
+        company.name = "renamed".to_string();
+        assert!(store.store(company.clone()).await.is_err());
+
+        assert!(store.recall_revisions(&company).await.unwrap().is_empty());
+        let recalled = store.recall_by_id(&company).await.unwrap();
+        assert_eq!(recalled.name, "company");
+    }
+
+    #[tokio::test]
+    async fn test_purge_is_all_or_nothing() {
+        let connection = SqliteConnection::open_in_memory().unwrap();
+        let mut store = SqliteStore::<Company>::new_scoped(connection.clone()).unwrap();
+        let mut company = Company::new("company");
+        store.store(company.clone()).await.unwrap();
+        company.date_deleted = Some(Timestamp::now());
+        store.store(company.clone()).await.unwrap();
+        store.attach(&company, "notes.txt", b"notes").await.unwrap();
+
+        connection
+            .lock()
+            .unwrap()
+            .execute_batch(
+                "CREATE TRIGGER no_deletes BEFORE DELETE ON company_attachment
+                    BEGIN SELECT RAISE(ABORT, 'no deletes'); END;",
+            )
+            .unwrap();
+        assert!(store.purge(company.clone()).await.is_err());
+
+        let deleted: Vec<Company> = store.recall_deleted().await.unwrap();
+        assert_eq!(deleted.len(), 1);
+        assert_eq!(store.recall_revisions(&company).await.unwrap().len(), 1);
+        assert_eq!(
+            store
+                .recall_attachment(&company, "notes.txt")
+                .await
+                .unwrap(),
+            b"notes"
+        );
+    }
+}
@@ -1,30 +1,187 @@
 use crate::storable::*;
 use crate::storage::*;
-use crate::StorageError;
+use crate::{Query, Revision, StorageError, StorageResult, Timestamp};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use uuid::Uuid;
 
+/// Items in the order they were last stored, keyed by when that was
+type Ordered = BTreeMap<u64, Uuid>;
+
+/// Keeps everything in memory, indexed by id and by the company or role each item belongs to.
+///
+/// Items are shared rather than copied, so recalling through [`RecallById::recall_shared_by_id`]
+/// and friends is cheap. The other recall methods still hand out owned copies of whatever matched.
 #[derive(Clone)]
 pub struct StubStore<T> {
-    store: Vec<T>,
+    items: HashMap<Uuid, (u64, Arc<T>)>,
+    order: Ordered,
+    by_company: HashMap<Uuid, Ordered>,
+    by_role: HashMap<Uuid, Ordered>,
+    next_order: u64,
+    revisions: HashMap<Uuid, Vec<Revision>>,
+    attachments: HashMap<Uuid, HashMap<String, Arc<[u8]>>>,
 }
 
 impl<T> Default for StubStore<T> {
     fn default() -> Self {
-        StubStore { store: Vec::new() }
+        StubStore {
+            items: HashMap::new(),
+            order: BTreeMap::new(),
+            by_company: HashMap::new(),
+            by_role: HashMap::new(),
+            next_order: 0,
+            revisions: HashMap::new(),
+            attachments: HashMap::new(),
+        }
     }
 }
 
-impl<O> BaseStore<O> for StubStore<O>
+impl<T> StubStore<T> {
+    /// Every stored item, including those that have been deleted
+    pub(crate) fn items(&self) -> impl Iterator<Item = &T> {
+        self.shared_in_order(&self.order).map(Arc::as_ref)
+    }
+
+    fn shared_in_order<'a>(&'a self, ordered: &'a Ordered) -> impl Iterator<Item = &'a Arc<T>> {
+        ordered
+            .values()
+            .filter_map(|id| self.items.get(id))
+            .map(|(_, item)| item)
+    }
+
+    fn live_in_order<'a>(&'a self, ordered: Option<&'a Ordered>) -> Vec<Arc<T>>
+    where
+        T: HasDeleted,
+    {
+        ordered
+            .map(|ordered| {
+                self.shared_in_order(ordered)
+                    .filter(|item| !item.is_deleted())
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl<T> StubStore<T>
 where
-    O: HasId + Clone,
+    T: HasId + HasDeleted,
 {
-    async fn store(&mut self, storable: O) -> anyhow::Result<()> {
-        // Remove the item if its already stored
-        self.store
-            .retain(|stored_item| storable.get_id() != stored_item.get_id());
+    /// The stored item with the same id, unless it has been deleted
+    pub fn get_shared<I: HasId>(&self, id: I) -> Option<Arc<T>> {
+        self.items
+            .get(&id.get_id())
+            .map(|(_, item)| item)
+            .filter(|item| !item.is_deleted())
+            .cloned()
+    }
+
+    /// Everything belonging to the company that hasn't been deleted
+    pub fn get_shared_by_company<I: HasId>(&self, company: I) -> Vec<Arc<T>> {
+        self.live_in_order(self.by_company.get(&company.get_id()))
+    }
+
+    /// Everything belonging to the role that hasn't been deleted
+    pub fn get_shared_by_role<I: HasId>(&self, role: I) -> Vec<Arc<T>> {
+        self.live_in_order(self.by_role.get(&role.get_id()))
+    }
+}
+
+impl<T> StubStore<T>
+where
+    T: HasId,
+{
+    /// The stored item with the same id, even if it has been deleted
+    pub(crate) fn get<I: HasId>(&self, id: I) -> Option<&T> {
+        self.items
+            .get(&id.get_id())
+            .map(|(_, stored_item)| stored_item.as_ref())
+    }
+}
+
+impl<T> StubStore<T>
+where
+    T: Queryable,
+{
+    /// Stores an item as it is, without stamping it or recording a revision
+    pub(crate) fn replace(&mut self, storable: T) {
+        let order = self.next_order;
+        self.next_order += 1;
+        self.insert(order, Arc::new(storable));
+    }
+
+    /// Stores an item at a particular place in the order, replacing any earlier version of it
+    fn insert(&mut self, order: u64, storable: Arc<T>) {
+        let id = storable.get_id();
+        self.remove(id);
 
-        // Store the new item
-        self.store.push(storable);
+        self.order.insert(order, id);
+        if let Some(company_id) = storable.get_parent_company_id() {
+            self.by_company
+                .entry(company_id)
+                .or_default()
+                .insert(order, id);
+        }
+        if let Some(role_id) = storable.get_parent_role_id() {
+            self.by_role.entry(role_id).or_default().insert(order, id);
+        }
+        self.items.insert(id, (order, storable));
+    }
+
+    /// Removes the item along with everything that indexes it, returns false if it wasn't stored
+    fn remove(&mut self, id: Uuid) -> bool {
+        let Some((order, stored_item)) = self.items.remove(&id) else {
+            return false;
+        };
+        self.order.remove(&order);
+        if let Some(company_id) = stored_item.get_parent_company_id() {
+            remove_from_index(&mut self.by_company, company_id, order);
+        }
+        if let Some(role_id) = stored_item.get_parent_role_id() {
+            remove_from_index(&mut self.by_role, role_id, order);
+        }
+        true
+    }
+}
 
+/// How an item was stored in a [`StubStore`], see [`Rollback`]
+#[derive(Clone)]
+pub struct StubSnapshot<T> {
+    pub(crate) id: Uuid,
+    pub(crate) stored: Option<(u64, Arc<T>)>,
+    revision_count: usize,
+}
+
+fn remove_from_index(index: &mut HashMap<Uuid, Ordered>, parent_id: Uuid, order: u64) {
+    if let Some(ordered) = index.get_mut(&parent_id) {
+        ordered.remove(&order);
+        if ordered.is_empty() {
+            index.remove(&parent_id);
+        }
+    }
+}
+
+impl<O> BaseStore<O> for StubStore<O>
+where
+    O: Queryable + SetTimestamps + HasSchemaVersion + Clone + Serialize,
+{
+    async fn store(&mut self, mut storable: O) -> StorageResult<()> {
+        let previous = self.get(storable.get_id());
+        match prepare_store(&mut storable, previous, Timestamp::now())? {
+            StoreChange::Unchanged => return Ok(()),
+            StoreChange::Created => {}
+            StoreChange::Updated(revision) => {
+                self.revisions
+                    .entry(storable.get_id())
+                    .or_default()
+                    .push(revision);
+            }
+        }
+        self.replace(storable);
         Ok(())
     }
 }
@@ -33,14 +190,14 @@ impl<O> RecallById<O> for StubStore<O>
 where
     O: HasId + HasDeleted + Clone,
 {
-    async fn recall_by_id<I: HasId>(&self, id: I) -> anyhow::Result<O> {
-        Ok(self
-            .store
-            .iter()
-            .filter(|item| !item.is_deleted())
-            .find(|stored_item| id.get_id() == stored_item.get_id())
-            .cloned()
-            .ok_or(StorageError::NotFound)?)
+    async fn recall_by_id<I: HasId>(&self, id: I) -> StorageResult<O> {
+        self.get_shared(id)
+            .map(|item| O::clone(&item))
+            .ok_or(StorageError::NotFound)
+    }
+
+    async fn recall_shared_by_id<I: HasId>(&self, id: I) -> StorageResult<Arc<O>> {
+        self.get_shared(id).ok_or(StorageError::NotFound)
     }
 }
 
@@ -48,11 +205,10 @@ impl<T> RecallByName<T> for StubStore<T>
 where
     T: HasName + HasDeleted + Clone,
 {
-    async fn recall_by_name<N: AsRef<str>>(&self, name: N) -> anyhow::Result<Vec<T>> {
+    async fn recall_by_name<N: AsRef<str>>(&self, name: N) -> StorageResult<Vec<T>> {
         let search_string = name.as_ref().to_lowercase();
         Ok(self
-            .store
-            .iter()
+            .items()
             .filter(|stored_item| {
                 stored_item
                     .get_name()
@@ -67,40 +223,187 @@ where
 
 impl<T> RecallByCompany<T> for StubStore<T>
 where
-    T: HasCompany + HasDeleted + Clone,
+    T: HasCompany + HasId + HasDeleted + Clone,
 {
-    async fn recall_by_company<C: HasId>(&self, company: C) -> anyhow::Result<Vec<T>> {
+    async fn recall_by_company<C: HasId>(&self, company: C) -> StorageResult<Vec<T>> {
         Ok(self
-            .store
+            .get_shared_by_company(company)
             .iter()
-            .filter(|stored_item| stored_item.get_company_id() == company.get_id())
-            .filter(|item| !item.is_deleted())
-            .cloned()
+            .map(|item| T::clone(item))
             .collect())
     }
+
+    async fn recall_shared_by_company<C: HasId>(&self, company: C) -> StorageResult<Vec<Arc<T>>> {
+        Ok(self.get_shared_by_company(company))
+    }
 }
 
 impl<T> RecallByRole<T> for StubStore<T>
 where
-    T: HasRole + HasDeleted + Clone,
+    T: HasRole + HasId + HasDeleted + Clone,
 {
-    async fn recall_by_role<I: HasId>(&self, role: I) -> anyhow::Result<Vec<T>> {
+    async fn recall_by_role<I: HasId>(&self, role: I) -> StorageResult<Vec<T>> {
         Ok(self
-            .store
+            .get_shared_by_role(role)
             .iter()
-            .filter(|stored_item| stored_item.get_role_id() == role.get_id())
-            .filter(|item| !item.is_deleted())
+            .map(|item| T::clone(item))
+            .collect())
+    }
+
+    async fn recall_shared_by_role<I: HasId>(&self, role: I) -> StorageResult<Vec<Arc<T>>> {
+        Ok(self.get_shared_by_role(role))
+    }
+}
+
+impl<T> RecallDeleted<T> for StubStore<T>
+where
+    T: HasId + HasDeleted + Clone,
+{
+    async fn recall_deleted(&self) -> StorageResult<Vec<T>> {
+        Ok(self
+            .items()
+            .filter(|item| item.is_deleted())
             .cloned()
             .collect())
     }
 }
 
+impl<T> Purge<T> for StubStore<T>
+where
+    T: Queryable + Clone,
+{
+    async fn purge(&mut self, storable: T) -> StorageResult<()> {
+        match self.get(&storable) {
+            None => return Err(StorageError::NotFound),
+            Some(stored) if !stored.is_deleted() => return Err(StorageError::NotDeleted),
+            Some(_) => {}
+        }
+        self.remove(storable.get_id());
+        self.revisions.remove(&storable.get_id());
+        self.attachments.remove(&storable.get_id());
+        Ok(())
+    }
+}
+
+impl<T> Attachments<T> for StubStore<T>
+where
+    T: HasId + Clone,
+{
+    async fn attach<I: HasId>(
+        &mut self,
+        storable: I,
+        name: &str,
+        data: &[u8],
+    ) -> StorageResult<()> {
+        check_attachment_name(name)?;
+        let id = storable.get_id();
+        if !self.items.contains_key(&id) {
+            return Err(StorageError::NotFound);
+        }
+        let attachments = self.attachments.entry(id).or_default();
+        if attachments.contains_key(name) {
+            return Err(StorageError::AlreadyExists);
+        }
+        attachments.insert(name.to_string(), Arc::from(data));
+        Ok(())
+    }
+
+    async fn recall_attachment<I: HasId>(&self, storable: I, name: &str) -> StorageResult<Vec<u8>> {
+        self.attachments
+            .get(&storable.get_id())
+            .and_then(|attachments| attachments.get(name))
+            .map(|data| data.to_vec())
+            .ok_or(StorageError::NotFound)
+    }
+}
+
+impl<T> RecallRevisions<T> for StubStore<T>
+where
+    T: Queryable
+        + SetTimestamps
+        + HasSchemaVersion
+        + RestoreRevision
+        + Clone
+        + Serialize
+        + DeserializeOwned,
+{
+    async fn recall_revisions(&self, storable: &T) -> StorageResult<Vec<Revision>> {
+        Ok(self
+            .revisions
+            .get(&storable.get_id())
+            .cloned()
+            .unwrap_or_default())
+    }
+}
+
+impl<T> RecallByQuery<T> for StubStore<T>
+where
+    T: Queryable + Clone,
+{
+    async fn recall_by_query(&self, query: &Query) -> StorageResult<Vec<T>> {
+        // The parent indexes narrow things down before the rest of the query is checked
+        let candidates: Vec<&T> = match (query.company_id, query.role_id) {
+            (Some(company_id), _) => self
+                .by_company
+                .get(&company_id)
+                .map(|ordered| self.shared_in_order(ordered).map(Arc::as_ref).collect())
+                .unwrap_or_default(),
+            (None, Some(role_id)) => self
+                .by_role
+                .get(&role_id)
+                .map(|ordered| self.shared_in_order(ordered).map(Arc::as_ref).collect())
+                .unwrap_or_default(),
+            (None, None) => self.items().collect(),
+        };
+        Ok(query.apply(candidates))
+    }
+}
+
+impl<T> Rollback<T> for StubStore<T>
+where
+    T: Queryable + Clone,
+{
+    type Snapshot = StubSnapshot<T>;
+
+    async fn snapshot(&self, id: Uuid) -> StorageResult<StubSnapshot<T>> {
+        Ok(StubSnapshot {
+            id,
+            stored: self.items.get(&id).cloned(),
+            revision_count: self.revisions.get(&id).map_or(0, Vec::len),
+        })
+    }
+
+    async fn roll_back(&mut self, snapshot: StubSnapshot<T>) -> StorageResult<()> {
+        let StubSnapshot {
+            id,
+            stored,
+            revision_count,
+        } = snapshot;
+        // Put back where it was, rather than at the end as if it had just been stored
+        match stored {
+            Some((order, stored_item)) => self.insert(order, stored_item),
+            None => {
+                self.remove(id);
+            }
+        }
+        if let Some(revisions) = self.revisions.get_mut(&id) {
+            revisions.truncate(revision_count);
+            if revisions.is_empty() {
+                self.revisions.remove(&id);
+            }
+        }
+        Ok(())
+    }
+}
+
 impl CompanyStore for StubStore<Company> {}
 impl RoleStore for StubStore<Role> {}
 impl FlagStore for StubStore<Flag> {}
 impl QuestionStore for StubStore<Question> {}
 impl InterviewStore for StubStore<Interview> {}
 impl ValueStore for StubStore<Value> {}
+impl ContactStore for StubStore<Contact> {}
+impl ReminderStore for StubStore<Reminder> {}
 
 #[cfg(test)]
 mod test_helper {
@@ -123,10 +426,14 @@ mod test_helper {
 mod tests {
     use super::*;
     use crate::storage::{
+        attachments::test_helper::test_attachments, purge::test_helper::test_purge,
         recall_by_company::test_helper::test_recall_by_company,
         recall_by_id::test_helper::test_recall_by_id,
         recall_by_name::test_helper::test_recall_by_name,
+        recall_by_query::test_helper::test_recall_by_query,
         recall_by_role::test_helper::test_recall_by_role,
+        recall_deleted::test_helper::test_recall_deleted,
+        recall_revisions::test_helper::test_recall_revisions, rollback::test_helper::test_rollback,
     };
     use crate::test_helper::*;
     use paste::paste;
@@ -137,15 +444,119 @@ mod tests {
     test_recall_by_id!(StubStore, Value);
     test_recall_by_id!(StubStore, Question);
     test_recall_by_id!(StubStore, Interview);
+    test_recall_by_id!(StubStore, Contact);
+    test_recall_by_id!(StubStore, Reminder);
     test_recall_by_name!(StubStore, Company);
     test_recall_by_name!(StubStore, Flag);
     test_recall_by_name!(StubStore, Role);
     test_recall_by_name!(StubStore, Value);
     test_recall_by_name!(StubStore, Question);
     test_recall_by_name!(StubStore, Interview);
+    test_recall_by_name!(StubStore, Contact);
+    test_recall_by_name!(StubStore, Reminder);
     test_recall_by_company!(StubStore, Flag);
     test_recall_by_company!(StubStore, Role);
     test_recall_by_company!(StubStore, Value);
+    test_recall_by_company!(StubStore, Contact);
+    test_recall_by_company!(StubStore, Reminder);
     test_recall_by_role!(StubStore, Question);
     test_recall_by_role!(StubStore, Interview);
+    test_recall_deleted!(StubStore, Company);
+    test_recall_deleted!(StubStore, Flag);
+    test_recall_deleted!(StubStore, Role);
+    test_recall_deleted!(StubStore, Value);
+    test_recall_deleted!(StubStore, Question);
+    test_recall_deleted!(StubStore, Interview);
+    test_recall_deleted!(StubStore, Contact);
+    test_recall_deleted!(StubStore, Reminder);
+    test_purge!(StubStore, Company);
+    test_purge!(StubStore, Flag);
+    test_purge!(StubStore, Role);
+    test_purge!(StubStore, Value);
+    test_purge!(StubStore, Question);
+    test_purge!(StubStore, Interview);
+    test_purge!(StubStore, Contact);
+    test_purge!(StubStore, Reminder);
+    test_recall_revisions!(StubStore, Company);
+    test_recall_revisions!(StubStore, Flag);
+    test_recall_revisions!(StubStore, Role);
+    test_recall_revisions!(StubStore, Value);
+    test_recall_revisions!(StubStore, Question);
+    test_recall_revisions!(StubStore, Interview);
+    test_recall_revisions!(StubStore, Contact);
+    test_recall_revisions!(StubStore, Reminder);
+    test_recall_by_query!(StubStore, Company);
+    test_recall_by_query!(StubStore, Flag);
+    test_recall_by_query!(StubStore, Role);
+    test_recall_by_query!(StubStore, Value);
+    test_recall_by_query!(StubStore, Question);
+    test_recall_by_query!(StubStore, Interview);
+    test_recall_by_query!(StubStore, Contact);
+    test_recall_by_query!(StubStore, Reminder);
+    test_rollback!(StubStore, Company);
+    test_rollback!(StubStore, Flag);
+    test_rollback!(StubStore, Role);
+    test_rollback!(StubStore, Value);
+    test_rollback!(StubStore, Question);
+    test_rollback!(StubStore, Interview);
+    test_rollback!(StubStore, Contact);
+    test_rollback!(StubStore, Reminder);
+    test_attachments!(StubStore, Role);
+
+    #[tokio::test]
+    async fn test_indexes_follow_changes() {
+        let mut store = StubStore::default();
+        let first_role = Uuid::new_v4();
+        let second_role = Uuid::new_v4();
+        let mut question = Question::new(first_role, "question", "answer");
+        store.store(question.clone()).await.unwrap();
+        assert_eq!(store.get_shared_by_role(first_role).len(), 1);
+
+        // Moving it to another role moves it in the index
+        question.role_id = second_role;
+        store.store(question.clone()).await.unwrap();
+        assert!(store.get_shared_by_role(first_role).is_empty());
+        assert_eq!(store.get_shared_by_role(second_role).len(), 1);
+        assert!(!store.by_role.contains_key(&first_role));
+
+        question.date_deleted = Some(Timestamp::now());
+        store.store(question.clone()).await.unwrap();
+        store.purge(question).await.unwrap();
+        assert!(store.get_shared_by_role(second_role).is_empty());
+        assert!(store.by_role.is_empty());
+        assert!(store.order.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_items_keep_the_order_they_were_stored_in() {
+        let mut store = StubStore::default();
+        let company_id = Uuid::new_v4();
+        let mut first = Flag::new_green(company_id, "first");
+        let second = Flag::new_red(company_id, "second");
+        store.store(first.clone()).await.unwrap();
+        store.store(second.clone()).await.unwrap();
+
+        // Storing it again moves it to the end
+        first.name = "first again".to_string();
+        store.store(first).await.unwrap();
+
+        let flags: Vec<Flag> = store.recall_by_company(company_id).await.unwrap();
+        let names: Vec<_> = flags.iter().map(|flag| flag.name.as_str()).collect();
+        assert_eq!(names, vec!["second", "first again"]);
+    }
+
+    #[tokio::test]
+    async fn test_recalled_items_are_shared() {
+        let mut store = StubStore::default();
+        let company = Company::new("company");
+        store.store(company.clone()).await.unwrap();
+
+        let first = store.get_shared(&company).unwrap();
+        let second = store.get_shared(&company).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+
+        // As they are through the recall traits
+        let recalled = store.recall_shared_by_id(&company).await.unwrap();
+        assert!(Arc::ptr_eq(&first, &recalled));
+    }
 }
@@ -1,4 +1,17 @@
 use crate::storable::Company;
-use crate::storage::{BaseStore, RecallById, RecallByName};
+use crate::storage::{
+    BaseStore, Purge, RecallById, RecallByName, RecallByQuery, RecallDeleted, RecallRevisions,
+    Rollback,
+};
 
-pub trait CompanyStore: BaseStore<Company> + RecallById<Company> + RecallByName<Company> {}
+pub trait CompanyStore:
+    BaseStore<Company>
+    + RecallById<Company>
+    + RecallByName<Company>
+    + RecallDeleted<Company>
+    + Purge<Company>
+    + RecallRevisions<Company>
+    + RecallByQuery<Company>
+    + Rollback<Company>
+{
+}
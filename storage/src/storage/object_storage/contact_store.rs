@@ -0,0 +1,15 @@
+use crate::storable::Contact;
+use crate::storage::*;
+
+pub trait ContactStore:
+    BaseStore<Contact>
+    + RecallById<Contact>
+    + RecallByName<Contact>
+    + RecallByCompany<Contact>
+    + RecallDeleted<Contact>
+    + Purge<Contact>
+    + RecallRevisions<Contact>
+    + RecallByQuery<Contact>
+    + Rollback<Contact>
+{
+}
@@ -2,6 +2,14 @@ use crate::storable::Flag;
 use crate::storage::*;
 
 pub trait FlagStore:
-    BaseStore<Flag> + RecallById<Flag> + RecallByName<Flag> + RecallByCompany<Flag>
+    BaseStore<Flag>
+    + RecallById<Flag>
+    + RecallByName<Flag>
+    + RecallByCompany<Flag>
+    + RecallDeleted<Flag>
+    + Purge<Flag>
+    + RecallRevisions<Flag>
+    + RecallByQuery<Flag>
+    + Rollback<Flag>
 {
 }
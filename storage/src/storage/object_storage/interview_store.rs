@@ -2,6 +2,14 @@ use crate::storable::Interview;
 use crate::storage::*;
 
 pub trait InterviewStore:
-    BaseStore<Interview> + RecallById<Interview> + RecallByName<Interview> + RecallByRole<Interview>
+    BaseStore<Interview>
+    + RecallById<Interview>
+    + RecallByName<Interview>
+    + RecallByRole<Interview>
+    + RecallDeleted<Interview>
+    + Purge<Interview>
+    + RecallRevisions<Interview>
+    + RecallByQuery<Interview>
+    + Rollback<Interview>
 {
 }
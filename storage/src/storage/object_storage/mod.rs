@@ -15,3 +15,9 @@ pub use question_store::*;
 
 mod value_store;
 pub use value_store::*;
+
+mod contact_store;
+pub use contact_store::*;
+
+mod reminder_store;
+pub use reminder_store::*;
@@ -2,6 +2,14 @@ use crate::storable::Question;
 use crate::storage::*;
 
 pub trait QuestionStore:
-    BaseStore<Question> + RecallById<Question> + RecallByName<Question> + RecallByRole<Question>
+    BaseStore<Question>
+    + RecallById<Question>
+    + RecallByName<Question>
+    + RecallByRole<Question>
+    + RecallDeleted<Question>
+    + Purge<Question>
+    + RecallRevisions<Question>
+    + RecallByQuery<Question>
+    + Rollback<Question>
 {
 }
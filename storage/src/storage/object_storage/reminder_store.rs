@@ -0,0 +1,15 @@
+use crate::storable::Reminder;
+use crate::storage::*;
+
+pub trait ReminderStore:
+    BaseStore<Reminder>
+    + RecallById<Reminder>
+    + RecallByName<Reminder>
+    + RecallByCompany<Reminder>
+    + RecallDeleted<Reminder>
+    + Purge<Reminder>
+    + RecallRevisions<Reminder>
+    + RecallByQuery<Reminder>
+    + Rollback<Reminder>
+{
+}
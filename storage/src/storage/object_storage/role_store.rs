@@ -2,6 +2,15 @@ use crate::storable::Role;
 use crate::storage::*;
 
 pub trait RoleStore:
-    BaseStore<Role> + RecallById<Role> + RecallByName<Role> + RecallByCompany<Role>
+    BaseStore<Role>
+    + RecallById<Role>
+    + RecallByName<Role>
+    + RecallByCompany<Role>
+    + RecallDeleted<Role>
+    + Purge<Role>
+    + RecallRevisions<Role>
+    + RecallByQuery<Role>
+    + Rollback<Role>
+    + Attachments<Role>
 {
 }
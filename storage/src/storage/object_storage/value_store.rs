@@ -2,6 +2,14 @@ use crate::prelude::Value;
 use crate::storage::*;
 
 pub trait ValueStore:
-    BaseStore<Value> + RecallById<Value> + RecallByName<Value> + RecallByCompany<Value>
+    BaseStore<Value>
+    + RecallById<Value>
+    + RecallByName<Value>
+    + RecallByCompany<Value>
+    + RecallDeleted<Value>
+    + Purge<Value>
+    + RecallRevisions<Value>
+    + RecallByQuery<Value>
+    + Rollback<Value>
 {
 }
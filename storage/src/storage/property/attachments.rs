@@ -0,0 +1,76 @@
+use crate::storable::*;
+use crate::{StorageError, StorageResult};
+
+/// Files kept alongside an item rather than inside it, such as the original HTML of a job posting.
+///
+/// An attachment is a record of how something was when it was captured, so it can only be written
+/// once. It goes when the item is purged.
+pub trait Attachments<T>
+where
+    T: HasId + Clone,
+{
+    /// Fails with `AlreadyExists` if the item already has an attachment with this name, or
+    /// `NotFound` if there is no such item
+    async fn attach<I: HasId>(&mut self, storable: I, name: &str, data: &[u8])
+        -> StorageResult<()>;
+
+    async fn recall_attachment<I: HasId>(&self, storable: I, name: &str) -> StorageResult<Vec<u8>>;
+}
+
+/// Names end up in file names, so they're kept to a single plain file name that can't be mistaken
+/// for one of the store's own files
+pub(crate) fn check_attachment_name(name: &str) -> StorageResult<()> {
+    let is_valid = !name.is_empty()
+        && !name.starts_with('.')
+        && !matches!(name.rsplit('.').next(), Some("json" | "tmp"))
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'));
+    if is_valid {
+        Ok(())
+    } else {
+        Err(StorageError::integrity(format!(
+            "Invalid attachment name '{name}'"
+        )))
+    }
+}
+
+#[cfg(test)]
+pub mod test_helper {
+    macro_rules! test_attachments {
+        ($storage:ty, $storable:ty) => {
+            paste! {
+                #[tokio::test]
+                async fn [< test_attachments_ $storage:snake _with_ $storable:snake >] () {
+                    let mut test_subject = $storage::new_test().await.expect("Could not create storage");
+                    let storable = $storable::new_test().await.expect("Could not create storable");
+
+                    let result = test_subject.attach(&storable, "posting.html", b"<p>Early</p>").await;
+                    assert!(result.unwrap_err().is_not_found());
+
+                    test_subject.store(storable.clone()).await.expect("Could not store storable in storage");
+                    let result = test_subject.recall_attachment(&storable, "posting.html").await;
+                    assert!(result.unwrap_err().is_not_found());
+
+                    test_subject.attach(&storable, "posting.html", b"<p>First</p>").await.expect("Could not attach file");
+                    let result = test_subject.attach(&storable, "posting.html", b"<p>Second</p>").await;
+                    assert!(result.unwrap_err().is_already_exists());
+                    let attachment = test_subject.recall_attachment(&storable, "posting.html").await.expect("Could not recall attachment");
+                    assert_eq!(attachment, b"<p>First</p>");
+
+                    let result = test_subject.attach(&storable, "../escape.json", b"{}").await;
+                    assert!(result.unwrap_err().is_integrity_violation());
+
+                    let mut deleted = storable.clone();
+                    deleted.date_deleted = Some(crate::Timestamp::now());
+                    test_subject.store(deleted.clone()).await.expect("Could not store storable in storage");
+                    test_subject.purge(deleted).await.expect("Could not purge storable from storage");
+                    test_subject.store(storable.clone()).await.expect("Could not store storable in storage");
+                    let result = test_subject.recall_attachment(&storable, "posting.html").await;
+                    assert!(result.unwrap_err().is_not_found());
+                }
+            }
+        };
+    }
+    pub(crate) use test_attachments;
+}
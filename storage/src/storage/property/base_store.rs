@@ -1,11 +1,51 @@
-use crate::storable::HasId;
-use anyhow::Result;
+use crate::storable::{HasId, HasSchemaVersion, SetTimestamps};
+use crate::{Revision, StorageResult, Timestamp};
+use serde::Serialize;
 
 pub trait BaseStore<T>
 where
     T: HasId + Clone,
 {
-    async fn store(&mut self, storable: T) -> Result<()>;
+    async fn store(&mut self, storable: T) -> StorageResult<()>;
+}
+
+/// What storing something will do to the version that is already stored
+pub(crate) enum StoreChange {
+    /// It's identical to what is already stored, so there is nothing to do
+    Unchanged,
+    Created,
+    /// The revision records how it looked before
+    Updated(Revision),
+}
+
+/// Stamps a storable with when it was created and last updated.
+///
+/// An update keeps the creation date of whatever it replaces. Something new keeps any timestamps
+/// it already has, such as when it's copied over from another store.
+pub(crate) fn prepare_store<O>(
+    storable: &mut O,
+    previous: Option<&O>,
+    now: Timestamp,
+) -> StorageResult<StoreChange>
+where
+    O: SetTimestamps + HasSchemaVersion + Serialize,
+{
+    let Some(previous) = previous else {
+        storable.set_date_created(Some(storable.get_date_created().unwrap_or(now)));
+        storable.set_date_updated(Some(storable.get_date_updated().unwrap_or(now)));
+        return Ok(StoreChange::Created);
+    };
+
+    // Only the stored timestamps count, so they aren't part of the comparison
+    storable.set_date_created(previous.get_date_created());
+    storable.set_date_updated(previous.get_date_updated());
+    let Some(revision) = Revision::between(previous, storable, now)? else {
+        return Ok(StoreChange::Unchanged);
+    };
+
+    storable.set_date_created(Some(previous.get_date_created().unwrap_or(now)));
+    storable.set_date_updated(Some(now));
+    Ok(StoreChange::Updated(revision))
 }
 
 #[cfg(test)]
@@ -13,6 +53,7 @@ mod tests {
     use super::*;
     use crate::storable::{Company, Flag};
     use crate::storage::{RecallById, StubStore};
+    use crate::test_helper::without_timestamps;
 
     #[tokio::test]
     async fn test_company() {
@@ -20,7 +61,7 @@ mod tests {
         let mut store = StubStore::default();
         store.store(company.clone()).await.unwrap();
         let recalled_company = store.recall_by_id(&company).await.unwrap();
-        assert_eq!(recalled_company, company);
+        assert_eq!(without_timestamps(recalled_company), company);
     }
 
     #[tokio::test]
@@ -30,6 +71,48 @@ mod tests {
         let mut store = StubStore::default();
         store.store(flag.clone()).await.unwrap();
         let recalled_flag = store.recall_by_id(&flag).await.unwrap();
-        assert_eq!(recalled_flag, flag);
+        assert_eq!(without_timestamps(recalled_flag), flag);
+    }
+
+    #[test]
+    fn test_prepare_store_created() {
+        let now = Timestamp::from_timestamp(1_000);
+        let mut company = Company::new("company");
+        let change = prepare_store(&mut company, None, now).unwrap();
+        assert!(matches!(change, StoreChange::Created));
+        assert_eq!(company.date_created, Some(now));
+        assert_eq!(company.date_updated, Some(now));
+
+        // Copied from another store, so it already knows when it was created
+        let mut copied = company.clone();
+        let change = prepare_store(&mut copied, None, Timestamp::from_timestamp(2_000)).unwrap();
+        assert!(matches!(change, StoreChange::Created));
+        assert_eq!(copied, company);
+    }
+
+    #[test]
+    fn test_prepare_store_updated() {
+        let created = Timestamp::from_string("2025-01-02T03:04");
+        let now = Timestamp::from_string("2025-01-03T03:04");
+        let mut previous = Company::new("company");
+        prepare_store(&mut previous, None, created).unwrap();
+
+        let mut unchanged = previous.clone();
+        unchanged.date_updated = None;
+        let change = prepare_store(&mut unchanged, Some(&previous), now).unwrap();
+        assert!(matches!(change, StoreChange::Unchanged));
+
+        let mut company = previous.clone();
+        company.name = "new name".to_string();
+        company.date_created = None;
+        let StoreChange::Updated(revision) =
+            prepare_store(&mut company, Some(&previous), now).unwrap()
+        else {
+            panic!("Expected an update");
+        };
+        assert_eq!(company.date_created, Some(created));
+        assert_eq!(company.date_updated, Some(now));
+        assert_eq!(revision.get_changes().len(), 1);
+        assert_eq!(revision.get_previous::<Company>().unwrap(), previous);
     }
 }
@@ -1,5 +1,6 @@
 pub mod base_store;
 pub use base_store::BaseStore;
+pub(crate) use base_store::{prepare_store, StoreChange};
 
 pub mod recall_by_id;
 pub use recall_by_id::RecallById;
@@ -12,3 +13,25 @@ pub use recall_by_name::RecallByName;
 
 pub mod recall_by_role;
 pub use recall_by_role::RecallByRole;
+
+pub mod recall_deleted;
+pub use recall_deleted::RecallDeleted;
+
+pub mod purge;
+pub use purge::Purge;
+
+pub mod recall_revisions;
+pub use recall_revisions::RecallRevisions;
+
+pub mod recall_by_query;
+pub use recall_by_query::RecallByQuery;
+
+pub mod recall_due;
+pub use recall_due::RecallDue;
+
+pub mod attachments;
+pub(crate) use attachments::check_attachment_name;
+pub use attachments::Attachments;
+
+pub mod rollback;
+pub use rollback::Rollback;
@@ -0,0 +1,49 @@
+use crate::storable::*;
+use crate::StorageResult;
+
+pub trait Purge<T>
+where
+    T: HasId + Clone,
+{
+    /// Permanently removes an item, unlike setting `date_deleted` this can't be undone.
+    ///
+    /// Only items that are stored as deleted can be purged.
+    async fn purge(&mut self, storable: T) -> StorageResult<()>;
+}
+
+#[cfg(test)]
+pub mod test_helper {
+    macro_rules! test_purge {
+        ($storage:ty, $storable:ty) => {
+            paste! {
+                #[tokio::test]
+                async fn [< test_purge_ $storage:snake _with_ $storable:snake >] () {
+                    use crate::Timestamp;
+
+                    let mut test_subject = $storage::new_test().await.expect("Could not create storage");
+                    let mut storable = $storable::new_test().await.expect("Could not create storable");
+                    test_subject.store(storable.clone()).await.expect("Could not store storable in storage");
+
+                    // Whatever is passed in, it's what is stored that has to be deleted
+                    let mut live = storable.clone();
+                    live.date_deleted = Some(Timestamp::from_string("2025-07-28T09:30"));
+                    let result = test_subject.purge(live).await;
+                    assert!(result.unwrap_err().is_not_deleted());
+                    let recalled: $storable = test_subject.recall_by_id(&storable).await.expect("Could not recall storable from storage");
+                    assert_eq!(recalled.id, storable.id);
+
+                    storable.date_deleted = Some(Timestamp::from_string("2025-07-28T09:30"));
+                    test_subject.store(storable.clone()).await.expect("Could not store storable in storage");
+
+                    test_subject.purge(storable.clone()).await.expect("Could not purge storable from storage");
+                    let v: Vec<$storable> = test_subject.recall_deleted().await.expect("Could not recall deleted storables from storage");
+                    assert!(v.is_empty());
+
+                    let result = test_subject.purge(storable.clone()).await;
+                    assert!(result.is_err());
+                }
+            }
+        };
+    }
+    pub(crate) use test_purge;
+}
@@ -1,11 +1,18 @@
 use crate::storable::*;
-use anyhow::Result;
+use crate::StorageResult;
+use std::sync::Arc;
 
 pub trait RecallById<T>
 where
     T: HasId + HasDeleted + Clone,
 {
-    async fn recall_by_id<I: HasId>(&self, id: I) -> Result<T>;
+    async fn recall_by_id<I: HasId>(&self, id: I) -> StorageResult<T>;
+
+    /// The same as [`RecallById::recall_by_id`], but stores that keep their items in memory hand
+    /// out the item they hold rather than a copy of it
+    async fn recall_shared_by_id<I: HasId>(&self, id: I) -> StorageResult<Arc<T>> {
+        self.recall_by_id(id).await.map(Arc::new)
+    }
 }
 
 #[cfg(test)]
@@ -13,6 +20,7 @@ mod tests {
     use super::*;
     use crate::storable::{Company, Flag};
     use crate::storage::{BaseStore, StubStore};
+    use crate::test_helper::without_timestamps;
 
     #[tokio::test]
     async fn test_company() {
@@ -20,7 +28,7 @@ mod tests {
         let mut store = StubStore::default();
         store.store(company.clone()).await.unwrap();
         let recalled_company = store.recall_by_id(&company).await.unwrap();
-        assert_eq!(recalled_company, company);
+        assert_eq!(without_timestamps(recalled_company), company);
     }
 
     #[tokio::test]
@@ -30,7 +38,7 @@ mod tests {
         let mut store = StubStore::default();
         store.store(flag.clone()).await.unwrap();
         let recalled_flag = store.recall_by_id(&flag).await.unwrap();
-        assert_eq!(recalled_flag, flag);
+        assert_eq!(without_timestamps(recalled_flag), flag);
     }
 }
 
@@ -41,20 +49,27 @@ pub mod test_helper {
             paste! {
                 #[tokio::test]
                 async fn [< test_recall_by_id_ $storage:snake _with_ $storable:snake >] () {
+                    use crate::storable::HasTimestamps;
                     use crate::Timestamp;
 
                     let mut test_subject = $storage::new_test().await.expect("Could not create storage");
                     let mut storable = $storable::new_test().await.expect("Could not create storable");
                     test_subject.store(storable.clone()).await.expect("Could not store storable in storage");
 
-                    let recalled_storable = test_subject.recall_by_id(&storable.get_id()).await.expect("Could not recall storable from storage by id");
-                    assert_eq!(storable, recalled_storable);
+                    let recalled_storable: $storable = test_subject.recall_by_id(&storable.get_id()).await.expect("Could not recall storable from storage by id");
+                    assert!(recalled_storable.get_date_created().is_some());
+                    assert!(recalled_storable.get_date_updated().is_some());
+                    let shared: std::sync::Arc<$storable> = test_subject.recall_shared_by_id(&storable.get_id()).await.expect("Could not recall shared storable from storage by id");
+                    assert_eq!(*shared, recalled_storable);
+                    assert_eq!(storable, without_timestamps(recalled_storable));
 
-                    storable.date_deleted = Some(Timestamp::now());
+                    storable.date_deleted = Some(Timestamp::from_string("2025-07-28T09:30"));
                     test_subject.store(storable.clone()).await.expect("Could not store storable in storage");
 
                     let result: Result<$storable, _> = test_subject.recall_by_id(&storable.get_id()).await;
                     assert!(result.is_err());
+                    let result: Result<std::sync::Arc<$storable>, _> = test_subject.recall_shared_by_id(&storable.get_id()).await;
+                    assert!(result.is_err());
                 }
             }
         };
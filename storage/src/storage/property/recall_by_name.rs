@@ -1,11 +1,11 @@
 use crate::storable::*;
-use anyhow::Result;
+use crate::StorageResult;
 
 pub trait RecallByName<T>
 where
     T: HasName + HasDeleted + Clone,
 {
-    async fn recall_by_name<N: AsRef<str>>(&self, name: N) -> Result<Vec<T>>;
+    async fn recall_by_name<N: AsRef<str>>(&self, name: N) -> StorageResult<Vec<T>>;
 }
 
 #[cfg(test)]
@@ -23,9 +23,9 @@ pub mod test_helper {
 
                     let recalled_storable = test_subject.recall_by_name(&storable.name).await.expect("Could not recall storable from storage by name");
                     assert_eq!(recalled_storable.len(), 1);
-                    assert!(recalled_storable.contains(&storable));
+                    assert!(all_without_timestamps(recalled_storable).contains(&storable));
 
-                    storable.date_deleted = Some(Timestamp::now());
+                    storable.date_deleted = Some(Timestamp::from_string("2025-07-28T09:30"));
                     test_subject.store(storable.clone()).await.expect("Could not store storable in storage");
 
                     let v: Vec<$storable> = test_subject.recall_by_name(&storable.name).await.expect("Could not recall storable from storage by name");
@@ -0,0 +1,65 @@
+use crate::storable::*;
+use crate::Query;
+use crate::StorageResult;
+
+pub trait RecallByQuery<T>
+where
+    T: Queryable + Clone,
+{
+    async fn recall_by_query(&self, query: &Query) -> StorageResult<Vec<T>>;
+}
+
+#[cfg(test)]
+pub mod test_helper {
+    macro_rules! test_recall_by_query {
+        ($storage:ty, $storable:ty) => {
+            paste! {
+                #[tokio::test]
+                async fn [< test_recall_by_query_ $storage:snake _with_ $storable:snake >] () {
+                    use crate::{Query, SortBy, SortOrder, Timestamp};
+
+                    let mut test_subject = $storage::new_test().await.expect("Could not create storage");
+                    let mut stored = Vec::new();
+                    for name in ["Bravo", "alpha", "Charlie", "delta"] {
+                        let mut storable = $storable::new_test().await.expect("Could not create storable");
+                        storable.name = name.to_string();
+                        test_subject.store(storable.clone()).await.expect("Could not store storable in storage");
+                        stored.push(storable);
+                    }
+                    let mut deleted = stored.pop().unwrap();
+                    deleted.date_deleted = Some(Timestamp::from_string("2025-07-28T09:30"));
+                    test_subject.store(deleted.clone()).await.expect("Could not store storable in storage");
+
+                    let query = Query::new().sort_by(SortBy::Name, SortOrder::Ascending);
+                    let recalled: Vec<$storable> = test_subject.recall_by_query(&query).await.expect("Could not recall storables from storage by query");
+                    let names: Vec<_> = recalled.iter().map(|storable| storable.name.as_str()).collect();
+                    assert_eq!(names, vec!["alpha", "Bravo", "Charlie"]);
+
+                    let query = Query::new()
+                        .include_deleted()
+                        .sort_by(SortBy::Name, SortOrder::Descending)
+                        .offset(1)
+                        .limit(2);
+                    let recalled: Vec<$storable> = test_subject.recall_by_query(&query).await.expect("Could not recall storables from storage by query");
+                    let names: Vec<_> = recalled.iter().map(|storable| storable.name.as_str()).collect();
+                    assert_eq!(names, vec!["Charlie", "Bravo"]);
+
+                    let query = Query::new().name_contains("ALP");
+                    let recalled: Vec<$storable> = test_subject.recall_by_query(&query).await.expect("Could not recall storables from storage by query");
+                    assert_eq!(all_without_timestamps(recalled), vec![stored[1].clone()]);
+
+                    // Each test storable has a parent of its own
+                    let first = &stored[0];
+                    let query = match (first.get_parent_company_id(), first.get_parent_role_id()) {
+                        (Some(company_id), _) => Query::new().company(company_id),
+                        (_, Some(role_id)) => Query::new().role(role_id),
+                        (None, None) => Query::new().name_contains(&first.name),
+                    };
+                    let recalled: Vec<$storable> = test_subject.recall_by_query(&query).await.expect("Could not recall storables from storage by query");
+                    assert_eq!(all_without_timestamps(recalled), vec![first.clone()]);
+                }
+            }
+        };
+    }
+    pub(crate) use test_recall_by_query;
+}
@@ -1,10 +1,19 @@
 use crate::storable::{HasDeleted, HasId, HasRole};
+use crate::StorageResult;
+use std::sync::Arc;
 
 pub trait RecallByRole<T>
 where
     T: HasRole + HasDeleted + Clone,
 {
-    async fn recall_by_role<I: HasId>(&self, role: I) -> anyhow::Result<Vec<T>>;
+    async fn recall_by_role<I: HasId>(&self, role: I) -> StorageResult<Vec<T>>;
+
+    /// The same as [`RecallByRole::recall_by_role`], but without copying items that are kept in
+    /// memory
+    async fn recall_shared_by_role<I: HasId>(&self, role: I) -> StorageResult<Vec<Arc<T>>> {
+        let items = self.recall_by_role(role).await?;
+        Ok(items.into_iter().map(Arc::new).collect())
+    }
 }
 
 #[cfg(test)]
@@ -22,13 +31,18 @@ pub mod test_helper {
 
                     let recalled_storable = test_subject.recall_by_role(&storable.get_role_id()).await.expect("Could not recall storable from storage by role id");
                     assert_eq!(recalled_storable.len(), 1);
-                    assert!(recalled_storable.contains(&storable));
+                    let shared: Vec<std::sync::Arc<$storable>> = test_subject.recall_shared_by_role(&storable.get_role_id()).await.expect("Could not recall shared storable from storage by role id");
+                    assert_eq!(shared.len(), 1);
+                    assert_eq!(*shared[0], recalled_storable[0]);
+                    assert!(all_without_timestamps(recalled_storable).contains(&storable));
 
-                    storable.date_deleted = Some(Timestamp::now());
+                    storable.date_deleted = Some(Timestamp::from_string("2025-07-28T09:30"));
                     test_subject.store(storable.clone()).await.expect("Could not store storable in storage");
 
                     let v: Vec<$storable> = test_subject.recall_by_role(&storable.get_role_id()).await.expect("Could not recall storable from storage by role id");
                     assert!(v.is_empty());
+                    let shared: Vec<std::sync::Arc<$storable>> = test_subject.recall_shared_by_role(&storable.get_role_id()).await.expect("Could not recall shared storable from storage by role id");
+                    assert!(shared.is_empty());
                 }
             }
         };
@@ -0,0 +1,39 @@
+use crate::storable::*;
+use crate::StorageResult;
+
+pub trait RecallDeleted<T>
+where
+    T: HasId + HasDeleted + Clone,
+{
+    /// Everything that has been soft deleted but not yet purged
+    async fn recall_deleted(&self) -> StorageResult<Vec<T>>;
+}
+
+#[cfg(test)]
+pub mod test_helper {
+    macro_rules! test_recall_deleted {
+        ($storage:ty, $storable:ty) => {
+            paste! {
+                #[tokio::test]
+                async fn [< test_recall_deleted_ $storage:snake _with_ $storable:snake >] () {
+                    use crate::Timestamp;
+
+                    let mut test_subject = $storage::new_test().await.expect("Could not create storage");
+                    let mut storable = $storable::new_test().await.expect("Could not create storable");
+                    test_subject.store(storable.clone()).await.expect("Could not store storable in storage");
+
+                    let v: Vec<$storable> = test_subject.recall_deleted().await.expect("Could not recall deleted storables from storage");
+                    assert!(v.is_empty());
+
+                    storable.date_deleted = Some(Timestamp::from_string("2025-07-28T09:30"));
+                    test_subject.store(storable.clone()).await.expect("Could not store storable in storage");
+
+                    let recalled_storable = test_subject.recall_deleted().await.expect("Could not recall deleted storables from storage");
+                    assert_eq!(recalled_storable.len(), 1);
+                    assert!(all_without_timestamps(recalled_storable).contains(&storable));
+                }
+            }
+        };
+    }
+    pub(crate) use test_recall_deleted;
+}
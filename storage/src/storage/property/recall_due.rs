@@ -0,0 +1,79 @@
+use crate::storable::Reminder;
+use crate::storage::RecallByQuery;
+use crate::{Query, SortBy, SortOrder, StorageResult, Timestamp};
+
+/// Reminders that still need doing, anything that can query reminders can answer this
+pub trait RecallDue: RecallByQuery<Reminder> {
+    /// Reminders that aren't complete and are due before the cutoff, including any that are
+    /// overdue, soonest first
+    async fn recall_due_before(&self, cutoff: Timestamp) -> StorageResult<Vec<Reminder>> {
+        let query = Query::new()
+            .date_to(cutoff)
+            .sort_by(SortBy::Date, SortOrder::Ascending);
+        Ok(self
+            .recall_by_query(&query)
+            .await?
+            .into_iter()
+            .filter(|reminder| !reminder.is_complete())
+            .collect())
+    }
+
+    async fn count_overdue(&self, now: Timestamp) -> StorageResult<usize> {
+        Ok(self.recall_due_before(now).await?.len())
+    }
+}
+
+impl<T> RecallDue for T where T: RecallByQuery<Reminder> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{BaseStore, StubStore};
+    use crate::test_helper::all_without_timestamps;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_recall_due_before() {
+        let now = Timestamp::from_string("2025-08-12T12:00");
+        let company_id = Uuid::new_v4();
+        let overdue = Reminder::new(
+            company_id,
+            "overdue",
+            Timestamp::from_string("2025-08-01T09:00"),
+        );
+        let mut done = Reminder::new(
+            company_id,
+            "done",
+            Timestamp::from_string("2025-08-02T09:00"),
+        );
+        done.complete(now);
+        let soon = Reminder::new(
+            company_id,
+            "soon",
+            Timestamp::from_string("2025-08-14T09:00"),
+        );
+        let later = Reminder::new(
+            company_id,
+            "later",
+            Timestamp::from_string("2025-09-01T09:00"),
+        );
+        let mut deleted = Reminder::new(
+            company_id,
+            "deleted",
+            Timestamp::from_string("2025-08-03T09:00"),
+        );
+        deleted.date_deleted = Some(now);
+
+        let mut store = StubStore::default();
+        for reminder in [&later, &soon, &done, &overdue, &deleted] {
+            store.store(reminder.clone()).await.unwrap();
+        }
+
+        let due = store
+            .recall_due_before(Timestamp::from_string("2025-08-19T12:00"))
+            .await
+            .unwrap();
+        assert_eq!(all_without_timestamps(due), vec![overdue, soon]);
+        assert_eq!(store.count_overdue(now).await.unwrap(), 1);
+    }
+}
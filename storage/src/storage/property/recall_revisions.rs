@@ -0,0 +1,83 @@
+use crate::storable::*;
+use crate::storage::{BaseStore, RecallById};
+use crate::{Revision, StorageError, StorageResult, Timestamp};
+use serde::de::DeserializeOwned;
+
+pub trait RecallRevisions<T>: BaseStore<T> + RecallById<T>
+where
+    T: HasId + HasDeleted + HasSchemaVersion + RestoreRevision + Clone + DeserializeOwned,
+{
+    /// Every earlier version of an item, oldest first
+    async fn recall_revisions(&self, storable: &T) -> StorageResult<Vec<Revision>>;
+
+    /// Puts an item back how it was before the given revision.
+    ///
+    /// This is stored like any other edit, so it gets a revision of its own and can be undone too.
+    /// What is only ever added to is kept, see [`RestoreRevision`].
+    async fn restore_revision(&mut self, revision: &Revision) -> StorageResult<T> {
+        let previous: T = revision.get_previous()?;
+        let current: T = self.recall_by_id(&previous).await?;
+        let storable = current.restore_over(previous, Timestamp::now());
+        self.store(storable.clone()).await?;
+        Ok(storable)
+    }
+
+    /// Reverts the most recent edit, undoing an undo puts the edit back
+    async fn undo_last_edit(&mut self, storable: &T) -> StorageResult<T> {
+        let revision = self
+            .recall_revisions(storable)
+            .await?
+            .pop()
+            .ok_or(StorageError::NotFound)?;
+        self.restore_revision(&revision).await
+    }
+}
+
+#[cfg(test)]
+pub mod test_helper {
+    macro_rules! test_recall_revisions {
+        ($storage:ty, $storable:ty) => {
+            paste! {
+                #[tokio::test]
+                async fn [< test_recall_revisions_ $storage:snake _with_ $storable:snake >] () {
+                    let mut test_subject = $storage::new_test().await.expect("Could not create storage");
+                    let original = $storable::new_test().await.expect("Could not create storable");
+                    test_subject.store(original.clone()).await.expect("Could not store storable in storage");
+
+                    // Nothing to undo until the storable has been edited
+                    let revisions = test_subject.recall_revisions(&original).await.expect("Could not recall revisions from storage");
+                    assert!(revisions.is_empty());
+                    let result: crate::StorageResult<$storable> = test_subject.undo_last_edit(&original).await;
+                    assert!(result.is_err());
+
+                    // Storing the same thing again isn't an edit
+                    test_subject.store(original.clone()).await.expect("Could not store storable in storage");
+                    let revisions = test_subject.recall_revisions(&original).await.expect("Could not recall revisions from storage");
+                    assert!(revisions.is_empty());
+
+                    let mut edited = original.clone();
+                    edited.name = "Edited name".to_string();
+                    test_subject.store(edited.clone()).await.expect("Could not store storable in storage");
+
+                    let revisions = test_subject.recall_revisions(&original).await.expect("Could not recall revisions from storage");
+                    assert_eq!(revisions.len(), 1);
+                    assert_eq!(revisions[0].get_changes().len(), 1);
+                    assert_eq!(revisions[0].get_changes()[0].field, "name");
+                    assert_eq!(without_timestamps(revisions[0].get_previous::<$storable>().unwrap()), original);
+
+                    // Undoing is itself an edit, so it can be undone
+                    let restored: $storable = test_subject.undo_last_edit(&original).await.expect("Could not undo the last edit");
+                    assert_eq!(without_timestamps(restored), original);
+                    let recalled: $storable = test_subject.recall_by_id(&original).await.expect("Could not recall storable from storage");
+                    assert_eq!(without_timestamps(recalled), original);
+                    let revisions = test_subject.recall_revisions(&original).await.expect("Could not recall revisions from storage");
+                    assert_eq!(revisions.len(), 2);
+
+                    let restored: $storable = test_subject.undo_last_edit(&original).await.expect("Could not undo the last edit");
+                    assert_eq!(without_timestamps(restored), edited);
+                }
+            }
+        };
+    }
+    pub(crate) use test_recall_revisions;
+}
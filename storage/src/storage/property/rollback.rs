@@ -0,0 +1,56 @@
+use crate::storable::*;
+use crate::StorageResult;
+use uuid::Uuid;
+
+/// Undoing writes exactly, without recording the undo as another edit
+pub trait Rollback<T>
+where
+    T: HasId + Clone,
+{
+    type Snapshot;
+
+    /// Remembers how an item is stored right now, along with its revisions
+    async fn snapshot(&self, id: Uuid) -> StorageResult<Self::Snapshot>;
+
+    /// Puts an item back how it was when the snapshot was taken, removing it if it wasn't stored
+    /// at the time
+    async fn roll_back(&mut self, snapshot: Self::Snapshot) -> StorageResult<()>;
+}
+
+#[cfg(test)]
+pub mod test_helper {
+    macro_rules! test_rollback {
+        ($storage:ty, $storable:ty) => {
+            paste! {
+                #[tokio::test]
+                async fn [< test_rollback_ $storage:snake _with_ $storable:snake >] () {
+                    let mut test_subject = $storage::new_test().await.expect("Could not create storage");
+                    let mut storable = $storable::new_test().await.expect("Could not create storable");
+
+                    // Something that wasn't stored goes away again
+                    let snapshot = test_subject.snapshot(storable.get_id()).await.expect("Could not take a snapshot");
+                    test_subject.store(storable.clone()).await.expect("Could not store storable in storage");
+                    test_subject.roll_back(snapshot).await.expect("Could not roll back");
+                    let result: crate::StorageResult<$storable> = test_subject.recall_by_id(&storable).await;
+                    assert!(result.is_err());
+                    let deleted: Vec<$storable> = test_subject.recall_deleted().await.expect("Could not recall deleted storables from storage");
+                    assert!(deleted.is_empty());
+
+                    // An edit is undone without leaving a revision behind
+                    test_subject.store(storable.clone()).await.expect("Could not store storable in storage");
+                    let stored: $storable = test_subject.recall_by_id(&storable).await.expect("Could not recall storable from storage");
+                    let snapshot = test_subject.snapshot(storable.get_id()).await.expect("Could not take a snapshot");
+                    storable.name = "edited".to_string();
+                    test_subject.store(storable.clone()).await.expect("Could not store storable in storage");
+                    test_subject.roll_back(snapshot).await.expect("Could not roll back");
+
+                    let recalled: $storable = test_subject.recall_by_id(&storable).await.expect("Could not recall storable from storage");
+                    assert_eq!(recalled, stored);
+                    let revisions = test_subject.recall_revisions(&recalled).await.expect("Could not recall revisions from storage");
+                    assert!(revisions.is_empty());
+                }
+            }
+        };
+    }
+    pub(crate) use test_rollback;
+}
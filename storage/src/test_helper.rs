@@ -1,5 +1,18 @@
+use crate::storable::SetTimestamps;
 use anyhow::Result;
 
 pub trait TestHelper: Sized {
     async fn new_test() -> Result<Self>;
 }
+
+/// The stores stamp everything with when it was created and updated, so take those off anything
+/// recalled before comparing it with what the test stored
+pub fn without_timestamps<O: SetTimestamps>(mut storable: O) -> O {
+    storable.set_date_created(None);
+    storable.set_date_updated(None);
+    storable
+}
+
+pub fn all_without_timestamps<O: SetTimestamps>(storables: Vec<O>) -> Vec<O> {
+    storables.into_iter().map(without_timestamps).collect()
+}
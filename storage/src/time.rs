@@ -1,36 +1,465 @@
-use chrono::{DateTime, NaiveDateTime, Utc};
-use serde::{Deserialize, Deserializer, Serialize};
+use chrono::{
+    DateTime, Datelike, Days, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, TimeZone,
+    Utc, Weekday,
+};
+use chrono_tz::Tz;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt;
 use std::ops::Deref;
+use std::str::FromStr;
+use thiserror::Error;
 
 const FORMAT: &str = "%Y-%m-%dT%H:%M";
+const ZONED_FORMAT: &str = "%Y-%m-%dT%H:%M%:z";
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Serialize, Deserialize)]
-#[serde(transparent)]
-pub struct Timestamp(#[serde(with = "timestamp_serde")] DateTime<Utc>);
+const OFFSET_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M%#z",
+    "%Y-%m-%dT%H:%M:%S%z",
+    "%Y-%m-%d %H:%M%:z",
+    "%Y-%m-%d %H:%M:%S%:z",
+];
+const DATE_TIME_FORMATS: &[&str] = &[
+    FORMAT,
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%d %H:%M:%S",
+];
+const DATE_FORMATS: &[&str] = &[
+    "%Y-%m-%d",
+    "%Y/%m/%d",
+    "%d %B %Y",
+    "%d %b %Y",
+    "%B %d, %Y",
+    "%b %d, %Y",
+    "%B %d %Y",
+    "%b %d %Y",
+];
+const DAY_FIRST_FORMATS: &[&str] = &["%d/%m/%Y", "%d.%m.%Y", "%d-%m-%Y"];
+const MONTH_FIRST_FORMATS: &[&str] = &["%m/%d/%Y", "%m.%d.%Y", "%m-%d-%Y"];
+
+/// Which way round a date written in numbers, such as 03/04/2025, puts the day and month
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DateOrder {
+    /// 03/04/2025 is the 3rd of April, as it's written in the UK and most of Europe
+    DayFirst,
+    /// 03/04/2025 is the 4th of March, as it's written in the US
+    MonthFirst,
+}
+
+impl DateOrder {
+    pub const ALL: [DateOrder; 2] = [DateOrder::DayFirst, DateOrder::MonthFirst];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DateOrder::DayFirst => "day_first",
+            DateOrder::MonthFirst => "month_first",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DateOrder::DayFirst => "day first (31/12/2025)",
+            DateOrder::MonthFirst => "month first (12/31/2025)",
+        }
+    }
+
+    fn formats(&self) -> &'static [&'static str] {
+        match self {
+            DateOrder::DayFirst => DAY_FIRST_FORMATS,
+            DateOrder::MonthFirst => MONTH_FIRST_FORMATS,
+        }
+    }
+}
+
+impl FromStr for DateOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DateOrder::ALL
+            .into_iter()
+            .find(|order| order.as_str() == s)
+            .ok_or_else(|| format!("Unknown date order `{s}`"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TimestampParseError {
+    #[error("No date or time was given")]
+    Empty,
+    #[error("Couldn't understand `{0}` as a date or time")]
+    Unrecognised(String),
+    #[error("`{0}` could be day then month or month then day")]
+    Ambiguous(String),
+}
+
+/// A moment in time, along with the zone it was entered in when that's known.
+///
+/// Timestamps are compared by the moment alone, the same time entered in two zones is equal. One
+/// without a zone is written as UTC in the original `%Y-%m-%dT%H:%M` format, one with a zone is
+/// written in that zone's local time followed by its offset and name, eg
+/// `2025-08-12T15:00+01:00[Europe/London]`.
+#[derive(Debug, Copy, Clone)]
+pub struct Timestamp {
+    time: DateTime<Utc>,
+    zone: Option<Tz>,
+}
 
 impl Timestamp {
     pub fn from_timestamp(time: i64) -> Self {
-        Timestamp(DateTime::from_timestamp(time, 0).expect("Given timestamp is out of range"))
+        Timestamp::from(DateTime::from_timestamp(time, 0).expect("Given timestamp is out of range"))
     }
 
     pub fn from_string<S: AsRef<str>>(time: S) -> Self {
-        // ToDo: We should accept a series of possible formats here
-        let dt = NaiveDateTime::parse_from_str(time.as_ref(), FORMAT).expect("Invalid format");
-        Timestamp(DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
+        Timestamp::parse(time).expect("Invalid format")
+    }
+
+    /// Reads a date and time written the way people tend to write them, ISO 8601 with or without
+    /// seconds and an offset, a date on its own, common locale formats such as "20/07/1969" or
+    /// "July 20, 1969", and relative phrases such as "tomorrow 3pm" or "next Tuesday".
+    ///
+    /// A date without a time is taken as midnight, a time without a date as today. An IANA zone
+    /// name at the end, eg "tomorrow 3pm America/New_York", is kept with the timestamp and the rest
+    /// is read as that zone's local time, without one it's read as UTC.
+    ///
+    /// A date in numbers that reads as a different day either way round, such as "03/04/2025", is
+    /// `Ambiguous` rather than guessed at, see `parse_in_order`.
+    pub fn parse<S: AsRef<str>>(time: S) -> Result<Self, TimestampParseError> {
+        Timestamp::parse_relative_to(time, Timestamp::now())
+    }
+
+    /// As `parse`, with anything that doesn't name its own zone read as local time in `zone`
+    pub fn parse_in<S: AsRef<str>>(time: S, zone: Tz) -> Result<Self, TimestampParseError> {
+        Timestamp::parse_with(time.as_ref(), Timestamp::now(), Some(zone), None)
+    }
+
+    /// As `parse`, with dates in numbers read in the given order when there is one
+    pub fn parse_in_order<S: AsRef<str>>(
+        time: S,
+        order: Option<DateOrder>,
+    ) -> Result<Self, TimestampParseError> {
+        Timestamp::parse_with(time.as_ref(), Timestamp::now(), None, order)
+    }
+
+    /// As `parse`, with relative phrases worked out from `now` rather than the current time
+    pub fn parse_relative_to<S: AsRef<str>>(
+        time: S,
+        now: Timestamp,
+    ) -> Result<Self, TimestampParseError> {
+        Timestamp::parse_with(time.as_ref(), now, None, None)
+    }
+
+    fn parse_with(
+        time: &str,
+        now: Timestamp,
+        zone: Option<Tz>,
+        order: Option<DateOrder>,
+    ) -> Result<Self, TimestampParseError> {
+        let time = time.trim();
+        if time.is_empty() {
+            return Err(TimestampParseError::Empty);
+        }
+        let unrecognised = || TimestampParseError::Unrecognised(time.to_string());
+        let (text, zone) = match split_zone(time) {
+            Some((text, zone)) => (text, Some(zone)),
+            None => (time, zone),
+        };
+
+        let instant = match parse_instant(text) {
+            Some(instant) => instant,
+            None if text.eq_ignore_ascii_case("now") => now.time,
+            None => {
+                let local_now = match zone {
+                    Some(zone) => now.time.with_timezone(&zone).naive_local(),
+                    None => now.time.naive_utc(),
+                };
+                let natural = |order| parse_natural(&text.to_lowercase(), local_now, order);
+                // Without an order, a date that reads in one is only refused for being unclear
+                let refused = || match order {
+                    None if natural(Some(DateOrder::DayFirst)).is_some() => {
+                        TimestampParseError::Ambiguous(time.to_string())
+                    }
+                    _ => unrecognised(),
+                };
+                let local = parse_naive(text)
+                    .or_else(|| natural(order))
+                    .ok_or_else(refused)?;
+                match zone {
+                    // Times skipped by a clock change don't exist, so can't be read
+                    Some(zone) => zone
+                        .from_local_datetime(&local)
+                        .earliest()
+                        .ok_or_else(unrecognised)?
+                        .with_timezone(&Utc),
+                    None => local.and_utc(),
+                }
+            }
+        };
+        Ok(Timestamp {
+            time: instant,
+            zone,
+        })
+    }
+
+    /// The zone the timestamp was entered in, if it was given one
+    pub fn zone(&self) -> Option<Tz> {
+        self.zone
+    }
+
+    /// The same moment, remembered as being in `zone`
+    pub fn with_zone(self, zone: Tz) -> Self {
+        Timestamp {
+            zone: Some(zone),
+            ..self
+        }
+    }
+
+    pub fn in_zone(&self, zone: Tz) -> DateTime<Tz> {
+        self.time.with_timezone(&zone)
+    }
+
+    /// The local time where the timestamp was entered, if its zone is known
+    pub fn in_original_zone(&self) -> Option<DateTime<Tz>> {
+        self.zone.map(|zone| self.in_zone(zone))
+    }
+
+    /// The same local time `days` later, in the timestamp's own zone or UTC when it has none, so
+    /// a clock change in between doesn't move it
+    pub fn add_days(self, days: u64) -> Self {
+        self.shift_local(|local| local.checked_add_days(Days::new(days)))
+    }
+
+    /// As `add_days`, landing on the last day of the month when the day doesn't exist in it
+    pub fn add_months(self, months: u32) -> Self {
+        self.shift_local(|local| local.checked_add_months(Months::new(months)))
+    }
+
+    fn shift_local(self, shift: impl FnOnce(NaiveDateTime) -> Option<NaiveDateTime>) -> Self {
+        let zone = self.zone.unwrap_or(Tz::UTC);
+        let local = self.in_zone(zone).naive_local();
+        let shifted = shift(local).expect("Shifted timestamp is out of range");
+        let time = zone
+            .from_local_datetime(&shifted)
+            .earliest()
+            .map(|time| time.with_timezone(&Utc))
+            // Skipped by a clock change, move by the same amount of actual time instead
+            .unwrap_or_else(|| self.time + (shifted - local));
+        Timestamp { time, ..self }
     }
 
-    #[cfg(any(test, feature = "desktop"))]
     pub fn now() -> Self {
+        // Unlike `SystemTime::now`, this also works in the browser
+        Timestamp::from_timestamp(Utc::now().timestamp())
+    }
+
+    pub fn from_system_time(time: std::time::SystemTime) -> Self {
         Timestamp::from_timestamp(
-            std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
+            time.duration_since(std::time::UNIX_EPOCH)
                 .expect("Time went backwards!")
                 .as_secs() as i64,
         )
     }
 
     pub fn looks_valid(self) -> bool {
-        self.0.timestamp() > 0
+        self.time.timestamp() > 0
+    }
+}
+
+impl From<DateTime<Utc>> for Timestamp {
+    fn from(time: DateTime<Utc>) -> Self {
+        Timestamp { time, zone: None }
+    }
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.time == other.time
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.time.cmp(&other.time)
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = TimestampParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Timestamp::parse(s)
+    }
+}
+
+/// Splits off a trailing zone, either an IANA name after a space or in brackets as it's written
+fn split_zone(time: &str) -> Option<(&str, Tz)> {
+    let (text, zone) = match time.strip_suffix(']') {
+        Some(time) => time.rsplit_once('[')?,
+        None => time.rsplit_once(char::is_whitespace)?,
+    };
+    Some((text.trim_end(), zone.parse().ok()?))
+}
+
+/// Times that carry their own offset, so are the same moment whichever zone they're read in
+fn parse_instant(time: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(time)
+        .ok()
+        .or_else(|| {
+            OFFSET_FORMATS
+                .iter()
+                .find_map(|format| DateTime::parse_from_str(time, format).ok())
+        })
+        .map(|time| time.with_timezone(&Utc))
+}
+
+fn parse_naive(time: &str) -> Option<NaiveDateTime> {
+    DATE_TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(time, format).ok())
+}
+
+/// Splits the phrase into a date followed by an optional time, eg "next tuesday at 3 pm"
+fn parse_natural(
+    time: &str,
+    now: NaiveDateTime,
+    order: Option<DateOrder>,
+) -> Option<NaiveDateTime> {
+    let words = time
+        .split_whitespace()
+        .filter(|word| !matches!(*word, "at" | "on"))
+        .collect::<Vec<_>>();
+    let today = now.date();
+    (0..=words.len().min(2)).find_map(|time_words| {
+        let (date, time) = words.split_at(words.len() - time_words);
+        let time = match time {
+            [] => NaiveTime::MIN,
+            time => parse_time(&time.concat())?,
+        };
+        let date = match date {
+            [] if time_words > 0 => today,
+            date => parse_date(&date.join(" "), today, order)?,
+        };
+        Some(date.and_time(time))
+    })
+}
+
+fn parse_time(time: &str) -> Option<NaiveTime> {
+    match time {
+        "noon" | "midday" => return NaiveTime::from_hms_opt(12, 0, 0),
+        "midnight" => return Some(NaiveTime::MIN),
+        _ => {}
+    }
+    if let Some(time) = ["%H:%M", "%H:%M:%S"]
+        .iter()
+        .find_map(|format| NaiveTime::parse_from_str(time, format).ok())
+    {
+        return Some(time);
+    }
+
+    let (clock, afternoon) = match (time.strip_suffix("am"), time.strip_suffix("pm")) {
+        (Some(clock), _) => (clock, false),
+        (_, Some(clock)) => (clock, true),
+        _ => return None,
+    };
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) => (hour.parse::<u32>().ok()?, minute.parse().ok()?),
+        None => (clock.parse::<u32>().ok()?, 0),
+    };
+    if !(1..=12).contains(&hour) {
+        return None;
+    }
+    let hour = hour % 12 + if afternoon { 12 } else { 0 };
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
+fn parse_date(date: &str, today: NaiveDate, order: Option<DateOrder>) -> Option<NaiveDate> {
+    let words = date.split_whitespace().collect::<Vec<_>>();
+    match words.as_slice() {
+        ["today"] => Some(today),
+        ["tomorrow"] => today.succ_opt(),
+        ["yesterday"] => today.pred_opt(),
+        ["next", "week"] => Some(today + TimeDelta::weeks(1)),
+        ["last", "week"] => Some(today - TimeDelta::weeks(1)),
+        ["next", day] => Some(today + TimeDelta::days(days_until(today, day.parse().ok()?, 1))),
+        ["last", day] => Some(today - TimeDelta::days(days_since(today, day.parse().ok()?))),
+        ["in", count, unit] => shift(today, parse_count(count)?, unit),
+        [count, unit, "ago"] => shift(today, parse_count(count)?.checked_neg()?, unit),
+        [day] if day.parse::<Weekday>().is_ok() => {
+            Some(today + TimeDelta::days(days_until(today, day.parse().ok()?, 0)))
+        }
+        _ => parse_formats(date, DATE_FORMATS).or_else(|| parse_numeric_date(date, order)),
+    }
+}
+
+fn parse_formats(date: &str, formats: &[&str]) -> Option<NaiveDate> {
+    formats
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(date, format).ok())
+}
+
+/// Without an order a date is only read when it's the same day either way round, or can only be
+/// read one way, eg 31/12/2025
+fn parse_numeric_date(date: &str, order: Option<DateOrder>) -> Option<NaiveDate> {
+    if let Some(order) = order {
+        return parse_formats(date, order.formats());
+    }
+    match (
+        parse_formats(date, DAY_FIRST_FORMATS),
+        parse_formats(date, MONTH_FIRST_FORMATS),
+    ) {
+        (Some(day_first), Some(month_first)) if day_first != month_first => None,
+        (day_first, month_first) => day_first.or(month_first),
+    }
+}
+
+/// Days from today to the next `day`, counting today itself when `at_least` is zero
+fn days_until(today: NaiveDate, day: Weekday, at_least: i64) -> i64 {
+    let ahead = (day.num_days_from_monday() as i64 - today.weekday().num_days_from_monday() as i64)
+        .rem_euclid(7);
+    if ahead < at_least {
+        ahead + 7
+    } else {
+        ahead
+    }
+}
+
+/// Days back from today to the last `day`, not counting today itself
+fn days_since(today: NaiveDate, day: Weekday) -> i64 {
+    match (today.weekday().num_days_from_monday() as i64 - day.num_days_from_monday() as i64)
+        .rem_euclid(7)
+    {
+        0 => 7,
+        behind => behind,
+    }
+}
+
+fn parse_count(count: &str) -> Option<i64> {
+    match count {
+        "a" | "an" | "one" => Some(1),
+        count => count.parse().ok(),
+    }
+}
+
+/// Counts too big for a date come back as `None`, the same as a unit that isn't understood
+fn shift(today: NaiveDate, count: i64, unit: &str) -> Option<NaiveDate> {
+    let months = |count: i64| Some(Months::new(u32::try_from(count.unsigned_abs()).ok()?));
+    match (unit.trim_end_matches('s'), count >= 0) {
+        ("day", _) => today.checked_add_signed(TimeDelta::try_days(count)?),
+        ("week", _) => today.checked_add_signed(TimeDelta::try_weeks(count)?),
+        ("month", true) => today.checked_add_months(months(count)?),
+        ("month", false) => today.checked_sub_months(months(count)?),
+        ("year", true) => today.checked_add_months(months(count.checked_mul(12)?)?),
+        ("year", false) => today.checked_sub_months(months(count.checked_mul(12)?)?),
+        _ => None,
     }
 }
 
@@ -38,28 +467,56 @@ impl Deref for Timestamp {
     type Target = DateTime<Utc>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.time
+    }
+}
+
+/// Writes the timestamp as it's stored, see `Timestamp`
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.in_original_zone() {
+            Some(local) => write!(
+                f,
+                "{}[{}]",
+                local.format(ZONED_FORMAT),
+                local.timezone().name()
+            ),
+            None => write!(f, "{}", self.time.format(FORMAT)),
+        }
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        timestamp_serde::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        timestamp_serde::deserialize(deserializer)
     }
 }
 
 mod timestamp_serde {
     use super::*;
-    use chrono::NaiveDateTime;
-    use serde::Serializer;
-
-    // The signature of a serialize_with function must follow the pattern:
-    //
-    //    fn serialize<S>(&T, S) -> Result<S::Ok, S::Error>
-    //    where
-    //        S: Serializer
-    //
-    // although it may also be generic over the input types T.
-    pub fn serialize<S>(date: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+
+    pub fn serialize<S>(timestamp: &Timestamp, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let s = format!("{}", date.format(FORMAT));
-        serializer.serialize_str(&s)
+        serializer.collect_str(timestamp)
+    }
+
+    /// Only the forms `Display` writes are read back, a stored timestamp is never guessed at
+    fn parse_stored(time: &str) -> Option<Timestamp> {
+        let Some((text, zone)) = time.strip_suffix(']').and_then(|t| t.rsplit_once('[')) else {
+            let time = NaiveDateTime::parse_from_str(time, FORMAT).ok()?;
+            return Some(Timestamp::from(time.and_utc()));
+        };
+        let zone = zone.parse().ok()?;
+        let time = DateTime::parse_from_str(text, ZONED_FORMAT).ok()?;
+        Some(Timestamp::from(time.with_timezone(&Utc)).with_zone(zone))
     }
 
     #[derive(Deserialize)]
@@ -67,34 +524,36 @@ mod timestamp_serde {
     enum TempDateTimeInfo {
         TimeStamp(i64),
         DateTimeString(String),
+        // A time and the zone to read it in, as `TimestampInput` gives them. It's typed by hand, so
+        // unlike a stored timestamp it's read as leniently as `parse` reads it
+        DateTimeInZone(String, String),
     }
 
     impl TempDateTimeInfo {
-        fn to_datetime(&self) -> anyhow::Result<DateTime<Utc>> {
+        fn to_timestamp(&self) -> anyhow::Result<Timestamp> {
             match self {
                 Self::TimeStamp(i) => DateTime::<Utc>::from_timestamp(*i, 0)
+                    .map(Timestamp::from)
                     .ok_or(anyhow::anyhow!("Invalid timestamp")),
-                Self::DateTimeString(s) => {
-                    let dt = NaiveDateTime::parse_from_str(s, FORMAT)?;
-                    Ok(DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
+                Self::DateTimeString(s) => parse_stored(s).ok_or(anyhow::anyhow!(
+                    "`{s}` isn't written as {FORMAT}, with or without an offset and zone"
+                )),
+                Self::DateTimeInZone(s, zone) => {
+                    let zone = zone
+                        .parse()
+                        .map_err(|_| anyhow::anyhow!("Unknown zone `{zone}`"))?;
+                    Ok(Timestamp::parse_in(s, zone)?)
                 }
             }
         }
     }
 
-    // The signature of a deserialize_with function must follow the pattern:
-    //
-    //    fn deserialize<'de, D>(D) -> Result<T, D::Error>
-    //    where
-    //        D: Deserializer<'de>
-    //
-    // although it may also be generic over the output types T.
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Timestamp, D::Error>
     where
         D: Deserializer<'de>,
     {
         TempDateTimeInfo::deserialize(deserializer)?
-            .to_datetime()
+            .to_timestamp()
             .map_err(|e| serde::de::Error::custom(format!("{e}")))
     }
 }
@@ -116,6 +575,309 @@ mod tests {
         assert_eq!(timestamp.timestamp(), -14182980);
     }
 
+    #[test]
+    fn test_parse_iso_8601() {
+        let expected = Timestamp::from_timestamp(-14182980);
+        for time in [
+            "1969-07-20T20:17",
+            "1969-07-20T20:17:00",
+            "1969-07-20T20:17:00.000",
+            "1969-07-20 20:17",
+            "1969-07-20T20:17:00Z",
+            "1969-07-20T21:17:00+01:00",
+            "1969-07-20T15:17-05:00",
+        ] {
+            assert_eq!(Timestamp::parse(time), Ok(expected), "{time}");
+        }
+    }
+
+    #[test]
+    fn test_parse_date_only() {
+        let expected = Timestamp::from_string("1969-07-20T00:00");
+        for time in [
+            "1969-07-20",
+            "1969/07/20",
+            "20/07/1969",
+            "20.07.1969",
+            "20 July 1969",
+            "20 Jul 1969",
+            "July 20, 1969",
+            "jul 20 1969",
+        ] {
+            assert_eq!(Timestamp::parse(time), Ok(expected), "{time}");
+        }
+    }
+
+    #[test]
+    fn test_parse_date_with_time() {
+        let expected = Timestamp::from_string("1969-07-20T20:17");
+        for time in [
+            "20/07/1969 20:17",
+            "20 July 1969 at 8:17pm",
+            "July 20, 1969 8:17 pm",
+        ] {
+            assert_eq!(Timestamp::parse(time), Ok(expected), "{time}");
+        }
+    }
+
+    #[test]
+    fn test_parse_relative() {
+        // A Wednesday
+        let now = Timestamp::from_string("2025-08-06T10:30");
+        let parse = |time| Timestamp::parse_relative_to(time, now).unwrap();
+
+        assert_eq!(parse("now"), now);
+        assert_eq!(parse("today"), Timestamp::from_string("2025-08-06T00:00"));
+        assert_eq!(parse("3pm"), Timestamp::from_string("2025-08-06T15:00"));
+        assert_eq!(
+            parse("tomorrow 3pm"),
+            Timestamp::from_string("2025-08-07T15:00")
+        );
+        assert_eq!(
+            parse("Tomorrow at noon"),
+            Timestamp::from_string("2025-08-07T12:00")
+        );
+        assert_eq!(
+            parse("yesterday 9:30am"),
+            Timestamp::from_string("2025-08-05T09:30")
+        );
+        assert_eq!(parse("tuesday"), Timestamp::from_string("2025-08-12T00:00"));
+        assert_eq!(
+            parse("wednesday"),
+            Timestamp::from_string("2025-08-06T00:00")
+        );
+        assert_eq!(
+            parse("next Tuesday"),
+            Timestamp::from_string("2025-08-12T00:00")
+        );
+        assert_eq!(
+            parse("next wed 14:00"),
+            Timestamp::from_string("2025-08-13T14:00")
+        );
+        assert_eq!(
+            parse("last wednesday"),
+            Timestamp::from_string("2025-07-30T00:00")
+        );
+        assert_eq!(
+            parse("on friday at 12am"),
+            Timestamp::from_string("2025-08-08T00:00")
+        );
+        assert_eq!(
+            parse("in 3 days"),
+            Timestamp::from_string("2025-08-09T00:00")
+        );
+        assert_eq!(
+            parse("in a week"),
+            Timestamp::from_string("2025-08-13T00:00")
+        );
+        assert_eq!(
+            parse("2 weeks ago"),
+            Timestamp::from_string("2025-07-23T00:00")
+        );
+        assert_eq!(
+            parse("in 1 month"),
+            Timestamp::from_string("2025-09-06T00:00")
+        );
+    }
+
+    #[test]
+    fn test_parse_failures() {
+        assert_eq!(Timestamp::parse("  "), Err(TimestampParseError::Empty));
+        for time in [
+            "soon",
+            "13pm",
+            "0am",
+            "32/01/2025",
+            "next blursday",
+            "tomorrow 25:00",
+            "in 100000000 days",
+            "in 99999999999999 days",
+            "in 9999999999999999 weeks",
+            "in 5000000000 months",
+            "in 9223372036854775807 years",
+            "-9223372036854775808 days ago",
+        ] {
+            assert_eq!(
+                Timestamp::parse(time),
+                Err(TimestampParseError::Unrecognised(time.to_string())),
+                "{time}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_ambiguous_date() {
+        for time in ["03/04/2025", "03.04.2025 10:00", "03-04-2025"] {
+            assert_eq!(
+                Timestamp::parse(time),
+                Err(TimestampParseError::Ambiguous(time.to_string())),
+                "{time}"
+            );
+        }
+        // Only readable one way round, or the same day either way
+        assert_eq!(
+            Timestamp::parse("04/30/2025"),
+            Ok(Timestamp::from_string("2025-04-30T00:00"))
+        );
+        assert_eq!(
+            Timestamp::parse("04/04/2025"),
+            Ok(Timestamp::from_string("2025-04-04T00:00"))
+        );
+    }
+
+    #[test]
+    fn test_parse_in_order() {
+        assert_eq!(
+            Timestamp::parse_in_order("03/04/2025", Some(DateOrder::DayFirst)),
+            Ok(Timestamp::from_string("2025-04-03T00:00"))
+        );
+        assert_eq!(
+            Timestamp::parse_in_order("03/04/2025", Some(DateOrder::MonthFirst)),
+            Ok(Timestamp::from_string("2025-03-04T00:00"))
+        );
+        assert_eq!(
+            Timestamp::parse_in_order("30/04/2025", Some(DateOrder::MonthFirst)),
+            Err(TimestampParseError::Unrecognised("30/04/2025".to_string()))
+        );
+        for order in DateOrder::ALL {
+            assert_eq!(order.as_str().parse(), Ok(order));
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        let timestamp: Timestamp = "1969-07-20T20:17".parse().unwrap();
+        assert_eq!(timestamp.timestamp(), -14182980);
+        assert!("not a date".parse::<Timestamp>().is_err());
+    }
+
+    #[test]
+    fn test_parse_with_zone() {
+        let expected = Timestamp::from_string("2025-08-12T14:00");
+        for time in [
+            "2025-08-12T15:00 Europe/London",
+            "2025-08-12T15:00+01:00[Europe/London]",
+            "12 Aug 2025 3pm Europe/London",
+        ] {
+            let timestamp = Timestamp::parse(time).unwrap();
+            assert_eq!(timestamp, expected, "{time}");
+            assert_eq!(timestamp.zone(), Some(Tz::Europe__London), "{time}");
+        }
+        assert_eq!(Timestamp::from_string("2025-08-12T15:00").zone(), None);
+    }
+
+    #[test]
+    fn test_parse_in() {
+        let timestamp = Timestamp::parse_in("2025-08-12 10:00", Tz::America__New_York).unwrap();
+        assert_eq!(timestamp, Timestamp::from_string("2025-08-12T14:00"));
+        assert_eq!(timestamp.zone(), Some(Tz::America__New_York));
+
+        // A zone in the text wins over the one it's read in
+        let timestamp = Timestamp::parse_in("2025-08-12 10:00 Asia/Tokyo", Tz::UTC).unwrap();
+        assert_eq!(timestamp.zone(), Some(Tz::Asia__Tokyo));
+
+        // An offset pins the moment, the zone is only remembered
+        let timestamp = Timestamp::parse_in("2025-08-12T10:00Z", Tz::Europe__Paris).unwrap();
+        assert_eq!(timestamp, Timestamp::from_string("2025-08-12T10:00"));
+        assert_eq!(timestamp.zone(), Some(Tz::Europe__Paris));
+    }
+
+    #[test]
+    fn test_parse_relative_in_zone() {
+        // Already Thursday in Tokyo while it's still Wednesday in UTC
+        let now = Timestamp::from_string("2025-08-06T20:00");
+        let timestamp = Timestamp::parse_relative_to("tomorrow 9am Asia/Tokyo", now).unwrap();
+        assert_eq!(timestamp, Timestamp::from_string("2025-08-08T00:00"));
+    }
+
+    #[test]
+    fn test_parse_skipped_by_clock_change() {
+        let time = "2025-03-30 01:30 Europe/London";
+        assert_eq!(
+            Timestamp::parse(time),
+            Err(TimestampParseError::Unrecognised(time.to_string()))
+        );
+    }
+
+    #[test]
+    fn test_in_zone() {
+        let timestamp = Timestamp::from_string("2025-08-12T14:00").with_zone(Tz::America__New_York);
+        let local = timestamp.in_original_zone().unwrap();
+        assert_eq!(local.format("%H:%M %Z").to_string(), "10:00 EDT");
+        let london = timestamp.in_zone(Tz::Europe__London);
+        assert_eq!(london.format("%H:%M %Z").to_string(), "15:00 BST");
+        assert_eq!(Timestamp::from_timestamp(0).in_original_zone(), None);
+    }
+
+    #[test]
+    fn test_add_days_and_months() {
+        let timestamp = Timestamp::from_string("2025-01-31T09:00");
+        assert_eq!(
+            timestamp.add_days(1),
+            Timestamp::from_string("2025-02-01T09:00")
+        );
+        assert_eq!(
+            timestamp.add_months(1),
+            Timestamp::from_string("2025-02-28T09:00")
+        );
+        assert_eq!(
+            timestamp.add_months(12),
+            Timestamp::from_string("2026-01-31T09:00")
+        );
+
+        // Across the clocks going forward the local time stays the same
+        let london = Timestamp::parse("2025-03-29 09:00 Europe/London").unwrap();
+        let next_day = london.add_days(1);
+        assert_eq!(next_day, Timestamp::from_string("2025-03-30T08:00"));
+        assert_eq!(next_day.zone(), Some(Tz::Europe__London));
+    }
+
+    #[test]
+    fn test_serialize_with_zone() {
+        let timestamp = Timestamp::from_string("2025-08-12T14:00").with_zone(Tz::Europe__London);
+        let serialized = serde_json::to_string(&timestamp).unwrap();
+        assert_eq!(serialized, "\"2025-08-12T15:00+01:00[Europe/London]\"");
+
+        let deserialized: Timestamp = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, timestamp);
+        assert_eq!(deserialized.zone(), Some(Tz::Europe__London));
+    }
+
+    #[test]
+    fn test_deserialize_time_and_zone() {
+        let string = r#"["2025-08-12 10:00", "America/New_York"]"#;
+        let timestamp: Timestamp = serde_json::from_str(string).unwrap();
+        assert_eq!(timestamp, Timestamp::from_string("2025-08-12T14:00"));
+        assert_eq!(timestamp.zone(), Some(Tz::America__New_York));
+
+        let unknown = r#"["2025-08-12 10:00", "Mars/Olympus"]"#;
+        assert!(serde_json::from_str::<Timestamp>(unknown).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_is_strict() {
+        for string in [
+            r#""2025-08-12 10:00""#,
+            r#""2025-08-12""#,
+            r#""tomorrow 3pm""#,
+            r#""2025-08-12T10:00 Europe/London""#,
+            r#""2025-08-12T10:00+01:00[Mars/Olympus]""#,
+        ] {
+            assert!(
+                serde_json::from_str::<Timestamp>(string).is_err(),
+                "{string} was read"
+            );
+        }
+    }
+
+    #[test]
+    fn test_compared_by_moment() {
+        let utc = Timestamp::from_string("2025-08-12T14:00");
+        let london = utc.with_zone(Tz::Europe__London);
+        assert_eq!(utc, london);
+        assert!(Timestamp::from_string("2025-08-12T14:01") > london);
+    }
+
     #[test]
     fn test_now() {
         let timestamp = Timestamp::now();
@@ -0,0 +1,105 @@
+use crate::storage::write_atomically;
+use crate::{IoResultExt, StorageError, StorageResult, Timestamp};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+const SECONDS_PER_DAY: i64 = 60 * 60 * 24;
+
+/// How long deleted items are kept before they are purged automatically.
+///
+/// These are changed from inside the app, so the settings remember where they were loaded from.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashSettings {
+    auto_purge_after_days: Option<u32>,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl TrashSettings {
+    /// Loads the settings from disk, a missing file means never purge automatically
+    pub async fn load<P: AsRef<Path>>(path: P) -> StorageResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        let mut settings: Self = match tokio::fs::read(&path).await {
+            Ok(data) => {
+                serde_json::from_slice(&data).map_err(|e| StorageError::deserialize(&path, e))?
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(e).at_path(&path),
+        };
+        settings.path = path;
+        Ok(settings)
+    }
+
+    pub async fn save(&self) -> StorageResult<()> {
+        let data = serde_json::to_vec_pretty(self).map_err(StorageError::integrity)?;
+        write_atomically(&self.path, &data).await
+    }
+
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get_auto_purge_after_days(&self) -> Option<u32> {
+        self.auto_purge_after_days
+    }
+
+    pub fn set_auto_purge_after_days(&mut self, days: Option<u32>) {
+        self.auto_purge_after_days = days;
+    }
+
+    /// Anything deleted before the returned time should be purged, if auto purge is on
+    pub fn purge_cutoff(&self, now: Timestamp) -> Option<Timestamp> {
+        self.auto_purge_after_days
+            .map(|days| Timestamp::from_timestamp(now.timestamp() - days as i64 * SECONDS_PER_DAY))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_purge_cutoff() {
+        let now = Timestamp::from_timestamp(10 * SECONDS_PER_DAY);
+        let mut settings = TrashSettings::default();
+        assert_eq!(settings.purge_cutoff(now), None);
+
+        settings.set_auto_purge_after_days(Some(7));
+        assert_eq!(
+            settings.purge_cutoff(now),
+            Some(Timestamp::from_timestamp(3 * SECONDS_PER_DAY))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load() {
+        let path = tempfile::tempdir()
+            .unwrap()
+            .keep()
+            .join("trash_settings.json");
+
+        let mut settings = TrashSettings::load(&path).await.unwrap();
+        assert_eq!(settings.get_auto_purge_after_days(), None);
+        assert_eq!(settings.get_path(), path);
+
+        settings.set_auto_purge_after_days(Some(30));
+        settings.save().await.unwrap();
+
+        let loaded = TrashSettings::load(&path).await.unwrap();
+        assert_eq!(loaded, settings);
+    }
+
+    #[tokio::test]
+    async fn test_save_fails_without_a_directory() {
+        let path = tempfile::tempdir()
+            .unwrap()
+            .keep()
+            .join("missing")
+            .join("trash_settings.json");
+
+        let settings = TrashSettings::load(&path).await.unwrap();
+        let error = settings.save().await.unwrap_err();
+        assert!(error.is_io());
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,101 @@
+use crate::storage::write_atomically;
+use crate::{IoResultExt, StorageError, StorageResult};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The zone times are shown in.
+///
+/// Like exchange rates this is a small file the user can edit by hand, without one the zone the
+/// system is set to is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZoneSettings {
+    zone: Tz,
+}
+
+impl Default for ZoneSettings {
+    fn default() -> Self {
+        Self::new(Self::system_zone())
+    }
+}
+
+impl ZoneSettings {
+    pub fn new(zone: Tz) -> Self {
+        Self { zone }
+    }
+
+    /// The zone the system is set to, or UTC if it can't be worked out
+    pub fn system_zone() -> Tz {
+        iana_time_zone::get_timezone()
+            .ok()
+            .and_then(|zone| zone.parse().ok())
+            .unwrap_or(Tz::UTC)
+    }
+
+    /// Loads the settings from disk, a missing file gives the system zone
+    pub async fn load<P: AsRef<Path>>(path: P) -> StorageResult<Self> {
+        let path = path.as_ref();
+        match tokio::fs::read(path).await {
+            Ok(data) => {
+                serde_json::from_slice(&data).map_err(|e| StorageError::deserialize(path, e))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).at_path(path),
+        }
+    }
+
+    pub async fn save<P: AsRef<Path>>(&self, path: P) -> StorageResult<()> {
+        let data = serde_json::to_vec_pretty(self).map_err(StorageError::integrity)?;
+        write_atomically(path.as_ref(), &data).await
+    }
+
+    pub fn get_zone(&self) -> Tz {
+        self.zone
+    }
+
+    pub fn set_zone(&mut self, zone: Tz) {
+        self.zone = zone;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_save_and_load() {
+        let path = tempfile::tempdir()
+            .unwrap()
+            .keep()
+            .join("zone_settings.json");
+
+        let mut settings = ZoneSettings::load(&path).await.unwrap();
+        assert_eq!(settings.get_zone(), ZoneSettings::system_zone());
+
+        settings.set_zone(Tz::Europe__London);
+        settings.save(&path).await.unwrap();
+
+        let loaded = ZoneSettings::load(&path).await.unwrap();
+        assert_eq!(loaded.get_zone(), Tz::Europe__London);
+    }
+
+    #[tokio::test]
+    async fn test_load_unreadable() {
+        let path = tempfile::tempdir()
+            .unwrap()
+            .keep()
+            .join("zone_settings.json");
+        tokio::fs::write(&path, "{ \"zone\": ").await.unwrap();
+
+        let error = ZoneSettings::load(&path).await.unwrap_err();
+        assert!(error.is_deserialize());
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let settings: ZoneSettings =
+            serde_json::from_str(r#"{ "zone": "America/New_York" }"#).unwrap();
+        assert_eq!(settings.get_zone(), Tz::America__New_York);
+        assert!(serde_json::from_str::<ZoneSettings>(r#"{ "zone": "Mars/Olympus" }"#).is_err());
+    }
+}
@@ -0,0 +1,14 @@
+use dioxus::prelude::*;
+
+#[component]
+pub fn DeleteButton(onclick: EventHandler<MouseEvent>) -> Element {
+    rsx! {
+        button {
+            class: "delete",
+            r#type: "button",
+            title: "Move to trash",
+            onclick: move |e: MouseEvent| onclick.call(e),
+            "🗑️"
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::helpers::ReportedError;
 use crate::EMIT_ERROR;
 use dioxus::prelude::*;
 
@@ -10,7 +11,7 @@ use dioxus::prelude::*;
 #[component]
 pub fn ErrorDisplay() -> Element {
     let mut show_errors = use_signal(|| false);
-    let mut errors = use_signal(Vec::<String>::new);
+    let mut errors = use_signal(Vec::<ReportedError>::new);
 
     use_effect(move || {
         if let Some(error) = EMIT_ERROR() {
@@ -27,10 +28,29 @@ pub fn ErrorDisplay() -> Element {
 
     let show_class = if show_errors() { "show-errors" } else { "" };
 
-    let error_strings = errors();
-    let error_list = error_strings.iter().map(|e| {
+    let reported = errors();
+    let error_list = reported.into_iter().enumerate().map(|(index, error)| {
+        let class = error.kind.class();
+        let heading = error.kind.heading();
+        let message = error.message;
+        let retry = error.retry.map(|retry| {
+            rsx! {
+                button {
+                    onclick: move |_| {
+                        // Whatever happens next gets reported again if it fails
+                        errors.with_mut(|errors| errors.remove(index));
+                        retry();
+                    },
+                    "Retry"
+                }
+            }
+        });
         rsx! {
-            li { "{e}" }
+            li { class,
+                h4 { "{heading}" }
+                p { "{message}" }
+                {retry}
+            }
         }
     });
 
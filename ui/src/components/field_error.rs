@@ -0,0 +1,19 @@
+use dioxus::prelude::*;
+use storage::prelude::{FieldKey, IncompletePartialErrors};
+
+/// Shows why the field didn't pass its check, nothing while it's fine
+#[component]
+pub fn FieldErrorMessage<F>(errors: Signal<IncompletePartialErrors>, field: F) -> Element
+where
+    F: FieldKey + Clone + PartialEq + 'static,
+{
+    let message = errors
+        .read()
+        .for_field(field)
+        .map(|error| error.message().to_string());
+    rsx! {
+        if let Some(message) = message {
+            p { class: "field-error", "{message}" }
+        }
+    }
+}
@@ -9,3 +9,18 @@ pub use shortcuts::*;
 
 mod error_display;
 pub use error_display::*;
+
+mod delete_button;
+pub use delete_button::*;
+
+mod revision_history;
+pub use revision_history::*;
+
+mod search_box;
+pub use search_box::*;
+
+mod field_error;
+pub use field_error::*;
+
+mod timestamp_input;
+pub use timestamp_input::*;
@@ -0,0 +1,114 @@
+use crate::helpers::unwrap_or_report_and_return;
+use crate::StoreType;
+use dioxus::prelude::*;
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
+use storage::prelude::{
+    FieldChange, HasDeleted, HasId, HasSchemaVersion, RecallRevisions, RestoreRevision, Revision,
+};
+
+/// Long values such as descriptions are cut short so the list stays readable
+const MAX_VALUE_LENGTH: usize = 60;
+
+fn display_value(value: &serde_json::Value) -> String {
+    let value = match value {
+        serde_json::Value::Null => "nothing".to_string(),
+        serde_json::Value::String(s) if s.is_empty() => "nothing".to_string(),
+        serde_json::Value::String(s) => s.clone(),
+        value => value.to_string(),
+    };
+    match value.char_indices().nth(MAX_VALUE_LENGTH) {
+        Some((index, _)) => format!("{}…", &value[..index]),
+        None => value,
+    }
+}
+
+#[component]
+fn FieldChangeItem(change: FieldChange) -> Element {
+    let field = change.field.replace('_', " ");
+    let before = display_value(&change.before);
+    let after = display_value(&change.after);
+    rsx! {
+        li { "{field}: {before} → {after}" }
+    }
+}
+
+#[component]
+fn RevisionItem(revision: Revision, on_restore: EventHandler<Revision>) -> Element {
+    let date = revision.get_date().to_string();
+    let changes = revision.get_changes().to_vec();
+    rsx! {
+        li {
+            "{date} "
+            button {
+                r#type: "button",
+                title: "Put back how it was before this edit",
+                onclick: move |_| on_restore.call(revision.clone()),
+                "Restore"
+            }
+            ul {
+                for change in changes {
+                    FieldChangeItem { change }
+                }
+            }
+        }
+    }
+}
+
+/// Every earlier version of a storable, newest first, with undo and restore
+#[component]
+pub fn RevisionHistory<O>(storable: Arc<O>, callback: Callback<O>) -> Element
+where
+    O: HasId
+        + HasDeleted
+        + HasSchemaVersion
+        + RestoreRevision
+        + Clone
+        + PartialEq
+        + DeserializeOwned
+        + 'static,
+    StoreType: RecallRevisions<O>,
+{
+    let store = use_context::<StoreType>();
+    let resource_store = store.clone();
+
+    let revisions_resource = use_resource(use_reactive!(|storable| {
+        let store = resource_store.clone();
+        async move {
+            let mut revisions =
+                unwrap_or_report_and_return!(store.recall_revisions(&storable).await);
+            revisions.reverse();
+            revisions
+        }
+    }));
+    let revisions = revisions_resource().unwrap_or_default();
+
+    let restore = use_callback(move |revision: Revision| {
+        let mut store = store.clone();
+        spawn(async move {
+            let storable: O = unwrap_or_report_and_return!(store.restore_revision(&revision).await);
+            callback(storable);
+        });
+    });
+    let undo_revision = revisions.first().cloned();
+
+    rsx! {
+        details { class: "history",
+            summary { "History" }
+            if let Some(revision) = undo_revision {
+                button {
+                    r#type: "button",
+                    onclick: move |_| restore(revision.clone()),
+                    "Undo last edit"
+                }
+                ul {
+                    for revision in revisions {
+                        RevisionItem { revision, on_restore: restore }
+                    }
+                }
+            } else {
+                "No edits yet"
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::Route;
+use dioxus::prelude::*;
+
+#[component]
+pub fn SearchBox() -> Element {
+    let route = use_route::<Route>();
+    let mut query = use_signal(|| match &route {
+        Route::Search { query } => query.clone(),
+        _ => String::new(),
+    });
+    let is_searching = matches!(route, Route::Search { .. });
+
+    rsx! {
+        form {
+            role: "search",
+            onsubmit: move |e: FormEvent| {
+                e.prevent_default();
+                navigator().push(Route::Search { query: query() });
+            },
+            input {
+                r#type: "search",
+                placeholder: "Search everything",
+                value: query,
+                oninput: move |e: Event<FormData>| {
+                    query.set(e.value());
+                    // Keep the results up to date while typing, without filling the history
+                    if is_searching {
+                        navigator().replace(Route::Search { query: query() });
+                    }
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::helpers::{describe_in_zones, use_local_zone};
+use dioxus::prelude::*;
+use storage::prelude::{Timestamp, TimestampParseError, Tz};
+use storage::TZ_VARIANTS;
+
+/// A free text date and time, "tomorrow 3pm" or "20/07/1969 20:17", with what it resolves to shown
+/// alongside so there are no surprises once it's saved.
+///
+/// The time is submitted along with the zone to read it in, the user's own zone unless another is
+/// chosen, so the zone is remembered with the timestamp.
+#[component]
+pub fn TimestampInput(
+    name: &'static str,
+    value: String,
+    #[props(default)] zone: Option<Tz>,
+    #[props(default)] choose_zone: bool,
+    #[props(default)] class: &'static str,
+) -> Element {
+    let local = use_local_zone();
+    let mut typed = use_signal(|| value);
+    let mut zone_name = use_signal(|| zone.unwrap_or(local).name().to_string());
+
+    let preview = match zone_name.read().parse::<Tz>() {
+        Err(_) => format!("Unknown zone `{zone_name}`"),
+        Ok(zone) => match Timestamp::parse_in(typed(), zone) {
+            Ok(time) => describe_in_zones(&time, local),
+            Err(TimestampParseError::Empty) => String::new(),
+            Err(e) => e.to_string(),
+        },
+    };
+
+    rsx! {
+        input {
+            class,
+            name,
+            placeholder: "eg tomorrow 3pm",
+            value: "{typed}",
+            oninput: move |e: FormEvent| typed.set(e.value()),
+        }
+        // Both inputs share the name, so the form gives the time and its zone together
+        if choose_zone {
+            input {
+                class: "zone-input",
+                name,
+                list: "time-zones",
+                value: "{zone_name}",
+                oninput: move |e: FormEvent| zone_name.set(e.value()),
+            }
+            datalist { id: "time-zones",
+                for zone in TZ_VARIANTS {
+                    option { value: zone.name() }
+                }
+            }
+        } else {
+            input { r#type: "hidden", name, value: "{zone_name}" }
+        }
+        span { class: "timestamp-preview", "{preview}" }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::helpers::log_error_with_retry;
+use crate::StoreType;
+use dioxus::prelude::*;
+use std::sync::Arc;
+use storage::prelude::{HasId, Posting, Role, Timestamp};
+
+/// Captures the posting with the role then calls back with the updated role, a failure is reported
+/// with the option to retry
+pub fn capture_posting_with_retry(
+    store: StoreType,
+    role: Role,
+    posting: Arc<Posting>,
+    callback: Callback<Role>,
+) {
+    spawn(async move {
+        match store
+            .capture_posting(role.get_id(), &posting, Timestamp::now())
+            .await
+        {
+            Ok(role) => callback(role),
+            Err(e) => log_error_with_retry(e, move || {
+                capture_posting_with_retry(store.clone(), role.clone(), posting.clone(), callback)
+            }),
+        }
+    });
+}
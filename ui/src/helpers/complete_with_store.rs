@@ -0,0 +1,21 @@
+use crate::helpers::store_with_retry;
+use dioxus::core::Callback;
+use dioxus_html::MouseEvent;
+use std::sync::Arc;
+use storage::prelude::{BaseStore, Reminder, Timestamp};
+
+/// Marks the reminder as done, one that repeats moves on to when it's next due instead
+pub fn complete_with_store<S>(
+    store: S,
+    reminder: Arc<Reminder>,
+    callback: Callback<Reminder>,
+) -> impl FnMut(MouseEvent)
+where
+    S: BaseStore<Reminder> + Clone + 'static,
+{
+    move |_: MouseEvent| {
+        let mut reminder = Reminder::clone(&reminder);
+        reminder.complete(Timestamp::now());
+        store_with_retry(store.clone(), reminder, callback);
+    }
+}
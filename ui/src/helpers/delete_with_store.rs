@@ -0,0 +1,22 @@
+use crate::helpers::store_with_retry;
+use dioxus::core::Callback;
+use dioxus_html::MouseEvent;
+use std::sync::Arc;
+use storage::prelude::{BaseStore, HasId, SetDeleted, Timestamp};
+
+/// Soft deletes the storable, it can then be restored or purged from the trash
+pub fn delete_with_store<O, S>(
+    store: S,
+    storable: Arc<O>,
+    callback: Callback<O>,
+) -> impl FnMut(MouseEvent)
+where
+    O: SetDeleted + Clone + HasId + 'static,
+    S: BaseStore<O> + Clone + 'static,
+{
+    move |_: MouseEvent| {
+        let mut storable = O::clone(&storable);
+        storable.delete(Timestamp::now());
+        store_with_retry(store.clone(), storable, callback);
+    }
+}
@@ -1,9 +1,12 @@
-use crate::helpers::{log_error, report_if_error};
-use dioxus::core::{spawn, Callback};
+use crate::helpers::{check_edit, log_error, store_with_retry};
+use dioxus::core::Callback;
+use dioxus::prelude::{Signal, WritableExt};
 use dioxus_html::FormEvent;
 use serde::de::DeserializeOwned;
 use std::sync::Arc;
-use storage::prelude::{ApplyPartial, BaseStore, HasId};
+use storage::prelude::{
+    ApplyPartial, BaseStore, CheckPartialComplete, HasId, IncompletePartialErrors,
+};
 use storage::Partial;
 
 pub fn edit_with_form<O, P, S>(
@@ -20,12 +23,36 @@ where
         e.prevent_default();
         if let Ok(form_data) = e.parsed_values::<P>().map_err(log_error) {
             let mut storable = O::clone(&storable);
-            let mut store = store.clone();
-            spawn(async move {
-                storable.apply(form_data);
-                report_if_error!(store.store(storable.clone()).await);
-                callback(storable);
-            });
+            storable.apply(form_data);
+            store_with_retry(store.clone(), storable, callback);
+        }
+    }
+}
+
+/// Like `edit_with_form`, but the edit is only stored once it passes its check, any failures are
+/// put in `errors` for the form to show against its inputs
+pub fn edit_with_validated_form<O, P, S>(
+    store: S,
+    storable: Arc<O>,
+    callback: Callback<O>,
+    mut errors: Signal<IncompletePartialErrors>,
+) -> impl FnMut(FormEvent)
+where
+    O: Partial<Item = P> + Clone + HasId + 'static,
+    P: CheckPartialComplete + DeserializeOwned + 'static,
+    S: BaseStore<O> + Clone + 'static,
+{
+    move |e: FormEvent| {
+        e.prevent_default();
+        if let Ok(form_data) = e.parsed_values::<P>().map_err(log_error) {
+            if let Err(failed) = check_edit(&form_data) {
+                errors.set(failed);
+                return;
+            }
+            errors.set(IncompletePartialErrors::default());
+            let mut storable = O::clone(&storable);
+            storable.apply(form_data);
+            store_with_retry(store.clone(), storable, callback);
         }
     }
 }
@@ -1,6 +1,125 @@
-pub fn log_error(e: impl std::fmt::Display) {
+use dioxus::core::{spawn, Callback};
+use std::any::Any;
+use std::fmt::Display;
+use std::rc::Rc;
+use storage::prelude::{BaseStore, HasId, IncompletePartialErrors, StorageError};
+
+/// What sort of thing went wrong, decides how an error is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// A file couldn't be read or written
+    Io,
+    /// Something stored couldn't be read back
+    Corrupt,
+    /// What was entered isn't complete
+    Validation,
+    /// What is stored doesn't fit together
+    Integrity,
+    /// The store is in use, it should be free again soon
+    Locked,
+    NotFound,
+    Other,
+}
+
+impl ErrorKind {
+    pub fn heading(&self) -> &'static str {
+        match self {
+            ErrorKind::Io => "Could not access a file",
+            ErrorKind::Corrupt => "Could not read saved data",
+            ErrorKind::Validation => "Some fields need filling in",
+            ErrorKind::Integrity => "Saved data doesn't add up",
+            ErrorKind::Locked => "Busy",
+            ErrorKind::NotFound => "Not found",
+            ErrorKind::Other => "Something went wrong",
+        }
+    }
+
+    pub fn class(&self) -> &'static str {
+        match self {
+            ErrorKind::Io => "error-io",
+            ErrorKind::Corrupt => "error-corrupt",
+            ErrorKind::Validation => "error-validation",
+            ErrorKind::Integrity => "error-integrity",
+            ErrorKind::Locked => "error-locked",
+            ErrorKind::NotFound => "error-not-found",
+            ErrorKind::Other => "error-other",
+        }
+    }
+
+    fn of(error: &StorageError) -> Self {
+        match error {
+            StorageError::Io { .. } => ErrorKind::Io,
+            #[cfg(feature = "sqlite")]
+            StorageError::Database(_) => ErrorKind::Io,
+            StorageError::Deserialize { .. } => ErrorKind::Corrupt,
+            StorageError::Validation(_) => ErrorKind::Validation,
+            // Whatever couldn't be undone no longer fits with the rest of what is stored
+            StorageError::IntegrityViolation(_)
+            | StorageError::MissingParent { .. }
+            | StorageError::RollbackFailed { .. } => ErrorKind::Integrity,
+            StorageError::Locked => ErrorKind::Locked,
+            StorageError::NotFound => ErrorKind::NotFound,
+            StorageError::AlreadyExists | StorageError::NotDeleted => ErrorKind::Other,
+        }
+    }
+}
+
+/// An error ready to be shown, with a way to try again if that could help
+#[derive(Clone)]
+pub struct ReportedError {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub retry: Option<Rc<dyn Fn()>>,
+}
+
+/// Finds the storage error behind whatever was reported, if there is one
+fn as_storage_error(error: &dyn Any) -> Option<&StorageError> {
+    if let Some(error) = error.downcast_ref::<StorageError>() {
+        return Some(error);
+    }
+    error
+        .downcast_ref::<anyhow::Error>()
+        .and_then(|error| error.downcast_ref::<StorageError>())
+}
+
+fn report<E: Display + 'static>(e: E, retry: Option<Rc<dyn Fn()>>) {
     log::error!("{e}");
-    *crate::EMIT_ERROR.write() = Some(format!("{e}"));
+    let (kind, retryable) = match as_storage_error(&e) {
+        Some(error) => (ErrorKind::of(error), error.is_retryable()),
+        None if (&e as &dyn Any).is::<IncompletePartialErrors>() => (ErrorKind::Validation, false),
+        None => (ErrorKind::Other, false),
+    };
+    *crate::EMIT_ERROR.write() = Some(ReportedError {
+        kind,
+        message: format!("{e}"),
+        retry: retry.filter(|_| retryable),
+    });
+}
+
+pub fn log_error<E: Display + 'static>(e: E) {
+    report(e, None);
+}
+
+/// Reports the error, offering to try again if it's the sort of error that could go away
+pub fn log_error_with_retry<E: Display + 'static>(e: E, retry: impl Fn() + 'static) {
+    report(e, Some(Rc::new(retry)));
+}
+
+/// Stores the storable then calls back with it, a failure is reported with the option to retry
+pub fn store_with_retry<O, S>(store: S, storable: O, callback: Callback<O>)
+where
+    O: Clone + HasId + 'static,
+    S: BaseStore<O> + Clone + 'static,
+{
+    spawn(async move {
+        let mut writer = store.clone();
+        match writer.store(storable.clone()).await {
+            Ok(()) => callback(storable),
+            Err(e) => log_error_with_retry(e, move || {
+                store_with_retry(store.clone(), storable.clone(), callback)
+            }),
+        }
+    });
 }
 
 macro_rules! unwrap_or_report_and_return {
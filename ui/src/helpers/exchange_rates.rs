@@ -0,0 +1,13 @@
+use dioxus::prelude::*;
+use storage::prelude::ExchangeRates;
+
+/// Keeps the exchange rates the app was started with in a signal, so a new base currency shows
+/// everywhere straight away. Without any (eg on the web) the defaults are used.
+pub fn use_exchange_rates_provider() {
+    use_context_provider(|| Signal::new(try_use_context::<ExchangeRates>().unwrap_or_default()));
+}
+
+/// The rates compensation is compared with, see [`use_exchange_rates_provider`]
+pub fn use_exchange_rates() -> Signal<ExchangeRates> {
+    use_context::<Signal<ExchangeRates>>()
+}
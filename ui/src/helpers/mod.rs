@@ -4,5 +4,26 @@ pub use create_route::*;
 mod edit_with_form;
 pub use edit_with_form::*;
 
+mod exchange_rates;
+pub use exchange_rates::*;
+
 mod error_handlers;
 pub(crate) use error_handlers::*;
+
+mod delete_with_store;
+pub use delete_with_store::*;
+
+mod complete_with_store;
+pub use complete_with_store::*;
+
+mod capture_posting;
+pub use capture_posting::*;
+
+mod restart_on_change;
+pub use restart_on_change::*;
+
+mod validation;
+pub use validation::*;
+
+mod zoned_time;
+pub use zoned_time::*;
@@ -0,0 +1,41 @@
+use crate::StoreType;
+use dioxus::prelude::*;
+use std::time::Duration;
+use storage::prelude::SearchKind;
+
+/// Reruns the resource whenever something of one of the kinds changes in the store, so it stays
+/// up to date with changes made by other views
+pub fn use_restart_on_change<T: 'static>(mut resource: Resource<T>, kinds: &'static [SearchKind]) {
+    let store = use_context::<StoreType>();
+    use_future(move || {
+        let mut changes = store.subscribe();
+        async move {
+            while changes
+                .wait_for(|change| kinds.contains(&change.kind))
+                .await
+            {
+                resource.restart();
+            }
+        }
+    });
+}
+
+/// Reruns the resource every `period`, for what goes stale without anything in the store changing
+pub fn use_restart_every<T: 'static>(mut resource: Resource<T>, period: Duration) {
+    use_future(move || async move {
+        loop {
+            sleep(period).await;
+            resource.restart();
+        }
+    });
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn sleep(period: Duration) {
+    gloo_timers::future::sleep(period).await
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn sleep(period: Duration) {
+    tokio::time::sleep(period).await
+}
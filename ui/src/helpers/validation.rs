@@ -0,0 +1,40 @@
+use dioxus::prelude::*;
+use serde::de::DeserializeOwned;
+use storage::prelude::{CheckPartialComplete, FieldKey, IncompletePartialErrors};
+
+/// The class an input takes when its field failed the check
+pub fn invalid_class<F: FieldKey>(
+    errors: Signal<IncompletePartialErrors>,
+    field: F,
+) -> &'static str {
+    match errors.read().for_field(field) {
+        Some(_) => "field-invalid",
+        None => "",
+    }
+}
+
+/// Once a submit has been refused, checks the form again as it changes so errors clear when fixed
+pub fn revalidate_on_input<P, F>(
+    mut errors: Signal<IncompletePartialErrors>,
+    check: F,
+) -> impl FnMut(FormEvent)
+where
+    P: DeserializeOwned,
+    F: Fn(&P) -> Result<(), IncompletePartialErrors>,
+{
+    move |e: FormEvent| {
+        if errors.read().is_empty() {
+            return;
+        }
+        if let Ok(partial) = e.parsed_values::<P>() {
+            errors.set(check(&partial).err().unwrap_or_default());
+        }
+    }
+}
+
+/// Checks a partial that edits a storable, fields left out of the form keep what was stored
+pub fn check_edit<P: CheckPartialComplete>(partial: &P) -> Result<(), IncompletePartialErrors> {
+    partial
+        .check_complete()
+        .or_else(|errors| errors.ignoring_missing().into())
+}
@@ -0,0 +1,25 @@
+use dioxus::prelude::*;
+use storage::prelude::{Timestamp, Tz, ZoneSettings};
+
+const DISPLAY_FORMAT: &str = "%a %-d %b %Y, %H:%M %Z";
+
+/// The zone the user has chosen to see times in, the system's zone if they haven't
+pub fn use_local_zone() -> Tz {
+    try_use_context::<ZoneSettings>()
+        .unwrap_or_default()
+        .get_zone()
+}
+
+/// The time in the user's zone, followed by the time where it was entered when that's elsewhere,
+/// eg an interview arranged in the interviewer's zone
+pub fn describe_in_zones(timestamp: &Timestamp, local: Tz) -> String {
+    let here = timestamp.in_zone(local).format(DISPLAY_FORMAT).to_string();
+    match timestamp.in_original_zone() {
+        Some(there) if there.timezone() != local => format!(
+            "{here} ({} in {})",
+            there.format("%a %H:%M %Z"),
+            there.timezone().name()
+        ),
+        _ => here,
+    }
+}
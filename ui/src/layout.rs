@@ -1,9 +1,41 @@
-use crate::components::ErrorDisplay;
-use crate::{Navbar, Route};
+use crate::components::{ErrorDisplay, SearchBox};
+use crate::helpers::{
+    unwrap_or_report_and_return, use_exchange_rates_provider, use_restart_every,
+    use_restart_on_change,
+};
+use crate::{Navbar, Route, StoreType};
 use dioxus::prelude::*;
+use std::time::Duration;
+use storage::prelude::{RecallDue, SearchKind, Timestamp};
+
+/// Reminders fall overdue as time passes, not only when they're changed
+const OVERDUE_REFRESH: Duration = Duration::from_secs(60);
+
+/// How many reminders are past due, nothing when none are
+#[component]
+fn OverdueCount() -> Element {
+    let overdue_resource = use_resource(move || async move {
+        unwrap_or_report_and_return!(
+            use_context::<StoreType>()
+                .count_overdue(Timestamp::now())
+                .await
+        )
+    });
+    use_restart_on_change(overdue_resource, &[SearchKind::Reminder]);
+    use_restart_every(overdue_resource, OVERDUE_REFRESH);
+    let overdue = overdue_resource().unwrap_or_default();
+
+    rsx! {
+        if overdue > 0 {
+            span { class: "overdue-count", title: "Overdue reminders", "{overdue}" }
+        }
+    }
+}
 
 #[component]
 pub fn Layout() -> Element {
+    use_exchange_rates_provider();
+
     rsx! {
         div { class: "flex mb-4",
 
@@ -16,10 +48,17 @@ pub fn Layout() -> Element {
             }
 
             Navbar {
-                Link { to: Route::HomeHome {}, "Home" }
+                Link { to: Route::HomeHome {},
+                    "Home"
+                    OverdueCount {}
+                }
                 Link { to: Route::Support {}, "Support ❤️" }
+                Link { to: Route::Spreadsheets {}, "Spreadsheets" }
+                Link { to: Route::Trash {}, "Trash" }
                 Link { to: Route::Help {}, "Help" }
             }
+
+            SearchBox {}
         }
 
         Outlet::<Route> {}
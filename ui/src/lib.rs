@@ -21,9 +21,12 @@ pub type LogFetcherType = JsonLogFetcher;
 #[cfg(all(test, not(feature = "desktop")))]
 pub type LogFetcherType = StubLogFetcher;
 
-#[cfg(feature = "desktop")]
+#[cfg(all(feature = "desktop", not(feature = "sqlite")))]
 pub type StoreType = JsonThreadSafeGeneralStore;
 
+#[cfg(all(feature = "desktop", feature = "sqlite"))]
+pub type StoreType = SqliteThreadSafeGeneralStore;
+
 #[cfg(all(test, not(feature = "desktop")))]
 pub type StoreType = StubThreadSafeGeneralStore;
 
@@ -31,7 +34,7 @@ pub static SHORTCUT_SIGNAL: GlobalSignal<Option<ShortcutEvent>> = Global::new(||
 
 pub static SHOW_MODIFIERS: GlobalSignal<bool> = Global::new(|| false);
 
-pub static EMIT_ERROR: GlobalSignal<Option<String>> = Global::new(|| None);
+pub static EMIT_ERROR: GlobalSignal<Option<helpers::ReportedError>> = Global::new(|| None);
 
 pub mod prelude {
     pub use super::components::ShortcutEvent;
@@ -1,5 +1,5 @@
 use crate::layout::Layout;
-use crate::views::{Help, Home, Support};
+use crate::views::{Help, Home, Search, Spreadsheets, Support, Trash};
 use dioxus::prelude::*;
 use std::fmt;
 use std::str::FromStr;
@@ -87,6 +87,12 @@ pub enum Route {
     Support { },
     #[route("/help")]
     Help { },
+    #[route("/trash")]
+    Trash { },
+    #[route("/spreadsheets")]
+    Spreadsheets { },
+    #[route("/search?:query")]
+    Search { query: String },
     #[route("/")]
     HomeHome,
     #[route("/:company_id")]
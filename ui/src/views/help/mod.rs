@@ -23,8 +23,46 @@ fn open_dir(path: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[component]
+fn QuarantinedFileItem(file: QuarantinedFile) -> Element {
+    let mut restored = use_signal(|| false);
+    let path = file.quarantine_path.to_string_lossy().to_string();
+
+    rsx! {
+        li {
+            p {
+                "{file.store}: "
+                a {
+                    href: "#",
+                    onclick: move |e| {
+                        e.prevent_default();
+                        report_if_error!(open_dir(& path));
+                    },
+                    {file.quarantine_path.to_string_lossy().to_string()}
+                }
+            }
+            p { {file.error.clone()} }
+            if restored() {
+                p { "Restored, restart the app to load it again" }
+            } else {
+                button {
+                    onclick: move |_| {
+                        let file = file.clone();
+                        spawn(async move {
+                            unwrap_or_report_and_return!(file.restore().await);
+                            restored.set(true);
+                        });
+                    },
+                    "Restore"
+                }
+            }
+        }
+    }
+}
+
 #[component]
 pub fn Help() -> Element {
+    let load_report = try_use_context::<LoadReport>().unwrap_or_default();
     let log_getter = use_context::<LogFetcherType>();
     let log_cleaner = use_context::<LogFetcherType>();
     let mut logs_resource = use_resource(move || {
@@ -55,6 +93,19 @@ pub fn Help() -> Element {
             }
         }
 
+        if !load_report.is_empty() {
+            h3 { "Files that could not be loaded" }
+            p {
+                "These files were moved aside so that everything else could load. You can open them
+                 to see what is wrong, then restore them once they are fixed."
+            }
+            ul {
+                for file in load_report.get_quarantined() {
+                    QuarantinedFileItem { file: file.clone() }
+                }
+            }
+        }
+
         h3 { "Logs" }
         if logs.len() > 0 {
             button {
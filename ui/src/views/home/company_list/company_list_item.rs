@@ -1,12 +1,13 @@
+use crate::helpers::unwrap_or_report_and_return;
 use crate::views::home::company_list::forms::edit_company_name::EditCompanyName;
-use crate::{Editable, Route};
+use crate::{DeleteButton, Editable, Route, StoreType};
 use application_context::prelude::*;
 use dioxus::prelude::*;
 use std::sync::Arc;
 use storage::prelude::*;
 
 #[component]
-pub fn CompanyListItem(company: Arc<Company>, reload_companies: Callback) -> Element {
+pub fn CompanyListItem(company: Arc<Company>) -> Element {
     let context = use_context::<Signal<ApplicationContext>>();
     let is_editable = use_signal::<bool>(|| false);
 
@@ -37,6 +38,18 @@ pub fn CompanyListItem(company: Arc<Company>, reload_companies: Callback) -> Ele
         });
     });
 
+    // Takes the company's roles, flags, values and contacts with it
+    let store = use_context::<StoreType>();
+    let delete = move |_| {
+        let store = store.clone();
+        spawn(async move {
+            unwrap_or_report_and_return!(store.delete_company(company_id, Timestamp::now()).await);
+            if checked {
+                navigator().push(Route::HomeHome {});
+            }
+        });
+    };
+
     let editable = rsx! {
         EditCompanyName { company, callback }
     };
@@ -44,6 +57,7 @@ pub fn CompanyListItem(company: Arc<Company>, reload_companies: Callback) -> Ele
     rsx! {
         li { key: "{company_id}",
             Editable { display, editable, is_editable }
+            DeleteButton { onclick: delete }
         }
     }
 }
@@ -1,9 +1,10 @@
-use crate::helpers::log_error;
+use crate::helpers::{log_error, store_with_retry};
 use crate::StoreType;
 use dioxus::prelude::*;
-use storage::prelude::{BaseStore, Company, CompanyFieldName, PartialCompany};
+use storage::prelude::{Company, CompanyFieldName, PartialCompany};
 
 fn create_on_submit(callback: Callback<Company>) -> impl FnMut(FormEvent) {
+    let store = use_context::<StoreType>();
     move |e: FormEvent| {
         e.prevent_default();
         if let Ok(company) = e
@@ -12,14 +13,7 @@ fn create_on_submit(callback: Callback<Company>) -> impl FnMut(FormEvent) {
             .and_then(|form_data| Company::new_from_partial(form_data).map_err(log_error))
         {
             // If the company was successfully created, save it
-            spawn(async move {
-                let mut stores = use_context::<StoreType>();
-                stores
-                    .store(company.clone())
-                    .await
-                    .unwrap_or_else(log_error);
-                callback(company);
-            });
+            store_with_retry(store.clone(), company, callback);
         }
     }
 }
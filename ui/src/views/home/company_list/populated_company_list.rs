@@ -1,5 +1,5 @@
 use super::company_list_item::CompanyListItem;
-use crate::helpers::unwrap_or_report_and_return;
+use crate::helpers::{unwrap_or_report_and_return, use_restart_on_change};
 use crate::views::home::company_list::forms::create_company::CreateCompany;
 use crate::StoreType;
 use dioxus::prelude::*;
@@ -10,26 +10,28 @@ use storage::prelude::*;
 pub fn CompanyList() -> Element {
     let mut company_name_search = use_signal(|| "".to_string());
 
-    let mut companies_resource = use_resource(move || async move {
+    let companies_resource = use_resource(move || async move {
         let search = company_name_search();
-        unwrap_or_report_and_return!(use_context::<StoreType>().recall_by_name(search).await)
+        let query = Query::new()
+            .name_contains(search)
+            .sort_by(SortBy::DateUpdated, SortOrder::Descending);
+        let companies: Vec<Company> =
+            unwrap_or_report_and_return!(use_context::<StoreType>().recall_by_query(&query).await);
+        companies
     });
-    let reload_companies = use_callback(move |()| companies_resource.restart());
+    use_restart_on_change(companies_resource, &[SearchKind::Company]);
     let companies = companies_resource().unwrap_or_default();
     let companies_list = companies.into_iter().map(Arc::new).map(|company| {
         rsx! {
-            CompanyListItem { company, reload_companies }
+            CompanyListItem { company }
         }
     });
 
     let callback = use_callback(move |_company| {
         // ToDo: Shouldn't the company be selected after creating?
 
-        // Reset search to empty
+        // Reset search to empty, the new company reruns the resource
         company_name_search.set("".to_string());
-
-        // Rerun the resource
-        companies_resource.restart();
     });
 
     let company_search = move |event: Event<FormData>| {
@@ -1,14 +1,27 @@
+use super::contact_list::ContactList;
 use super::flag_list::PopulatedFlagList;
+use super::reminder_list::ReminderList;
 use super::value_list::ValueList;
+use crate::RevisionHistory;
+use application_context::prelude::ApplicationContext;
 use dioxus::prelude::*;
 use std::sync::Arc;
 use storage::prelude::*;
 
 #[component]
 pub fn CompanyDetails(company: Arc<Company>) -> Element {
+    let mut context = use_context::<Signal<ApplicationContext>>();
+    let history_callback = use_callback(move |company: Company| {
+        let new_context = context().set_company(company);
+        context.set(new_context);
+    });
+
     rsx! {
         h2 { {company.get_name()} }
         ValueList { company: company.clone() }
-        PopulatedFlagList { company }
+        PopulatedFlagList { company: company.clone() }
+        ContactList { company: company.clone() }
+        ReminderList { company_id: company.id }
+        RevisionHistory { storable: company, callback: history_callback }
     }
 }
@@ -0,0 +1,52 @@
+use crate::contact_list::forms::edit_contact::EditContact;
+use crate::helpers::delete_with_store;
+use crate::{DeleteButton, Editable, StoreType};
+use dioxus::prelude::*;
+use std::sync::Arc;
+use storage::prelude::*;
+
+#[component]
+pub fn ContactListItem(contact: Arc<Contact>) -> Element {
+    let mut is_editable = use_signal(|| false);
+    let callback = use_callback(move |_contact| is_editable.set(false));
+
+    let delete_callback = use_callback(|_contact| ());
+    let delete = delete_with_store(use_context::<StoreType>(), contact.clone(), delete_callback);
+
+    let display = rsx! {
+        header {
+            "{contact.name}"
+            if !contact.title.is_empty() {
+                " ({contact.title})"
+            }
+        }
+        dl { class: "contact-details",
+            if !contact.email.is_empty() {
+                dt { "Email:" }
+                dd {
+                    a { href: "mailto:{contact.email}", "{contact.email}" }
+                }
+            }
+            if !contact.phone.is_empty() {
+                dt { "Phone:" }
+                dd { "{contact.phone}" }
+            }
+            if !contact.linkedin_url.is_empty() {
+                dt { "LinkedIn:" }
+                dd { "{contact.linkedin_url}" }
+            }
+        }
+        "{contact.notes}"
+    };
+
+    let editable = rsx! {
+        EditContact { contact: contact.clone(), callback }
+    };
+
+    rsx! {
+        li { id: "contact-{contact.id}",
+            Editable { display, editable, is_editable }
+            DeleteButton { onclick: delete }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::helpers::{log_error, store_with_retry};
+use crate::StoreType;
+use dioxus::prelude::*;
+use std::sync::Arc;
+use storage::prelude::{Company, Contact, ContactFieldName, PartialContact};
+
+fn create_on_submit(company: Arc<Company>, callback: Callback<Contact>) -> impl FnMut(FormEvent) {
+    let store = use_context::<StoreType>();
+    move |e: FormEvent| {
+        e.prevent_default();
+        if let Ok(contact) = e
+            .parsed_values::<PartialContact>()
+            .map_err(log_error)
+            .and_then(|form_data| {
+                company
+                    .create_contact_from_partial(form_data)
+                    .map_err(log_error)
+            })
+        {
+            // If the contact was successfully created, save it
+            store_with_retry(store.clone(), contact, callback);
+        }
+    }
+}
+
+#[component]
+pub fn CreateContact(company: Arc<Company>, callback: Callback<Contact>) -> Element {
+    rsx! {
+        form { onsubmit: create_on_submit(company, callback),
+            input {
+                name: ContactFieldName::Name.name(),
+                placeholder: "Name",
+                value: "",
+            }
+            input {
+                name: ContactFieldName::Title.name(),
+                placeholder: "Title",
+                value: "",
+            }
+            input { r#type: "submit" }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::helpers::edit_with_form;
+use crate::StoreType;
+use dioxus::prelude::*;
+use std::sync::Arc;
+use storage::prelude::{Contact, ContactFieldName};
+
+#[component]
+pub fn EditContact(contact: Arc<Contact>, callback: Callback<Contact>) -> Element {
+    rsx! {
+        form { onsubmit: edit_with_form(use_context::<StoreType>(), contact.clone(), callback),
+            dl { class: "contact-details",
+                dt { "Name:" }
+                dd {
+                    input {
+                        name: ContactFieldName::Name.name(),
+                        value: "{contact.name}",
+                    }
+                }
+                dt { "Title:" }
+                dd {
+                    input {
+                        name: ContactFieldName::Title.name(),
+                        value: "{contact.title}",
+                    }
+                }
+                dt { "Email:" }
+                dd {
+                    input {
+                        name: ContactFieldName::Email.name(),
+                        r#type: "email",
+                        value: "{contact.email}",
+                    }
+                }
+                dt { "Phone:" }
+                dd {
+                    input {
+                        name: ContactFieldName::Phone.name(),
+                        r#type: "tel",
+                        value: "{contact.phone}",
+                    }
+                }
+                dt { "LinkedIn:" }
+                dd {
+                    input {
+                        name: ContactFieldName::LinkedinUrl.name(),
+                        r#type: "url",
+                        value: "{contact.linkedin_url}",
+                    }
+                }
+            }
+            textarea { name: ContactFieldName::Notes.name(), value: "{contact.notes}" }
+            input { r#type: "submit" }
+        }
+    }
+}
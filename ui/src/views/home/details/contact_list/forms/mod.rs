@@ -0,0 +1,2 @@
+pub mod create_contact;
+pub mod edit_contact;
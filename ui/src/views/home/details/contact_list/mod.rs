@@ -0,0 +1,7 @@
+mod populated_contact_list;
+pub use populated_contact_list::*;
+
+mod contact_list_item;
+pub use contact_list_item::*;
+
+mod forms;
@@ -0,0 +1,41 @@
+use crate::contact_list::forms::create_contact::CreateContact;
+use crate::contact_list::ContactListItem;
+use crate::helpers::{unwrap_or_report_and_return, use_restart_on_change};
+use crate::StoreType;
+use dioxus::prelude::*;
+use std::sync::Arc;
+use storage::prelude::{Company, Contact, Query, RecallByQuery, SearchKind, SortBy, SortOrder};
+
+#[component]
+pub fn ContactList(company: Arc<Company>) -> Element {
+    let company_id = company.id;
+
+    let contacts_resource = use_resource(use_reactive!(|(company_id)| async move {
+        let query = Query::new()
+            .company(company_id)
+            .sort_by(SortBy::DateUpdated, SortOrder::Descending);
+        let contacts: Vec<Contact> =
+            unwrap_or_report_and_return!(use_context::<StoreType>().recall_by_query(&query).await);
+
+        contacts.into_iter().map(Arc::new).collect::<Vec<_>>()
+    }));
+
+    use_restart_on_change(contacts_resource, &[SearchKind::Contact]);
+    let contacts = contacts_resource().unwrap_or_default();
+    let contacts_list = contacts.iter().cloned().map(move |contact| {
+        rsx! {
+            ContactListItem { contact }
+        }
+    });
+
+    let callback = use_callback(|_contact| ());
+
+    rsx! {
+        div { id: "contacts",
+            h3 { "Contacts" }
+            ul { {contacts_list} }
+
+            CreateContact { company, callback }
+        }
+    }
+}
@@ -1,11 +1,12 @@
 use crate::flag_list::forms::edit_flag::EditFlag;
-use crate::Editable;
+use crate::helpers::delete_with_store;
+use crate::{DeleteButton, Editable, StoreType};
 use dioxus::prelude::*;
 use std::sync::Arc;
 use storage::prelude::*;
 
 #[component]
-pub fn FlagListItem(flag: Arc<Flag>, reload_flags: Callback) -> Element {
+pub fn FlagListItem(flag: Arc<Flag>) -> Element {
     let is_editable = use_signal(|| false);
 
     let id = flag.id;
@@ -15,7 +16,8 @@ pub fn FlagListItem(flag: Arc<Flag>, reload_flags: Callback) -> Element {
         FlagColor::Red => "🚩",
     };
 
-    let callback = use_callback(move |_flag| reload_flags(()));
+    let callback = use_callback(|_flag| ());
+    let delete = delete_with_store(use_context::<StoreType>(), flag.clone(), callback);
 
     let display = rsx! { "{flag_icon} {flag.name}" };
     let editable: Element = rsx! {
@@ -25,6 +27,7 @@ pub fn FlagListItem(flag: Arc<Flag>, reload_flags: Callback) -> Element {
     rsx! {
         li { id: "flag-{id}",
             Editable { display, editable, is_editable }
+            DeleteButton { onclick: delete }
         }
     }
 }
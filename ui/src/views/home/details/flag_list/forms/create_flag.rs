@@ -1,40 +1,55 @@
-use crate::helpers::log_error;
+use crate::components::FieldErrorMessage;
+use crate::helpers::{invalid_class, log_error, revalidate_on_input, store_with_retry};
 use crate::StoreType;
 use dioxus::prelude::*;
 use std::sync::Arc;
-use storage::prelude::{BaseStore, Company, Flag, FlagFieldName, PartialFlag};
+use storage::prelude::{
+    CheckPartialComplete, Company, Flag, FlagFieldName, IncompletePartialErrors, PartialFlag,
+};
 
-fn create_on_submit(company: Arc<Company>, callback: Callback<Flag>) -> impl FnMut(FormEvent) {
+fn create_on_submit(
+    company: Arc<Company>,
+    callback: Callback<Flag>,
+    mut errors: Signal<IncompletePartialErrors>,
+) -> impl FnMut(FormEvent) {
+    let store = use_context::<StoreType>();
     move |e: FormEvent| {
         e.prevent_default();
-        if let Ok(flag) = e
-            .parsed_values::<PartialFlag>()
-            .map_err(log_error)
-            .and_then(|form_data| {
-                company
-                    .create_flag_from_partial(form_data)
-                    .map_err(log_error)
-            })
-        {
-            spawn(async move {
-                let mut stores = use_context::<StoreType>();
-                stores.store(flag.clone()).await.unwrap_or_else(log_error);
-                callback(flag);
-            });
+        let Ok(form_data) = e.parsed_values::<PartialFlag>().map_err(log_error) else {
+            return;
+        };
+        match company.create_flag_from_partial(form_data) {
+            Ok(flag) => {
+                errors.set(IncompletePartialErrors::default());
+                store_with_retry(store.clone(), flag, callback);
+            }
+            Err(failed) => errors.set(failed),
         }
     }
 }
 
 #[component]
 pub fn CreateFlag(company: Arc<Company>, callback: Callback<Flag>) -> Element {
+    let errors = use_signal(IncompletePartialErrors::default);
     rsx! {
-        form { onsubmit: create_on_submit(company, callback),
-            select { id: "flag_color", name: FlagFieldName::FlagColor.name(),
+        form {
+            onsubmit: create_on_submit(company, callback, errors),
+            oninput: revalidate_on_input(errors, PartialFlag::check_complete),
+            select {
+                id: "flag_color",
+                class: invalid_class(errors, FlagFieldName::FlagColor),
+                name: FlagFieldName::FlagColor.name(),
                 option { value: "red", "🚩 Red" }
                 option { value: "green", "💚 Green" }
             }
-            input { id: "add_flag", name: FlagFieldName::Name.name() }
-            input { r#type: "submit" }
+            input {
+                id: "add_flag",
+                class: invalid_class(errors, FlagFieldName::Name),
+                name: FlagFieldName::Name.name(),
+            }
+            input { r#type: "submit", disabled: !errors.read().is_empty() }
+            FieldErrorMessage { errors, field: FlagFieldName::FlagColor }
+            FieldErrorMessage { errors, field: FlagFieldName::Name }
         }
     }
 }
@@ -1,6 +1,6 @@
 use super::flag_list_item::FlagListItem;
 use crate::flag_list::forms::create_flag::CreateFlag;
-use crate::helpers::unwrap_or_report_and_return;
+use crate::helpers::{unwrap_or_report_and_return, use_restart_on_change};
 use crate::StoreType;
 use dioxus::prelude::*;
 use std::sync::Arc;
@@ -11,22 +11,23 @@ pub fn PopulatedFlagList(company: Arc<Company>) -> Element {
     let company_id = company.id;
 
     // Get flags for company
-    let mut flags_resource = use_resource(use_reactive!(|(company_id)| async move {
-        unwrap_or_report_and_return!(
-            use_context::<StoreType>()
-                .recall_by_company(company_id)
-                .await
-        )
+    let flags_resource = use_resource(use_reactive!(|(company_id)| async move {
+        let query = Query::new()
+            .company(company_id)
+            .sort_by(SortBy::DateUpdated, SortOrder::Descending);
+        let flags: Vec<Flag> =
+            unwrap_or_report_and_return!(use_context::<StoreType>().recall_by_query(&query).await);
+        flags
     }));
-    let reload_flags = use_callback(move |()| flags_resource.restart());
+    use_restart_on_change(flags_resource, &[SearchKind::Flag]);
     let flags = flags_resource().unwrap_or_default();
     let flags_list = flags.into_iter().map(Arc::new).map(|flag| {
         rsx! {
-            FlagListItem { flag, reload_flags }
+            FlagListItem { flag }
         }
     });
 
-    let callback = use_callback(move |_flag| flags_resource.restart());
+    let callback = use_callback(|_flag| ());
 
     rsx! {
         div { id: "flags",
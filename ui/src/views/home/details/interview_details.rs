@@ -1,7 +1,10 @@
-use super::interviews::InterviewNav;
-use crate::helpers::unwrap_or_report_and_return;
+use super::interviews::{InterviewContacts, InterviewNav};
+use crate::helpers::{
+    create_route, delete_with_store, describe_in_zones, unwrap_or_report_and_return,
+    use_local_zone, use_restart_on_change,
+};
 use crate::interviews::forms::EditInterview;
-use crate::{Editable, StoreType};
+use crate::{DeleteButton, DetailsView, Editable, RevisionHistory, StoreType};
 use application_context::prelude::*;
 use dioxus::prelude::*;
 use std::sync::Arc;
@@ -9,9 +12,10 @@ use storage::prelude::*;
 
 #[component]
 fn InterviewDetailsDisplay(interview: Arc<Interview>) -> Element {
+    let local = use_local_zone();
     let when = interview
         .date_time
-        .map(|t| t.to_string())
+        .map(|t| describe_in_zones(&t, local))
         .unwrap_or_default();
     rsx! {
         dl { class: "interview-details",
@@ -35,23 +39,24 @@ pub fn InterviewDetails(role: Arc<Role>) -> Element {
     let resource_store = store.clone();
 
     // Forcibly load a new version of the interview from the DB
-    let mut interview_resource: Resource<Option<Arc<Interview>>> =
+    let interview_resource: Resource<Option<Arc<Interview>>> =
         use_resource(use_reactive!(|interview| {
             let interview = interview.clone();
             let store = resource_store.clone();
             async move {
                 if let Some(interview) = interview {
                     let id = interview.id;
-                    Some(unwrap_or_report_and_return!(store
-                        .recall_by_id(id)
-                        .await
-                        .map(Arc::new)))
+                    Some(unwrap_or_report_and_return!(
+                        store.recall_shared_by_id(id).await
+                    ))
                 } else {
                     None
                 }
             }
         }));
 
+    use_restart_on_change(interview_resource, &[SearchKind::Interview]);
+
     let interview = interview_resource().unwrap_or_default();
 
     let Some(interview) = interview else {
@@ -62,16 +67,35 @@ pub fn InterviewDetails(role: Arc<Role>) -> Element {
         };
     };
 
-    let callback = use_callback(move |_interview| {
-        interview_resource.restart();
-        is_editable.set(false);
+    let callback = use_callback(move |_interview| is_editable.set(false));
+
+    let contacts_callback = use_callback(|_interview| ());
+    let history_callback = use_callback(|_interview| ());
+
+    let company_id = role.company_id;
+    let role_id = role.id;
+    let delete_callback = use_callback(move |_interview| {
+        navigator().push(create_route(
+            Some(company_id),
+            Some(role_id),
+            None,
+            Some(DetailsView::Role),
+        ));
     });
+    let delete = delete_with_store(store, interview.clone(), delete_callback);
 
     let display = rsx! {
         InterviewDetailsDisplay { interview: interview.clone() }
     };
+    let contacts = rsx! {
+        InterviewContacts {
+            interview: interview.clone(),
+            company_id: role.company_id,
+            callback: contacts_callback,
+        }
+    };
     let editable = rsx! {
-        EditInterview { interview, callback }
+        EditInterview { interview: interview.clone(), callback }
     };
 
     rsx! {
@@ -80,6 +104,9 @@ pub fn InterviewDetails(role: Arc<Role>) -> Element {
 
         div {
             Editable { display, editable, is_editable }
+            DeleteButton { onclick: delete }
+            {contacts}
+            RevisionHistory { storable: interview, callback: history_callback }
         }
     }
 }
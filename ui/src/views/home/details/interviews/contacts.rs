@@ -0,0 +1,87 @@
+use crate::helpers::{log_error, unwrap_or_report_and_return, use_restart_on_change};
+use crate::StoreType;
+use dioxus::prelude::*;
+use std::sync::Arc;
+use storage::prelude::*;
+use uuid::Uuid;
+
+fn store_interview(interview: Interview, callback: Callback<Interview>) {
+    spawn(async move {
+        let mut stores = use_context::<StoreType>();
+        if let Err(e) = stores.store(interview.clone()).await {
+            log_error(e);
+            return;
+        }
+        callback(interview);
+    });
+}
+
+#[component]
+pub fn InterviewContacts(
+    interview: Arc<Interview>,
+    company_id: Uuid,
+    callback: Callback<Interview>,
+) -> Element {
+    let contacts_resource = use_resource(use_reactive!(|(company_id)| async move {
+        let contacts: Vec<Contact> = unwrap_or_report_and_return!(
+            use_context::<StoreType>()
+                .recall_by_company(company_id)
+                .await
+        );
+        contacts
+    }));
+    use_restart_on_change(contacts_resource, &[SearchKind::Contact]);
+    let contacts = contacts_resource().unwrap_or_default();
+
+    let (linked, unlinked): (Vec<Contact>, Vec<Contact>) = contacts
+        .into_iter()
+        .partition(|contact| interview.has_contact(contact));
+
+    let remove_interview = interview.clone();
+    let linked_list = linked.into_iter().map(move |contact| {
+        let interview = remove_interview.clone();
+        let contact_id = contact.id;
+        rsx! {
+            li { key: "{contact_id}",
+                "{contact.name}"
+                if !contact.title.is_empty() {
+                    " ({contact.title})"
+                }
+                " "
+                button {
+                    r#type: "button",
+                    onclick: move |_| {
+                        let mut interview = Interview::clone(&interview);
+                        if interview.remove_contact(contact_id) {
+                            store_interview(interview, callback);
+                        }
+                    },
+                    "❌"
+                }
+            }
+        }
+    });
+
+    let add_contact = move |e: FormEvent| {
+        let Ok(contact_id) = Uuid::parse_str(&e.value()) else {
+            return;
+        };
+        let mut interview = Interview::clone(&interview);
+        if interview.add_contact(contact_id) {
+            store_interview(interview, callback);
+        }
+    };
+
+    rsx! {
+        h4 { "Contacts" }
+        ul { {linked_list} }
+        if !unlinked.is_empty() {
+            select { onchange: add_contact,
+                option { value: "", selected: true, "+ Contact" }
+                for contact in unlinked {
+                    option { value: "{contact.id}", "{contact.name}" }
+                }
+            }
+        }
+    }
+}
@@ -1,10 +1,11 @@
-use crate::helpers::log_error;
+use crate::helpers::{log_error, store_with_retry};
 use crate::StoreType;
 use dioxus::prelude::*;
 use std::sync::Arc;
-use storage::prelude::{BaseStore, Interview, InterviewFieldName, PartialInterview, Role};
+use storage::prelude::{Interview, InterviewFieldName, PartialInterview, Role};
 
 fn create_on_submit(role: Arc<Role>, callback: Callback<Interview>) -> impl FnMut(FormEvent) {
+    let store = use_context::<StoreType>();
     move |e: FormEvent| {
         e.prevent_default();
         if let Ok(interview) = e
@@ -16,14 +17,7 @@ fn create_on_submit(role: Arc<Role>, callback: Callback<Interview>) -> impl FnMu
             })
         {
             // If the role was successfully created, save it
-            spawn(async move {
-                let mut stores = use_context::<StoreType>();
-                stores
-                    .store(interview.clone())
-                    .await
-                    .unwrap_or_else(log_error);
-                callback(interview);
-            });
+            store_with_retry(store.clone(), interview, callback);
         }
     }
 }
@@ -1,24 +1,39 @@
-use crate::helpers::edit_with_form;
+use crate::components::{FieldErrorMessage, TimestampInput};
+use crate::helpers::{
+    check_edit, edit_with_validated_form, invalid_class, revalidate_on_input, use_local_zone,
+};
 use crate::StoreType;
 use dioxus::prelude::*;
 use std::sync::Arc;
-use storage::prelude::{Interview, InterviewFieldName};
+use storage::prelude::{IncompletePartialErrors, Interview, InterviewFieldName, PartialInterview};
 
 #[component]
 pub fn EditInterview(interview: Arc<Interview>, callback: Callback<Interview>) -> Element {
+    // Shown in the zone it was arranged in, usually the interviewer's
+    let local = use_local_zone();
+    let zone = interview.date_time.and_then(|t| t.zone()).unwrap_or(local);
     let when = interview
         .date_time
-        .map(|t| t.to_string())
+        .map(|t| t.in_zone(zone).format("%Y-%m-%d %H:%M").to_string())
         .unwrap_or_default();
+    let errors = use_signal(IncompletePartialErrors::default);
     rsx! {
-        form { onsubmit: edit_with_form(use_context::<StoreType>(), interview.clone(), callback),
+        form {
+            onsubmit: edit_with_validated_form(
+                use_context::<StoreType>(),
+                interview.clone(),
+                callback,
+                errors,
+            ),
+            oninput: revalidate_on_input(errors, check_edit::<PartialInterview>),
             dl { class: "interview-details",
                 dt { "When:" }
                 dd {
-                    input {
+                    TimestampInput {
                         name: InterviewFieldName::DateTime.name(),
-                        r#type: "datetime-local",
-                        value: "{when}",
+                        value: when,
+                        zone,
+                        choose_zone: true,
                     }
                 }
                 dt { "Who:" }
@@ -31,9 +46,11 @@ pub fn EditInterview(interview: Arc<Interview>, callback: Callback<Interview>) -
                 dt { "Interview:" }
                 dd {
                     input {
+                        class: invalid_class(errors, InterviewFieldName::Name),
                         name: InterviewFieldName::Name.name(),
                         value: "{interview.name}",
                     }
+                    FieldErrorMessage { errors, field: InterviewFieldName::Name }
                 }
             }
             section {
@@ -42,7 +59,7 @@ pub fn EditInterview(interview: Arc<Interview>, callback: Callback<Interview>) -
                     value: "{interview.notes}",
                 }
             }
-            input { r#type: "submit" }
+            input { r#type: "submit", disabled: !errors.read().is_empty() }
         }
     }
 }
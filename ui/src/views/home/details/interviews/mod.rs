@@ -1,4 +1,7 @@
 mod nav;
 pub use nav::*;
 
+mod contacts;
+pub use contacts::*;
+
 pub mod forms;
@@ -1,4 +1,4 @@
-use crate::helpers::create_route;
+use crate::helpers::{create_route, use_restart_on_change};
 use crate::interviews::forms::CreateInterview;
 use crate::{DetailsView, StoreType};
 use dioxus::prelude::*;
@@ -8,19 +8,21 @@ use uuid::Uuid;
 
 #[component]
 pub fn InterviewNav(role: Arc<Role>) -> Element {
-    let mut interview_resource = use_resource(use_reactive!(|role| async move {
-        use_context::<StoreType>()
-            .recall_by_role(role.id)
+    let interview_resource = use_resource(use_reactive!(|role| async move {
+        let query = Query::new()
+            .role(role.id)
+            .sort_by(SortBy::DateUpdated, SortOrder::Descending);
+        let interviews: Vec<Interview> = use_context::<StoreType>()
+            .recall_by_query(&query)
             .await
-            .unwrap_or_default()
+            .unwrap_or_default();
+        interviews
     }));
+    use_restart_on_change(interview_resource, &[SearchKind::Interview]);
     let interviews: Vec<Interview> = interview_resource().unwrap_or_default();
 
     let mut is_editable = use_signal(|| false);
-    let callback = use_callback(move |_interview| {
-        is_editable.set(false);
-        interview_resource.restart();
-    });
+    let callback = use_callback(move |_interview| is_editable.set(false));
 
     let company_id = role.company_id;
     let role_id = role.id;
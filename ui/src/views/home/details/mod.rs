@@ -7,10 +7,12 @@ pub use questions_details::*;
 mod role_details;
 pub use role_details::*;
 
+pub mod contact_list;
 pub mod flag_list;
 mod interview_details;
 pub mod interviews;
 pub mod questions_list;
+pub mod reminder_list;
 pub mod role_information;
 pub mod value_list;
 
@@ -1,10 +1,11 @@
-use crate::helpers::log_error;
+use crate::helpers::{log_error, store_with_retry};
 use crate::StoreType;
 use dioxus::prelude::*;
 use std::sync::Arc;
-use storage::prelude::{BaseStore, PartialQuestion, Question, QuestionFieldName, Role};
+use storage::prelude::{PartialQuestion, Question, QuestionFieldName, Role};
 
 fn create_on_submit(role: Arc<Role>, callback: Callback<Question>) -> impl FnMut(FormEvent) {
+    let store = use_context::<StoreType>();
     move |e: FormEvent| {
         e.prevent_default();
         if let Ok(question) = e
@@ -16,14 +17,7 @@ fn create_on_submit(role: Arc<Role>, callback: Callback<Question>) -> impl FnMut
             })
         {
             // If the role was successfully created, save it
-            spawn(async move {
-                let mut stores = use_context::<StoreType>();
-                stores
-                    .store(question.clone())
-                    .await
-                    .unwrap_or_else(log_error);
-                callback(question);
-            });
+            store_with_retry(store.clone(), question, callback);
         }
     }
 }
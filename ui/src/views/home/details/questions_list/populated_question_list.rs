@@ -1,4 +1,4 @@
-use crate::helpers::unwrap_or_report_and_return;
+use crate::helpers::{unwrap_or_report_and_return, use_restart_on_change};
 use crate::questions_list::forms::CreateQuestion;
 use crate::questions_list::QuestionListItem;
 use crate::StoreType;
@@ -10,21 +10,24 @@ use storage::prelude::*;
 pub fn QuestionList(role: Arc<Role>) -> Element {
     let role_id = role.id;
 
-    let mut questions_resource = use_resource(use_reactive!(|(role_id)| async move {
+    let questions_resource = use_resource(use_reactive!(|(role_id)| async move {
+        let query = Query::new()
+            .role(role_id)
+            .sort_by(SortBy::DateUpdated, SortOrder::Descending);
         let questions: Vec<Question> =
-            unwrap_or_report_and_return!(use_context::<StoreType>().recall_by_role(role_id).await);
+            unwrap_or_report_and_return!(use_context::<StoreType>().recall_by_query(&query).await);
         questions
     }));
 
-    let reload_questions = use_callback(move |()| questions_resource.restart());
+    use_restart_on_change(questions_resource, &[SearchKind::Question]);
     let questions = questions_resource().unwrap_or_default();
     let questions_list = questions.into_iter().map(Arc::new).map(move |question| {
         rsx! {
-            QuestionListItem { question, reload_questions }
+            QuestionListItem { question }
         }
     });
 
-    let callback = use_callback(move |_question| questions_resource.restart());
+    let callback = use_callback(|_question| ());
 
     rsx! {
         div { id: "flags",
@@ -1,11 +1,12 @@
+use crate::helpers::delete_with_store;
 use crate::questions_list::forms::EditQuestion;
-use crate::Editable;
+use crate::{DeleteButton, Editable, StoreType};
 use dioxus::prelude::*;
 use std::sync::Arc;
 use storage::prelude::*;
 
 #[component]
-pub fn QuestionListItem(question: Arc<Question>, reload_questions: Callback) -> Element {
+pub fn QuestionListItem(question: Arc<Question>) -> Element {
     let is_editable = use_signal(|| false);
 
     let id = question.id;
@@ -15,7 +16,8 @@ pub fn QuestionListItem(question: Arc<Question>, reload_questions: Callback) ->
         "{question.answer}"
     };
 
-    let callback = use_callback(move |_question| reload_questions(()));
+    let callback = use_callback(|_question| ());
+    let delete = delete_with_store(use_context::<StoreType>(), question.clone(), callback);
 
     let editable = rsx! {
         EditQuestion { question, callback }
@@ -24,6 +26,7 @@ pub fn QuestionListItem(question: Arc<Question>, reload_questions: Callback) ->
     rsx! {
         li { id: "question-{id}",
             Editable { display, editable, is_editable }
+            DeleteButton { onclick: delete }
         }
     }
 }
@@ -0,0 +1,70 @@
+use crate::components::{FieldErrorMessage, TimestampInput};
+use crate::helpers::{invalid_class, log_error, revalidate_on_input, store_with_retry};
+use crate::StoreType;
+use dioxus::prelude::*;
+use std::sync::Arc;
+use storage::prelude::{
+    CheckPartialComplete, IncompletePartialErrors, PartialReminder, Recurrence, Reminder,
+    ReminderFieldName, Role,
+};
+use uuid::Uuid;
+
+fn create_on_submit(
+    company_id: Uuid,
+    role: Option<Arc<Role>>,
+    callback: Callback<Reminder>,
+    mut errors: Signal<IncompletePartialErrors>,
+) -> impl FnMut(FormEvent) {
+    let store = use_context::<StoreType>();
+    move |e: FormEvent| {
+        e.prevent_default();
+        let Ok(form_data) = e.parsed_values::<PartialReminder>().map_err(log_error) else {
+            return;
+        };
+        let reminder = match &role {
+            Some(role) => role.create_reminder_from_partial(form_data),
+            None => Reminder::new_from_partial(company_id, form_data),
+        };
+        match reminder {
+            Ok(reminder) => {
+                errors.set(IncompletePartialErrors::default());
+                store_with_retry(store.clone(), reminder, callback);
+            }
+            Err(failed) => errors.set(failed),
+        }
+    }
+}
+
+#[component]
+pub fn CreateReminder(
+    company_id: Uuid,
+    role: Option<Arc<Role>>,
+    callback: Callback<Reminder>,
+) -> Element {
+    let errors = use_signal(IncompletePartialErrors::default);
+    rsx! {
+        form {
+            onsubmit: create_on_submit(company_id, role, callback, errors),
+            oninput: revalidate_on_input(errors, PartialReminder::check_complete),
+            input {
+                class: invalid_class(errors, ReminderFieldName::Name),
+                name: ReminderFieldName::Name.name(),
+                placeholder: "Follow up on…",
+                value: "",
+            }
+            FieldErrorMessage { errors, field: ReminderFieldName::Name }
+            TimestampInput {
+                class: invalid_class(errors, ReminderFieldName::Due),
+                name: ReminderFieldName::Due.name(),
+                value: "next week",
+            }
+            FieldErrorMessage { errors, field: ReminderFieldName::Due }
+            select { name: ReminderFieldName::Recurrence.name(),
+                for recurrence in Recurrence::ALL {
+                    option { value: recurrence.as_str(), "{recurrence}" }
+                }
+            }
+            input { r#type: "submit", disabled: !errors.read().is_empty() }
+        }
+    }
+}
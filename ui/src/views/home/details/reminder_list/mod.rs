@@ -0,0 +1,7 @@
+mod populated_reminder_list;
+pub use populated_reminder_list::*;
+
+mod reminder_list_item;
+pub use reminder_list_item::*;
+
+mod forms;
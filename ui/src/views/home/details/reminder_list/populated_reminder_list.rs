@@ -0,0 +1,45 @@
+use crate::helpers::{unwrap_or_report_and_return, use_restart_on_change};
+use crate::reminder_list::forms::create_reminder::CreateReminder;
+use crate::reminder_list::ReminderListItem;
+use crate::StoreType;
+use dioxus::prelude::*;
+use std::sync::Arc;
+use storage::prelude::{Query, RecallByQuery, Reminder, Role, SearchKind, SortBy, SortOrder};
+use uuid::Uuid;
+
+/// Reminders for the company, or only those for the role when one is given
+#[component]
+pub fn ReminderList(company_id: Uuid, role: Option<Arc<Role>>) -> Element {
+    let role_id = role.as_ref().map(|role| role.id);
+
+    let reminders_resource = use_resource(use_reactive!(|(company_id, role_id)| async move {
+        let query = match role_id {
+            Some(role_id) => Query::new().role(role_id),
+            None => Query::new().company(company_id),
+        }
+        .sort_by(SortBy::Date, SortOrder::Ascending);
+        let reminders: Vec<Reminder> =
+            unwrap_or_report_and_return!(use_context::<StoreType>().recall_by_query(&query).await);
+
+        reminders.into_iter().map(Arc::new).collect::<Vec<_>>()
+    }));
+
+    use_restart_on_change(reminders_resource, &[SearchKind::Reminder]);
+    let reminders = reminders_resource().unwrap_or_default();
+    let reminders_list = reminders.iter().cloned().map(move |reminder| {
+        rsx! {
+            ReminderListItem { reminder }
+        }
+    });
+
+    let callback = use_callback(|_reminder| ());
+
+    rsx! {
+        div { id: "reminders",
+            h3 { "Reminders" }
+            ul { {reminders_list} }
+
+            CreateReminder { company_id, role, callback }
+        }
+    }
+}
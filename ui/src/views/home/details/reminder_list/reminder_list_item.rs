@@ -0,0 +1,51 @@
+use crate::helpers::{
+    complete_with_store, delete_with_store, describe_in_zones, store_with_retry, use_local_zone,
+};
+use crate::{DeleteButton, StoreType};
+use dioxus::prelude::*;
+use std::sync::Arc;
+use storage::prelude::*;
+
+#[component]
+pub fn ReminderListItem(reminder: Arc<Reminder>) -> Element {
+    let store = use_context::<StoreType>();
+    let callback = use_callback(|_reminder| ());
+    let complete = complete_with_store(store.clone(), reminder.clone(), callback);
+    let delete = delete_with_store(store.clone(), reminder.clone(), callback);
+    let reopen_reminder = reminder.clone();
+    let reopen = move |_: MouseEvent| {
+        let mut reminder = Reminder::clone(&reopen_reminder);
+        reminder.reopen();
+        store_with_retry(store.clone(), reminder, callback);
+    };
+
+    let due = describe_in_zones(&reminder.due, use_local_zone());
+    let status = if reminder.is_complete() {
+        "reminder-complete"
+    } else if reminder.is_overdue(Timestamp::now()) {
+        "reminder-overdue"
+    } else {
+        ""
+    };
+
+    rsx! {
+        li { id: "reminder-{reminder.id}", class: status,
+            header { "{reminder.name}" }
+            small {
+                "Due {due}"
+                if reminder.recurrence != Recurrence::Never {
+                    ", repeats {reminder.recurrence.as_str()}"
+                }
+            }
+            if !reminder.notes.is_empty() {
+                p { "{reminder.notes}" }
+            }
+            if reminder.is_complete() {
+                button { r#type: "button", onclick: reopen, "Reopen" }
+            } else {
+                button { r#type: "button", onclick: complete, "Done" }
+            }
+            DeleteButton { onclick: delete }
+        }
+    }
+}
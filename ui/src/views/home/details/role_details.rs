@@ -1,12 +1,30 @@
+use crate::helpers::unwrap_or_report_and_return;
+use crate::reminder_list::ReminderList;
+use crate::role_information::role_compensation::RoleCompensation;
 use crate::role_information::role_description::RoleDescription;
+use crate::role_information::role_posting::RolePosting;
+use crate::role_information::role_status::RoleStatusSelector;
+use crate::RevisionHistory;
+use application_context::prelude::ApplicationContext;
 use dioxus::prelude::*;
 use std::sync::Arc;
 use storage::prelude::*;
 
 #[component]
 pub fn RoleDetails(role: Arc<Role>) -> Element {
+    let mut context = use_context::<Signal<ApplicationContext>>();
+    let history_callback = use_callback(move |role: Role| {
+        let new_context = unwrap_or_report_and_return!(context().set_role(role));
+        context.set(new_context);
+    });
+
     rsx! {
         h2 { "{role.get_name()}" }
-        RoleDescription { role }
+        RoleStatusSelector { role: role.clone() }
+        RoleCompensation { role: role.clone() }
+        RoleDescription { role: role.clone() }
+        RolePosting { role: role.clone() }
+        ReminderList { company_id: role.company_id, role: role.clone() }
+        RevisionHistory { storable: role, callback: history_callback }
     }
 }
@@ -0,0 +1,40 @@
+use crate::helpers::capture_posting_with_retry;
+use crate::StoreType;
+use dioxus::prelude::*;
+use std::sync::Arc;
+use storage::prelude::{Posting, Role};
+
+#[component]
+pub fn CapturePosting(role: Arc<Role>, callback: Callback<Role>) -> Element {
+    let store = use_context::<StoreType>();
+    let mut pasted = use_signal(String::new);
+
+    rsx! {
+        form {
+            onsubmit: move |e: FormEvent| {
+                e.prevent_default();
+                let posting = Posting::parse(pasted());
+                if posting.is_empty() {
+                    return;
+                }
+                capture_posting_with_retry(
+                    store.clone(),
+                    Role::clone(&role),
+                    Arc::new(posting),
+                    callback,
+                );
+            },
+            textarea {
+                class: "posting-paste",
+                placeholder: "Paste the job advert (HTML or markdown) to keep a copy of it",
+                value: "{pasted}",
+                oninput: move |e: FormEvent| pasted.set(e.value()),
+            }
+            input {
+                r#type: "submit",
+                value: "Capture",
+                disabled: pasted.read().trim().is_empty(),
+            }
+        }
+    }
+}
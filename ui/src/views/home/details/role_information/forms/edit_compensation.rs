@@ -0,0 +1,77 @@
+use crate::components::FieldErrorMessage;
+use crate::helpers::{check_edit, edit_with_validated_form, invalid_class, revalidate_on_input};
+use crate::StoreType;
+use dioxus::prelude::*;
+use std::sync::Arc;
+use storage::prelude::{IncompletePartialErrors, PartialRole, PayPeriod, Role, RoleFieldName};
+
+#[component]
+pub fn EditRoleCompensation(role: Arc<Role>, callback: Callback<Role>) -> Element {
+    let salary_min = role.salary_min.map(|n| n.to_string()).unwrap_or_default();
+    let salary_max = role.salary_max.map(|n| n.to_string()).unwrap_or_default();
+    let bonus = role.bonus.map(|n| n.to_string()).unwrap_or_default();
+    let current_period = role.pay_period;
+    let errors = use_signal(IncompletePartialErrors::default);
+
+    rsx! {
+        form {
+            onsubmit: edit_with_validated_form(
+                use_context::<StoreType>(),
+                role.clone(),
+                callback,
+                errors,
+            ),
+            oninput: revalidate_on_input(errors, check_edit::<PartialRole>),
+            dl { class: "role-compensation",
+                dt { "Salary:" }
+                dd {
+                    input {
+                        class: invalid_class(errors, RoleFieldName::SalaryMin),
+                        name: RoleFieldName::SalaryMin.name(),
+                        inputmode: "numeric",
+                        placeholder: "Min",
+                        value: "{salary_min}",
+                    }
+                    " - "
+                    input {
+                        name: RoleFieldName::SalaryMax.name(),
+                        inputmode: "numeric",
+                        placeholder: "Max",
+                        value: "{salary_max}",
+                    }
+                    input {
+                        class: invalid_class(errors, RoleFieldName::Currency),
+                        name: RoleFieldName::Currency.name(),
+                        placeholder: "Currency",
+                        maxlength: 3,
+                        value: "{role.currency}",
+                    }
+                    select { name: RoleFieldName::PayPeriod.name(),
+                        for period in PayPeriod::ALL {
+                            option {
+                                selected: period == current_period,
+                                value: period.as_str(),
+                                "{period}"
+                            }
+                        }
+                    }
+                    FieldErrorMessage { errors, field: RoleFieldName::SalaryMin }
+                    FieldErrorMessage { errors, field: RoleFieldName::Currency }
+                }
+                dt { "Bonus:" }
+                dd {
+                    input {
+                        name: RoleFieldName::Bonus.name(),
+                        inputmode: "numeric",
+                        value: "{bonus}",
+                    }
+                }
+                dt { "Equity:" }
+                dd {
+                    input { name: RoleFieldName::Equity.name(), value: "{role.equity}" }
+                }
+            }
+            input { r#type: "submit", disabled: !errors.read().is_empty() }
+        }
+    }
+}
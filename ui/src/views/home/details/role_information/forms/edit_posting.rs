@@ -0,0 +1,32 @@
+use crate::helpers::edit_with_form;
+use crate::StoreType;
+use dioxus::prelude::*;
+use std::sync::Arc;
+use storage::prelude::{Role, RoleFieldName};
+
+#[component]
+pub fn EditRolePosting(role: Arc<Role>, callback: Callback<Role>) -> Element {
+    rsx! {
+        form { onsubmit: edit_with_form(use_context::<StoreType>(), role.clone(), callback),
+            dl { class: "role-posting",
+                dt { "Posting:" }
+                dd {
+                    input {
+                        r#type: "url",
+                        name: RoleFieldName::PostingUrl.name(),
+                        value: "{role.posting_url}",
+                    }
+                }
+                dt { "Source:" }
+                dd {
+                    input { name: RoleFieldName::Source.name(), value: "{role.source}" }
+                }
+                dt { "Location:" }
+                dd {
+                    input { name: RoleFieldName::Location.name(), value: "{role.location}" }
+                }
+            }
+            input { r#type: "submit" }
+        }
+    }
+}
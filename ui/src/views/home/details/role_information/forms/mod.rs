@@ -1,2 +1,11 @@
 mod edit_description;
 pub use edit_description::*;
+
+mod edit_compensation;
+pub use edit_compensation::*;
+
+mod edit_posting;
+pub use edit_posting::*;
+
+mod capture_posting;
+pub use capture_posting::*;
@@ -1,4 +1,7 @@
 mod populated_role_description;
+pub mod role_compensation;
 pub mod role_description;
+pub mod role_posting;
+pub mod role_status;
 
 mod forms;
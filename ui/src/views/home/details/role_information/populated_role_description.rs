@@ -4,10 +4,8 @@ use storage::prelude::*;
 
 #[component]
 pub fn PopulatedRoleDescription(role: Arc<Role>) -> Element {
-    // Turn Markdown into markup
-    let parser = pulldown_cmark::Parser::new(&role.description);
-    let mut role_description_html = String::new();
-    pulldown_cmark::html::push_html(&mut role_description_html, parser);
+    // Turn Markdown into markup, descriptions are often pasted or imported from job adverts
+    let role_description_html = markdown_to_html(&role.description);
 
     rsx! {
         div { dangerous_inner_html: role_description_html }
@@ -0,0 +1,81 @@
+use crate::components::Editable;
+use crate::helpers::{unwrap_or_report_and_return, use_exchange_rates};
+use crate::role_information::forms::EditRoleCompensation;
+use application_context::prelude::ApplicationContext;
+use dioxus::prelude::*;
+use std::sync::Arc;
+use storage::prelude::*;
+
+fn format_range(min: Option<u64>, max: Option<u64>) -> String {
+    match (min, max) {
+        (Some(min), Some(max)) if min != max => format!("{min} - {max}"),
+        (Some(amount), _) | (None, Some(amount)) => amount.to_string(),
+        (None, None) => String::new(),
+    }
+}
+
+#[component]
+fn PopulatedRoleCompensation(role: Arc<Role>) -> Element {
+    let exchange_rates = use_exchange_rates()();
+    let salary = format_range(role.salary_min, role.salary_max);
+    // Only worth showing the normalised figure when it differs from what was entered
+    let normalised = role
+        .normalised_compensation(&exchange_rates)
+        .filter(|_| {
+            role.pay_period != PayPeriod::Annual
+                || role.currency != exchange_rates.get_base_currency()
+        })
+        .map(|n| {
+            let range = format_range(Some(n.salary_min), Some(n.salary_max));
+            format!(" (≈ {range} {} per year)", n.currency)
+        });
+
+    rsx! {
+        dl { class: "role-compensation",
+            dt { "Salary:" }
+            dd {
+                if role.has_compensation() {
+                    "{salary} {role.currency} {role.pay_period}"
+                } else {
+                    "Unknown"
+                }
+                if let Some(normalised) = normalised {
+                    "{normalised}"
+                }
+            }
+            if let Some(bonus) = role.bonus {
+                dt { "Bonus:" }
+                dd { "{bonus} {role.currency}" }
+            }
+            if !role.equity.is_empty() {
+                dt { "Equity:" }
+                dd { "{role.equity}" }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn RoleCompensation(role: Arc<Role>) -> Element {
+    let mut is_editable = use_signal(|| false);
+
+    let callback = use_callback(move |role: Role| {
+        let mut context = use_context::<Signal<ApplicationContext>>();
+        let new_context = unwrap_or_report_and_return!(context().set_role(role));
+        context.set(new_context);
+        is_editable.set(false);
+    });
+
+    let editable = rsx! {
+        EditRoleCompensation { role: role.clone(), callback }
+    };
+    let display = rsx! {
+        PopulatedRoleCompensation { role }
+    };
+
+    rsx! {
+        h3 { "Compensation" }
+
+        Editable { display, editable, is_editable }
+    }
+}
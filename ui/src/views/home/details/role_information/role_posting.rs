@@ -0,0 +1,112 @@
+use crate::components::Editable;
+use crate::helpers::{describe_in_zones, log_error, unwrap_or_report_and_return, use_local_zone};
+use crate::role_information::forms::{CapturePosting, EditRolePosting};
+use crate::StoreType;
+use application_context::prelude::ApplicationContext;
+use dioxus::prelude::*;
+use std::sync::Arc;
+use storage::prelude::*;
+
+#[component]
+fn PopulatedRolePosting(role: Arc<Role>) -> Element {
+    let date_posted = role
+        .date_posted
+        .map(|date_posted| describe_in_zones(&date_posted, use_local_zone()));
+
+    rsx! {
+        dl { class: "role-posting",
+            dt { "Posting:" }
+            dd {
+                if role.posting_url.is_empty() {
+                    "Unknown"
+                } else {
+                    a { href: "{role.posting_url}", target: "_blank", "{role.posting_url}" }
+                }
+            }
+            dt { "Source:" }
+            dd {
+                if role.source.is_empty() {
+                    "Unknown"
+                } else {
+                    "{role.source}"
+                }
+            }
+            if !role.location.is_empty() {
+                dt { "Location:" }
+                dd { "{role.location}" }
+            }
+            if let Some(date_posted) = date_posted {
+                dt { "Posted:" }
+                dd { "{date_posted}" }
+            }
+        }
+    }
+}
+
+#[component]
+fn CapturedPosting(role: Arc<Role>, snapshot: PostingSnapshot) -> Element {
+    let captured = describe_in_zones(&snapshot.date_captured, use_local_zone());
+    let mut original = use_signal(|| None::<String>);
+
+    // Turn Markdown into markup, the advert is someone else's page so only what's safe is kept
+    let posting_html = markdown_to_html(&snapshot.markdown);
+
+    let show_original = move |_: MouseEvent| {
+        let role = role.clone();
+        spawn(async move {
+            let store = use_context::<StoreType>();
+            match store.recall_posting_html(&*role).await {
+                Ok(html) => original.set(Some(html)),
+                Err(e) => log_error(e),
+            }
+        });
+    };
+
+    rsx! {
+        details { class: "posting-snapshot",
+            summary { "Advert as captured {captured}" }
+            div { dangerous_inner_html: posting_html }
+            if snapshot.has_html {
+                if let Some(html) = original() {
+                    pre { class: "posting-original", "{html}" }
+                } else {
+                    button { r#type: "button", onclick: show_original, "Show original HTML" }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn RolePosting(role: Arc<Role>) -> Element {
+    let mut is_editable = use_signal(|| false);
+
+    let callback = use_callback(move |role: Role| {
+        let mut context = use_context::<Signal<ApplicationContext>>();
+        let new_context = unwrap_or_report_and_return!(context().set_role(role));
+        context.set(new_context);
+        is_editable.set(false);
+    });
+
+    let editable = rsx! {
+        EditRolePosting { role: role.clone(), callback }
+    };
+    let display = rsx! {
+        PopulatedRolePosting { role: role.clone() }
+    };
+
+    rsx! {
+        h3 { "Job Posting" }
+
+        Editable { display, editable, is_editable }
+
+        match role.posting.clone() {
+            Some(snapshot) => rsx! {
+                CapturedPosting { role, snapshot }
+            },
+            None => rsx! {
+                CapturePosting { role, callback }
+            },
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::helpers::{log_error, unwrap_or_report_and_return};
+use crate::StoreType;
+use application_context::prelude::ApplicationContext;
+use dioxus::prelude::*;
+use std::str::FromStr;
+use std::sync::Arc;
+use storage::prelude::*;
+
+fn change_status(role: Arc<Role>) -> impl FnMut(FormEvent) {
+    move |e: FormEvent| {
+        let Ok(status) = RoleStatus::from_str(&e.value()).map_err(log_error) else {
+            return;
+        };
+        let mut role = Role::clone(&role);
+        if !role.set_status(status, Timestamp::now()) {
+            return;
+        }
+        spawn(async move {
+            let mut stores = use_context::<StoreType>();
+            if let Err(e) = stores.store(role.clone()).await {
+                log_error(e);
+                return;
+            }
+            let mut context = use_context::<Signal<ApplicationContext>>();
+            let new_context = unwrap_or_report_and_return!(context().set_role(role));
+            context.set(new_context);
+        });
+    }
+}
+
+#[component]
+pub fn RoleStatusSelector(role: Arc<Role>) -> Element {
+    let current_status = role.status;
+    let history = role.get_status_history().iter().rev().map(|transition| {
+        let when = transition.date_changed.format("%Y-%m-%d %H:%M");
+        rsx! {
+            li { "{when}: {transition.from} → {transition.to}" }
+        }
+    });
+
+    rsx! {
+        h3 { "Status" }
+
+        select { name: RoleFieldName::Status.name(), onchange: change_status(role.clone()),
+            for status in RoleStatus::ALL {
+                option {
+                    selected: status == current_status,
+                    value: status.as_str(),
+                    "{status}"
+                }
+            }
+        }
+
+        if !role.get_status_history().is_empty() {
+            ol { class: "status-history", {history} }
+        }
+    }
+}
@@ -1,10 +1,11 @@
-use crate::helpers::log_error;
+use crate::helpers::{log_error, store_with_retry};
 use crate::StoreType;
 use dioxus::prelude::*;
 use std::sync::Arc;
-use storage::prelude::{BaseStore, Company, PartialValue, Value, ValueFieldName};
+use storage::prelude::{Company, PartialValue, Value, ValueFieldName};
 
 fn create_on_submit(company: Arc<Company>, callback: Callback<Value>) -> impl FnMut(FormEvent) {
+    let store = use_context::<StoreType>();
     move |e: FormEvent| {
         e.prevent_default();
         if let Ok(role) = e
@@ -17,11 +18,7 @@ fn create_on_submit(company: Arc<Company>, callback: Callback<Value>) -> impl Fn
             })
         {
             // If the role was successfully created, save it
-            spawn(async move {
-                let mut stores = use_context::<StoreType>();
-                stores.store(role.clone()).await.unwrap_or_else(log_error);
-                callback(role);
-            });
+            store_with_retry(store.clone(), role, callback);
         }
     }
 }
@@ -1,34 +1,34 @@
-use crate::helpers::unwrap_or_report_and_return;
+use crate::helpers::{unwrap_or_report_and_return, use_restart_on_change};
 use crate::value_list::forms::create_value::CreateValue;
 use crate::value_list::ValueListItem;
 use crate::StoreType;
 use dioxus::prelude::*;
 use std::sync::Arc;
-use storage::prelude::{Company, RecallByCompany};
+use storage::prelude::{Company, Query, RecallByQuery, SearchKind, SortBy, SortOrder, Value};
 
 #[component]
 pub fn ValueList(company: Arc<Company>) -> Element {
     let company_id = company.id;
 
-    let mut values_resource = use_resource(use_reactive!(|(company_id)| async move {
-        let values = unwrap_or_report_and_return!(
-            use_context::<StoreType>()
-                .recall_by_company(company_id)
-                .await
-        );
+    let values_resource = use_resource(use_reactive!(|(company_id)| async move {
+        let query = Query::new()
+            .company(company_id)
+            .sort_by(SortBy::DateUpdated, SortOrder::Descending);
+        let values: Vec<Value> =
+            unwrap_or_report_and_return!(use_context::<StoreType>().recall_by_query(&query).await);
 
         values.into_iter().map(Arc::new).collect::<Vec<_>>()
     }));
 
-    let reload_values = use_callback(move |()| values_resource.restart());
+    use_restart_on_change(values_resource, &[SearchKind::Value]);
     let values = values_resource().unwrap_or_default();
     let values_list = values.iter().cloned().map(move |value| {
         rsx! {
-            ValueListItem { value, reload_values }
+            ValueListItem { value }
         }
     });
 
-    let callback = use_callback(move |_value| values_resource.restart());
+    let callback = use_callback(|_value| ());
 
     rsx! {
         div { id: "flags",
@@ -1,16 +1,17 @@
+use crate::helpers::delete_with_store;
 use crate::value_list::forms::edit_value::EditValue;
-use crate::Editable;
+use crate::{DeleteButton, Editable, StoreType};
 use dioxus::prelude::*;
 use std::sync::Arc;
 use storage::prelude::*;
 
 #[component]
-pub fn ValueListItem(value: Arc<Value>, reload_values: Callback) -> Element {
+pub fn ValueListItem(value: Arc<Value>) -> Element {
     let mut is_editable = use_signal(|| false);
-    let callback = use_callback(move |_value| {
-        reload_values(());
-        is_editable.set(false);
-    });
+    let callback = use_callback(move |_value| is_editable.set(false));
+
+    let delete_callback = use_callback(|_value| ());
+    let delete = delete_with_store(use_context::<StoreType>(), value.clone(), delete_callback);
 
     let display = rsx! {
         header { "{value.name}" }
@@ -24,6 +25,7 @@ pub fn ValueListItem(value: Arc<Value>, reload_values: Callback) -> Element {
     rsx! {
         li { id: "value-{value.id}",
             Editable { display, editable, is_editable }
+            DeleteButton { onclick: delete }
         }
     }
 }
@@ -0,0 +1,67 @@
+use crate::helpers::{
+    complete_with_store, create_route, describe_in_zones, unwrap_or_report_and_return,
+    use_local_zone, use_restart_on_change,
+};
+use crate::router::DetailsView;
+use crate::StoreType;
+use dioxus::prelude::*;
+use std::sync::Arc;
+use storage::prelude::{RecallDue, Reminder, SearchKind, Timestamp};
+
+/// How far ahead the panel looks
+const DUE_SOON_DAYS: u64 = 7;
+
+#[component]
+fn DueSoonItem(reminder: Arc<Reminder>) -> Element {
+    let callback = use_callback(|_reminder| ());
+    let complete = complete_with_store(use_context::<StoreType>(), reminder.clone(), callback);
+    let (role_id, view) = match reminder.role_id {
+        Some(role_id) => (Some(role_id), DetailsView::Role),
+        None => (None, DetailsView::Company),
+    };
+    let route = create_route(Some(reminder.company_id), role_id, None, Some(view));
+    let due = describe_in_zones(&reminder.due, use_local_zone());
+    let overdue = if reminder.is_overdue(Timestamp::now()) {
+        "reminder-overdue"
+    } else {
+        ""
+    };
+
+    rsx! {
+        li { class: overdue,
+            Link { to: route, "{reminder.name}" }
+            " "
+            small { "{due}" }
+            " "
+            button { r#type: "button", onclick: complete, "Done" }
+        }
+    }
+}
+
+/// Reminders due in the next week, along with any that are overdue
+#[component]
+pub fn DueSoon() -> Element {
+    let due_resource = use_resource(move || async move {
+        let cutoff = Timestamp::now().add_days(DUE_SOON_DAYS);
+        let reminders = unwrap_or_report_and_return!(
+            use_context::<StoreType>().recall_due_before(cutoff).await
+        );
+        reminders.into_iter().map(Arc::new).collect::<Vec<_>>()
+    });
+    use_restart_on_change(due_resource, &[SearchKind::Reminder]);
+    let reminders = due_resource().unwrap_or_default();
+
+    rsx! {
+        div { id: "due-soon",
+            h3 { "Due soon" }
+            if reminders.is_empty() {
+                p { "Nothing due this week" }
+            }
+            ul {
+                for reminder in reminders {
+                    DueSoonItem { key: "{reminder.id}", reminder }
+                }
+            }
+        }
+    }
+}
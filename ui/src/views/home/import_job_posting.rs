@@ -0,0 +1,55 @@
+use crate::helpers::log_error;
+use crate::prelude::Route;
+use crate::router::DetailsView;
+use crate::StoreType;
+use dioxus::prelude::*;
+use storage::prelude::{JobPosting, Timestamp};
+
+/// Reads a job posting page saved from a job board, creating its role (and company if needed)
+async fn import_file(store: StoreType, e: FormEvent) {
+    let Some(file) = e.files().into_iter().next() else {
+        return;
+    };
+    let html = match file.read_string().await {
+        Ok(html) => html,
+        Err(error) => return log_error(error),
+    };
+    let job_posting = match JobPosting::extract(&html) {
+        Ok(job_posting) => job_posting,
+        Err(error) => return log_error(format!("{}: {error}", file.name())),
+    };
+    match store
+        .import_job_posting(&job_posting, &html, Timestamp::now())
+        .await
+    {
+        Ok(imported) => {
+            if let Some(error) = imported.html_error {
+                log_error(error);
+            }
+            navigator().push(Route::HomeRole {
+                company_id: imported.company.id,
+                role_id: imported.role.id,
+                view: DetailsView::Role,
+            });
+        }
+        Err(error) => log_error(error),
+    }
+}
+
+#[component]
+pub fn ImportJobPosting() -> Element {
+    let store = use_context::<StoreType>();
+
+    rsx! {
+        div { id: "import-job-posting",
+            label {
+                "Import a saved job posting "
+                input {
+                    r#type: "file",
+                    accept: ".html,.htm",
+                    onchange: move |e: FormEvent| import_file(store.clone(), e),
+                }
+            }
+        }
+    }
+}
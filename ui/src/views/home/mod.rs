@@ -1,6 +1,8 @@
 use crate::helpers::unwrap_or_report_and_return;
 use crate::router::DetailsView;
 use crate::views::home::company_list::CompanyList;
+use crate::views::home::due_soon::DueSoon;
+use crate::views::home::import_job_posting::ImportJobPosting;
 use crate::views::home::role_list::RoleList;
 use crate::{Details, StoreType, SHOW_MODIFIERS};
 use application_context::prelude::*;
@@ -10,6 +12,8 @@ use uuid::Uuid;
 
 pub mod company_list;
 pub mod details;
+mod due_soon;
+mod import_job_posting;
 pub mod role_list;
 
 #[component]
@@ -64,7 +68,9 @@ pub fn Home(
         div { id: "home", class: "flex {show_modifiers}",
 
             section { class: "mr-4 mt-0",
+                DueSoon {}
                 CompanyList {}
+                ImportJobPosting {}
                 RoleList {}
             }
 
@@ -1,40 +1,115 @@
-use crate::helpers::log_error;
+use crate::components::{FieldErrorMessage, TimestampInput};
+use crate::helpers::{
+    capture_posting_with_retry, invalid_class, log_error, revalidate_on_input, store_with_retry,
+};
 use crate::StoreType;
 use dioxus::prelude::*;
 use std::sync::Arc;
-use storage::prelude::{BaseStore, Company, PartialRole, Role, RoleFieldName};
+use storage::prelude::{
+    CheckPartialComplete, Company, IncompletePartialErrors, PartialRole, Posting, Role,
+    RoleFieldName,
+};
 
-fn create_on_submit(company: Arc<Company>, callback: Callback<Role>) -> impl FnMut(FormEvent) {
+fn create_on_submit(
+    company: Arc<Company>,
+    callback: Callback<Role>,
+    mut errors: Signal<IncompletePartialErrors>,
+    posting: Signal<Option<Arc<Posting>>>,
+) -> impl FnMut(FormEvent) {
+    let store = use_context::<StoreType>();
     move |e: FormEvent| {
         e.prevent_default();
         // Get the partial from the form data, then create the role from the partial
-        if let Ok(role) = e
-            .parsed_values::<PartialRole>()
-            .map_err(log_error)
-            .and_then(|form_data| {
-                company
-                    .create_role_from_partial(form_data)
-                    .map_err(log_error)
-            })
-        {
-            // If the role was successfully created, save it
-            spawn(async move {
-                let mut stores = use_context::<StoreType>();
-                stores.store(role.clone()).await.unwrap_or_else(log_error);
-                callback(role);
-            });
+        let Ok(form_data) = e.parsed_values::<PartialRole>().map_err(log_error) else {
+            return;
+        };
+        match company.create_role_from_partial(form_data) {
+            // If the role was successfully created, save it along with any pasted posting
+            Ok(role) => {
+                errors.set(IncompletePartialErrors::default());
+                let callback = match posting() {
+                    Some(posting) => {
+                        let store = store.clone();
+                        Callback::new(move |role: Role| {
+                            capture_posting_with_retry(
+                                store.clone(),
+                                role,
+                                posting.clone(),
+                                callback,
+                            )
+                        })
+                    }
+                    None => callback,
+                };
+                store_with_retry(store.clone(), role, callback);
+            }
+            // Otherwise show what needs fixing against the inputs
+            Err(failed) => errors.set(failed),
         }
     }
 }
 
 #[component]
 pub fn CreateRole(company: Arc<Company>, callback: Callback<Role>) -> Element {
+    let errors = use_signal(IncompletePartialErrors::default);
+    let mut posting = use_signal(|| None::<Arc<Posting>>);
+    let mut name = use_signal(String::new);
+    let mut description = use_signal(String::new);
+
+    // Pasting an advert fills in the name and description, which can still be changed before the
+    // role is created. The posting itself is captured as it was pasted.
+    let on_paste = move |e: FormEvent| {
+        let parsed = Posting::parse(e.value());
+        if parsed.is_empty() {
+            posting.set(None);
+            return;
+        }
+        name.set(parsed.name.clone());
+        description.set(parsed.markdown.clone());
+        posting.set(Some(Arc::new(parsed)));
+    };
+
     rsx! {
         form {
             class: "flex flex-col",
-            onsubmit: create_on_submit(company, callback),
-            input { name: RoleFieldName::Name.name(), value: "" }
-            input { r#type: "submit" }
+            onsubmit: create_on_submit(company, callback, errors, posting),
+            oninput: revalidate_on_input(errors, PartialRole::check_complete),
+            textarea {
+                class: "posting-paste",
+                placeholder: "Paste the job advert (HTML or markdown) to fill in the role",
+                oninput: on_paste,
+            }
+            input {
+                class: invalid_class(errors, RoleFieldName::Name),
+                name: RoleFieldName::Name.name(),
+                value: "{name}",
+                oninput: move |e: FormEvent| name.set(e.value()),
+            }
+            FieldErrorMessage { errors, field: RoleFieldName::Name }
+            textarea {
+                name: RoleFieldName::Description.name(),
+                placeholder: "Description",
+                value: "{description}",
+                oninput: move |e: FormEvent| description.set(e.value()),
+            }
+            input {
+                r#type: "url",
+                name: RoleFieldName::PostingUrl.name(),
+                placeholder: "Posting URL",
+                value: "",
+            }
+            input {
+                name: RoleFieldName::Source.name(),
+                placeholder: "Source, eg a job board or referral",
+                value: "",
+            }
+            TimestampInput {
+                class: invalid_class(errors, RoleFieldName::DateApplied),
+                name: RoleFieldName::DateApplied.name(),
+                value: "today",
+            }
+            FieldErrorMessage { errors, field: RoleFieldName::DateApplied }
+            input { r#type: "submit", disabled: !errors.read().is_empty() }
         }
     }
 }
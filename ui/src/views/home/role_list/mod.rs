@@ -3,6 +3,7 @@ mod forms;
 mod empty_role_list;
 mod populated_role_list;
 mod role_list_item;
+mod sort_roles;
 
 use application_context::prelude::ApplicationContext;
 use dioxus::prelude::*;
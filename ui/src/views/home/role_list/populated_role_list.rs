@@ -1,5 +1,6 @@
 use super::role_list_item::RoleListItem;
-use crate::helpers::unwrap_or_report_and_return;
+use super::sort_roles::SortRoles;
+use crate::helpers::{unwrap_or_report_and_return, use_exchange_rates, use_restart_on_change};
 use crate::router::DetailsView;
 use crate::views::home::role_list::forms::create_role::CreateRole;
 use crate::Route::HomeRole;
@@ -11,20 +12,24 @@ use storage::prelude::*;
 #[component]
 pub fn PopulatedRoleList(company: Arc<Company>) -> Element {
     let company_id = company.id;
+    let sort_by = use_signal(|| SortBy::DateUpdated);
+    let exchange_rates = use_exchange_rates();
 
     // Get roles for the company
-    let mut roles_resource = use_resource(use_reactive!(|(company_id)| async move {
-        unwrap_or_report_and_return!(
-            use_context::<StoreType>()
-                .recall_by_company(company_id)
-                .await
-        )
+    let roles_resource = use_resource(use_reactive!(|(company_id)| async move {
+        let query = Query::new()
+            .company(company_id)
+            .sort_by(sort_by(), SortOrder::Descending)
+            .exchange_rates(exchange_rates());
+        let roles: Vec<Role> =
+            unwrap_or_report_and_return!(use_context::<StoreType>().recall_by_query(&query).await);
+        roles
     }));
-    let reload_roles = use_callback(move |()| roles_resource.restart());
+    use_restart_on_change(roles_resource, &[SearchKind::Role]);
     let roles = roles_resource().unwrap_or_default();
     let roles_list = roles.into_iter().map(Arc::new).map(|role| {
         rsx! {
-            RoleListItem { role, reload_roles }
+            RoleListItem { role }
         }
     });
 
@@ -42,6 +47,8 @@ pub fn PopulatedRoleList(company: Arc<Company>) -> Element {
 
             h3 { "Roles" }
 
+            SortRoles { sort_by }
+
             ul { {roles_list} }
 
             CreateRole { company, callback: on_create_role }
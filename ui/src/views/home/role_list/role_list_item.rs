@@ -1,14 +1,15 @@
 use crate::components::Editable;
+use crate::helpers::unwrap_or_report_and_return;
 use crate::router::DetailsView;
 use crate::views::home::role_list::forms::edit_role_name::EditRoleName;
-use crate::Route;
+use crate::{DeleteButton, Route, StoreType};
 use application_context::prelude::*;
 use dioxus::prelude::*;
 use std::sync::Arc;
 use storage::prelude::*;
 
 #[component]
-pub fn RoleListItem(role: Arc<Role>, reload_roles: Callback) -> Element {
+pub fn RoleListItem(role: Arc<Role>) -> Element {
     let context = use_context::<Signal<ApplicationContext>>();
     let is_editable = use_signal::<bool>(|| false);
 
@@ -37,7 +38,19 @@ pub fn RoleListItem(role: Arc<Role>, reload_roles: Callback) -> Element {
         label { r#for: "{id}", "{name}" }
     };
 
-    let callback = use_callback(move |_role| reload_roles(()));
+    let callback = use_callback(|_role| ());
+
+    // Takes the role's interviews and questions with it
+    let store = use_context::<StoreType>();
+    let delete = move |_| {
+        let store = store.clone();
+        spawn(async move {
+            unwrap_or_report_and_return!(store.delete_role(id, Timestamp::now()).await);
+            if checked {
+                navigator().push(Route::HomeCompany { company_id });
+            }
+        });
+    };
 
     let editable = rsx! {
         EditRoleName { role, callback }
@@ -46,6 +59,7 @@ pub fn RoleListItem(role: Arc<Role>, reload_roles: Callback) -> Element {
     rsx! {
         li {
             Editable { display, editable, is_editable }
+            DeleteButton { onclick: delete }
         }
     }
 }
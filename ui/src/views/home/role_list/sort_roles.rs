@@ -0,0 +1,82 @@
+use crate::helpers::{log_error, use_exchange_rates};
+use dioxus::prelude::*;
+use storage::prelude::SortBy;
+
+/// Picks the base currency compensation is compared in, from the currencies there are rates for
+#[component]
+fn BaseCurrency() -> Element {
+    let mut exchange_rates = use_exchange_rates();
+    // Without a file (eg on the web) there's nowhere to keep the choice, so don't offer it
+    if exchange_rates.read().get_path().as_os_str().is_empty() {
+        return rsx! {};
+    }
+
+    let base_currency = exchange_rates.read().get_base_currency().to_string();
+    let mut currencies: Vec<String> = exchange_rates.read().get_rates().keys().cloned().collect();
+    currencies.push(base_currency.clone());
+    currencies.sort();
+
+    let onchange = move |e: FormEvent| {
+        let mut rates = exchange_rates();
+        if !rates.rebase(e.value()) {
+            return;
+        }
+        spawn(async move {
+            match rates.save().await {
+                Ok(()) => exchange_rates.set(rates),
+                Err(e) => log_error(e),
+            }
+        });
+    };
+
+    rsx! {
+        label {
+            " in "
+            select { onchange,
+                for currency in currencies {
+                    option {
+                        value: "{currency}",
+                        selected: currency == base_currency,
+                        "{currency}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// How the roles are listed, the best paid first or the most recently updated
+#[component]
+pub fn SortRoles(sort_by: Signal<SortBy>) -> Element {
+    let onchange = move |e: FormEvent| {
+        let by_compensation = e.value() == "compensation";
+        sort_by.set(if by_compensation {
+            SortBy::Compensation
+        } else {
+            SortBy::DateUpdated
+        });
+    };
+
+    rsx! {
+        p { class: "sort-roles",
+            label {
+                "Sort by "
+                select { onchange,
+                    option {
+                        value: "updated",
+                        selected: sort_by() == SortBy::DateUpdated,
+                        "recently updated"
+                    }
+                    option {
+                        value: "compensation",
+                        selected: sort_by() == SortBy::Compensation,
+                        "compensation"
+                    }
+                }
+            }
+            if sort_by() == SortBy::Compensation {
+                BaseCurrency {}
+            }
+        }
+    }
+}
@@ -7,4 +7,13 @@ pub use support::Support;
 mod help;
 pub use help::Help;
 
+mod trash;
+pub use trash::Trash;
+
+mod search;
+pub use search::Search;
+
+mod spreadsheets;
+pub use spreadsheets::Spreadsheets;
+
 pub use home::details::*;
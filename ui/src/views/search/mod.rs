@@ -0,0 +1,76 @@
+use crate::helpers::{create_route, unwrap_or_report_and_return};
+use crate::router::DetailsView;
+use crate::StoreType;
+use dioxus::prelude::*;
+use storage::prelude::*;
+
+/// Where a search result lives in the home view
+fn result_route(result: &SearchResult) -> String {
+    let (role_id, interview_id, view) = match result.kind {
+        SearchKind::Company | SearchKind::Flag | SearchKind::Value | SearchKind::Contact => {
+            (None, None, DetailsView::Company)
+        }
+        SearchKind::Role => (Some(result.id), None, DetailsView::Role),
+        SearchKind::Reminder => match result.role_id {
+            Some(role_id) => (Some(role_id), None, DetailsView::Role),
+            None => (None, None, DetailsView::Company),
+        },
+        SearchKind::Question => (result.role_id, None, DetailsView::Questions),
+        SearchKind::Interview => (result.role_id, Some(result.id), DetailsView::Interview),
+    };
+    let company_id = match result.kind {
+        SearchKind::Company => Some(result.id),
+        _ => result.company_id,
+    };
+    create_route(company_id, role_id, interview_id, Some(view))
+}
+
+#[component]
+fn SearchResultItem(result: SearchResult) -> Element {
+    rsx! {
+        li {
+            Link { to: result_route(&result), "{result.title}" }
+            if !result.snippet.is_empty() {
+                p {
+                    for part in result.snippet {
+                        if part.highlighted {
+                            mark { "{part.text}" }
+                        } else {
+                            "{part.text}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn Search(query: String) -> Element {
+    let results_resource = use_resource(use_reactive!(|query| async move {
+        let results = unwrap_or_report_and_return!(use_context::<StoreType>().search(query).await);
+        group_by_kind(results)
+    }));
+    let groups = results_resource().unwrap_or_default();
+
+    rsx! {
+        h2 { "Search" }
+
+        if query.trim().is_empty() {
+            p { "Type into the search box to search everything" }
+        } else if groups.is_empty() {
+            p { "Nothing matched \"{query}\"" }
+        }
+
+        for (kind , results) in groups {
+            section { key: "{kind}",
+                h3 { "{kind}" }
+                ul {
+                    for result in results {
+                        SearchResultItem { key: "{result.id}", result }
+                    }
+                }
+            }
+        }
+    }
+}
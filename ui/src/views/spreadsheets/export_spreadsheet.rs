@@ -0,0 +1,138 @@
+use crate::helpers::unwrap_or_report_and_return;
+use crate::StoreType;
+use dioxus::prelude::*;
+use storage::prelude::*;
+
+/// A link that saves the CSV without needing a round trip through the file system
+fn data_url(csv: &str) -> String {
+    let mut url = String::from("data:text/csv;charset=utf-8,");
+    for byte in csv.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                url.push(byte as char)
+            }
+            byte => url.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    url
+}
+
+/// Everything that can be saved as a spreadsheet
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ExportKind {
+    Companies,
+    Roles,
+    Flags,
+    Values,
+    Interviews,
+    Questions,
+    Contacts,
+    Reminders,
+}
+
+impl ExportKind {
+    const ALL: [ExportKind; 8] = [
+        ExportKind::Companies,
+        ExportKind::Roles,
+        ExportKind::Flags,
+        ExportKind::Values,
+        ExportKind::Interviews,
+        ExportKind::Questions,
+        ExportKind::Contacts,
+        ExportKind::Reminders,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            ExportKind::Companies => "companies",
+            ExportKind::Roles => "roles",
+            ExportKind::Flags => "flags",
+            ExportKind::Values => "values",
+            ExportKind::Interviews => "interviews",
+            ExportKind::Questions => "questions",
+            ExportKind::Contacts => "contacts",
+            ExportKind::Reminders => "reminders",
+        }
+    }
+}
+
+async fn export(store: &StoreType, kind: ExportKind) -> anyhow::Result<String> {
+    let csv = match kind {
+        ExportKind::Companies => {
+            let companies: Vec<Company> = store.recall_by_name("").await?;
+            export_csv(&companies)?
+        }
+        ExportKind::Roles => {
+            let companies: Vec<Company> = store.recall_by_name("").await?;
+            let roles: Vec<Role> = store.recall_by_name("").await?;
+            export_roles_csv(&roles, &companies)?
+        }
+        ExportKind::Flags => {
+            let flags: Vec<Flag> = store.recall_by_name("").await?;
+            export_csv(&flags)?
+        }
+        ExportKind::Values => {
+            let values: Vec<Value> = store.recall_by_name("").await?;
+            export_csv(&values)?
+        }
+        ExportKind::Interviews => {
+            let interviews: Vec<Interview> = store.recall_by_name("").await?;
+            export_csv(&interviews)?
+        }
+        ExportKind::Questions => {
+            let questions: Vec<Question> = store.recall_by_name("").await?;
+            export_csv(&questions)?
+        }
+        ExportKind::Contacts => {
+            let contacts: Vec<Contact> = store.recall_by_name("").await?;
+            export_csv(&contacts)?
+        }
+        ExportKind::Reminders => {
+            let reminders: Vec<Reminder> = store.recall_by_name("").await?;
+            export_csv(&reminders)?
+        }
+    };
+    Ok(csv)
+}
+
+#[component]
+pub fn ExportSpreadsheet() -> Element {
+    let store = use_context::<StoreType>();
+    let mut exported = use_signal(|| None::<(ExportKind, String)>);
+
+    rsx! {
+        section { id: "export-spreadsheet",
+            h3 { "Export" }
+            p {
+                for kind in ExportKind::ALL {
+                    button {
+                        key: "{kind.as_str()}",
+                        r#type: "button",
+                        onclick: {
+                            let store = store.clone();
+                            move |_| {
+                                let store = store.clone();
+                                spawn(async move {
+                                    let csv = unwrap_or_report_and_return!(export(&store, kind).await);
+                                    exported.set(Some((kind, csv)));
+                                });
+                            }
+                        },
+                        "Export {kind.as_str()}"
+                    }
+                }
+            }
+            if let Some((kind, csv)) = exported() {
+                p {
+                    a {
+                        href: data_url(&csv),
+                        download: "{kind.as_str()}.csv",
+                        "Save {kind.as_str()}.csv"
+                    }
+                    " or copy it from below"
+                }
+                textarea { class: "spreadsheet-export", readonly: true, value: csv }
+            }
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use crate::helpers::{log_error, unwrap_or_report_and_return};
+use crate::StoreType;
+use dioxus::prelude::*;
+use storage::prelude::*;
+
+fn describe_match(company: &Option<CompanyMatch>) -> String {
+    match company {
+        None => String::new(),
+        Some(CompanyMatch::Existing(_)) => "already there".to_string(),
+        Some(CompanyMatch::New) => "new".to_string(),
+        Some(CompanyMatch::Earlier { line }) => format!("same as line {line}"),
+    }
+}
+
+fn describe_import(imported: &ImportedSpreadsheet) -> String {
+    let skipped = match imported.skipped.len() {
+        0 => String::new(),
+        skipped => format!(", {skipped} rows skipped"),
+    };
+    format!(
+        "Imported {} companies and {} roles{skipped}",
+        imported.companies.len(),
+        imported.roles.len()
+    )
+}
+
+/// Which field each column goes into, chosen from the fields that kind of row has
+#[component]
+fn ColumnMappingSelect(
+    column: usize,
+    header: String,
+    mapping: Signal<Option<ColumnMapping>>,
+) -> Element {
+    let Some(current) = mapping() else {
+        return rsx! {};
+    };
+    let fields = current.kind().fields();
+    let selected = current.get(column);
+
+    rsx! {
+        label { class: "spreadsheet-column",
+            "{header} "
+            select {
+                onchange: move |e: FormEvent| {
+                    let field = e.value().parse::<usize>().ok().and_then(|i| fields.get(i)).copied();
+                    mapping.with_mut(|mapping| {
+                        if let Some(mapping) = mapping {
+                            mapping.set(column, field);
+                        }
+                    });
+                },
+                option { value: "", selected: selected.is_none(), "Leave out" }
+                for (i , field) in fields.iter().enumerate() {
+                    option {
+                        key: "{i}",
+                        value: "{i}",
+                        selected: selected == Some(*field),
+                        {field.label()}
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn ImportSpreadsheet() -> Element {
+    let store = use_context::<StoreType>();
+    let mut kind = use_signal(|| SpreadsheetKind::Roles);
+    let mut table = use_signal(|| None::<SpreadsheetTable>);
+    let mut mapping = use_signal(|| None::<ColumnMapping>);
+    let mut imported = use_signal(|| None::<String>);
+    // The preset chosen, otherwise the one that recognises the most headings
+    let mut chosen_preset = use_signal(|| None::<&'static SpreadsheetPreset>);
+
+    let preview_store = store.clone();
+    let mut preview_resource = use_resource(move || {
+        let store = preview_store.clone();
+        async move {
+            let (Some(table), Some(mapping)) = (table(), mapping()) else {
+                return Vec::new();
+            };
+            unwrap_or_report_and_return!(store.preview_spreadsheet(&table, &mapping).await)
+        }
+    });
+    let preview = preview_resource().unwrap_or_default();
+    let importable = preview.iter().filter(|row| row.will_import(kind())).count();
+
+    let mut choose_kind = move |new_kind: SpreadsheetKind| {
+        kind.set(new_kind);
+        chosen_preset.set(None);
+        if let Some(table) = table() {
+            mapping.set(Some(ColumnMapping::guess(new_kind, &table.headers)));
+        }
+    };
+
+    let read_file = move |e: FormEvent| async move {
+        let Some(file) = e.files().into_iter().next() else {
+            return;
+        };
+        let csv = match file.read_string().await {
+            Ok(csv) => csv,
+            Err(error) => return log_error(error),
+        };
+        match SpreadsheetTable::parse(&csv) {
+            Ok(new_table) => {
+                mapping.set(Some(ColumnMapping::guess(kind(), &new_table.headers)));
+                table.set(Some(new_table));
+                chosen_preset.set(None);
+                imported.set(None);
+            }
+            Err(error) => log_error(format!("{}: {error}", file.name())),
+        }
+    };
+
+    let choose_preset = move |e: FormEvent| {
+        let (Some(table), Some(preset)) = (table(), SpreadsheetPreset::by_name(&e.value())) else {
+            return;
+        };
+        chosen_preset.set(Some(preset));
+        mapping.set(Some(ColumnMapping::from_preset(
+            kind(),
+            &table.headers,
+            preset,
+        )));
+    };
+
+    // Starts as the preset's, for spreadsheets the preset can't know the order of
+    let choose_date_order = move |e: FormEvent| {
+        mapping.with_mut(|mapping| {
+            if let Some(mapping) = mapping {
+                mapping.set_date_order(e.value().parse().ok());
+            }
+        });
+    };
+    let date_order = mapping().and_then(|mapping| mapping.date_order());
+
+    let import = move |_| {
+        let store = store.clone();
+        let (Some(table_to_import), Some(mapping_to_import)) = (table(), mapping()) else {
+            return;
+        };
+        spawn(async move {
+            let result = unwrap_or_report_and_return!(
+                store
+                    .import_spreadsheet(&table_to_import, &mapping_to_import)
+                    .await
+            );
+            imported.set(Some(describe_import(&result)));
+            table.set(None);
+            mapping.set(None);
+            preview_resource.restart();
+        });
+    };
+
+    let selected_preset = chosen_preset()
+        .or_else(|| table().map(|table| SpreadsheetPreset::detect(kind(), &table.headers)))
+        .map(|preset| preset.name)
+        .unwrap_or_default();
+
+    rsx! {
+        section { id: "import-spreadsheet",
+            h3 { "Import" }
+            p {
+                label {
+                    "Each row is a "
+                    select {
+                        onchange: move |e: FormEvent| {
+                            if let Some(new_kind) = SpreadsheetKind::ALL
+                                .into_iter()
+                                .find(|kind| kind.as_str() == e.value())
+                            {
+                                choose_kind(new_kind);
+                            }
+                        },
+                        for option_kind in SpreadsheetKind::ALL {
+                            option {
+                                key: "{option_kind.as_str()}",
+                                value: option_kind.as_str(),
+                                selected: option_kind == kind(),
+                                {option_kind.as_str().trim_end_matches('s')}
+                            }
+                        }
+                    }
+                }
+                " "
+                label {
+                    "from "
+                    input { r#type: "file", accept: ".csv", onchange: read_file }
+                }
+            }
+
+            if let Some(message) = imported() {
+                p { "{message}" }
+            }
+
+            if let Some(current_table) = table() {
+                p {
+                    label {
+                        "Columns are named like "
+                        select { onchange: choose_preset,
+                            for preset in SpreadsheetPreset::ALL {
+                                option {
+                                    key: "{preset.name}",
+                                    value: preset.name,
+                                    selected: preset.name == selected_preset,
+                                    {preset.name}
+                                }
+                            }
+                        }
+                    }
+                    if kind() == SpreadsheetKind::Roles {
+                        " "
+                        label {
+                            "with dates written "
+                            select { onchange: choose_date_order,
+                                option { value: "", selected: date_order.is_none(), "unknown, unclear dates are flagged" }
+                                for order in DateOrder::ALL {
+                                    option {
+                                        key: "{order.as_str()}",
+                                        value: order.as_str(),
+                                        selected: date_order == Some(order),
+                                        {order.label()}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                fieldset { class: "spreadsheet-mapping",
+                    legend { "Read each column into" }
+                    for (column , header) in current_table.headers.iter().enumerate() {
+                        ColumnMappingSelect {
+                            key: "{column}",
+                            column,
+                            header: header.clone(),
+                            mapping,
+                        }
+                    }
+                }
+
+                table { class: "spreadsheet-preview",
+                    thead {
+                        tr {
+                            th { "Line" }
+                            th { "Company" }
+                            if kind() == SpreadsheetKind::Roles {
+                                th { "Role" }
+                                th { "Date applied" }
+                            }
+                            th { "" }
+                        }
+                    }
+                    tbody {
+                        for row in preview.iter() {
+                            tr {
+                                key: "{row.row.line}",
+                                class: if row.will_import(kind()) { "" } else { "skipped" },
+                                td { "{row.row.line}" }
+                                td {
+                                    "{row.row.company_name()} "
+                                    small { {describe_match(&row.company)} }
+                                }
+                                if let Some(role) = &row.row.role {
+                                    td { {role.name.clone().unwrap_or_default()} }
+                                    td {
+                                        {role.date_applied.map(|date| date.to_string()).unwrap_or_default()}
+                                    }
+                                }
+                                td {
+                                    for error in row.row.errors.get_errors() {
+                                        p { class: "field-error", "{error}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                button {
+                    r#type: "button",
+                    disabled: importable == 0,
+                    onclick: import,
+                    "Import {importable} of {preview.len()} rows"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,21 @@
+mod export_spreadsheet;
+use export_spreadsheet::ExportSpreadsheet;
+
+mod import_spreadsheet;
+use import_spreadsheet::ImportSpreadsheet;
+
+use dioxus::prelude::*;
+
+#[component]
+pub fn Spreadsheets() -> Element {
+    rsx! {
+        h2 { "Spreadsheets" }
+        p {
+            "Bring in companies and roles kept in a spreadsheet or another job tracker, or take "
+            "them out as CSV."
+        }
+
+        ImportSpreadsheet {}
+        ExportSpreadsheet {}
+    }
+}
@@ -0,0 +1,153 @@
+use crate::StoreType;
+use storage::prelude::*;
+
+/// Anything that can be sat in the trash, so that every type can be listed together
+#[derive(Clone, PartialEq)]
+pub enum DeletedItem {
+    Company(Company),
+    Role(Role),
+    Interview(Interview),
+    Question(Question),
+    Flag(Flag),
+    Value(Value),
+    Contact(Contact),
+    Reminder(Reminder),
+}
+
+async fn restore<O, S>(mut store: S, mut storable: O) -> StorageResult<()>
+where
+    O: SetDeleted + HasId + Clone,
+    S: BaseStore<O>,
+{
+    storable.restore();
+    store.store(storable).await
+}
+
+impl DeletedItem {
+    pub fn get_name(&self) -> &str {
+        match self {
+            DeletedItem::Company(company) => company.get_name(),
+            DeletedItem::Role(role) => role.get_name(),
+            DeletedItem::Interview(interview) => interview.get_name(),
+            DeletedItem::Question(question) => question.get_name(),
+            DeletedItem::Flag(flag) => flag.get_name(),
+            DeletedItem::Value(value) => value.get_name(),
+            DeletedItem::Contact(contact) => contact.get_name(),
+            DeletedItem::Reminder(reminder) => reminder.get_name(),
+        }
+    }
+
+    pub fn get_date_deleted(&self) -> Option<Timestamp> {
+        match self {
+            DeletedItem::Company(company) => company.get_date_deleted(),
+            DeletedItem::Role(role) => role.get_date_deleted(),
+            DeletedItem::Interview(interview) => interview.get_date_deleted(),
+            DeletedItem::Question(question) => question.get_date_deleted(),
+            DeletedItem::Flag(flag) => flag.get_date_deleted(),
+            DeletedItem::Value(value) => value.get_date_deleted(),
+            DeletedItem::Contact(contact) => contact.get_date_deleted(),
+            DeletedItem::Reminder(reminder) => reminder.get_date_deleted(),
+        }
+    }
+
+    pub fn get_id(&self) -> uuid::Uuid {
+        match self {
+            DeletedItem::Company(company) => company.get_id(),
+            DeletedItem::Role(role) => role.get_id(),
+            DeletedItem::Interview(interview) => interview.get_id(),
+            DeletedItem::Question(question) => question.get_id(),
+            DeletedItem::Flag(flag) => flag.get_id(),
+            DeletedItem::Value(value) => value.get_id(),
+            DeletedItem::Contact(contact) => contact.get_id(),
+            DeletedItem::Reminder(reminder) => reminder.get_id(),
+        }
+    }
+
+    pub async fn restore(self, store: StoreType) -> StorageResult<()> {
+        match self {
+            // Companies and roles bring back everything that was deleted with them
+            DeletedItem::Company(company) => store.restore_company(company).await,
+            DeletedItem::Role(role) => store.restore_role(role).await,
+            DeletedItem::Interview(interview) => restore(store, interview).await,
+            DeletedItem::Question(question) => restore(store, question).await,
+            DeletedItem::Flag(flag) => restore(store, flag).await,
+            DeletedItem::Value(value) => restore(store, value).await,
+            DeletedItem::Contact(contact) => restore(store, contact).await,
+            DeletedItem::Reminder(reminder) => restore(store, reminder).await,
+        }
+    }
+
+    pub async fn purge(self, mut store: StoreType) -> StorageResult<()> {
+        match self {
+            // As with restoring, companies and roles take everything in the trash with them
+            DeletedItem::Company(company) => store.purge_company(company).await,
+            DeletedItem::Role(role) => store.purge_role(role).await,
+            DeletedItem::Interview(interview) => store.purge(interview).await,
+            DeletedItem::Question(question) => store.purge(question).await,
+            DeletedItem::Flag(flag) => store.purge(flag).await,
+            DeletedItem::Value(value) => store.purge(value).await,
+            DeletedItem::Contact(contact) => store.purge(contact).await,
+            DeletedItem::Reminder(reminder) => store.purge(reminder).await,
+        }
+    }
+}
+
+/// Everything in the trash, grouped by type with the most recently deleted first
+pub async fn recall_deleted_items(
+    store: &StoreType,
+) -> StorageResult<Vec<(&'static str, Vec<DeletedItem>)>> {
+    let mut groups = vec![
+        (
+            "Companies",
+            recall_deleted::<Company>(store, DeletedItem::Company).await?,
+        ),
+        (
+            "Roles",
+            recall_deleted::<Role>(store, DeletedItem::Role).await?,
+        ),
+        (
+            "Interviews",
+            recall_deleted::<Interview>(store, DeletedItem::Interview).await?,
+        ),
+        (
+            "Questions",
+            recall_deleted::<Question>(store, DeletedItem::Question).await?,
+        ),
+        (
+            "Flags",
+            recall_deleted::<Flag>(store, DeletedItem::Flag).await?,
+        ),
+        (
+            "Values",
+            recall_deleted::<Value>(store, DeletedItem::Value).await?,
+        ),
+        (
+            "Contacts",
+            recall_deleted::<Contact>(store, DeletedItem::Contact).await?,
+        ),
+        (
+            "Reminders",
+            recall_deleted::<Reminder>(store, DeletedItem::Reminder).await?,
+        ),
+    ];
+    for (_, items) in groups.iter_mut() {
+        items.sort_by_key(|item| std::cmp::Reverse(item.get_date_deleted()));
+    }
+    Ok(groups)
+}
+
+async fn recall_deleted<O>(
+    store: &StoreType,
+    wrap: fn(O) -> DeletedItem,
+) -> StorageResult<Vec<DeletedItem>>
+where
+    O: HasId + HasDeleted + Clone,
+    StoreType: RecallDeleted<O>,
+{
+    Ok(store
+        .recall_deleted()
+        .await?
+        .into_iter()
+        .map(wrap)
+        .collect())
+}
@@ -0,0 +1,100 @@
+mod deleted_item;
+use deleted_item::*;
+
+mod trash_settings;
+use trash_settings::AutoPurgeSettings;
+
+use crate::helpers::{unwrap_or_report_and_return, use_restart_on_change};
+use crate::StoreType;
+use dioxus::prelude::*;
+use storage::prelude::SearchKind;
+
+#[component]
+fn DeletedListItem(item: DeletedItem, reload: Callback) -> Element {
+    let restore_store = use_context::<StoreType>();
+    let purge_store = restore_store.clone();
+    let restore_item = item.clone();
+    let purge_item = item.clone();
+
+    let date_deleted = item
+        .get_date_deleted()
+        .map(|date| date.to_string())
+        .unwrap_or_default();
+
+    rsx! {
+        li { id: "deleted-{item.get_id()}",
+            "{item.get_name()} "
+            small { "deleted {date_deleted}" }
+            " "
+            button {
+                r#type: "button",
+                onclick: move |_| {
+                    let item = restore_item.clone();
+                    let store = restore_store.clone();
+                    spawn(async move {
+                        unwrap_or_report_and_return!(item.restore(store).await);
+                        reload(());
+                    });
+                },
+                "Restore"
+            }
+            button {
+                r#type: "button",
+                onclick: move |_| {
+                    let item = purge_item.clone();
+                    let store = purge_store.clone();
+                    spawn(async move {
+                        unwrap_or_report_and_return!(item.purge(store).await);
+                        reload(());
+                    });
+                },
+                "Delete forever"
+            }
+        }
+    }
+}
+
+#[component]
+pub fn Trash() -> Element {
+    let mut deleted_resource = use_resource(move || async move {
+        let store = use_context::<StoreType>();
+        unwrap_or_report_and_return!(recall_deleted_items(&store).await)
+    });
+    use_restart_on_change(
+        deleted_resource,
+        &[
+            SearchKind::Company,
+            SearchKind::Role,
+            SearchKind::Interview,
+            SearchKind::Question,
+            SearchKind::Flag,
+            SearchKind::Value,
+            SearchKind::Contact,
+            SearchKind::Reminder,
+        ],
+    );
+    let reload = use_callback(move |()| deleted_resource.restart());
+    let groups = deleted_resource().unwrap_or_default();
+    let is_empty = groups.iter().all(|(_, items)| items.is_empty());
+
+    rsx! {
+        h2 { "Trash" }
+
+        AutoPurgeSettings {}
+
+        if is_empty {
+            p { "The trash is empty" }
+        }
+
+        for (title , items) in groups.into_iter().filter(|(_, items)| !items.is_empty()) {
+            section { key: "{title}",
+                h3 { "{title}" }
+                ul {
+                    for item in items {
+                        DeletedListItem { key: "{item.get_id()}", item, reload }
+                    }
+                }
+            }
+        }
+    }
+}
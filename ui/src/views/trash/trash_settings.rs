@@ -0,0 +1,53 @@
+use crate::helpers::{log_error, unwrap_or_report_and_return};
+use dioxus::prelude::*;
+use storage::prelude::*;
+
+const AUTO_PURGE_OPTIONS: [u32; 4] = [7, 30, 90, 365];
+
+#[component]
+pub fn AutoPurgeSettings() -> Element {
+    // Without a settings file (eg on the web, or when it couldn't be read) there's nowhere to keep
+    // the choice, so don't offer it
+    let Some(initial_settings) = try_use_context::<TrashSettings>()
+        .filter(|settings| !settings.get_path().as_os_str().is_empty())
+    else {
+        return rsx! {};
+    };
+
+    // Always read the latest settings, they may have changed since the app started
+    let mut settings_resource = use_resource(move || {
+        let path = initial_settings.get_path().to_path_buf();
+        async move { unwrap_or_report_and_return!(TrashSettings::load(path).await) }
+    });
+    let Some(settings) = settings_resource() else {
+        return rsx! {};
+    };
+    let selected = settings.get_auto_purge_after_days();
+
+    let onchange = move |e: FormEvent| {
+        let mut settings = settings.clone();
+        // "never" has no value so fails to parse
+        settings.set_auto_purge_after_days(e.value().parse().ok());
+        spawn(async move {
+            settings.save().await.unwrap_or_else(log_error);
+            settings_resource.restart();
+        });
+    };
+
+    rsx! {
+        p {
+            label { r#for: "auto-purge", "Permanently delete items in the trash after " }
+            select { id: "auto-purge", onchange,
+                option { value: "", selected: selected.is_none(), "never" }
+                for days in AUTO_PURGE_OPTIONS {
+                    option {
+                        value: "{days}",
+                        selected: selected == Some(days),
+                        "{days} days"
+                    }
+                }
+            }
+        }
+        p { small { "Old items are removed each time the app starts." } }
+    }
+}